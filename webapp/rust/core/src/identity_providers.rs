@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+pub mod oidc_provider;
+
+#[derive(Debug, Error)]
+pub enum IdentityProviderError {
+    #[error("identity provider request error: {0}")]
+    RequestError(String),
+    #[error("invalid id token: {0}")]
+    InvalidIdToken(String),
+}
+
+pub type IdentityProviderResult<T> = Result<T, IdentityProviderError>;
@@ -0,0 +1,44 @@
+use crate::identity_providers::IdentityProviderResult;
+use async_trait::async_trait;
+use serde::Deserialize;
+
+// 検証済みのIDトークンのクレーム
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct IdTokenClaims {
+    pub sub: String,
+    pub nonce: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl IdTokenClaims {
+    pub fn claim_str(&self, name: &str) -> Option<&str> {
+        if name == "sub" {
+            return Some(&self.sub);
+        }
+        self.extra.get(name).and_then(|v| v.as_str())
+    }
+
+    // 文字列のクレームは一致、配列のクレームは要素に含まれるかで判定する
+    pub fn claim_contains(&self, name: &str, value: &str) -> bool {
+        match self.extra.get(name) {
+            Some(serde_json::Value::String(s)) => s == value,
+            Some(serde_json::Value::Array(arr)) => arr.iter().any(|v| v.as_str() == Some(value)),
+            _ => false,
+        }
+    }
+}
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait OidcProvider {
+    fn is_enabled(&self) -> bool;
+    fn authorization_url(&self, state: &str, nonce: &str) -> String;
+    async fn exchange_code(&self, code: &str) -> IdentityProviderResult<IdTokenClaims>;
+}
+
+pub trait HaveOidcProvider {
+    type Provider: Sync + OidcProvider;
+
+    fn oidc_provider(&self) -> &Self::Provider;
+}
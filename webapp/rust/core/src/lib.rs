@@ -1,5 +1,7 @@
 pub mod db;
+pub mod identity_providers;
 pub mod models;
+pub mod password_hashers;
 pub mod repos;
 pub mod services;
 pub mod storages;
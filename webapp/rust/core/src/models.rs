@@ -1,15 +1,32 @@
 pub mod announcement;
 pub mod announcement_detail;
 pub mod assignment_path;
+pub mod audit_action;
+pub mod audit_log;
 pub mod class;
 pub mod class_score;
 pub mod course;
+pub mod course_prerequisite;
 pub mod course_result;
 pub mod course_status;
+pub mod course_student;
 pub mod course_type;
 pub mod day_of_week;
+pub mod drop_outcome;
+pub mod login_attempt;
+pub mod lottery_outcome;
+pub mod lottery_preference;
+pub mod personal_access_token;
+pub mod personal_access_token_scope;
+pub mod personal_data;
+pub mod registration_request;
+pub mod registration_window;
 pub mod score;
 pub mod submission;
 pub mod summary;
 pub mod user;
+pub mod user_import;
+pub mod user_profile;
+pub mod user_recovery_code;
+pub mod user_totp_secret;
 pub mod user_type;
@@ -0,0 +1,125 @@
+use fake::Dummy;
+use sqlx::database::{HasArguments, HasValueRef};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode};
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Dummy)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    ImpersonationStart,
+    ImpersonationEnd,
+    CourseCreate,
+    CourseUpdate,
+    CourseStatusUpdate,
+    CourseCapacityUpdate,
+    CoursePrerequisiteUpdate,
+    CourseLotteryUpdate,
+    LotteryDraw,
+    CourseApprovalUpdate,
+    RegistrationRequestApprove,
+    RegistrationRequestDeny,
+    CourseStudentsExport,
+    RegistrationWindowCreate,
+    RegistrationWindowDelete,
+    ClassCreate,
+    ScoreRegister,
+    AssignmentExport,
+    AnnouncementCreate,
+    UserCreate,
+    UserUpdate,
+    UserDeactivate,
+    UserCreditLimitUpdate,
+    UserImport,
+    PersonalDataExport,
+    PersonalDataErase,
+}
+
+impl<DB: Database> sqlx::Type<DB> for AuditAction
+where
+    str: sqlx::Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as sqlx::Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &<DB as sqlx::Database>::TypeInfo) -> bool {
+        <&str as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'r, DB: Database> sqlx::Decode<'r, DB> for AuditAction
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        let value = <&str as Decode<DB>>::decode(value)?;
+        match value {
+            "impersonation_start" => Ok(Self::ImpersonationStart),
+            "impersonation_end" => Ok(Self::ImpersonationEnd),
+            "course_create" => Ok(Self::CourseCreate),
+            "course_update" => Ok(Self::CourseUpdate),
+            "course_status_update" => Ok(Self::CourseStatusUpdate),
+            "course_capacity_update" => Ok(Self::CourseCapacityUpdate),
+            "course_prerequisite_update" => Ok(Self::CoursePrerequisiteUpdate),
+            "course_lottery_update" => Ok(Self::CourseLotteryUpdate),
+            "lottery_draw" => Ok(Self::LotteryDraw),
+            "course_approval_update" => Ok(Self::CourseApprovalUpdate),
+            "registration_request_approve" => Ok(Self::RegistrationRequestApprove),
+            "registration_request_deny" => Ok(Self::RegistrationRequestDeny),
+            "course_students_export" => Ok(Self::CourseStudentsExport),
+            "registration_window_create" => Ok(Self::RegistrationWindowCreate),
+            "registration_window_delete" => Ok(Self::RegistrationWindowDelete),
+            "class_create" => Ok(Self::ClassCreate),
+            "score_register" => Ok(Self::ScoreRegister),
+            "assignment_export" => Ok(Self::AssignmentExport),
+            "announcement_create" => Ok(Self::AnnouncementCreate),
+            "user_create" => Ok(Self::UserCreate),
+            "user_update" => Ok(Self::UserUpdate),
+            "user_deactivate" => Ok(Self::UserDeactivate),
+            "user_credit_limit_update" => Ok(Self::UserCreditLimitUpdate),
+            "user_import" => Ok(Self::UserImport),
+            "personal_data_export" => Ok(Self::PersonalDataExport),
+            "personal_data_erase" => Ok(Self::PersonalDataErase),
+            v => Err(format!("Unknown enum variant: {}", v).into()),
+        }
+    }
+}
+
+impl<'q, DB: Database> sqlx::Encode<'q, DB> for AuditAction
+where
+    &'q str: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        let result = match *self {
+            Self::ImpersonationStart => "impersonation_start",
+            Self::ImpersonationEnd => "impersonation_end",
+            Self::CourseCreate => "course_create",
+            Self::CourseUpdate => "course_update",
+            Self::CourseStatusUpdate => "course_status_update",
+            Self::CourseCapacityUpdate => "course_capacity_update",
+            Self::CoursePrerequisiteUpdate => "course_prerequisite_update",
+            Self::CourseLotteryUpdate => "course_lottery_update",
+            Self::LotteryDraw => "lottery_draw",
+            Self::CourseApprovalUpdate => "course_approval_update",
+            Self::RegistrationRequestApprove => "registration_request_approve",
+            Self::RegistrationRequestDeny => "registration_request_deny",
+            Self::CourseStudentsExport => "course_students_export",
+            Self::RegistrationWindowCreate => "registration_window_create",
+            Self::RegistrationWindowDelete => "registration_window_delete",
+            Self::ClassCreate => "class_create",
+            Self::ScoreRegister => "score_register",
+            Self::AssignmentExport => "assignment_export",
+            Self::AnnouncementCreate => "announcement_create",
+            Self::UserCreate => "user_create",
+            Self::UserUpdate => "user_update",
+            Self::UserDeactivate => "user_deactivate",
+            Self::UserCreditLimitUpdate => "user_credit_limit_update",
+            Self::UserImport => "user_import",
+            Self::PersonalDataExport => "personal_data_export",
+            Self::PersonalDataErase => "personal_data_erase",
+        };
+
+        <&str as Encode<'_, DB>>::encode_by_ref(&result, buf)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::models::announcement::AnnouncementID;
+use crate::models::audit_action::AuditAction;
+use crate::models::class::ClassID;
+use crate::models::course::CourseID;
+use crate::models::user::UserID;
+use chrono::NaiveDateTime;
+use fake::Dummy;
+use kubetsu::Id;
+
+// 追記のみで、更新・削除はしない
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq, Dummy)]
+pub struct AuditLog {
+    pub id: AuditLogID,
+    pub actor_id: UserID,
+    pub action: AuditAction,
+    pub target_user_id: Option<UserID>,
+    pub target_course_id: Option<CourseID>,
+    pub target_class_id: Option<ClassID>,
+    pub target_announcement_id: Option<AnnouncementID>,
+    pub request_id: Option<String>,
+    // 操作前後の状態の要約(JSON)
+    pub before_summary: Option<String>,
+    pub after_summary: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+pub type AuditLogID = Id<AuditLog, String>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Dummy)]
+pub struct CreateAuditLog {
+    pub actor_id: UserID,
+    pub action: AuditAction,
+    pub target_user_id: Option<UserID>,
+    pub target_course_id: Option<CourseID>,
+    pub target_class_id: Option<ClassID>,
+    pub target_announcement_id: Option<AnnouncementID>,
+    pub request_id: Option<String>,
+    pub before_summary: Option<String>,
+    pub after_summary: Option<String>,
+}
+
+impl CreateAuditLog {
+    pub fn new(actor_id: UserID, action: AuditAction, request_id: Option<String>) -> Self {
+        Self {
+            actor_id,
+            action,
+            target_user_id: None,
+            target_course_id: None,
+            target_class_id: None,
+            target_announcement_id: None,
+            request_id,
+            before_summary: None,
+            after_summary: None,
+        }
+    }
+
+    pub fn into_audit_log(self, id: AuditLogID, created_at: NaiveDateTime) -> AuditLog {
+        AuditLog {
+            id,
+            actor_id: self.actor_id,
+            action: self.action,
+            target_user_id: self.target_user_id,
+            target_course_id: self.target_course_id,
+            target_class_id: self.target_class_id,
+            target_announcement_id: self.target_announcement_id,
+            request_id: self.request_id,
+            before_summary: self.before_summary,
+            after_summary: self.after_summary,
+            created_at,
+        }
+    }
+}
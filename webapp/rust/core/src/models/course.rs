@@ -19,6 +19,8 @@ pub struct Course {
     pub teacher_id: UserID,
     pub keywords: String,
     pub status: CourseStatus,
+    // 定員。Noneの場合は上限なし
+    pub capacity: Option<u32>,
 }
 
 pub type CourseID = Id<Course, String>;
@@ -40,6 +42,7 @@ pub struct CourseWithTeacher {
     pub teacher_id: UserID,
     pub keywords: String,
     pub status: CourseStatus,
+    pub capacity: Option<u32>,
     pub teacher: String,
 }
 
@@ -56,4 +59,15 @@ pub struct CreateCourse {
     pub day_of_week: DayOfWeek,
     pub teacher_id: UserID,
     pub keywords: String,
+    pub capacity: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Dummy)]
+pub struct UpdateCourse {
+    pub name: String,
+    pub description: String,
+    pub credit: u8,
+    pub period: u8,
+    pub day_of_week: DayOfWeek,
+    pub keywords: String,
 }
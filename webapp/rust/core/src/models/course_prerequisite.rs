@@ -0,0 +1,18 @@
+use crate::models::course::{CourseCode, CourseID};
+use fake::Dummy;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, Dummy)]
+pub struct CoursePrerequisite {
+    #[serde(skip)]
+    pub course_id: CourseID,
+    // 事前に修了している必要がある科目の科目コード
+    pub code: CourseCode,
+    // 事前科目で必要な最終成績(課題の合計点)
+    pub min_score: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct UpdateCoursePrerequisite {
+    pub code: CourseCode,
+    pub min_score: u32,
+}
@@ -12,3 +12,10 @@ pub struct CourseResult {
     pub total_score_min: i64,     // 最小値
     pub class_scores: Vec<ClassScore>,
 }
+
+// 修了した科目ごとの最終成績
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseTotalScore {
+    pub code: CourseCode,
+    pub total_score: i64,
+}
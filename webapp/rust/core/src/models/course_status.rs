@@ -12,6 +12,17 @@ pub enum CourseStatus {
     Closed,
 }
 
+impl CourseStatus {
+    // 科目のステータスは registration → in-progress → closed の順にしか進めない
+    pub fn can_transition_to(&self, next: &CourseStatus) -> bool {
+        matches!(
+            (self, next),
+            (CourseStatus::Registration, CourseStatus::InProgress)
+                | (CourseStatus::InProgress, CourseStatus::Closed)
+        )
+    }
+}
+
 impl<DB: Database> sqlx::Type<DB> for CourseStatus
 where
     str: sqlx::Type<DB>,
@@ -0,0 +1,15 @@
+use crate::models::user::{UserCode, UserID};
+use chrono::NaiveDateTime;
+use fake::Dummy;
+
+// 科目の履修者と、その科目での提出状況
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, serde::Serialize, Dummy)]
+pub struct CourseStudent {
+    #[serde(skip)]
+    pub user_id: UserID,
+    pub code: UserCode,
+    pub name: String,
+    pub registered_at: NaiveDateTime,
+    pub submission_count: i64,
+    pub total_score: i64,
+}
@@ -4,7 +4,7 @@ use sqlx::encode::IsNull;
 use sqlx::error::BoxDynError;
 use sqlx::{Database, Decode, Encode};
 
-#[derive(Debug, PartialEq, Eq, serde::Deserialize, Clone, Dummy)]
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Clone, Dummy)]
 #[serde(rename_all = "kebab-case")]
 pub enum CourseType {
     LiberalArts,
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DropOutcome {
+    // 履修登録そのものを取り消す
+    Dropped,
+    // 履修登録は残したまま履修中止として記録する
+    Withdrawn,
+}
@@ -0,0 +1,15 @@
+use chrono::NaiveDateTime;
+use fake::Dummy;
+use kubetsu::Id;
+
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq, Dummy)]
+pub struct LoginAttempt {
+    pub id: LoginAttemptID,
+    // 存在しないユーザーコードでの試行も記録するため、UserCodeではなく入力値をそのまま保持する
+    pub user_code: String,
+    pub ip_address: String,
+    pub succeeded: bool,
+    pub created_at: NaiveDateTime,
+}
+
+pub type LoginAttemptID = Id<LoginAttempt, String>;
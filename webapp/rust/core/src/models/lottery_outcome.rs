@@ -0,0 +1,34 @@
+use crate::models::course::CourseID;
+use crate::models::user::UserID;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LotteryOutcome {
+    Won,
+    // 抽選前に先着順などで履修登録済みだった
+    AlreadyRegistered,
+    CourseFull,
+    ScheduleConflict,
+    CreditLimitExceeded,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct LotteryResult {
+    pub course_id: CourseID,
+    pub rank: u32,
+    pub outcome: LotteryOutcome,
+}
+
+// 学生ごとの抽選結果。希望を処理した順に並ぶ
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct LotteryStudentReport {
+    pub user_id: UserID,
+    pub results: Vec<LotteryResult>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct LotteryDrawReport {
+    // 同じseedと希望で再実行すると同じ結果になる
+    pub seed: u64,
+    pub students: Vec<LotteryStudentReport>,
+}
@@ -0,0 +1,12 @@
+use crate::models::course::CourseID;
+use crate::models::user::UserID;
+use fake::Dummy;
+
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq, serde::Serialize, Dummy)]
+pub struct LotteryPreference {
+    #[serde(skip)]
+    pub user_id: UserID,
+    pub course_id: CourseID,
+    // 希望順位。1が第一希望
+    pub rank: u32,
+}
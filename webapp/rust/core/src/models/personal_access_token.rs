@@ -0,0 +1,26 @@
+use crate::models::personal_access_token_scope::PersonalAccessTokenScope;
+use crate::models::user::UserID;
+use chrono::NaiveDateTime;
+use fake::Dummy;
+use kubetsu::Id;
+
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq, Dummy)]
+pub struct PersonalAccessToken {
+    pub id: PersonalAccessTokenID,
+    pub user_id: UserID,
+    pub name: String,
+    // トークンそのものは保存せず、SHA-256のhex文字列のみを保持する
+    pub hashed_token: String,
+    pub scope: PersonalAccessTokenScope,
+    pub expires_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+pub type PersonalAccessTokenID = Id<PersonalAccessToken, String>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatePersonalAccessToken {
+    pub name: String,
+    pub scope: PersonalAccessTokenScope,
+    pub expires_in_days: i64,
+}
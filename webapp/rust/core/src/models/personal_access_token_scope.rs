@@ -0,0 +1,63 @@
+use fake::Dummy;
+use sqlx::database::{HasArguments, HasValueRef};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode};
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Dummy)]
+#[serde(rename_all = "kebab-case")]
+pub enum PersonalAccessTokenScope {
+    Read,
+    Write,
+}
+
+impl PersonalAccessTokenScope {
+    // readは参照系のリクエストのみ、writeは全てのリクエストを許可する
+    pub fn allows(&self, method: &str) -> bool {
+        match *self {
+            Self::Read => matches!(method, "GET" | "HEAD" | "OPTIONS"),
+            Self::Write => true,
+        }
+    }
+}
+
+impl<DB: Database> sqlx::Type<DB> for PersonalAccessTokenScope
+where
+    str: sqlx::Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as sqlx::Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &<DB as sqlx::Database>::TypeInfo) -> bool {
+        <&str as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'r, DB: Database> sqlx::Decode<'r, DB> for PersonalAccessTokenScope
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        let value = <&str as Decode<DB>>::decode(value)?;
+        match value {
+            "read" => Ok(Self::Read),
+            "write" => Ok(Self::Write),
+            v => Err(format!("Unknown enum variant: {}", v).into()),
+        }
+    }
+}
+
+impl<'q, DB: Database> sqlx::Encode<'q, DB> for PersonalAccessTokenScope
+where
+    &'q str: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        let result = match *self {
+            Self::Read => "read",
+            Self::Write => "write",
+        };
+
+        <&str as Encode<'_, DB>>::encode_by_ref(&result, buf)
+    }
+}
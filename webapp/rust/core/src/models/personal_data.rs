@@ -0,0 +1,126 @@
+use crate::models::announcement::AnnouncementWithoutDetail;
+use crate::models::audit_action::AuditAction;
+use crate::models::audit_log::{AuditLog, AuditLogID};
+use crate::models::course::{Course, CourseCode, CourseID};
+use crate::models::login_attempt::LoginAttempt;
+use crate::models::lottery_preference::LotteryPreference;
+use crate::models::personal_access_token::{PersonalAccessToken, PersonalAccessTokenID};
+use crate::models::personal_access_token_scope::PersonalAccessTokenScope;
+use crate::models::submission::UserSubmission;
+use crate::models::user::{User, UserCode, UserID};
+use crate::models::user_profile::UserProfile;
+use crate::models::user_type::UserType;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+// 個人データのエクスポートに含める内容。パスワードのハッシュ値などの認証情報は含めない
+#[derive(Debug, Serialize)]
+pub struct PersonalData {
+    pub user: PersonalDataUser,
+    pub profile: Option<UserProfile>,
+    pub registered_courses: Vec<PersonalDataCourse>,
+    pub submissions: Vec<UserSubmission>,
+    pub announcements: Vec<AnnouncementWithoutDetail>,
+    pub waitlisted_course_ids: Vec<CourseID>,
+    pub lottery_preferences: Vec<LotteryPreference>,
+    pub registration_request_course_ids: Vec<CourseID>,
+    pub credit_limit: Option<u32>,
+    pub personal_access_tokens: Vec<PersonalDataAccessToken>,
+    pub login_attempts: Vec<PersonalDataLoginAttempt>,
+    pub audit_logs: Vec<PersonalDataAuditLog>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PersonalDataUser {
+    pub id: UserID,
+    pub code: UserCode,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: UserType,
+    pub deactivated_at: Option<NaiveDateTime>,
+}
+
+impl From<User> for PersonalDataUser {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            code: user.code,
+            name: user.name,
+            type_: user.type_,
+            deactivated_at: user.deactivated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PersonalDataCourse {
+    pub id: CourseID,
+    pub code: CourseCode,
+    pub name: String,
+}
+
+impl From<Course> for PersonalDataCourse {
+    fn from(course: Course) -> Self {
+        Self {
+            id: course.id,
+            code: course.code,
+            name: course.name,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PersonalDataAccessToken {
+    pub id: PersonalAccessTokenID,
+    pub name: String,
+    pub scope: PersonalAccessTokenScope,
+    pub expires_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<PersonalAccessToken> for PersonalDataAccessToken {
+    fn from(token: PersonalAccessToken) -> Self {
+        Self {
+            id: token.id,
+            name: token.name,
+            scope: token.scope,
+            expires_at: token.expires_at,
+            created_at: token.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PersonalDataLoginAttempt {
+    pub ip_address: String,
+    pub succeeded: bool,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<LoginAttempt> for PersonalDataLoginAttempt {
+    fn from(attempt: LoginAttempt) -> Self {
+        Self {
+            ip_address: attempt.ip_address,
+            succeeded: attempt.succeeded,
+            created_at: attempt.created_at,
+        }
+    }
+}
+
+// 本人を対象とした操作の記録。操作した管理者は本人の情報ではないため含めない
+#[derive(Debug, Serialize)]
+pub struct PersonalDataAuditLog {
+    pub id: AuditLogID,
+    pub action: AuditAction,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<AuditLog> for PersonalDataAuditLog {
+    fn from(audit_log: AuditLog) -> Self {
+        Self {
+            id: audit_log.id,
+            action: audit_log.action,
+            created_at: audit_log.created_at,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::models::user::{UserCode, UserID};
+use fake::Dummy;
+
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, serde::Serialize, Dummy)]
+pub struct RegistrationRequest {
+    pub user_id: UserID,
+    pub user_code: UserCode,
+    pub user_name: String,
+}
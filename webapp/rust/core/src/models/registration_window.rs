@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use fake::Dummy;
+use kubetsu::Id;
+
+// 履修登録を受け付ける期間。cohortは学籍番号の接頭辞で、Noneの場合は全学生が対象
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq, serde::Serialize, Dummy)]
+pub struct RegistrationWindow {
+    pub id: RegistrationWindowID,
+    pub term: String,
+    pub cohort: Option<String>,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+}
+
+pub type RegistrationWindowID = Id<RegistrationWindow, String>;
+
+impl RegistrationWindow {
+    pub fn is_applicable_to(&self, user_code: &str) -> bool {
+        match self.cohort {
+            Some(ref cohort) => user_code.starts_with(cohort.as_str()),
+            None => true,
+        }
+    }
+
+    pub fn contains(&self, now: &NaiveDateTime) -> bool {
+        self.starts_at <= *now && *now < self.ends_at
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct CreateRegistrationWindow {
+    pub term: String,
+    pub cohort: Option<String>,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+}
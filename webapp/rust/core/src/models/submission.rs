@@ -1,4 +1,5 @@
 use crate::models::class::ClassID;
+use crate::models::course::CourseID;
 use crate::models::user::{UserCode, UserID};
 use fake::Dummy;
 
@@ -9,6 +10,14 @@ pub struct CreateSubmission {
     pub file_name: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, serde::Serialize)]
+pub struct UserSubmission {
+    pub class_id: ClassID,
+    pub course_id: CourseID,
+    pub file_name: String,
+    pub score: Option<u8>,
+}
+
 #[derive(Debug, sqlx::FromRow)]
 pub struct SubmissionWithUserCode {
     pub user_id: UserID,
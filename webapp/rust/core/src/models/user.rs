@@ -1,4 +1,5 @@
 use crate::models::user_type::UserType;
+use chrono::NaiveDateTime;
 use fake::Dummy;
 use kubetsu::Id;
 use serde::Serialize;
@@ -12,6 +13,15 @@ pub struct User {
     pub hashed_password: Vec<u8>,
     #[sqlx(rename = "type")]
     pub type_: UserType,
+    // 無効化されたユーザーはログインできない
+    #[dummy(default)]
+    pub deactivated_at: Option<NaiveDateTime>,
+}
+
+impl User {
+    pub fn is_active(&self) -> bool {
+        self.deactivated_at.is_none()
+    }
 }
 
 pub type UserID = Id<User, String>;
@@ -33,3 +43,17 @@ impl Display for UserCodeCode {
         write!(f, "{}", self.0)
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateUser {
+    pub code: String,
+    pub name: String,
+    pub type_: UserType,
+}
+
+// 指定された項目のみ更新する
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdateUser {
+    pub name: Option<String>,
+    pub type_: Option<UserType>,
+}
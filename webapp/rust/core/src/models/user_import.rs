@@ -0,0 +1,46 @@
+use crate::models::user::UserID;
+use serde::Serialize;
+
+// CSVの1行分。typeの解釈も行ごとの検証結果として返すため文字列のまま受け取る
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportUserRow {
+    pub line: u64,
+    pub code: String,
+    pub name: String,
+    pub type_: String,
+    pub password: Option<String>,
+    // ヘッダーより列が多い行。余った列は読み捨てずにエラーとして返す
+    pub too_many_fields: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportUserError {
+    InvalidCode,
+    InvalidName,
+    InvalidType,
+    InvalidPassword,
+    TooManyFields,
+    DuplicateInFile,
+    CodeAlreadyExists,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportUserResult {
+    pub line: u64,
+    pub code: String,
+    pub errors: Vec<ImportUserError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserID>,
+    // パスワードが指定されなかった行にのみ、生成した初期パスワードを返す
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_password: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportUsersReport {
+    pub dry_run: bool,
+    // 1行でもエラーがあれば何も登録しない
+    pub imported: bool,
+    pub results: Vec<ImportUserResult>,
+}
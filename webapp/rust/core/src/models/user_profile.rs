@@ -0,0 +1,35 @@
+use crate::models::user::UserID;
+use fake::Dummy;
+
+// 本人が編集できるプロフィール。表示名はusers.nameを使う
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq, serde::Serialize, Dummy)]
+pub struct UserProfile {
+    pub user_id: UserID,
+    pub name_kana: Option<String>,
+    pub email: Option<String>,
+    pub notify_announcements: bool,
+    pub notify_course_updates: bool,
+}
+
+impl UserProfile {
+    // プロフィールが未登録のユーザーの既定値
+    pub fn new(user_id: UserID) -> Self {
+        Self {
+            user_id,
+            name_kana: None,
+            email: None,
+            notify_announcements: true,
+            notify_course_updates: true,
+        }
+    }
+}
+
+// 指定された項目のみ更新する。name_kanaとemailは空文字列で削除する
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpdateUserProfile {
+    pub name: Option<String>,
+    pub name_kana: Option<String>,
+    pub email: Option<String>,
+    pub notify_announcements: Option<bool>,
+    pub notify_course_updates: Option<bool>,
+}
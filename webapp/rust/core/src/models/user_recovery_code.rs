@@ -0,0 +1,15 @@
+use crate::models::user::UserID;
+use chrono::NaiveDateTime;
+use fake::Dummy;
+use kubetsu::Id;
+
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq, Dummy)]
+pub struct UserRecoveryCode {
+    pub id: UserRecoveryCodeID,
+    pub user_id: UserID,
+    // リカバリーコードそのものは保存せず、SHA-256のhex文字列のみを保持する
+    pub hashed_code: String,
+    pub used_at: Option<NaiveDateTime>,
+}
+
+pub type UserRecoveryCodeID = Id<UserRecoveryCode, String>;
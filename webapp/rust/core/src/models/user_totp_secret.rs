@@ -0,0 +1,15 @@
+use crate::models::user::UserID;
+use chrono::NaiveDateTime;
+use fake::Dummy;
+
+#[derive(Debug, Clone, sqlx::FromRow, PartialEq, Eq, Dummy)]
+pub struct UserTotpSecret {
+    pub user_id: UserID,
+    // RFC 4648のBase32(パディングなし)でエンコードした共有鍵
+    pub secret: String,
+    // 登録後に一度コードを検証するまではfalse
+    pub confirmed: bool,
+    // 同じコードの再利用を防ぐため、最後に受け付けたタイムステップを保持する
+    pub last_used_step: Option<i64>,
+    pub created_at: NaiveDateTime,
+}
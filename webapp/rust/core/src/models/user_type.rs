@@ -4,7 +4,8 @@ use sqlx::encode::IsNull;
 use sqlx::error::BoxDynError;
 use sqlx::{Database, Decode, Encode};
 
-#[derive(Debug, Clone, PartialEq, Eq, Dummy)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Dummy)]
+#[serde(rename_all = "lowercase")]
 pub enum UserType {
     Student,
     Teacher,
@@ -0,0 +1,44 @@
+use thiserror::Error;
+
+pub mod policy_password_hasher;
+
+#[derive(Debug, Error)]
+pub enum PasswordHasherError {
+    #[error("bcrypt error: {0}")]
+    BcryptError(#[from] bcrypt::BcryptError),
+    #[error("argon2 error: {0}")]
+    Argon2Error(String),
+    #[error("unsupported password hash format")]
+    UnsupportedFormat,
+}
+
+pub type PasswordHasherResult<T> = Result<T, PasswordHasherError>;
+
+pub trait PasswordHasher {
+    fn hash(&self, password: &str) -> PasswordHasherResult<String>;
+    fn verify(&self, password: &str, hashed_password: &str) -> PasswordHasherResult<bool>;
+    // 現在のポリシーより弱いアルゴリズムやコストでハッシュ化されている場合にtrue
+    fn needs_rehash(&self, hashed_password: &str) -> bool;
+}
+
+// ブロッキング用のスレッドへ複製して渡せるよう、モックもCloneを実装する
+#[cfg(any(test, feature = "test"))]
+mockall::mock! {
+    pub PasswordHasher {}
+
+    impl Clone for PasswordHasher {
+        fn clone(&self) -> Self;
+    }
+
+    impl PasswordHasher for PasswordHasher {
+        fn hash(&self, password: &str) -> PasswordHasherResult<String>;
+        fn verify(&self, password: &str, hashed_password: &str) -> PasswordHasherResult<bool>;
+        fn needs_rehash(&self, hashed_password: &str) -> bool;
+    }
+}
+
+pub trait HavePasswordHasher {
+    // 重いハッシュ化をブロッキング用のスレッドで行えるよう、複製して渡せるものに限る
+    type Hasher: Clone + Send + Sync + 'static + PasswordHasher;
+    fn password_hasher(&self) -> &Self::Hasher;
+}
@@ -0,0 +1,170 @@
+use crate::password_hashers::{PasswordHasher, PasswordHasherError, PasswordHasherResult};
+use argon2::password_hash::{PasswordHash, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use rand::RngCore;
+
+mod needs_rehash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordHashAlgorithm {
+    Bcrypt,
+    Argon2id,
+}
+
+impl std::str::FromStr for PasswordHashAlgorithm {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "bcrypt" => Ok(Self::Bcrypt),
+            "argon2id" => Ok(Self::Argon2id),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordHashPolicy {
+    // 新しくハッシュ化するときのアルゴリズム。検証は両方のアルゴリズムに対応する
+    pub algorithm: PasswordHashAlgorithm,
+    pub bcrypt_cost: u32,
+    pub argon2_memory_kib: u32,
+    pub argon2_iterations: u32,
+    pub argon2_parallelism: u32,
+}
+
+impl Default for PasswordHashPolicy {
+    fn default() -> Self {
+        Self {
+            algorithm: PasswordHashAlgorithm::Bcrypt,
+            bcrypt_cost: 10,
+            argon2_memory_kib: Params::DEFAULT_M_COST,
+            argon2_iterations: Params::DEFAULT_T_COST,
+            argon2_parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+impl PasswordHashPolicy {
+    pub fn from_env() -> Self {
+        fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default)
+        }
+
+        let default = Self::default();
+        Self {
+            algorithm: env_or("PASSWORD_HASH_ALGORITHM", default.algorithm),
+            bcrypt_cost: env_or("PASSWORD_HASH_BCRYPT_COST", default.bcrypt_cost),
+            argon2_memory_kib: env_or("PASSWORD_HASH_ARGON2_MEMORY_KIB", default.argon2_memory_kib),
+            argon2_iterations: env_or("PASSWORD_HASH_ARGON2_ITERATIONS", default.argon2_iterations),
+            argon2_parallelism: env_or(
+                "PASSWORD_HASH_ARGON2_PARALLELISM",
+                default.argon2_parallelism,
+            ),
+        }
+    }
+}
+
+// ポリシーで指定されたアルゴリズムでハッシュ化し、bcryptとArgon2idのどちらのハッシュも検証できるハッシャー
+#[derive(Debug, Clone, Default)]
+pub struct PolicyPasswordHasher {
+    policy: PasswordHashPolicy,
+}
+
+impl PolicyPasswordHasher {
+    pub fn new(policy: PasswordHashPolicy) -> Self {
+        Self { policy }
+    }
+
+    fn argon2(&self) -> PasswordHasherResult<Argon2<'static>> {
+        let params = Params::new(
+            self.policy.argon2_memory_kib,
+            self.policy.argon2_iterations,
+            self.policy.argon2_parallelism,
+            None,
+        )
+        .map_err(|e| PasswordHasherError::Argon2Error(e.to_string()))?;
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+}
+
+fn is_bcrypt_hash(hashed_password: &str) -> bool {
+    ["$2a$", "$2b$", "$2x$", "$2y$"]
+        .iter()
+        .any(|prefix| hashed_password.starts_with(prefix))
+}
+
+// $2a$04$... の形式からコストを取り出す
+fn bcrypt_cost(hashed_password: &str) -> Option<u32> {
+    hashed_password.split('$').nth(2)?.parse().ok()
+}
+
+impl PasswordHasher for PolicyPasswordHasher {
+    fn hash(&self, password: &str) -> PasswordHasherResult<String> {
+        match self.policy.algorithm {
+            PasswordHashAlgorithm::Bcrypt => Ok(bcrypt::hash(password, self.policy.bcrypt_cost)?),
+            PasswordHashAlgorithm::Argon2id => {
+                let mut salt = [0u8; 16];
+                rand::thread_rng().fill_bytes(&mut salt);
+                let salt = SaltString::encode_b64(&salt)
+                    .map_err(|e| PasswordHasherError::Argon2Error(e.to_string()))?;
+                let hashed_password = argon2::PasswordHasher::hash_password(
+                    &self.argon2()?,
+                    password.as_bytes(),
+                    &salt,
+                )
+                .map_err(|e| PasswordHasherError::Argon2Error(e.to_string()))?;
+                Ok(hashed_password.to_string())
+            }
+        }
+    }
+
+    fn verify(&self, password: &str, hashed_password: &str) -> PasswordHasherResult<bool> {
+        if is_bcrypt_hash(hashed_password) {
+            return Ok(bcrypt::verify(password, hashed_password)?);
+        }
+
+        let parsed = PasswordHash::new(hashed_password)
+            .map_err(|_| PasswordHasherError::UnsupportedFormat)?;
+        if parsed.algorithm != argon2::ARGON2ID_IDENT {
+            return Err(PasswordHasherError::UnsupportedFormat);
+        }
+        match argon2::PasswordVerifier::verify_password(
+            &Argon2::default(),
+            password.as_bytes(),
+            &parsed,
+        ) {
+            Ok(()) => Ok(true),
+            Err(argon2::password_hash::Error::Password) => Ok(false),
+            Err(e) => Err(PasswordHasherError::Argon2Error(e.to_string())),
+        }
+    }
+
+    fn needs_rehash(&self, hashed_password: &str) -> bool {
+        match self.policy.algorithm {
+            PasswordHashAlgorithm::Bcrypt => {
+                if !is_bcrypt_hash(hashed_password) {
+                    return true;
+                }
+                bcrypt_cost(hashed_password).is_none_or(|cost| cost < self.policy.bcrypt_cost)
+            }
+            PasswordHashAlgorithm::Argon2id => {
+                let parsed = match PasswordHash::new(hashed_password) {
+                    Ok(parsed) if parsed.algorithm == argon2::ARGON2ID_IDENT => parsed,
+                    _ => return true,
+                };
+                match Params::try_from(&parsed) {
+                    Ok(params) => {
+                        params.m_cost() < self.policy.argon2_memory_kib
+                            || params.t_cost() < self.policy.argon2_iterations
+                            || params.p_cost() < self.policy.argon2_parallelism
+                    }
+                    Err(_) => true,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::password_hashers::policy_password_hasher::{
+        PasswordHashAlgorithm, PasswordHashPolicy, PolicyPasswordHasher,
+    };
+    use crate::password_hashers::PasswordHasher;
+
+    fn hasher(algorithm: PasswordHashAlgorithm) -> PolicyPasswordHasher {
+        PolicyPasswordHasher::new(PasswordHashPolicy {
+            algorithm,
+            bcrypt_cost: 5,
+            argon2_memory_kib: 64,
+            argon2_iterations: 1,
+            argon2_parallelism: 1,
+        })
+    }
+
+    #[test]
+    fn low_bcrypt_cost_case() {
+        let hashed_password = bcrypt::hash("password", 4).unwrap();
+
+        let hasher = hasher(PasswordHashAlgorithm::Bcrypt);
+        assert!(hasher.verify("password", &hashed_password).unwrap());
+        assert!(hasher.needs_rehash(&hashed_password));
+
+        let rehashed_password = hasher.hash("password").unwrap();
+        assert!(rehashed_password.starts_with("$2b$05$"));
+        assert!(!hasher.needs_rehash(&rehashed_password));
+    }
+
+    #[test]
+    fn bcrypt_to_argon2id_case() {
+        let hashed_password = hasher(PasswordHashAlgorithm::Bcrypt)
+            .hash("password")
+            .unwrap();
+
+        let hasher = hasher(PasswordHashAlgorithm::Argon2id);
+        assert!(hasher.verify("password", &hashed_password).unwrap());
+        assert!(hasher.needs_rehash(&hashed_password));
+
+        let rehashed_password = hasher.hash("password").unwrap();
+        assert!(rehashed_password.starts_with("$argon2id$"));
+        assert!(hasher.verify("password", &rehashed_password).unwrap());
+        assert!(!hasher.verify("wrong", &rehashed_password).unwrap());
+        assert!(!hasher.needs_rehash(&rehashed_password));
+    }
+
+    #[test]
+    fn weak_argon2id_params_case() {
+        let mut weak = hasher(PasswordHashAlgorithm::Argon2id);
+        weak.policy.argon2_memory_kib = 32;
+        let hashed_password = weak.hash("password").unwrap();
+
+        let hasher = hasher(PasswordHashAlgorithm::Argon2id);
+        assert!(hasher.verify("password", &hashed_password).unwrap());
+        assert!(hasher.needs_rehash(&hashed_password));
+    }
+}
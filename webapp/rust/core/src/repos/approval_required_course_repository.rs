@@ -0,0 +1,23 @@
+use crate::db::DBConn;
+use crate::models::course::CourseID;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait ApprovalRequiredCourseRepository {
+    async fn create(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()>;
+    async fn delete(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()>;
+    async fn exist_by_course_id(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<bool>;
+    // 指定された科目のうち、該当するものの科目IDを返す
+    async fn find_course_ids_by_course_ids(
+        &self,
+        conn: &mut DBConn,
+        course_ids: &[CourseID],
+    ) -> Result<Vec<CourseID>>;
+}
+
+pub trait HaveApprovalRequiredCourseRepository {
+    type Repo: Sync + ApprovalRequiredCourseRepository;
+    fn approval_required_course_repo(&self) -> &Self::Repo;
+}
@@ -0,0 +1,40 @@
+use crate::db::DBConn;
+use crate::models::audit_log::AuditLog;
+use crate::models::course::CourseID;
+use crate::models::user::UserID;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditLogQuery {
+    pub actor_id: Option<UserID>,
+    pub course_id: Option<CourseID>,
+    pub since: Option<NaiveDateTime>,
+    pub until: Option<NaiveDateTime>,
+}
+
+// 監査ログは追記のみ行うため、更新・削除のメソッドは用意しない
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait AuditLogRepository {
+    async fn create(&self, conn: &mut DBConn, audit_log: &AuditLog) -> Result<()>;
+    async fn find_all(
+        &self,
+        conn: &mut DBConn,
+        query: &AuditLogQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AuditLog>>;
+    // 古い順に返す
+    async fn find_all_by_target_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<AuditLog>>;
+}
+
+pub trait HaveAuditLogRepository {
+    type Repo: Sync + AuditLogRepository;
+    fn audit_log_repo(&self) -> &Self::Repo;
+}
@@ -16,6 +16,11 @@ pub trait ClassRepository {
         class: &CreateClass,
     ) -> Result<()>;
     async fn update_submission_closed_by_id(&self, conn: &mut DBConn, id: &ClassID) -> Result<()>;
+    async fn update_submission_closed_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<()>;
     async fn find_submission_closed_by_id_with_shared_lock(
         &self,
         conn: &mut DBConn,
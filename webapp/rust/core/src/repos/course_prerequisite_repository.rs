@@ -0,0 +1,27 @@
+use crate::db::DBConn;
+use crate::models::course::CourseID;
+use crate::models::course_prerequisite::CoursePrerequisite;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait CoursePrerequisiteRepository {
+    async fn create(&self, conn: &mut DBConn, prerequisite: &CoursePrerequisite) -> Result<()>;
+    async fn find_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<Vec<CoursePrerequisite>>;
+    async fn find_by_course_ids(
+        &self,
+        conn: &mut DBConn,
+        course_ids: &[CourseID],
+    ) -> Result<Vec<CoursePrerequisite>>;
+    async fn delete_by_course_id(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()>;
+}
+
+pub trait HaveCoursePrerequisiteRepository {
+    type Repo: Sync + CoursePrerequisiteRepository;
+    fn course_prerequisite_repo(&self) -> &Self::Repo;
+}
@@ -1,5 +1,7 @@
 use crate::db::{DBConn, DBPool};
-use crate::models::course::{Course, CourseCode, CourseID, CourseWithTeacher, CreateCourse};
+use crate::models::course::{
+    Course, CourseCode, CourseID, CourseWithTeacher, CreateCourse, UpdateCourse,
+};
 use crate::models::course_status::CourseStatus;
 use crate::models::day_of_week::DayOfWeek;
 use crate::repos::error::Result;
@@ -40,14 +42,40 @@ pub trait CourseRepository {
         conn: &mut DBConn,
         id: &CourseID,
     ) -> Result<Option<Course>>;
+    // 存在しない科目は結果に含めない。並び順は保証しない
+    async fn find_for_share_lock_by_ids(
+        &self,
+        conn: &mut DBConn,
+        ids: &[CourseID],
+    ) -> Result<Vec<Course>>;
     async fn exist_by_id(&self, conn: &mut DBConn, id: &CourseID) -> Result<bool>;
+    async fn exist_by_code(&self, conn: &mut DBConn, code: &CourseCode) -> Result<bool>;
     async fn for_update_by_id(&self, conn: &mut DBConn, id: &CourseID) -> Result<bool>;
+    // 定員のある科目のIDを返す。ロックは取らない
+    async fn find_capacity_limited_ids_by_ids(
+        &self,
+        conn: &mut DBConn,
+        ids: &[CourseID],
+    ) -> Result<Vec<CourseID>>;
+    async fn for_update_by_ids(&self, conn: &mut DBConn, ids: &[CourseID]) -> Result<()>;
     async fn update_status_by_id(
         &self,
         conn: &mut DBConn,
         id: &CourseID,
         status: &CourseStatus,
     ) -> Result<()>;
+    async fn update_by_id(
+        &self,
+        conn: &mut DBConn,
+        id: &CourseID,
+        req: &UpdateCourse,
+    ) -> Result<()>;
+    async fn update_capacity_by_id(
+        &self,
+        conn: &mut DBConn,
+        id: &CourseID,
+        capacity: Option<u32>,
+    ) -> Result<()>;
     async fn find_by_code(&self, conn: &mut DBConn, code: &CourseCode) -> Result<Course>;
     async fn find_with_teacher_by_id(
         &self,
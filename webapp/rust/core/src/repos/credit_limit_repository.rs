@@ -0,0 +1,22 @@
+use crate::db::DBConn;
+use crate::models::user::UserID;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait CreditLimitRepository {
+    async fn find_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<Option<u32>>;
+    async fn create_or_update(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        credit_limit: u32,
+    ) -> Result<()>;
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()>;
+}
+
+pub trait HaveCreditLimitRepository {
+    type Repo: Sync + CreditLimitRepository;
+    fn credit_limit_repo(&self) -> &Self::Repo;
+}
@@ -12,6 +12,8 @@ pub enum ReposError {
     CourseDuplicate,
     #[error("A class  with the same code already exists.")]
     ClassDuplicate,
+    #[error("A user with the same code already exists.")]
+    UserDuplicate,
 }
 
 pub type Result<T> = std::result::Result<T, ReposError>;
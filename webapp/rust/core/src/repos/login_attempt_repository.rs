@@ -0,0 +1,44 @@
+use crate::db::DBConn;
+use crate::models::login_attempt::LoginAttempt;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait LoginAttemptRepository {
+    async fn create(&self, conn: &mut DBConn, attempt: &LoginAttempt) -> Result<()>;
+    async fn delete_by_user_code(&self, conn: &mut DBConn, user_code: &str) -> Result<()>;
+    // 新しい順に返す
+    async fn find_all_by_user_code(
+        &self,
+        conn: &mut DBConn,
+        user_code: &str,
+    ) -> Result<Vec<LoginAttempt>>;
+    // 新しい順に返す
+    async fn find_all_by_user_code_since(
+        &self,
+        conn: &mut DBConn,
+        user_code: &str,
+        since: &NaiveDateTime,
+    ) -> Result<Vec<LoginAttempt>>;
+    // 新しい順に返す
+    async fn find_all_by_ip_address_since(
+        &self,
+        conn: &mut DBConn,
+        ip_address: &str,
+        since: &NaiveDateTime,
+    ) -> Result<Vec<LoginAttempt>>;
+    async fn find_all_failed(
+        &self,
+        conn: &mut DBConn,
+        user_code: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<LoginAttempt>>;
+}
+
+pub trait HaveLoginAttemptRepository {
+    type Repo: Sync + LoginAttemptRepository;
+    fn login_attempt_repo(&self) -> &Self::Repo;
+}
@@ -0,0 +1,24 @@
+use crate::db::DBConn;
+use crate::models::course::CourseID;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait LotteryCourseRepository {
+    async fn create(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()>;
+    async fn delete(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()>;
+    async fn exist_by_course_id(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<bool>;
+    // 指定された科目のうち、該当するものの科目IDを返す
+    async fn find_course_ids_by_course_ids(
+        &self,
+        conn: &mut DBConn,
+        course_ids: &[CourseID],
+    ) -> Result<Vec<CourseID>>;
+    async fn find_all_course_ids(&self, conn: &mut DBConn) -> Result<Vec<CourseID>>;
+}
+
+pub trait HaveLotteryCourseRepository {
+    type Repo: Sync + LotteryCourseRepository;
+    fn lottery_course_repo(&self) -> &Self::Repo;
+}
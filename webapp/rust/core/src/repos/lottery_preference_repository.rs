@@ -0,0 +1,29 @@
+use crate::db::DBConn;
+use crate::models::course::CourseID;
+use crate::models::lottery_preference::LotteryPreference;
+use crate::models::user::UserID;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait LotteryPreferenceRepository {
+    async fn create(&self, conn: &mut DBConn, preference: &LotteryPreference) -> Result<()>;
+    async fn find_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<LotteryPreference>>;
+    async fn find_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<Vec<LotteryPreference>>;
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()>;
+    async fn delete_by_course_id(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()>;
+}
+
+pub trait HaveLotteryPreferenceRepository {
+    type Repo: Sync + LotteryPreferenceRepository;
+    fn lottery_preference_repo(&self) -> &Self::Repo;
+}
@@ -1,83 +1,226 @@
 use crate::repos::announcement_repository::HaveAnnouncementRepository;
+use crate::repos::approval_required_course_repository::HaveApprovalRequiredCourseRepository;
+use crate::repos::audit_log_repository::HaveAuditLogRepository;
 use crate::repos::class_repository::HaveClassRepository;
+use crate::repos::course_prerequisite_repository::HaveCoursePrerequisiteRepository;
 use crate::repos::course_repository::HaveCourseRepository;
+use crate::repos::credit_limit_repository::HaveCreditLimitRepository;
+use crate::repos::login_attempt_repository::HaveLoginAttemptRepository;
+use crate::repos::lottery_course_repository::HaveLotteryCourseRepository;
+use crate::repos::lottery_preference_repository::HaveLotteryPreferenceRepository;
+use crate::repos::personal_access_token_repository::HavePersonalAccessTokenRepository;
 use crate::repos::registration_course_repository::HaveRegistrationCourseRepository;
 use crate::repos::registration_repository::HaveRegistrationRepository;
+use crate::repos::registration_request_repository::HaveRegistrationRequestRepository;
+use crate::repos::registration_window_repository::HaveRegistrationWindowRepository;
 use crate::repos::submission_repository::HaveSubmissionRepository;
 use crate::repos::unread_announcement_repository::HaveUnreadAnnouncementRepository;
+use crate::repos::user_profile_repository::HaveUserProfileRepository;
+use crate::repos::user_recovery_code_repository::HaveUserRecoveryCodeRepository;
 use crate::repos::user_repository::HaveUserRepository;
+use crate::repos::user_totp_secret_repository::HaveUserTotpSecretRepository;
+use crate::repos::waitlist_repository::HaveWaitlistRepository;
 use crate::services::HaveDBPool;
 
 pub trait RepositoryManager:
     HaveDBPool
     + HaveAnnouncementRepository
+    + HaveApprovalRequiredCourseRepository
+    + HaveAuditLogRepository
     + HaveClassRepository
+    + HaveCoursePrerequisiteRepository
     + HaveCourseRepository
+    + HaveCreditLimitRepository
+    + HaveLoginAttemptRepository
+    + HaveLotteryCourseRepository
+    + HaveLotteryPreferenceRepository
+    + HavePersonalAccessTokenRepository
     + HaveRegistrationCourseRepository
     + HaveRegistrationRepository
+    + HaveRegistrationRequestRepository
+    + HaveRegistrationWindowRepository
     + HaveSubmissionRepository
     + HaveUnreadAnnouncementRepository
+    + HaveUserProfileRepository
+    + HaveUserRecoveryCodeRepository
     + HaveUserRepository
+    + HaveUserTotpSecretRepository
+    + HaveWaitlistRepository
 {
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
     use crate::db::DBPool;
+    use crate::identity_providers::oidc_provider::{HaveOidcProvider, MockOidcProvider};
+    use crate::password_hashers::{HavePasswordHasher, MockPasswordHasher};
     use crate::repos::announcement_repository::{
         HaveAnnouncementRepository, MockAnnouncementRepository,
     };
+    use crate::repos::approval_required_course_repository::{
+        HaveApprovalRequiredCourseRepository, MockApprovalRequiredCourseRepository,
+    };
+    use crate::repos::audit_log_repository::{HaveAuditLogRepository, MockAuditLogRepository};
     use crate::repos::class_repository::{HaveClassRepository, MockClassRepository};
+    use crate::repos::course_prerequisite_repository::{
+        HaveCoursePrerequisiteRepository, MockCoursePrerequisiteRepository,
+    };
     use crate::repos::course_repository::{HaveCourseRepository, MockCourseRepository};
+    use crate::repos::credit_limit_repository::{
+        HaveCreditLimitRepository, MockCreditLimitRepository,
+    };
+    use crate::repos::login_attempt_repository::{
+        HaveLoginAttemptRepository, MockLoginAttemptRepository,
+    };
+    use crate::repos::lottery_course_repository::{
+        HaveLotteryCourseRepository, MockLotteryCourseRepository,
+    };
+    use crate::repos::lottery_preference_repository::{
+        HaveLotteryPreferenceRepository, MockLotteryPreferenceRepository,
+    };
     use crate::repos::manager::RepositoryManager;
+    use crate::repos::personal_access_token_repository::{
+        HavePersonalAccessTokenRepository, MockPersonalAccessTokenRepository,
+    };
     use crate::repos::registration_course_repository::{
         HaveRegistrationCourseRepository, MockRegistrationCourseRepository,
     };
     use crate::repos::registration_repository::{
         HaveRegistrationRepository, MockRegistrationRepository,
     };
+    use crate::repos::registration_request_repository::{
+        HaveRegistrationRequestRepository, MockRegistrationRequestRepository,
+    };
+    use crate::repos::registration_window_repository::{
+        HaveRegistrationWindowRepository, MockRegistrationWindowRepository,
+    };
     use crate::repos::submission_repository::{HaveSubmissionRepository, MockSubmissionRepository};
     use crate::repos::unread_announcement_repository::{
         HaveUnreadAnnouncementRepository, MockUnreadAnnouncementRepository,
     };
+    use crate::repos::user_profile_repository::{
+        HaveUserProfileRepository, MockUserProfileRepository,
+    };
+    use crate::repos::user_recovery_code_repository::{
+        HaveUserRecoveryCodeRepository, MockUserRecoveryCodeRepository,
+    };
     use crate::repos::user_repository::{HaveUserRepository, MockUserRepository};
+    use crate::repos::user_totp_secret_repository::{
+        HaveUserTotpSecretRepository, MockUserTotpSecretRepository,
+    };
+    use crate::repos::waitlist_repository::{HaveWaitlistRepository, MockWaitlistRepository};
     use crate::services::announcement_service::AnnouncementServiceImpl;
+    use crate::services::audit_log_service::AuditLogServiceImpl;
     use crate::services::course_service::CourseServiceImpl;
+    use crate::services::impersonation_service::{
+        HaveImpersonationConfig, ImpersonationConfig, ImpersonationServiceImpl,
+    };
+    use crate::services::login_attempt_service::{
+        HaveLoginThrottleConfig, LoginAttemptServiceImpl, LoginThrottleConfig,
+    };
+    use crate::services::oidc_login_service::{
+        HaveOidcUserMappingConfig, OidcLoginServiceImpl, OidcUserMappingConfig,
+    };
+    use crate::services::personal_access_token_service::PersonalAccessTokenServiceImpl;
+    use crate::services::personal_data_service::PersonalDataServiceImpl;
+    use crate::services::registration_course_service::{
+        CreditLimitConfig, HaveCreditLimitConfig, RegistrationCourseServiceImpl,
+    };
+    use crate::services::two_factor_service::{
+        HaveTwoFactorConfig, TwoFactorConfig, TwoFactorServiceImpl,
+    };
     use crate::services::unread_announcement_service::UnreadAnnouncementServiceImpl;
+    use crate::services::user_service::UserServiceImpl;
     use crate::services::HaveDBPool;
+    use crate::storages::personal_data_storage::{
+        HavePersonalDataStorage, MockPersonalDataStorage,
+    };
 
     pub struct MockRepositoryManager {
         db_pool: DBPool,
+        pub impersonation_config: ImpersonationConfig,
+        pub credit_limit_config: CreditLimitConfig,
+        pub login_throttle_config: LoginThrottleConfig,
+        pub oidc_user_mapping_config: OidcUserMappingConfig,
+        pub oidc_provider: MockOidcProvider,
+        pub password_hasher: MockPasswordHasher,
+        pub personal_data_storage: MockPersonalDataStorage,
+        pub two_factor_config: TwoFactorConfig,
         pub announcement_repo: MockAnnouncementRepository,
+        pub approval_required_course_repo: MockApprovalRequiredCourseRepository,
+        pub audit_log_repo: MockAuditLogRepository,
         pub class_repo: MockClassRepository,
+        pub course_prerequisite_repo: MockCoursePrerequisiteRepository,
         pub course_repo: MockCourseRepository,
+        pub credit_limit_repo: MockCreditLimitRepository,
+        pub login_attempt_repo: MockLoginAttemptRepository,
+        pub lottery_course_repo: MockLotteryCourseRepository,
+        pub lottery_preference_repo: MockLotteryPreferenceRepository,
+        pub personal_access_token_repo: MockPersonalAccessTokenRepository,
         pub registration_course_repo: MockRegistrationCourseRepository,
         pub registration_repo: MockRegistrationRepository,
+        pub registration_request_repo: MockRegistrationRequestRepository,
+        pub registration_window_repo: MockRegistrationWindowRepository,
         pub submission_repo: MockSubmissionRepository,
         pub unread_announcement_repo: MockUnreadAnnouncementRepository,
+        pub user_profile_repo: MockUserProfileRepository,
+        pub user_recovery_code_repo: MockUserRecoveryCodeRepository,
         pub user_repo: MockUserRepository,
+        pub user_totp_secret_repo: MockUserTotpSecretRepository,
+        pub waitlist_repo: MockWaitlistRepository,
     }
 
     impl MockRepositoryManager {
         pub fn new(db_pool: DBPool) -> Self {
             Self {
                 db_pool,
+                impersonation_config: ImpersonationConfig::default(),
+                credit_limit_config: CreditLimitConfig::default(),
+                login_throttle_config: LoginThrottleConfig::default(),
+                oidc_user_mapping_config: OidcUserMappingConfig::default(),
+                oidc_provider: MockOidcProvider::new(),
+                password_hasher: MockPasswordHasher::new(),
+                personal_data_storage: MockPersonalDataStorage::new(),
+                two_factor_config: TwoFactorConfig::default(),
                 announcement_repo: MockAnnouncementRepository::new(),
+                approval_required_course_repo: MockApprovalRequiredCourseRepository::new(),
+                audit_log_repo: MockAuditLogRepository::new(),
                 class_repo: MockClassRepository::new(),
+                course_prerequisite_repo: MockCoursePrerequisiteRepository::new(),
                 course_repo: MockCourseRepository::new(),
+                credit_limit_repo: MockCreditLimitRepository::new(),
+                login_attempt_repo: MockLoginAttemptRepository::new(),
+                lottery_course_repo: MockLotteryCourseRepository::new(),
+                lottery_preference_repo: MockLotteryPreferenceRepository::new(),
+                personal_access_token_repo: MockPersonalAccessTokenRepository::new(),
                 registration_course_repo: MockRegistrationCourseRepository::new(),
                 registration_repo: MockRegistrationRepository::new(),
+                registration_request_repo: MockRegistrationRequestRepository::new(),
+                registration_window_repo: MockRegistrationWindowRepository::new(),
                 submission_repo: MockSubmissionRepository::new(),
                 unread_announcement_repo: MockUnreadAnnouncementRepository::new(),
+                user_profile_repo: MockUserProfileRepository::new(),
+                user_recovery_code_repo: MockUserRecoveryCodeRepository::new(),
                 user_repo: MockUserRepository::new(),
+                user_totp_secret_repo: MockUserTotpSecretRepository::new(),
+                waitlist_repo: MockWaitlistRepository::new(),
             }
         }
     }
 
     impl RepositoryManager for MockRepositoryManager {}
     impl AnnouncementServiceImpl for MockRepositoryManager {}
+    impl AuditLogServiceImpl for MockRepositoryManager {}
     impl CourseServiceImpl for MockRepositoryManager {}
+    impl ImpersonationServiceImpl for MockRepositoryManager {}
+    impl LoginAttemptServiceImpl for MockRepositoryManager {}
+    impl OidcLoginServiceImpl for MockRepositoryManager {}
+    impl PersonalAccessTokenServiceImpl for MockRepositoryManager {}
+    impl PersonalDataServiceImpl for MockRepositoryManager {}
+    impl RegistrationCourseServiceImpl for MockRepositoryManager {}
+    impl TwoFactorServiceImpl for MockRepositoryManager {}
     impl UnreadAnnouncementServiceImpl for MockRepositoryManager {}
+    impl UserServiceImpl for MockRepositoryManager {}
 
     impl HaveDBPool for MockRepositoryManager {
         fn get_db_pool(&self) -> &DBPool {
@@ -85,6 +228,60 @@ pub(crate) mod tests {
         }
     }
 
+    impl HaveImpersonationConfig for MockRepositoryManager {
+        fn impersonation_config(&self) -> &ImpersonationConfig {
+            &self.impersonation_config
+        }
+    }
+
+    impl HaveCreditLimitConfig for MockRepositoryManager {
+        fn credit_limit_config(&self) -> &CreditLimitConfig {
+            &self.credit_limit_config
+        }
+    }
+
+    impl HaveLoginThrottleConfig for MockRepositoryManager {
+        fn login_throttle_config(&self) -> &LoginThrottleConfig {
+            &self.login_throttle_config
+        }
+    }
+
+    impl HaveOidcUserMappingConfig for MockRepositoryManager {
+        fn oidc_user_mapping_config(&self) -> &OidcUserMappingConfig {
+            &self.oidc_user_mapping_config
+        }
+    }
+
+    impl HaveTwoFactorConfig for MockRepositoryManager {
+        fn two_factor_config(&self) -> &TwoFactorConfig {
+            &self.two_factor_config
+        }
+    }
+
+    impl HaveOidcProvider for MockRepositoryManager {
+        type Provider = MockOidcProvider;
+
+        fn oidc_provider(&self) -> &Self::Provider {
+            &self.oidc_provider
+        }
+    }
+
+    impl HavePasswordHasher for MockRepositoryManager {
+        type Hasher = MockPasswordHasher;
+
+        fn password_hasher(&self) -> &Self::Hasher {
+            &self.password_hasher
+        }
+    }
+
+    impl HavePersonalDataStorage for MockRepositoryManager {
+        type Storage = MockPersonalDataStorage;
+
+        fn personal_data_storage(&self) -> &Self::Storage {
+            &self.personal_data_storage
+        }
+    }
+
     impl HaveAnnouncementRepository for MockRepositoryManager {
         type Repo = MockAnnouncementRepository;
 
@@ -93,6 +290,22 @@ pub(crate) mod tests {
         }
     }
 
+    impl HaveApprovalRequiredCourseRepository for MockRepositoryManager {
+        type Repo = MockApprovalRequiredCourseRepository;
+
+        fn approval_required_course_repo(&self) -> &Self::Repo {
+            &self.approval_required_course_repo
+        }
+    }
+
+    impl HaveAuditLogRepository for MockRepositoryManager {
+        type Repo = MockAuditLogRepository;
+
+        fn audit_log_repo(&self) -> &Self::Repo {
+            &self.audit_log_repo
+        }
+    }
+
     impl HaveClassRepository for MockRepositoryManager {
         type Repo = MockClassRepository;
 
@@ -101,6 +314,14 @@ pub(crate) mod tests {
         }
     }
 
+    impl HaveCoursePrerequisiteRepository for MockRepositoryManager {
+        type Repo = MockCoursePrerequisiteRepository;
+
+        fn course_prerequisite_repo(&self) -> &Self::Repo {
+            &self.course_prerequisite_repo
+        }
+    }
+
     impl HaveCourseRepository for MockRepositoryManager {
         type Repo = MockCourseRepository;
 
@@ -109,6 +330,46 @@ pub(crate) mod tests {
         }
     }
 
+    impl HaveCreditLimitRepository for MockRepositoryManager {
+        type Repo = MockCreditLimitRepository;
+
+        fn credit_limit_repo(&self) -> &Self::Repo {
+            &self.credit_limit_repo
+        }
+    }
+
+    impl HaveLoginAttemptRepository for MockRepositoryManager {
+        type Repo = MockLoginAttemptRepository;
+
+        fn login_attempt_repo(&self) -> &Self::Repo {
+            &self.login_attempt_repo
+        }
+    }
+
+    impl HaveLotteryCourseRepository for MockRepositoryManager {
+        type Repo = MockLotteryCourseRepository;
+
+        fn lottery_course_repo(&self) -> &Self::Repo {
+            &self.lottery_course_repo
+        }
+    }
+
+    impl HaveLotteryPreferenceRepository for MockRepositoryManager {
+        type Repo = MockLotteryPreferenceRepository;
+
+        fn lottery_preference_repo(&self) -> &Self::Repo {
+            &self.lottery_preference_repo
+        }
+    }
+
+    impl HavePersonalAccessTokenRepository for MockRepositoryManager {
+        type Repo = MockPersonalAccessTokenRepository;
+
+        fn personal_access_token_repo(&self) -> &Self::Repo {
+            &self.personal_access_token_repo
+        }
+    }
+
     impl HaveRegistrationCourseRepository for MockRepositoryManager {
         type Repo = MockRegistrationCourseRepository;
 
@@ -125,6 +386,22 @@ pub(crate) mod tests {
         }
     }
 
+    impl HaveRegistrationRequestRepository for MockRepositoryManager {
+        type Repo = MockRegistrationRequestRepository;
+
+        fn registration_request_repo(&self) -> &Self::Repo {
+            &self.registration_request_repo
+        }
+    }
+
+    impl HaveRegistrationWindowRepository for MockRepositoryManager {
+        type Repo = MockRegistrationWindowRepository;
+
+        fn registration_window_repo(&self) -> &Self::Repo {
+            &self.registration_window_repo
+        }
+    }
+
     impl HaveSubmissionRepository for MockRepositoryManager {
         type Repo = MockSubmissionRepository;
 
@@ -148,4 +425,36 @@ pub(crate) mod tests {
             &self.user_repo
         }
     }
+
+    impl HaveUserProfileRepository for MockRepositoryManager {
+        type Repo = MockUserProfileRepository;
+
+        fn user_profile_repo(&self) -> &Self::Repo {
+            &self.user_profile_repo
+        }
+    }
+
+    impl HaveUserRecoveryCodeRepository for MockRepositoryManager {
+        type Repo = MockUserRecoveryCodeRepository;
+
+        fn user_recovery_code_repo(&self) -> &Self::Repo {
+            &self.user_recovery_code_repo
+        }
+    }
+
+    impl HaveUserTotpSecretRepository for MockRepositoryManager {
+        type Repo = MockUserTotpSecretRepository;
+
+        fn user_totp_secret_repo(&self) -> &Self::Repo {
+            &self.user_totp_secret_repo
+        }
+    }
+
+    impl HaveWaitlistRepository for MockRepositoryManager {
+        type Repo = MockWaitlistRepository;
+
+        fn waitlist_repo(&self) -> &Self::Repo {
+            &self.waitlist_repo
+        }
+    }
 }
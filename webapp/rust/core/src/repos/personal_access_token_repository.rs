@@ -0,0 +1,35 @@
+use crate::db::DBConn;
+use crate::models::personal_access_token::{PersonalAccessToken, PersonalAccessTokenID};
+use crate::models::user::UserID;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait PersonalAccessTokenRepository {
+    async fn create(&self, conn: &mut DBConn, token: &PersonalAccessToken) -> Result<()>;
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()>;
+    async fn find_all_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<PersonalAccessToken>>;
+    async fn find_unexpired_by_hashed_token(
+        &self,
+        conn: &mut DBConn,
+        hashed_token: &str,
+        now: &NaiveDateTime,
+    ) -> Result<Option<PersonalAccessToken>>;
+    async fn delete_by_id_and_user_id(
+        &self,
+        conn: &mut DBConn,
+        id: &PersonalAccessTokenID,
+        user_id: &UserID,
+    ) -> Result<bool>;
+}
+
+pub trait HavePersonalAccessTokenRepository {
+    type Repo: Sync + PersonalAccessTokenRepository;
+    fn personal_access_token_repo(&self) -> &Self::Repo;
+}
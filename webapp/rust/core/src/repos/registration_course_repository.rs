@@ -1,5 +1,6 @@
 use crate::db::DBConn;
 use crate::models::course::{Course, CourseID};
+use crate::models::course_result::CourseTotalScore;
 use crate::models::user::UserID;
 use crate::repos::error::Result;
 use async_trait::async_trait;
@@ -17,6 +18,11 @@ pub trait RegistrationCourseRepository {
         conn: &mut DBConn,
         user_id: &UserID,
     ) -> Result<Vec<Course>>;
+    async fn find_closed_total_scores_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<CourseTotalScore>>;
     async fn find_total_scores_by_course_id_group_by_user_id(
         &self,
         conn: &mut DBConn,
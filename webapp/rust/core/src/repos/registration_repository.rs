@@ -1,8 +1,10 @@
 use crate::db::DBConn;
 use crate::models::course::CourseID;
+use crate::models::course_student::CourseStudent;
 use crate::models::user::{User, UserID};
 use crate::repos::error::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
 #[cfg_attr(any(test, feature = "test"), mockall::automock)]
 #[async_trait]
@@ -13,17 +15,58 @@ pub trait RegistrationRepository {
         user_id: &UserID,
         course_id: &CourseID,
     ) -> Result<()>;
+    async fn create_or_update_all(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<()>;
     async fn exist_by_user_id_and_course_id(
         &self,
         conn: &mut DBConn,
         user_id: &UserID,
         course_id: &CourseID,
     ) -> Result<bool>;
+    // course_idsのうち、履修登録済みの科目を返す
+    async fn find_course_ids_by_user_id_and_course_ids(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<Vec<CourseID>>;
+    async fn count_for_share_lock_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<i64>;
+    // 科目IDと履修者数の組を返す。履修者のいない科目は含まない
+    async fn count_for_share_lock_by_course_ids(
+        &self,
+        conn: &mut DBConn,
+        course_ids: &[CourseID],
+    ) -> Result<Vec<(CourseID, i64)>>;
+    async fn delete(&self, conn: &mut DBConn, user_id: &UserID, course_id: &CourseID)
+        -> Result<()>;
+    async fn withdraw(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        course_id: &CourseID,
+        withdrawn_at: &NaiveDateTime,
+    ) -> Result<()>;
     async fn find_users_by_course_id(
         &self,
         conn: &mut DBConn,
         course_id: &CourseID,
     ) -> Result<Vec<User>>;
+    // limitがNoneの場合はすべての履修者を返す
+    async fn find_students_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+        limit: Option<i64>,
+        offset: i64,
+    ) -> Result<Vec<CourseStudent>>;
 }
 
 pub trait HaveRegistrationRepository {
@@ -0,0 +1,17 @@
+use crate::db::DBConn;
+use crate::models::registration_window::{RegistrationWindow, RegistrationWindowID};
+use crate::repos::error::Result;
+use async_trait::async_trait;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait RegistrationWindowRepository {
+    async fn create(&self, conn: &mut DBConn, window: &RegistrationWindow) -> Result<()>;
+    async fn find_all(&self, conn: &mut DBConn) -> Result<Vec<RegistrationWindow>>;
+    async fn delete_by_id(&self, conn: &mut DBConn, id: &RegistrationWindowID) -> Result<bool>;
+}
+
+pub trait HaveRegistrationWindowRepository {
+    type Repo: Sync + RegistrationWindowRepository;
+    fn registration_window_repo(&self) -> &Self::Repo;
+}
@@ -1,6 +1,6 @@
 use crate::db::DBConn;
 use crate::models::class::ClassID;
-use crate::models::submission::{CreateSubmission, SubmissionWithUserCode};
+use crate::models::submission::{CreateSubmission, SubmissionWithUserCode, UserSubmission};
 use crate::models::user::{UserCode, UserID};
 use crate::repos::error::Result;
 use async_trait::async_trait;
@@ -27,6 +27,18 @@ pub trait SubmissionRepository {
         class_id: &ClassID,
         user_id: &UserID,
     ) -> Result<Option<u8>>;
+    async fn find_all_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<UserSubmission>>;
+    // スコアは集計に使うため残し、ファイル名のみ置き換える
+    async fn update_file_name_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        file_name: &str,
+    ) -> Result<()>;
     async fn find_all_with_user_code_by_class_id(
         &self,
         conn: &mut DBConn,
@@ -30,6 +30,12 @@ pub trait UnreadAnnouncementRepository {
         offset: i64,
         course_id: Option<CourseID>,
     ) -> Result<Vec<AnnouncementWithoutDetail>>;
+    // 既読のお知らせも含め、登録を取り消した科目のものも返す
+    async fn find_all_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<AnnouncementWithoutDetail>>;
     async fn find_announcement_detail_by_announcement_id_and_user_id(
         &self,
         conn: &mut DBConn,
@@ -0,0 +1,22 @@
+use crate::db::DBConn;
+use crate::models::user::UserID;
+use crate::models::user_profile::UserProfile;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait UserProfileRepository {
+    async fn find_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Option<UserProfile>>;
+    async fn create_or_update(&self, conn: &mut DBConn, profile: &UserProfile) -> Result<()>;
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()>;
+}
+
+pub trait HaveUserProfileRepository {
+    type Repo: Sync + UserProfileRepository;
+    fn user_profile_repo(&self) -> &Self::Repo;
+}
@@ -0,0 +1,26 @@
+use crate::db::DBConn;
+use crate::models::user::UserID;
+use crate::models::user_recovery_code::UserRecoveryCode;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait UserRecoveryCodeRepository {
+    async fn create(&self, conn: &mut DBConn, code: &UserRecoveryCode) -> Result<()>;
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()>;
+    // 未使用のコードがあれば使用済みにし、使用できたかを返す
+    async fn use_by_user_id_and_hashed_code(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        hashed_code: &str,
+        used_at: &NaiveDateTime,
+    ) -> Result<bool>;
+}
+
+pub trait HaveUserRecoveryCodeRepository {
+    type Repo: Sync + UserRecoveryCodeRepository;
+    fn user_recovery_code_repo(&self) -> &Self::Repo;
+}
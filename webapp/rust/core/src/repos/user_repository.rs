@@ -1,15 +1,56 @@
 use crate::db::DBConn;
-use crate::models::user::{User, UserCode, UserID};
+use crate::models::user::{UpdateUser, User, UserCode, UserID};
+use crate::models::user_type::UserType;
 use crate::repos::error::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchUsersQuery {
+    pub type_: Option<UserType>,
+    pub code_prefix: Option<String>,
+}
 
 #[cfg_attr(any(test, feature = "test"), mockall::automock)]
 #[async_trait]
 pub trait UserRepository {
+    async fn create(&self, conn: &mut DBConn, user: &User) -> Result<()>;
+    async fn create_bulk(&self, conn: &mut DBConn, users: &[User]) -> Result<()>;
     async fn find(&self, conn: &mut DBConn, id: &UserID) -> Result<User>;
     async fn find_by_code(&self, conn: &mut DBConn, code: &UserCode) -> Result<Option<User>>;
     async fn find_code_by_id(&self, conn: &mut DBConn, id: &UserID) -> Result<Option<UserCode>>;
+    async fn find_codes_in(&self, conn: &mut DBConn, codes: &[String]) -> Result<Vec<UserCode>>;
     async fn find_gpas_group_by_user_id(&self, conn: &mut DBConn) -> Result<Vec<f64>>;
+    async fn update_hashed_password_by_id(
+        &self,
+        conn: &mut DBConn,
+        id: &UserID,
+        hashed_password: &[u8],
+    ) -> Result<()>;
+    async fn update_by_id(&self, conn: &mut DBConn, id: &UserID, req: &UpdateUser) -> Result<()>;
+    // 仮名化した識別情報で置き換え、無効化する
+    async fn anonymize_by_id(
+        &self,
+        conn: &mut DBConn,
+        id: &UserID,
+        code: &UserCode,
+        name: &str,
+        hashed_password: &[u8],
+        deactivated_at: &NaiveDateTime,
+    ) -> Result<()>;
+    async fn deactivate_by_id(
+        &self,
+        conn: &mut DBConn,
+        id: &UserID,
+        deactivated_at: &NaiveDateTime,
+    ) -> Result<()>;
+    async fn find_all(
+        &self,
+        conn: &mut DBConn,
+        query: &SearchUsersQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>>;
 }
 
 pub trait HaveUserRepository {
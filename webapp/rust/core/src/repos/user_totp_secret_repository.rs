@@ -0,0 +1,31 @@
+use crate::db::DBConn;
+use crate::models::user::UserID;
+use crate::models::user_totp_secret::UserTotpSecret;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait UserTotpSecretRepository {
+    async fn find_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Option<UserTotpSecret>>;
+    // 確認前の共有鍵があれば置き換える
+    async fn create_or_replace(&self, conn: &mut DBConn, secret: &UserTotpSecret) -> Result<()>;
+    async fn confirm_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()>;
+    // stepがこれまでに受け付けたタイムステップより新しい場合のみ更新し、更新できたかを返す
+    async fn update_last_used_step_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        step: i64,
+    ) -> Result<bool>;
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()>;
+}
+
+pub trait HaveUserTotpSecretRepository {
+    type Repo: Sync + UserTotpSecretRepository;
+    fn user_totp_secret_repo(&self) -> &Self::Repo;
+}
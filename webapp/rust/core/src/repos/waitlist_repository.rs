@@ -0,0 +1,36 @@
+use crate::db::DBConn;
+use crate::models::course::CourseID;
+use crate::models::user::UserID;
+use crate::repos::error::Result;
+use async_trait::async_trait;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait WaitlistRepository {
+    async fn create(&self, conn: &mut DBConn, course_id: &CourseID, user_id: &UserID)
+        -> Result<()>;
+    async fn find_position(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+        user_id: &UserID,
+    ) -> Result<Option<i64>>;
+    async fn find_first_for_update_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<Option<UserID>>;
+    async fn delete(&self, conn: &mut DBConn, course_id: &CourseID, user_id: &UserID)
+        -> Result<()>;
+    async fn find_course_ids_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<CourseID>>;
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()>;
+}
+
+pub trait HaveWaitlistRepository {
+    type Repo: Sync + WaitlistRepository;
+    fn waitlist_repo(&self) -> &Self::Repo;
+}
@@ -1,13 +1,20 @@
 use crate::db::DBPool;
 
 pub mod announcement_service;
+pub mod audit_log_service;
 pub mod class_service;
 pub mod course_service;
 pub mod error;
 pub mod grade_summary_service;
+pub mod impersonation_service;
+pub mod login_attempt_service;
 pub mod manager;
+pub mod oidc_login_service;
+pub mod personal_access_token_service;
+pub mod personal_data_service;
 pub mod registration_course_service;
 pub mod submission_service;
+pub mod two_factor_service;
 pub mod unread_announcement_service;
 pub mod user_service;
 
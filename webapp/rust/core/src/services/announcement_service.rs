@@ -1,11 +1,15 @@
 use crate::models::announcement::Announcement;
+use crate::models::audit_log::CreateAuditLog;
 use crate::repos::announcement_repository::{AnnouncementRepository, HaveAnnouncementRepository};
+use crate::repos::audit_log_repository::HaveAuditLogRepository;
 use crate::repos::course_repository::{CourseRepository, HaveCourseRepository};
 use crate::repos::error::ReposError;
 use crate::repos::registration_repository::{HaveRegistrationRepository, RegistrationRepository};
 use crate::repos::unread_announcement_repository::{
     HaveUnreadAnnouncementRepository, UnreadAnnouncementRepository,
 };
+use crate::repos::user_profile_repository::{HaveUserProfileRepository, UserProfileRepository};
+use crate::services::audit_log_service::create_audit_log;
 use crate::services::error::Error::{AnnouncementDuplicate, CourseNotFound};
 use crate::services::error::Result;
 use crate::services::HaveDBPool;
@@ -16,7 +20,7 @@ mod create;
 #[cfg_attr(any(test, feature = "test"), mockall::automock)]
 #[async_trait]
 pub trait AnnouncementService: Sync {
-    async fn create(&self, announcement: &Announcement) -> Result<()>;
+    async fn create(&self, announcement: &Announcement, audit_log: &CreateAuditLog) -> Result<()>;
 }
 
 #[cfg_attr(any(test, feature = "test"), mockall::automock(type Service = MockAnnouncementService;))]
@@ -33,8 +37,10 @@ pub trait AnnouncementServiceImpl:
     + HaveAnnouncementRepository
     + HaveRegistrationRepository
     + HaveUnreadAnnouncementRepository
+    + HaveUserProfileRepository
+    + HaveAuditLogRepository
 {
-    async fn create(&self, announcement: &Announcement) -> Result<()> {
+    async fn create(&self, announcement: &Announcement, audit_log: &CreateAuditLog) -> Result<()> {
         let pool = self.get_db_pool();
         let mut tx = pool.begin().await?;
 
@@ -63,6 +69,7 @@ pub trait AnnouncementServiceImpl:
                         {
                             return Err(AnnouncementDuplicate);
                         } else {
+                            create_audit_log(self.audit_log_repo(), &mut conn, audit_log).await?;
                             return Ok(());
                         }
                     }
@@ -76,10 +83,17 @@ pub trait AnnouncementServiceImpl:
             .find_users_by_course_id(&mut tx, &announcement.course_id)
             .await?;
 
+        // お知らせの通知を受け取らない履修者には、お知らせを既読の状態で届ける
         let repo = self.unread_announcement_repo();
+        let user_profile_repo = self.user_profile_repo();
         for user in targets {
             repo.create(&mut tx, &aid, &user.id).await?;
+            let profile = user_profile_repo.find_by_user_id(&mut tx, &user.id).await?;
+            if matches!(profile, Some(profile) if !profile.notify_announcements) {
+                repo.mark_read(&mut tx, &aid, &user.id).await?;
+            }
         }
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
 
         tx.commit().await?;
 
@@ -89,8 +103,8 @@ pub trait AnnouncementServiceImpl:
 
 #[async_trait]
 impl<S: AnnouncementServiceImpl> AnnouncementService for S {
-    async fn create(&self, announcement: &Announcement) -> Result<()> {
-        AnnouncementServiceImpl::create(self, announcement).await
+    async fn create(&self, announcement: &Announcement, audit_log: &CreateAuditLog) -> Result<()> {
+        AnnouncementServiceImpl::create(self, announcement, audit_log).await
     }
 }
 
@@ -3,6 +3,7 @@ mod tests {
     use crate::db::get_test_db_conn;
     use crate::models::announcement::Announcement;
     use crate::models::user::User;
+    use crate::models::user_profile::UserProfile;
     use crate::repos::error::ReposError::AnnouncementDuplicate;
     use crate::repos::manager::tests::MockRepositoryManager;
     use crate::services::announcement_service::AnnouncementService;
@@ -23,7 +24,7 @@ mod tests {
             .withf(move |_, course_id| course_id == &cid)
             .returning(|_, _| Ok(false));
 
-        service.create(&announcement).await.unwrap();
+        service.create(&announcement, &Faker.fake()).await.unwrap();
     }
 
     #[tokio::test]
@@ -54,8 +55,13 @@ mod tests {
             .expect_find_by_id()
             .withf(move |_, announcement_id| announcement_id == &aid)
             .returning(move |_, _| Ok(ann.clone()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
 
-        service.create(&announcement).await.unwrap();
+        service.create(&announcement, &Faker.fake()).await.unwrap();
     }
 
     #[tokio::test]
@@ -88,7 +94,7 @@ mod tests {
             .withf(move |_, announcement_id| announcement_id == &aid)
             .returning(move |_, _| Ok(ann.clone()));
 
-        service.create(&announcement).await.unwrap();
+        service.create(&announcement, &Faker.fake()).await.unwrap();
     }
     #[tokio::test]
     async fn success_case() {
@@ -113,21 +119,51 @@ mod tests {
 
         let cid = announcement.course_id.clone();
         let user: User = Faker.fake();
-        let student = user.clone();
+        let muted_user: User = Faker.fake();
+        let mut muted_profile = UserProfile::new(muted_user.id.clone());
+        muted_profile.notify_announcements = false;
+        let students = vec![user.clone(), muted_user.clone()];
         service
             .registration_repo
             .expect_find_users_by_course_id()
             .withf(move |_, course_id| course_id == &cid)
-            .returning(move |_, _| Ok(vec![student.clone()]));
+            .returning(move |_, _| Ok(students.clone()));
 
         let aid = announcement.id.clone();
-        let uid = user.id.clone();
         service
             .unread_announcement_repo
             .expect_create()
-            .withf(move |_, announcement_id, user_id| announcement_id == &aid && user_id == &uid)
+            .withf(move |_, announcement_id, _| announcement_id == &aid)
+            .times(2)
             .returning(|_, _, _| Ok(()));
+        let muted_id = muted_user.id.clone();
+        service
+            .user_profile_repo
+            .expect_find_by_user_id()
+            .returning(move |_, user_id| {
+                if user_id == &muted_id {
+                    Ok(Some(muted_profile.clone()))
+                } else {
+                    Ok(None)
+                }
+            });
+        // 通知を受け取らない履修者の分だけ既読にする
+        let aid = announcement.id.clone();
+        let muted_id = muted_user.id.clone();
+        service
+            .unread_announcement_repo
+            .expect_mark_read()
+            .withf(move |_, announcement_id, user_id| {
+                announcement_id == &aid && user_id == &muted_id
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
 
-        service.create(&announcement).await.unwrap();
+        service.create(&announcement, &Faker.fake()).await.unwrap();
     }
 }
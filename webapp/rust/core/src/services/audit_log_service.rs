@@ -0,0 +1,87 @@
+use crate::db::DBConn;
+use crate::models::audit_log::{AuditLog, AuditLogID, CreateAuditLog};
+use crate::repos::audit_log_repository::{
+    AuditLogQuery, AuditLogRepository, HaveAuditLogRepository,
+};
+use crate::services::error::Result;
+use crate::services::HaveDBPool;
+use crate::util;
+use async_trait::async_trait;
+
+mod record;
+
+// 監査ログを渡された接続で書き込む。管理者の操作を行うサービスは操作と同じトランザクションで呼び出し、
+// 監査ログの書き込みに失敗した場合は操作ごとロールバックして、記録のない変更が残らないようにする
+pub async fn create_audit_log<Repo: AuditLogRepository + Sync>(
+    repo: &Repo,
+    conn: &mut DBConn,
+    form: &CreateAuditLog,
+) -> Result<()> {
+    let audit_log = form.clone().into_audit_log(
+        AuditLogID::new(util::new_ulid().await),
+        chrono::Utc::now().naive_utc(),
+    );
+    repo.create(conn, &audit_log).await?;
+
+    Ok(())
+}
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait AuditLogService: Sync {
+    async fn record(&self, form: &CreateAuditLog) -> Result<()>;
+    async fn find_all(
+        &self,
+        query: &AuditLogQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AuditLog>>;
+}
+
+pub trait HaveAuditLogService {
+    type Service: AuditLogService;
+    fn audit_log_service(&self) -> &Self::Service;
+}
+
+#[async_trait]
+pub trait AuditLogServiceImpl: Sync + HaveDBPool + HaveAuditLogRepository {
+    async fn record(&self, form: &CreateAuditLog) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        create_audit_log(self.audit_log_repo(), &mut conn, form).await
+    }
+
+    async fn find_all(
+        &self,
+        query: &AuditLogQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AuditLog>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let audit_logs = self
+            .audit_log_repo()
+            .find_all(&mut conn, query, limit, offset)
+            .await?;
+
+        Ok(audit_logs)
+    }
+}
+
+#[async_trait]
+impl<S: AuditLogServiceImpl> AuditLogService for S {
+    async fn record(&self, form: &CreateAuditLog) -> Result<()> {
+        AuditLogServiceImpl::record(self, form).await
+    }
+
+    async fn find_all(
+        &self,
+        query: &AuditLogQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AuditLog>> {
+        AuditLogServiceImpl::find_all(self, query, limit, offset).await
+    }
+}
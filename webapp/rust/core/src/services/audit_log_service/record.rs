@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::audit_action::AuditAction;
+    use crate::models::audit_log::CreateAuditLog;
+    use crate::models::course::CourseID;
+    use crate::models::user::UserID;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::audit_log_service::AuditLogService;
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .audit_log_repo
+            .expect_create()
+            .withf(|_, audit_log| {
+                audit_log.actor_id.inner() == "admin"
+                    && audit_log.action == AuditAction::CourseStatusUpdate
+                    && audit_log
+                        .target_course_id
+                        .as_ref()
+                        .map(|id| id.inner().as_str())
+                        == Some("course")
+                    && audit_log.request_id.as_deref() == Some("request")
+                    && audit_log.before_summary.as_deref() == Some(r#"{"status":"registration"}"#)
+                    && audit_log.after_summary.as_deref() == Some(r#"{"status":"in-progress"}"#)
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut form = CreateAuditLog::new(
+            UserID::new("admin".to_string()),
+            AuditAction::CourseStatusUpdate,
+            Some("request".to_string()),
+        );
+        form.target_course_id = Some(CourseID::new("course".to_string()));
+        form.before_summary = Some(r#"{"status":"registration"}"#.to_string());
+        form.after_summary = Some(r#"{"status":"in-progress"}"#.to_string());
+
+        service.record(&form).await.unwrap();
+    }
+}
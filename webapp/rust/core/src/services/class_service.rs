@@ -1,9 +1,11 @@
+use crate::models::audit_log::CreateAuditLog;
 use crate::models::class::{ClassID, ClassWithSubmitted, CreateClass};
 use crate::models::class_score::ClassScore;
 use crate::models::course::{Course, CourseID};
 use crate::models::course_result::CourseResult;
 use crate::models::course_status::CourseStatus;
 use crate::models::user::UserID;
+use crate::repos::audit_log_repository::HaveAuditLogRepository;
 use crate::repos::class_repository::{ClassRepository, HaveClassRepository};
 use crate::repos::course_repository::{CourseRepository, HaveCourseRepository};
 use crate::repos::error::ReposError;
@@ -11,6 +13,7 @@ use crate::repos::registration_course_repository::{
     HaveRegistrationCourseRepository, RegistrationCourseRepository,
 };
 use crate::repos::submission_repository::{HaveSubmissionRepository, SubmissionRepository};
+use crate::services::audit_log_service::create_audit_log;
 use crate::services::error::Error::{CourseConflict, CourseIsNotInProgress, CourseNotFound};
 use crate::services::error::Result;
 use crate::services::HaveDBPool;
@@ -20,7 +23,7 @@ use async_trait::async_trait;
 #[cfg_attr(any(test, feature = "test"), mockall::automock)]
 #[async_trait]
 pub trait ClassService {
-    async fn create(&self, form: &CreateClass) -> Result<ClassID>;
+    async fn create(&self, form: &CreateClass, audit_log: &CreateAuditLog) -> Result<ClassID>;
 
     async fn get_user_scores_by_course_id(
         &self,
@@ -59,9 +62,12 @@ pub trait ClassServiceImpl:
     + HaveSubmissionRepository
     + HaveRegistrationCourseRepository
     + HaveCourseRepository
+    + HaveAuditLogRepository
 {
-    async fn create(&self, form: &CreateClass) -> Result<ClassID> {
+    async fn create(&self, form: &CreateClass, audit_log: &CreateAuditLog) -> Result<ClassID> {
         let class_id = ClassID::new(util::new_ulid().await);
+        let mut audit_log = audit_log.clone();
+        audit_log.target_class_id = Some(class_id.clone());
 
         let pool = self.get_db_pool();
         let mut tx = pool.begin().await?;
@@ -82,6 +88,7 @@ pub trait ClassServiceImpl:
         let result = class_repo.create(&mut tx, &class_id, form).await;
         match result {
             Ok(_) => {
+                create_audit_log(self.audit_log_repo(), &mut tx, &audit_log).await?;
                 tx.commit().await?;
             }
             Err(e) => {
@@ -94,6 +101,7 @@ pub trait ClassServiceImpl:
                     if form.title != class.title || form.description != class.description {
                         return Err(CourseConflict);
                     } else {
+                        create_audit_log(self.audit_log_repo(), &mut conn, &audit_log).await?;
                         return Ok(class_id);
                     }
                 }
@@ -243,8 +251,8 @@ pub trait ClassServiceImpl:
 
 #[async_trait]
 impl<S: ClassServiceImpl> ClassService for S {
-    async fn create(&self, form: &CreateClass) -> Result<ClassID> {
-        ClassServiceImpl::create(self, form).await
+    async fn create(&self, form: &CreateClass, audit_log: &CreateAuditLog) -> Result<ClassID> {
+        ClassServiceImpl::create(self, form, audit_log).await
     }
 
     async fn get_user_scores_by_course_id(
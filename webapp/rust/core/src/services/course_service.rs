@@ -1,27 +1,107 @@
-use crate::models::course::{Course, CourseID, CourseWithTeacher, CreateCourse};
+use crate::db::DBConn;
+use crate::models::announcement::{Announcement, AnnouncementID};
+use crate::models::audit_log::CreateAuditLog;
+use crate::models::course::{Course, CourseID, CourseWithTeacher, CreateCourse, UpdateCourse};
+use crate::models::course_prerequisite::{CoursePrerequisite, UpdateCoursePrerequisite};
 use crate::models::course_status::CourseStatus;
+use crate::models::course_student::CourseStudent;
 use crate::models::user::{User, UserID};
+use crate::repos::announcement_repository::{AnnouncementRepository, HaveAnnouncementRepository};
+use crate::repos::audit_log_repository::HaveAuditLogRepository;
+use crate::repos::class_repository::{ClassRepository, HaveClassRepository};
+use crate::repos::course_prerequisite_repository::{
+    CoursePrerequisiteRepository, HaveCoursePrerequisiteRepository,
+};
 use crate::repos::course_repository::{CourseRepository, HaveCourseRepository, SearchCoursesQuery};
 use crate::repos::error::ReposError;
 use crate::repos::registration_course_repository::{
     HaveRegistrationCourseRepository, RegistrationCourseRepository,
 };
+use crate::repos::registration_repository::{HaveRegistrationRepository, RegistrationRepository};
+use crate::repos::unread_announcement_repository::{
+    HaveUnreadAnnouncementRepository, UnreadAnnouncementRepository,
+};
+use crate::repos::user_profile_repository::{HaveUserProfileRepository, UserProfileRepository};
 use crate::repos::user_repository::{HaveUserRepository, UserRepository};
-use crate::services::error::Error::CourseNotFound;
+use crate::services::audit_log_service::create_audit_log;
+use crate::services::error::Error::{
+    CourseIsClosed, CourseNotFound, CourseScheduleConflict, InvalidCourse,
+    InvalidCoursePrerequisite, InvalidCourseStatusTransition, NotCourseTeacher,
+};
 use crate::services::error::Result;
 use crate::services::HaveDBPool;
+use crate::util;
 use async_trait::async_trait;
 
 mod create;
 mod find_all_with_teacher;
+mod find_students;
 mod find_with_teacher_by_id;
+mod update;
+mod update_prerequisites;
 mod update_status_by_id;
 
+const COURSE_NAME_MAX_LENGTH: usize = 255;
+const MAX_PERIOD: u8 = 6;
+
+fn validate_course(req: &UpdateCourse) -> Result<()> {
+    let name_length = req.name.chars().count();
+    if req.name.trim().is_empty() || name_length > COURSE_NAME_MAX_LENGTH {
+        return Err(InvalidCourse);
+    }
+    if req.credit == 0 || req.period == 0 || req.period > MAX_PERIOD {
+        return Err(InvalidCourse);
+    }
+
+    Ok(())
+}
+
+// 履修者への通知に載せる変更内容。変更がなければNoneを返す
+fn describe_course_changes(before: &Course, after: &UpdateCourse) -> Option<String> {
+    let mut changes = Vec::new();
+    if before.name != after.name {
+        changes.push(format!("科目名: {} → {}", before.name, after.name));
+    }
+    if before.description != after.description {
+        changes.push("講義概要が変更されました".to_string());
+    }
+    if before.keywords != after.keywords {
+        changes.push("キーワードが変更されました".to_string());
+    }
+    if before.credit != after.credit {
+        changes.push(format!("単位数: {} → {}", before.credit, after.credit));
+    }
+    if before.period != after.period || before.day_of_week != after.day_of_week {
+        changes.push(format!(
+            "開講日時: {:?} {}限 → {:?} {}限",
+            before.day_of_week, before.period, after.day_of_week, after.period
+        ));
+    }
+
+    if changes.is_empty() {
+        None
+    } else {
+        Some(changes.join("\n"))
+    }
+}
+
 #[cfg_attr(any(test, feature = "test"), mockall::automock)]
 #[async_trait]
 pub trait CourseService: Sync {
-    async fn create(&self, course: &CreateCourse) -> Result<CourseID>;
-    async fn update_status_by_id(&self, course_id: &CourseID, status: &CourseStatus) -> Result<()>;
+    async fn create(&self, course: &CreateCourse, audit_log: &CreateAuditLog) -> Result<CourseID>;
+    async fn update(
+        &self,
+        course_id: &CourseID,
+        req: &UpdateCourse,
+        audit_log: &CreateAuditLog,
+    ) -> Result<Course>;
+    async fn update_status_by_id(
+        &self,
+        course_id: &CourseID,
+        status: &CourseStatus,
+        is_override: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()>;
     async fn find_all_with_teacher(
         &self,
         limit: i64,
@@ -33,6 +113,23 @@ pub trait CourseService: Sync {
         course_id: &CourseID,
     ) -> Result<Option<CourseWithTeacher>>;
     async fn find_open_courses_by_user_id(&self, user_id: &UserID) -> Result<Vec<(Course, User)>>;
+    async fn find_prerequisites_by_course_id(
+        &self,
+        course_id: &CourseID,
+    ) -> Result<Vec<CoursePrerequisite>>;
+    async fn update_prerequisites(
+        &self,
+        course_id: &CourseID,
+        prerequisites: &[UpdateCoursePrerequisite],
+        audit_log: &CreateAuditLog,
+    ) -> Result<()>;
+    async fn find_students(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+        limit: Option<i64>,
+        offset: i64,
+    ) -> Result<Vec<CourseStudent>>;
 }
 
 pub trait HaveCourseService {
@@ -42,19 +139,30 @@ pub trait HaveCourseService {
 
 #[async_trait]
 pub trait CourseServiceImpl:
-    Sync + HaveDBPool + HaveUserRepository + HaveCourseRepository + HaveRegistrationCourseRepository
+    Sync
+    + HaveDBPool
+    + HaveUserRepository
+    + HaveCourseRepository
+    + HaveCoursePrerequisiteRepository
+    + HaveClassRepository
+    + HaveRegistrationCourseRepository
+    + HaveRegistrationRepository
+    + HaveAnnouncementRepository
+    + HaveUnreadAnnouncementRepository
+    + HaveUserProfileRepository
+    + HaveAuditLogRepository
 {
-    async fn create(&self, req: &CreateCourse) -> Result<CourseID> {
+    async fn create(&self, req: &CreateCourse, audit_log: &CreateAuditLog) -> Result<CourseID> {
         let db_pool = self.get_db_pool();
-        let mut conn = db_pool.acquire().await?;
+        let mut tx = db_pool.begin().await?;
 
         let course_repo = self.course_repo();
-        let result = course_repo.create(&mut conn, req).await;
-        match result {
-            Ok(course_id) => Ok(course_id),
+        let result = course_repo.create(&mut tx, req).await;
+        let course_id = match result {
+            Ok(course_id) => course_id,
             Err(e) => match e {
                 ReposError::CourseDuplicate => {
-                    let course = course_repo.find_by_code(&mut conn, &req.code).await?;
+                    let course = course_repo.find_by_code(&mut tx, &req.code).await?;
 
                     if req.type_ != course.type_
                         || req.name != course.name
@@ -63,18 +171,137 @@ pub trait CourseServiceImpl:
                         || req.period != course.period
                         || req.day_of_week != course.day_of_week
                         || req.keywords != course.keywords
+                        || req.capacity != course.capacity
                     {
                         return Err(e.into());
                     } else {
-                        return Ok(course.id);
+                        course.id
                     }
                 }
-                _ => Err(e.into()),
+                _ => return Err(e.into()),
             },
+        };
+
+        let mut audit_log = audit_log.clone();
+        audit_log.target_course_id = Some(course_id.clone());
+        create_audit_log(self.audit_log_repo(), &mut tx, &audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(course_id)
+    }
+
+    async fn update(
+        &self,
+        course_id: &CourseID,
+        req: &UpdateCourse,
+        audit_log: &CreateAuditLog,
+    ) -> Result<Course> {
+        validate_course(req)?;
+
+        let db_pool = self.get_db_pool();
+        let mut tx = db_pool.begin().await?;
+
+        // 同じ科目への履修登録と競合しないように、科目の行を排他ロックしてから検証する
+        let course_repo = self.course_repo();
+        if !course_repo.for_update_by_id(&mut tx, course_id).await? {
+            return Err(CourseNotFound);
         }
+        let course = match course_repo
+            .find_for_share_lock_by_id(&mut tx, course_id)
+            .await?
+        {
+            Some(course) => course,
+            None => return Err(CourseNotFound),
+        };
+        if course.status == CourseStatus::Closed {
+            return Err(CourseIsClosed);
+        }
+
+        let changes = match describe_course_changes(&course, req) {
+            Some(changes) => changes,
+            None => {
+                create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+                tx.commit().await?;
+                return Ok(course);
+            }
+        };
+
+        let students = self
+            .registration_repo()
+            .find_users_by_course_id(&mut tx, course_id)
+            .await?;
+
+        // 履修者がいる場合は、変更後の日時で各履修者の他の科目と重ならないことを確認する
+        if course.period != req.period || course.day_of_week != req.day_of_week {
+            let registration_course_repo = self.registration_course_repo();
+            for student in &students {
+                let registered_courses = registration_course_repo
+                    .find_open_courses_by_user_id(&mut tx, &student.id)
+                    .await?;
+                if registered_courses.iter().any(|registered| {
+                    registered.id != course.id
+                        && registered.period == req.period
+                        && registered.day_of_week == req.day_of_week
+                }) {
+                    return Err(CourseScheduleConflict);
+                }
+            }
+        }
+
+        course_repo.update_by_id(&mut tx, course_id, req).await?;
+
+        if !students.is_empty() {
+            let announcement = Announcement {
+                id: AnnouncementID::new(util::new_ulid().await),
+                course_id: course_id.clone(),
+                title: format!("{}の科目情報が変更されました", req.name),
+                message: changes,
+            };
+            self.announcement_repo()
+                .create(&mut tx, &announcement)
+                .await?;
+
+            // 科目情報の変更通知を受け取らない履修者には、お知らせを既読の状態で届ける
+            let unread_announcement_repo = self.unread_announcement_repo();
+            let user_profile_repo = self.user_profile_repo();
+            for student in &students {
+                unread_announcement_repo
+                    .create(&mut tx, &announcement.id, &student.id)
+                    .await?;
+                let profile = user_profile_repo
+                    .find_by_user_id(&mut tx, &student.id)
+                    .await?;
+                if matches!(profile, Some(profile) if !profile.notify_course_updates) {
+                    unread_announcement_repo
+                        .mark_read(&mut tx, &announcement.id, &student.id)
+                        .await?;
+                }
+            }
+        }
+
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(Course {
+            name: req.name.clone(),
+            description: req.description.clone(),
+            credit: req.credit,
+            period: req.period,
+            day_of_week: req.day_of_week.clone(),
+            keywords: req.keywords.clone(),
+            ..course
+        })
     }
 
-    async fn update_status_by_id(&self, course_id: &CourseID, status: &CourseStatus) -> Result<()> {
+    async fn update_status_by_id(
+        &self,
+        course_id: &CourseID,
+        status: &CourseStatus,
+        is_override: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
         let db_pool = self.get_db_pool();
         let course_repo = self.course_repo();
         let mut tx = db_pool.begin().await?;
@@ -83,16 +310,54 @@ pub trait CourseServiceImpl:
         if !is_exist {
             return Err(CourseNotFound);
         }
+        let current = match course_repo
+            .find_status_for_share_lock_by_id(&mut tx, course_id)
+            .await?
+        {
+            Some(current) => current,
+            None => return Err(CourseNotFound),
+        };
+
+        // 同じステータスへの変更は監査ログのみ残して成功とする
+        if current == *status {
+            create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+            tx.commit().await?;
+            return Ok(());
+        }
+        // 管理者による上書きの場合のみ、順序に沿わない遷移を許可する
+        if !is_override && !current.can_transition_to(status) {
+            return Err(InvalidCourseStatusTransition);
+        }
 
         course_repo
             .update_status_by_id(&mut tx, course_id, status)
             .await?;
+        self.on_status_transition(&mut tx, course_id, status)
+            .await?;
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
 
         tx.commit().await?;
 
         Ok(())
     }
 
+    // ステータス遷移に伴う副作用
+    async fn on_status_transition(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+        status: &CourseStatus,
+    ) -> Result<()> {
+        if *status == CourseStatus::Closed {
+            // 終了した科目の課題は提出できないように、全講義の提出を締め切る
+            self.class_repo()
+                .update_submission_closed_by_course_id(conn, course_id)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     async fn find_all_with_teacher(
         &self,
         limit: i64,
@@ -143,16 +408,136 @@ pub trait CourseServiceImpl:
 
         Ok(course)
     }
+
+    async fn find_prerequisites_by_course_id(
+        &self,
+        course_id: &CourseID,
+    ) -> Result<Vec<CoursePrerequisite>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+        let prerequisites = self
+            .course_prerequisite_repo()
+            .find_by_course_id(&mut conn, course_id)
+            .await?;
+
+        Ok(prerequisites)
+    }
+
+    // 履修者の一覧は科目の担当教員にのみ公開する
+    async fn find_students(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+        limit: Option<i64>,
+        offset: i64,
+    ) -> Result<Vec<CourseStudent>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let course = self
+            .course_repo()
+            .find_for_share_lock_by_id(&mut conn, course_id)
+            .await?
+            .ok_or(CourseNotFound)?;
+        if course.teacher_id != *teacher_id {
+            return Err(NotCourseTeacher);
+        }
+
+        let students = self
+            .registration_repo()
+            .find_students_by_course_id(&mut conn, course_id, limit, offset)
+            .await?;
+
+        Ok(students)
+    }
+
+    async fn update_prerequisites(
+        &self,
+        course_id: &CourseID,
+        prerequisites: &[UpdateCoursePrerequisite],
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let course_repo = self.course_repo();
+        if !course_repo.for_update_by_id(&mut tx, course_id).await? {
+            return Err(CourseNotFound);
+        }
+        let course = match course_repo
+            .find_for_share_lock_by_id(&mut tx, course_id)
+            .await?
+        {
+            Some(course) => course,
+            None => return Err(CourseNotFound),
+        };
+
+        for (i, prerequisite) in prerequisites.iter().enumerate() {
+            // 自分自身や同じ科目を重ねて事前科目にはできない
+            if prerequisite.code == course.code
+                || prerequisites[..i]
+                    .iter()
+                    .any(|other| other.code == prerequisite.code)
+            {
+                return Err(InvalidCoursePrerequisite);
+            }
+            if !course_repo
+                .exist_by_code(&mut tx, &prerequisite.code)
+                .await?
+            {
+                return Err(InvalidCoursePrerequisite);
+            }
+        }
+
+        // 事前科目は丸ごと置き換える
+        let course_prerequisite_repo = self.course_prerequisite_repo();
+        course_prerequisite_repo
+            .delete_by_course_id(&mut tx, course_id)
+            .await?;
+        for prerequisite in prerequisites {
+            course_prerequisite_repo
+                .create(
+                    &mut tx,
+                    &CoursePrerequisite {
+                        course_id: course_id.clone(),
+                        code: prerequisite.code.clone(),
+                        min_score: prerequisite.min_score,
+                    },
+                )
+                .await?;
+        }
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl<S: CourseServiceImpl> CourseService for S {
-    async fn create(&self, course: &CreateCourse) -> Result<CourseID> {
-        CourseServiceImpl::create(self, course).await
+    async fn create(&self, course: &CreateCourse, audit_log: &CreateAuditLog) -> Result<CourseID> {
+        CourseServiceImpl::create(self, course, audit_log).await
     }
 
-    async fn update_status_by_id(&self, course_id: &CourseID, status: &CourseStatus) -> Result<()> {
-        CourseServiceImpl::update_status_by_id(self, course_id, status).await
+    async fn update(
+        &self,
+        course_id: &CourseID,
+        req: &UpdateCourse,
+        audit_log: &CreateAuditLog,
+    ) -> Result<Course> {
+        CourseServiceImpl::update(self, course_id, req, audit_log).await
+    }
+
+    async fn update_status_by_id(
+        &self,
+        course_id: &CourseID,
+        status: &CourseStatus,
+        is_override: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        CourseServiceImpl::update_status_by_id(self, course_id, status, is_override, audit_log)
+            .await
     }
 
     async fn find_all_with_teacher(
@@ -174,4 +559,30 @@ impl<S: CourseServiceImpl> CourseService for S {
     async fn find_open_courses_by_user_id(&self, user_id: &UserID) -> Result<Vec<(Course, User)>> {
         CourseServiceImpl::find_open_courses_by_user_id(self, user_id).await
     }
+
+    async fn find_prerequisites_by_course_id(
+        &self,
+        course_id: &CourseID,
+    ) -> Result<Vec<CoursePrerequisite>> {
+        CourseServiceImpl::find_prerequisites_by_course_id(self, course_id).await
+    }
+
+    async fn update_prerequisites(
+        &self,
+        course_id: &CourseID,
+        prerequisites: &[UpdateCoursePrerequisite],
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        CourseServiceImpl::update_prerequisites(self, course_id, prerequisites, audit_log).await
+    }
+
+    async fn find_students(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+        limit: Option<i64>,
+        offset: i64,
+    ) -> Result<Vec<CourseStudent>> {
+        CourseServiceImpl::find_students(self, course_id, teacher_id, limit, offset).await
+    }
 }
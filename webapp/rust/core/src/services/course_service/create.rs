@@ -19,8 +19,15 @@ mod tests {
             .course_repo
             .expect_create()
             .returning(move |_, _| Ok(course_id.clone()));
+        let course_id = course.id.clone();
+        service
+            .audit_log_repo
+            .expect_create()
+            .withf(move |_, audit_log| audit_log.target_course_id.as_ref() == Some(&course_id))
+            .times(1)
+            .returning(|_, _| Ok(()));
 
-        let got = service.create(&course).await.unwrap();
+        let got = service.create(&course, &Faker.fake()).await.unwrap();
         assert_eq!(got, course.id)
     }
 
@@ -43,7 +50,7 @@ mod tests {
             .expect_find_by_code()
             .returning(move |_, _| Ok(c.clone()));
 
-        service.create(&course).await.unwrap();
+        service.create(&course, &Faker.fake()).await.unwrap();
     }
 
     #[tokio::test]
@@ -70,6 +77,7 @@ mod tests {
             teacher_id: Faker.fake(),
             keywords: course.keywords.clone(),
             status: Faker.fake(),
+            capacity: course.capacity,
         };
         let ccode = c.code.clone();
         service
@@ -77,8 +85,13 @@ mod tests {
             .expect_find_by_code()
             .withf(move |_, code| code == &ccode)
             .returning(move |_, _| Ok(c.clone()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
 
-        let got = service.create(&course).await.unwrap();
+        let got = service.create(&course, &Faker.fake()).await.unwrap();
         assert_eq!(got, course.id)
     }
 
@@ -95,6 +108,6 @@ mod tests {
             .expect_create()
             .returning(move |_, _| Err(TestError));
 
-        service.create(&course).await.unwrap();
+        service.create(&course, &Faker.fake()).await.unwrap();
     }
 }
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::Course;
+    use crate::models::course_student::CourseStudent;
+    use crate::models::user::UserID;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::course_service::CourseService;
+    use crate::services::error::Error;
+    use fake::{Fake, Faker};
+
+    fn expect_course(service: &mut MockRepositoryManager) -> Course {
+        let mut course: Course = Faker.fake();
+        course.teacher_id = UserID::new("teacher".to_string());
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        course
+    }
+
+    #[tokio::test]
+    async fn not_course_teacher_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = expect_course(&mut service);
+        service
+            .registration_repo
+            .expect_find_students_by_course_id()
+            .never();
+
+        let err = service
+            .find_students(&course.id, &UserID::new("other".to_string()), Some(20), 0)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::NotCourseTeacher));
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = expect_course(&mut service);
+        let student: CourseStudent = Faker.fake();
+        let expected = vec![student.clone()];
+        service
+            .registration_repo
+            .expect_find_students_by_course_id()
+            .withf(|_, _, limit, offset| *limit == Some(20) && *offset == 20)
+            .returning(move |_, _, _, _| Ok(vec![student.clone()]));
+
+        let students = service
+            .find_students(
+                &course.id,
+                &UserID::new("teacher".to_string()),
+                Some(20),
+                20,
+            )
+            .await
+            .unwrap();
+        assert_eq!(students, expected);
+    }
+}
@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::{Course, UpdateCourse};
+    use crate::models::course_status::CourseStatus;
+    use crate::models::day_of_week::DayOfWeek;
+    use crate::models::user::User;
+    use crate::models::user_profile::UserProfile;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::course_service::CourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    fn registration_course() -> Course {
+        let mut course: Course = Faker.fake();
+        course.name = "course".to_string();
+        course.credit = 1;
+        course.period = 1;
+        course.day_of_week = DayOfWeek::Monday;
+        course.status = CourseStatus::Registration;
+        course
+    }
+
+    fn update_course(course: &Course) -> UpdateCourse {
+        UpdateCourse {
+            name: course.name.clone(),
+            description: course.description.clone(),
+            credit: course.credit,
+            period: course.period,
+            day_of_week: course.day_of_week.clone(),
+            keywords: course.keywords.clone(),
+        }
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidCourse")]
+    async fn invalid_period_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = registration_course();
+        let mut req = update_course(&course);
+        req.period = 7;
+
+        service.course_repo.expect_update_by_id().never();
+
+        service
+            .update(&course.id, &req, &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CourseNotFound")]
+    async fn not_found_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = registration_course();
+
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(false));
+        service.course_repo.expect_update_by_id().never();
+
+        service
+            .update(&course.id, &update_course(&course), &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CourseIsClosed")]
+    async fn closed_course_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course = registration_course();
+        course.status = CourseStatus::Closed;
+        let mut req = update_course(&course);
+        req.name = "updated".to_string();
+
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service.course_repo.expect_update_by_id().never();
+
+        service
+            .update(&course.id, &req, &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CourseScheduleConflict")]
+    async fn schedule_conflict_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = registration_course();
+        let mut req = update_course(&course);
+        req.period = 2;
+
+        let mut other = registration_course();
+        other.period = 2;
+
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service
+            .registration_repo
+            .expect_find_users_by_course_id()
+            .returning(|_, _| Ok(vec![Faker.fake::<User>()]));
+        let registered = vec![course.clone(), other];
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .returning(move |_, _| Ok(registered.clone()));
+        service.course_repo.expect_update_by_id().never();
+        service.announcement_repo.expect_create().never();
+
+        service
+            .update(&course.id, &req, &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = registration_course();
+        let mut req = update_course(&course);
+        req.name = "updated".to_string();
+        req.period = 2;
+
+        let student: User = Faker.fake();
+        let muted_student: User = Faker.fake();
+        let mut muted_profile = UserProfile::new(muted_student.id.clone());
+        muted_profile.notify_course_updates = false;
+
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        let students = vec![student.clone(), muted_student.clone()];
+        service
+            .registration_repo
+            .expect_find_users_by_course_id()
+            .returning(move |_, _| Ok(students.clone()));
+        let registered = vec![course.clone()];
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .times(2)
+            .returning(move |_, _| Ok(registered.clone()));
+        let cid = course.id.clone();
+        service
+            .course_repo
+            .expect_update_by_id()
+            .withf(move |_, course_id, req| {
+                course_id == &cid && req.name == "updated" && req.period == 2
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let cid = course.id.clone();
+        service
+            .announcement_repo
+            .expect_create()
+            .withf(move |_, announcement| {
+                announcement.course_id == cid && announcement.message.contains("course → updated")
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .unread_announcement_repo
+            .expect_create()
+            .times(2)
+            .returning(|_, _, _| Ok(()));
+        let muted_id = muted_student.id.clone();
+        service
+            .user_profile_repo
+            .expect_find_by_user_id()
+            .returning(move |_, user_id| {
+                if user_id == &muted_id {
+                    Ok(Some(muted_profile.clone()))
+                } else {
+                    Ok(None)
+                }
+            });
+        // 通知を受け取らない履修者の分だけ既読にする
+        let muted_id = muted_student.id.clone();
+        service
+            .unread_announcement_repo
+            .expect_mark_read()
+            .withf(move |_, _, user_id| user_id == &muted_id)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let got = service
+            .update(&course.id, &req, &Faker.fake())
+            .await
+            .unwrap();
+        assert_eq!(got.name, "updated");
+        assert_eq!(got.period, 2);
+        assert_eq!(got.status, CourseStatus::Registration);
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::{Course, CourseCode};
+    use crate::models::course_prerequisite::UpdateCoursePrerequisite;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::course_service::CourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    fn locked(service: &mut MockRepositoryManager, course: &Course) {
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidCoursePrerequisite")]
+    async fn self_reference_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course: Course = Faker.fake();
+        locked(&mut service, &course);
+        service
+            .course_prerequisite_repo
+            .expect_delete_by_course_id()
+            .never();
+
+        service
+            .update_prerequisites(
+                &course.id,
+                &[UpdateCoursePrerequisite {
+                    code: course.code.clone(),
+                    min_score: 0,
+                }],
+                &Faker.fake(),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidCoursePrerequisite")]
+    async fn unknown_code_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course: Course = Faker.fake();
+        locked(&mut service, &course);
+        service
+            .course_repo
+            .expect_exist_by_code()
+            .returning(|_, _| Ok(false));
+        service
+            .course_prerequisite_repo
+            .expect_delete_by_course_id()
+            .never();
+
+        service
+            .update_prerequisites(
+                &course.id,
+                &[UpdateCoursePrerequisite {
+                    code: CourseCode::new("unknown".to_string()),
+                    min_score: 0,
+                }],
+                &Faker.fake(),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course: Course = Faker.fake();
+        locked(&mut service, &course);
+        service
+            .course_repo
+            .expect_exist_by_code()
+            .returning(|_, _| Ok(true));
+        service
+            .course_prerequisite_repo
+            .expect_delete_by_course_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let course_id = course.id.clone();
+        service
+            .course_prerequisite_repo
+            .expect_create()
+            .withf(move |_, prerequisite| {
+                prerequisite.course_id == course_id
+                    && prerequisite.code.inner() == "L0001"
+                    && prerequisite.min_score == 60
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        service
+            .update_prerequisites(
+                &course.id,
+                &[UpdateCoursePrerequisite {
+                    code: CourseCode::new("L0001".to_string()),
+                    min_score: 60,
+                }],
+                &Faker.fake(),
+            )
+            .await
+            .unwrap();
+    }
+}
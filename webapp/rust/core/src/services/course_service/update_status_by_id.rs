@@ -3,6 +3,7 @@ mod tests {
     use crate::db::get_test_db_conn;
     use crate::models::course::CourseID;
     use crate::models::course_status::CourseStatus;
+    use crate::repos::error::ReposError::TestError;
     use crate::repos::manager::tests::MockRepositoryManager;
     use crate::services::course_service::CourseServiceImpl;
     use fake::{Fake, Faker};
@@ -24,7 +25,7 @@ mod tests {
             .returning(|_, _| Ok(false));
 
         service
-            .update_status_by_id(&course_id, &status)
+            .update_status_by_id(&course_id, &status, false, &Faker.fake())
             .await
             .unwrap();
     }
@@ -35,7 +36,7 @@ mod tests {
         let mut service = MockRepositoryManager::new(conn);
 
         let course_id: CourseID = Faker.fake();
-        let status: CourseStatus = Faker.fake();
+        let status = CourseStatus::InProgress;
 
         let cid = course_id.clone();
         service
@@ -43,6 +44,10 @@ mod tests {
             .expect_for_update_by_id()
             .withf(move |_, course_id| course_id == &cid)
             .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_status_for_share_lock_by_id()
+            .returning(|_, _| Ok(Some(CourseStatus::Registration)));
 
         let cid = course_id.clone();
         let st = status.clone();
@@ -51,9 +56,120 @@ mod tests {
             .expect_update_status_by_id()
             .withf(move |_, course_id, status| course_id == &cid && status == &st)
             .returning(|_, _, _| Ok(()));
+        service
+            .class_repo
+            .expect_update_submission_closed_by_course_id()
+            .never();
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        service
+            .update_status_by_id(&course_id, &status, false, &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidCourseStatusTransition")]
+    async fn invalid_transition_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course_id: CourseID = Faker.fake();
+
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_status_for_share_lock_by_id()
+            .returning(|_, _| Ok(Some(CourseStatus::Closed)));
+        service.course_repo.expect_update_status_by_id().never();
+
+        service
+            .update_status_by_id(
+                &course_id,
+                &CourseStatus::Registration,
+                false,
+                &Faker.fake(),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn override_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course_id: CourseID = Faker.fake();
+
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_status_for_share_lock_by_id()
+            .returning(|_, _| Ok(Some(CourseStatus::Registration)));
+        service
+            .course_repo
+            .expect_update_status_by_id()
+            .withf(|_, _, status| status == &CourseStatus::Closed)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        // 科目の終了時は全講義の課題提出を締め切る
+        let cid = course_id.clone();
+        service
+            .class_repo
+            .expect_update_submission_closed_by_course_id()
+            .withf(move |_, course_id| course_id == &cid)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        service
+            .update_status_by_id(&course_id, &CourseStatus::Closed, true, &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    // 監査ログを書き込めなければ、ステータスの変更もコミットしない
+    #[tokio::test]
+    #[should_panic(expected = "ReposError(TestError)")]
+    async fn audit_log_error_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course_id: CourseID = Faker.fake();
+
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_status_for_share_lock_by_id()
+            .returning(|_, _| Ok(Some(CourseStatus::Registration)));
+        service
+            .course_repo
+            .expect_update_status_by_id()
+            .returning(|_, _, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .returning(|_, _| Err(TestError));
 
         service
-            .update_status_by_id(&course_id, &status)
+            .update_status_by_id(&course_id, &CourseStatus::InProgress, false, &Faker.fake())
             .await
             .unwrap();
     }
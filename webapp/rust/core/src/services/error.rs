@@ -1,4 +1,6 @@
+use crate::identity_providers::IdentityProviderError;
 use crate::models::course::CourseID;
+use crate::password_hashers::PasswordHasherError;
 use crate::repos::error::ReposError;
 use crate::storages::StorageError;
 use bcrypt::BcryptError;
@@ -17,6 +19,14 @@ pub enum Error {
     SqlxError(#[from] sqlx::Error),
     #[error("bcrypt error")]
     BcryptError(#[from] BcryptError),
+    #[error("password hasher error")]
+    PasswordHasherError(#[from] PasswordHasherError),
+    #[error("identity provider error")]
+    IdentityProviderError(#[from] IdentityProviderError),
+    #[error("serde json error")]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error("join error")]
+    JoinError(#[from] tokio::task::JoinError),
     #[error("no such announcement.")]
     AnnouncementNotFound,
     #[error("announcement is duplicated.")]
@@ -27,12 +37,80 @@ pub enum Error {
     ClassIsNotSubmissionClosed,
     #[error("no such course.")]
     CourseNotFound,
+    #[error("You are not a teacher of this course.")]
+    NotCourseTeacher,
     #[error("This course is not in progress")]
     CourseIsNotInProgress,
     #[error("A class with the same part already exists.")]
     CourseConflict,
+    #[error("Course name must not be empty, credit must be positive and period must be between 1 and 6.")]
+    InvalidCourse,
+    #[error("This course has already been closed.")]
+    CourseIsClosed,
+    #[error("The new schedule conflicts with other courses of registered students.")]
+    CourseScheduleConflict,
+    #[error("The course status cannot be changed in this order.")]
+    InvalidCourseStatusTransition,
+    #[error("Capacity must be a positive number.")]
+    InvalidCourseCapacity,
+    #[error("Credit limit must be a positive number.")]
+    InvalidCreditLimit,
+    #[error("Prerequisites must be other existing courses without duplicates.")]
+    InvalidCoursePrerequisite,
+    #[error("This course is not accepting registrations.")]
+    CourseNotRegistrable,
+    #[error("This course still has available seats.")]
+    CourseNotFull,
+    #[error("You have already registered for this course.")]
+    AlreadyRegistered,
+    #[error("This course can no longer be dropped.")]
+    CourseNotDroppable,
+    #[error("Registration window must have a term and end after it starts.")]
+    InvalidRegistrationWindow,
+    #[error("No such registration window.")]
+    RegistrationWindowNotFound,
+    #[error("Registration is not open for you now.")]
+    RegistrationClosed,
+    #[error("Lottery preferences must be distinct lottery courses accepting registrations.")]
+    InvalidLotteryPreference,
+    #[error("No such registration request.")]
+    RegistrationRequestNotFound,
+    #[error("The nonce in the ID token does not match.")]
+    OidcNonceMismatch,
+    #[error("The ID token does not have a valid user code claim.")]
+    OidcInvalidUserCodeClaim,
+    #[error("No user is associated with the identity.")]
+    OidcUserNotFound,
+    #[error("Two-factor authentication is already enabled.")]
+    TotpAlreadyEnabled,
+    #[error("Two-factor authentication is not enrolled.")]
+    TotpNotEnrolled,
+    #[error("The authentication code is invalid.")]
+    InvalidTotpCode,
+    #[error("Two-factor authentication is required for this account.")]
+    TotpRequired,
+    #[error("No such user to impersonate.")]
+    ImpersonationTargetNotFound,
+    #[error("Only students can be impersonated.")]
+    ImpersonationTargetNotAllowed,
+    #[error("No such personal access token.")]
+    PersonalAccessTokenNotFound,
+    #[error("No such user.")]
+    UserNotFound,
+    #[error("A user with the same code already exists.")]
+    UserDuplicate,
+    #[error("User code must be 6 alphanumeric characters.")]
+    InvalidUserCode,
+    #[error("User name must not be empty or longer than 255 characters.")]
+    InvalidUserName,
+    #[error("This user has been deactivated.")]
+    UserDeactivated,
+    #[error("Name reading must be written in kana and not longer than 255 characters.")]
+    InvalidNameKana,
+    #[error("Invalid email address.")]
+    InvalidEmail,
     #[error("validation error")]
-    RegistrationCourseValidationError(RegistrationCourseValidationError),
+    RegistrationCourseValidationError(Box<RegistrationCourseValidationError>),
     #[error("You have not taken this course.")]
     RegistrationAlready,
     #[error("Submission has been closed for this class.")]
@@ -46,4 +124,42 @@ pub struct RegistrationCourseValidationError {
     pub course_not_found: Vec<CourseID>,
     pub not_registrable_status: Vec<CourseID>,
     pub schedule_conflict: Vec<CourseID>,
+    // schedule_conflict の各科目がどの科目と時間割が重なっているか
+    pub schedule_conflict_details: Vec<ScheduleConflict>,
+    pub course_full: Vec<CourseID>,
+    pub prerequisite_not_met: Vec<CourseID>,
+    // 履修登録期間外の科目
+    pub registration_closed: Vec<CourseID>,
+    // 抽選でのみ履修者を決める科目
+    pub lottery_only: Vec<CourseID>,
+    pub credit_limit_exceeded: Option<CreditLimitExceeded>,
+}
+
+impl RegistrationCourseValidationError {
+    pub fn is_empty(&self) -> bool {
+        self.course_not_found.is_empty()
+            && self.not_registrable_status.is_empty()
+            && self.schedule_conflict.is_empty()
+            && self.course_full.is_empty()
+            && self.prerequisite_not_met.is_empty()
+            && self.registration_closed.is_empty()
+            && self.lottery_only.is_empty()
+            && self.credit_limit_exceeded.is_none()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScheduleConflict {
+    pub course_id: CourseID,
+    // 時間割が重なっている履修登録済みまたは今回追加しようとした科目すべて
+    pub conflicts_with: Vec<CourseID>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreditLimitExceeded {
+    pub credit_limit: u32,
+    // 履修登録済みの科目の単位数の合計
+    pub registered_credits: u32,
+    // 今回追加しようとした科目の単位数の合計
+    pub requested_credits: u32,
 }
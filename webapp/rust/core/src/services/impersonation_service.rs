@@ -0,0 +1,141 @@
+use crate::models::audit_action::AuditAction;
+use crate::models::audit_log::{AuditLogID, CreateAuditLog};
+use crate::models::user::{User, UserCode, UserID};
+use crate::models::user_type::UserType;
+use crate::repos::audit_log_repository::{AuditLogRepository, HaveAuditLogRepository};
+use crate::repos::user_repository::{HaveUserRepository, UserRepository};
+use crate::services::error::Error::{ImpersonationTargetNotAllowed, ImpersonationTargetNotFound};
+use crate::services::error::Result;
+use crate::services::HaveDBPool;
+use crate::util;
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime};
+
+mod start;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImpersonationConfig {
+    pub ttl_secs: i64,
+    // falseの場合、なりすまし中は参照系のリクエストのみ許可する
+    pub allow_writes: bool,
+}
+
+impl Default for ImpersonationConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: 30 * 60,
+            allow_writes: false,
+        }
+    }
+}
+
+impl ImpersonationConfig {
+    pub fn from_env() -> Self {
+        fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default)
+        }
+
+        let default = Self::default();
+        Self {
+            ttl_secs: env_or("IMPERSONATION_TTL_SECS", default.ttl_secs),
+            allow_writes: env_or("IMPERSONATION_ALLOW_WRITES", default.allow_writes),
+        }
+    }
+}
+
+pub trait HaveImpersonationConfig {
+    fn impersonation_config(&self) -> &ImpersonationConfig;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Impersonation {
+    pub user: User,
+    pub expires_at: NaiveDateTime,
+}
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait ImpersonationService: Sync {
+    fn allows_writes(&self) -> bool;
+    async fn start(&self, admin_id: &UserID, target_code: &UserCode) -> Result<Impersonation>;
+    async fn end(&self, admin_id: &UserID, target_id: &UserID, is_expired: bool) -> Result<()>;
+}
+
+pub trait HaveImpersonationService {
+    type Service: ImpersonationService;
+    fn impersonation_service(&self) -> &Self::Service;
+}
+
+#[async_trait]
+pub trait ImpersonationServiceImpl:
+    Sync + HaveDBPool + HaveUserRepository + HaveAuditLogRepository + HaveImpersonationConfig
+{
+    fn allows_writes(&self) -> bool {
+        self.impersonation_config().allow_writes
+    }
+
+    // 学生の画面を確認するための機能なので、なりすませるのは学生のみ
+    async fn start(&self, admin_id: &UserID, target_code: &UserCode) -> Result<Impersonation> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let user = match self
+            .user_repo()
+            .find_by_code(&mut conn, target_code)
+            .await?
+        {
+            None => return Err(ImpersonationTargetNotFound),
+            Some(user) => user,
+        };
+        if user.type_ != UserType::Student {
+            return Err(ImpersonationTargetNotAllowed);
+        }
+
+        let now = chrono::Utc::now().naive_utc();
+        let expires_at = now + Duration::seconds(self.impersonation_config().ttl_secs);
+        let mut form = CreateAuditLog::new(admin_id.clone(), AuditAction::ImpersonationStart, None);
+        form.target_user_id = Some(user.id.clone());
+        form.after_summary = Some(
+            serde_json::json!({ "expires_at": expires_at.and_utc().to_rfc3339() }).to_string(),
+        );
+        let audit_log = form.into_audit_log(AuditLogID::new(util::new_ulid().await), now);
+        self.audit_log_repo().create(&mut conn, &audit_log).await?;
+
+        Ok(Impersonation { user, expires_at })
+    }
+
+    async fn end(&self, admin_id: &UserID, target_id: &UserID, is_expired: bool) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let reason = if is_expired { "expired" } else { "ended" };
+        let mut form = CreateAuditLog::new(admin_id.clone(), AuditAction::ImpersonationEnd, None);
+        form.target_user_id = Some(target_id.clone());
+        form.after_summary = Some(serde_json::json!({ "reason": reason }).to_string());
+        let audit_log = form.into_audit_log(
+            AuditLogID::new(util::new_ulid().await),
+            chrono::Utc::now().naive_utc(),
+        );
+        self.audit_log_repo().create(&mut conn, &audit_log).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: ImpersonationServiceImpl> ImpersonationService for S {
+    fn allows_writes(&self) -> bool {
+        ImpersonationServiceImpl::allows_writes(self)
+    }
+
+    async fn start(&self, admin_id: &UserID, target_code: &UserCode) -> Result<Impersonation> {
+        ImpersonationServiceImpl::start(self, admin_id, target_code).await
+    }
+
+    async fn end(&self, admin_id: &UserID, target_id: &UserID, is_expired: bool) -> Result<()> {
+        ImpersonationServiceImpl::end(self, admin_id, target_id, is_expired).await
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::audit_action::AuditAction;
+    use crate::models::user::{User, UserCode, UserID};
+    use crate::models::user_type::UserType;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::impersonation_service::ImpersonationService;
+    use fake::{Fake, Faker};
+
+    #[tokio::test]
+    #[should_panic(expected = "ImpersonationTargetNotFound")]
+    async fn not_found_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_by_code()
+            .returning(|_, _| Ok(None));
+        service.audit_log_repo.expect_create().never();
+
+        service
+            .start(
+                &UserID::new("admin".to_string()),
+                &UserCode::new("S00001".to_string().into()),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ImpersonationTargetNotAllowed")]
+    async fn teacher_target_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut user: User = Faker.fake();
+        user.type_ = UserType::Teacher;
+        service
+            .user_repo
+            .expect_find_by_code()
+            .returning(move |_, _| Ok(Some(user.clone())));
+        service.audit_log_repo.expect_create().never();
+
+        service
+            .start(
+                &UserID::new("admin".to_string()),
+                &UserCode::new("T00001".to_string().into()),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut user: User = Faker.fake();
+        user.type_ = UserType::Student;
+        let u = user.clone();
+        service
+            .user_repo
+            .expect_find_by_code()
+            .returning(move |_, _| Ok(Some(u.clone())));
+        let target_id = user.id.clone();
+        service
+            .audit_log_repo
+            .expect_create()
+            .withf(move |_, audit_log| {
+                audit_log.actor_id.inner() == "admin"
+                    && audit_log.action == AuditAction::ImpersonationStart
+                    && audit_log.target_user_id.as_ref() == Some(&target_id)
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let before = chrono::Utc::now().naive_utc();
+        let impersonation = service
+            .start(
+                &UserID::new("admin".to_string()),
+                &UserCode::new("S00001".to_string().into()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(impersonation.user, user);
+        assert!(impersonation.expires_at >= before + chrono::Duration::seconds(30 * 60));
+    }
+}
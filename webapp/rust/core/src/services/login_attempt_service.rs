@@ -0,0 +1,223 @@
+use crate::models::login_attempt::{LoginAttempt, LoginAttemptID};
+use crate::repos::login_attempt_repository::{HaveLoginAttemptRepository, LoginAttemptRepository};
+use crate::services::error::Result;
+use crate::services::HaveDBPool;
+use crate::util;
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime};
+
+mod find_retry_after;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginThrottleConfig {
+    // この回数までの連続失敗は待ち時間なしで再試行できる
+    pub free_attempts: u32,
+    pub base_delay_secs: i64,
+    pub max_delay_secs: i64,
+    // ユーザーコードごとの連続失敗回数がこれに達するとロックアウトする
+    pub max_failures: u32,
+    // IPアドレスごとのlockout_secs以内の失敗回数がこれに達するとロックアウトする
+    pub max_failures_per_ip: u32,
+    pub lockout_secs: i64,
+}
+
+impl Default for LoginThrottleConfig {
+    fn default() -> Self {
+        Self {
+            free_attempts: 3,
+            base_delay_secs: 1,
+            max_delay_secs: 60,
+            max_failures: 10,
+            max_failures_per_ip: 100,
+            lockout_secs: 15 * 60,
+        }
+    }
+}
+
+impl LoginThrottleConfig {
+    pub fn from_env() -> Self {
+        fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default)
+        }
+
+        let default = Self::default();
+        Self {
+            free_attempts: env_or("LOGIN_THROTTLE_FREE_ATTEMPTS", default.free_attempts),
+            base_delay_secs: env_or("LOGIN_THROTTLE_BASE_DELAY_SECS", default.base_delay_secs),
+            max_delay_secs: env_or("LOGIN_THROTTLE_MAX_DELAY_SECS", default.max_delay_secs),
+            max_failures: env_or("LOGIN_THROTTLE_MAX_FAILURES", default.max_failures),
+            max_failures_per_ip: env_or(
+                "LOGIN_THROTTLE_MAX_FAILURES_PER_IP",
+                default.max_failures_per_ip,
+            ),
+            lockout_secs: env_or("LOGIN_THROTTLE_LOCKOUT_SECS", default.lockout_secs),
+        }
+    }
+
+    // attemptsは新しい順。直近の成功より後の連続失敗回数から、再試行可能になるまでの秒数を求める
+    pub fn retry_after(
+        &self,
+        attempts: &[LoginAttempt],
+        max_failures: u32,
+        now: &NaiveDateTime,
+    ) -> Option<i64> {
+        let failures = attempts.iter().take_while(|a| !a.succeeded).count() as u32;
+        if failures == 0 {
+            return None;
+        }
+        let last_failed_at = attempts[0].created_at;
+
+        let delay_secs = if failures >= max_failures {
+            self.lockout_secs
+        } else if failures >= self.free_attempts {
+            let exp = (failures - self.free_attempts).min(30);
+            self.base_delay_secs
+                .saturating_mul(1 << exp)
+                .min(self.max_delay_secs)
+        } else {
+            return None;
+        };
+
+        self.remaining_secs(&last_failed_at, delay_secs, now)
+    }
+
+    // attemptsは新しい順で、lockout_secs以内のもの。同じIPアドレスから別のユーザーで成功しても
+    // 数え直さないよう、成功を挟んでも期間内の失敗回数をすべて数え、上限に達したらロックアウトする
+    pub fn retry_after_per_ip(&self, attempts: &[LoginAttempt], now: &NaiveDateTime) -> Option<i64> {
+        let mut failed = attempts.iter().filter(|a| !a.succeeded);
+        let last_failed_at = failed.next()?.created_at;
+        let failures = 1 + failed.count() as u32;
+        if failures < self.max_failures_per_ip {
+            return None;
+        }
+
+        self.remaining_secs(&last_failed_at, self.lockout_secs, now)
+    }
+
+    fn remaining_secs(
+        &self,
+        last_failed_at: &NaiveDateTime,
+        delay_secs: i64,
+        now: &NaiveDateTime,
+    ) -> Option<i64> {
+        let remaining = *last_failed_at + Duration::seconds(delay_secs) - *now;
+        let remaining_millis = remaining.num_milliseconds();
+        if remaining_millis <= 0 {
+            return None;
+        }
+
+        Some((remaining_millis + 999) / 1000)
+    }
+}
+
+pub trait HaveLoginThrottleConfig {
+    fn login_throttle_config(&self) -> &LoginThrottleConfig;
+}
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait LoginAttemptService: Sync {
+    async fn find_retry_after(&self, user_code: &str, ip_address: &str) -> Result<Option<i64>>;
+    async fn record(&self, user_code: &str, ip_address: &str, succeeded: bool) -> Result<()>;
+    async fn find_all_failed(
+        &self,
+        user_code: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<LoginAttempt>>;
+}
+
+pub trait HaveLoginAttemptService {
+    type Service: LoginAttemptService;
+    fn login_attempt_service(&self) -> &Self::Service;
+}
+
+#[async_trait]
+pub trait LoginAttemptServiceImpl:
+    Sync + HaveDBPool + HaveLoginAttemptRepository + HaveLoginThrottleConfig
+{
+    async fn find_retry_after(&self, user_code: &str, ip_address: &str) -> Result<Option<i64>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let config = self.login_throttle_config();
+        let now = chrono::Utc::now().naive_utc();
+        let since = now - Duration::seconds(config.lockout_secs);
+
+        let repo = self.login_attempt_repo();
+        let user_attempts = repo
+            .find_all_by_user_code_since(&mut conn, user_code, &since)
+            .await?;
+        let ip_attempts = repo
+            .find_all_by_ip_address_since(&mut conn, ip_address, &since)
+            .await?;
+
+        let retry_after = [
+            config.retry_after(&user_attempts, config.max_failures, &now),
+            config.retry_after_per_ip(&ip_attempts, &now),
+        ]
+        .into_iter()
+        .flatten()
+        .max();
+
+        Ok(retry_after)
+    }
+
+    async fn record(&self, user_code: &str, ip_address: &str, succeeded: bool) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let attempt = LoginAttempt {
+            id: LoginAttemptID::new(util::new_ulid().await),
+            user_code: user_code.to_string(),
+            ip_address: ip_address.to_string(),
+            succeeded,
+            created_at: chrono::Utc::now().naive_utc(),
+        };
+        self.login_attempt_repo()
+            .create(&mut conn, &attempt)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_all_failed(
+        &self,
+        user_code: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<LoginAttempt>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let attempts = self
+            .login_attempt_repo()
+            .find_all_failed(&mut conn, user_code, limit, offset)
+            .await?;
+
+        Ok(attempts)
+    }
+}
+
+#[async_trait]
+impl<S: LoginAttemptServiceImpl> LoginAttemptService for S {
+    async fn find_retry_after(&self, user_code: &str, ip_address: &str) -> Result<Option<i64>> {
+        LoginAttemptServiceImpl::find_retry_after(self, user_code, ip_address).await
+    }
+
+    async fn record(&self, user_code: &str, ip_address: &str, succeeded: bool) -> Result<()> {
+        LoginAttemptServiceImpl::record(self, user_code, ip_address, succeeded).await
+    }
+
+    async fn find_all_failed(
+        &self,
+        user_code: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<LoginAttempt>> {
+        LoginAttemptServiceImpl::find_all_failed(self, user_code, limit, offset).await
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::login_attempt::LoginAttempt;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::login_attempt_service::{LoginAttemptService, LoginThrottleConfig};
+    use chrono::{Duration, NaiveDateTime};
+    use fake::{Fake, Faker};
+
+    fn build_attempts(now: &NaiveDateTime, succeeded: &[bool]) -> Vec<LoginAttempt> {
+        succeeded
+            .iter()
+            .enumerate()
+            .map(|(i, succeeded)| {
+                let mut attempt: LoginAttempt = Faker.fake();
+                attempt.succeeded = *succeeded;
+                attempt.created_at = *now - Duration::seconds(i as i64);
+                attempt
+            })
+            .collect()
+    }
+
+    #[test]
+    fn under_free_attempts_case() {
+        let config = LoginThrottleConfig::default();
+        let now = chrono::Utc::now().naive_utc();
+
+        let attempts = build_attempts(&now, &[false, false]);
+        assert_eq!(
+            config.retry_after(&attempts, config.max_failures, &now),
+            None
+        );
+    }
+
+    #[test]
+    fn backoff_case() {
+        let config = LoginThrottleConfig::default();
+        let now = chrono::Utc::now().naive_utc();
+
+        let attempts = build_attempts(&now, &[false; 3]);
+        assert_eq!(
+            config.retry_after(&attempts, config.max_failures, &now),
+            Some(1)
+        );
+        let attempts = build_attempts(&now, &[false; 5]);
+        assert_eq!(
+            config.retry_after(&attempts, config.max_failures, &now),
+            Some(4)
+        );
+        let attempts = build_attempts(&now, &[false; 9]);
+        assert_eq!(
+            config.retry_after(&attempts, config.max_failures, &now),
+            Some(config.max_delay_secs)
+        );
+    }
+
+    #[test]
+    fn lockout_case() {
+        let config = LoginThrottleConfig::default();
+        let now = chrono::Utc::now().naive_utc();
+
+        let attempts = build_attempts(&now, &[false; 10]);
+        assert_eq!(
+            config.retry_after(&attempts, config.max_failures, &now),
+            Some(config.lockout_secs)
+        );
+        let later = now + Duration::seconds(config.lockout_secs);
+        assert_eq!(
+            config.retry_after(&attempts, config.max_failures, &later),
+            None
+        );
+    }
+
+    #[test]
+    fn reset_by_success_case() {
+        let config = LoginThrottleConfig::default();
+        let now = chrono::Utc::now().naive_utc();
+
+        let mut succeeded = vec![false, false];
+        succeeded.push(true);
+        succeeded.extend([false; 10]);
+        let attempts = build_attempts(&now, &succeeded);
+        assert_eq!(
+            config.retry_after(&attempts, config.max_failures, &now),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn ip_address_lockout_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+        service.login_throttle_config.max_failures_per_ip = 3;
+        let lockout_secs = service.login_throttle_config.lockout_secs;
+
+        let now = chrono::Utc::now().naive_utc();
+        service
+            .login_attempt_repo
+            .expect_find_all_by_user_code_since()
+            .withf(|_, user_code, _| user_code == "000001")
+            .returning(|_, _, _| Ok(vec![]));
+        service
+            .login_attempt_repo
+            .expect_find_all_by_ip_address_since()
+            .withf(|_, ip_address, _| ip_address == "127.0.0.1")
+            .returning(move |_, _, _| Ok(build_attempts(&now, &[false; 3])));
+
+        let result = service
+            .find_retry_after("000001", "127.0.0.1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(result > 0 && result <= lockout_secs);
+    }
+
+    #[tokio::test]
+    async fn ip_address_not_reset_by_success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+        service.login_throttle_config.max_failures_per_ip = 3;
+
+        let now = chrono::Utc::now().naive_utc();
+        service
+            .login_attempt_repo
+            .expect_find_all_by_user_code_since()
+            .returning(|_, _, _| Ok(vec![]));
+        // 他人のアカウントへの失敗の合間に、同じIPアドレスから自分のアカウントでログインに成功している
+        service
+            .login_attempt_repo
+            .expect_find_all_by_ip_address_since()
+            .returning(move |_, _, _| {
+                Ok(build_attempts(&now, &[true, false, true, false, false]))
+            });
+
+        let result = service
+            .find_retry_after("000001", "127.0.0.1")
+            .await
+            .unwrap();
+        assert!(result.is_some());
+    }
+}
@@ -1,9 +1,16 @@
 use crate::services::announcement_service::HaveAnnouncementService;
+use crate::services::audit_log_service::HaveAuditLogService;
 use crate::services::class_service::HaveClassService;
 use crate::services::course_service::HaveCourseService;
 use crate::services::grade_summary_service::HaveGradeSummaryService;
+use crate::services::impersonation_service::HaveImpersonationService;
+use crate::services::login_attempt_service::HaveLoginAttemptService;
+use crate::services::oidc_login_service::HaveOidcLoginService;
+use crate::services::personal_access_token_service::HavePersonalAccessTokenService;
+use crate::services::personal_data_service::HavePersonalDataService;
 use crate::services::registration_course_service::HaveRegistrationCourseService;
 use crate::services::submission_service::HaveSubmissionService;
+use crate::services::two_factor_service::HaveTwoFactorService;
 use crate::services::unread_announcement_service::HaveUnreadAnnouncementService;
 use crate::services::user_service::HaveUserService;
 
@@ -16,22 +23,44 @@ pub trait ServiceManager:
     + HaveRegistrationCourseService
     + HaveGradeSummaryService
     + HaveSubmissionService
+    + HavePersonalAccessTokenService
+    + HavePersonalDataService
+    + HaveLoginAttemptService
+    + HaveOidcLoginService
+    + HaveTwoFactorService
+    + HaveImpersonationService
+    + HaveAuditLogService
 {
 }
 
 #[cfg(any(test, feature = "test"))]
 pub mod tests {
     use crate::services::announcement_service::{HaveAnnouncementService, MockAnnouncementService};
+    use crate::services::audit_log_service::{HaveAuditLogService, MockAuditLogService};
     use crate::services::class_service::{HaveClassService, MockClassService};
     use crate::services::course_service::{HaveCourseService, MockCourseService};
     use crate::services::grade_summary_service::{
         HaveGradeSummaryService, MockGradeSummaryService,
     };
+    use crate::services::impersonation_service::{
+        HaveImpersonationService, MockImpersonationService,
+    };
+    use crate::services::login_attempt_service::{
+        HaveLoginAttemptService, MockLoginAttemptService,
+    };
     use crate::services::manager::ServiceManager;
+    use crate::services::oidc_login_service::{HaveOidcLoginService, MockOidcLoginService};
+    use crate::services::personal_access_token_service::{
+        HavePersonalAccessTokenService, MockPersonalAccessTokenService,
+    };
+    use crate::services::personal_data_service::{
+        HavePersonalDataService, MockPersonalDataService,
+    };
     use crate::services::registration_course_service::{
         HaveRegistrationCourseService, MockRegistrationCourseService,
     };
     use crate::services::submission_service::{HaveSubmissionService, MockSubmissionService};
+    use crate::services::two_factor_service::{HaveTwoFactorService, MockTwoFactorService};
     use crate::services::unread_announcement_service::{
         HaveUnreadAnnouncementService, MockUnreadAnnouncementService,
     };
@@ -46,6 +75,13 @@ pub mod tests {
         pub registration_course_service: MockRegistrationCourseService,
         pub grade_summary_service: MockGradeSummaryService,
         pub submission_service: MockSubmissionService,
+        pub personal_access_token_service: MockPersonalAccessTokenService,
+        pub personal_data_service: MockPersonalDataService,
+        pub login_attempt_service: MockLoginAttemptService,
+        pub oidc_login_service: MockOidcLoginService,
+        pub two_factor_service: MockTwoFactorService,
+        pub impersonation_service: MockImpersonationService,
+        pub audit_log_service: MockAuditLogService,
     }
 
     impl Default for MockServiceManager {
@@ -65,6 +101,13 @@ pub mod tests {
                 registration_course_service: MockRegistrationCourseService::new(),
                 grade_summary_service: MockGradeSummaryService::new(),
                 submission_service: MockSubmissionService::new(),
+                personal_access_token_service: MockPersonalAccessTokenService::new(),
+                personal_data_service: MockPersonalDataService::new(),
+                login_attempt_service: MockLoginAttemptService::new(),
+                oidc_login_service: MockOidcLoginService::new(),
+                two_factor_service: MockTwoFactorService::new(),
+                impersonation_service: MockImpersonationService::new(),
+                audit_log_service: MockAuditLogService::new(),
             }
         }
     }
@@ -134,4 +177,60 @@ pub mod tests {
             &self.submission_service
         }
     }
+
+    impl HavePersonalDataService for MockServiceManager {
+        type Service = MockPersonalDataService;
+
+        fn personal_data_service(&self) -> &Self::Service {
+            &self.personal_data_service
+        }
+    }
+
+    impl HavePersonalAccessTokenService for MockServiceManager {
+        type Service = MockPersonalAccessTokenService;
+
+        fn personal_access_token_service(&self) -> &Self::Service {
+            &self.personal_access_token_service
+        }
+    }
+
+    impl HaveLoginAttemptService for MockServiceManager {
+        type Service = MockLoginAttemptService;
+
+        fn login_attempt_service(&self) -> &Self::Service {
+            &self.login_attempt_service
+        }
+    }
+
+    impl HaveOidcLoginService for MockServiceManager {
+        type Service = MockOidcLoginService;
+
+        fn oidc_login_service(&self) -> &Self::Service {
+            &self.oidc_login_service
+        }
+    }
+
+    impl HaveTwoFactorService for MockServiceManager {
+        type Service = MockTwoFactorService;
+
+        fn two_factor_service(&self) -> &Self::Service {
+            &self.two_factor_service
+        }
+    }
+
+    impl HaveImpersonationService for MockServiceManager {
+        type Service = MockImpersonationService;
+
+        fn impersonation_service(&self) -> &Self::Service {
+            &self.impersonation_service
+        }
+    }
+
+    impl HaveAuditLogService for MockServiceManager {
+        type Service = MockAuditLogService;
+
+        fn audit_log_service(&self) -> &Self::Service {
+            &self.audit_log_service
+        }
+    }
 }
@@ -0,0 +1,190 @@
+use crate::identity_providers::oidc_provider::{HaveOidcProvider, IdTokenClaims, OidcProvider};
+use crate::models::user::{User, UserCode, UserID};
+use crate::models::user_type::UserType;
+use crate::password_hashers::{HavePasswordHasher, PasswordHasher};
+use crate::repos::error::ReposError;
+use crate::repos::user_repository::{HaveUserRepository, UserRepository};
+use crate::services::error::Error::{
+    OidcInvalidUserCodeClaim, OidcNonceMismatch, OidcUserNotFound, UserDeactivated,
+};
+use crate::services::error::Result;
+use crate::services::HaveDBPool;
+use crate::util;
+use async_trait::async_trait;
+use rand::RngCore;
+
+mod login;
+
+const USER_CODE_MAX_LENGTH: usize = 6;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OidcUserMappingConfig {
+    // users.codeに対応付けるクレーム
+    pub code_claim: String,
+    pub name_claim: String,
+    // 未登録のユーザーがログインしたときにユーザーを作成する
+    pub jit_provisioning: bool,
+    // このクレームにteacher_claim_valueが含まれていれば教員として作成する
+    pub user_type_claim: String,
+    pub teacher_claim_value: String,
+}
+
+impl Default for OidcUserMappingConfig {
+    fn default() -> Self {
+        Self {
+            code_claim: "sub".to_string(),
+            name_claim: "name".to_string(),
+            jit_provisioning: false,
+            user_type_claim: "groups".to_string(),
+            teacher_claim_value: "teacher".to_string(),
+        }
+    }
+}
+
+impl OidcUserMappingConfig {
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            code_claim: std::env::var("OIDC_CODE_CLAIM").unwrap_or(default.code_claim),
+            name_claim: std::env::var("OIDC_NAME_CLAIM").unwrap_or(default.name_claim),
+            jit_provisioning: std::env::var("OIDC_JIT_PROVISIONING")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.jit_provisioning),
+            user_type_claim: std::env::var("OIDC_USER_TYPE_CLAIM")
+                .unwrap_or(default.user_type_claim),
+            teacher_claim_value: std::env::var("OIDC_TEACHER_CLAIM_VALUE")
+                .unwrap_or(default.teacher_claim_value),
+        }
+    }
+
+    pub fn user_type(&self, claims: &IdTokenClaims) -> UserType {
+        if claims.claim_contains(&self.user_type_claim, &self.teacher_claim_value) {
+            UserType::Teacher
+        } else {
+            UserType::Student
+        }
+    }
+}
+
+pub trait HaveOidcUserMappingConfig {
+    fn oidc_user_mapping_config(&self) -> &OidcUserMappingConfig;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OidcAuthorizationRequest {
+    pub url: String,
+    pub state: String,
+    pub nonce: String,
+}
+
+fn generate_random_string() -> String {
+    let mut buf = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut buf);
+    hex::encode(buf)
+}
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait OidcLoginService: Sync {
+    fn is_enabled(&self) -> bool;
+    fn authorization_request(&self) -> OidcAuthorizationRequest;
+    async fn login(&self, code: &str, nonce: &str) -> Result<User>;
+}
+
+pub trait HaveOidcLoginService {
+    type Service: OidcLoginService;
+    fn oidc_login_service(&self) -> &Self::Service;
+}
+
+#[async_trait]
+pub trait OidcLoginServiceImpl:
+    Sync
+    + HaveDBPool
+    + HaveUserRepository
+    + HaveOidcProvider
+    + HaveOidcUserMappingConfig
+    + HavePasswordHasher
+{
+    fn is_enabled(&self) -> bool {
+        self.oidc_provider().is_enabled()
+    }
+
+    fn authorization_request(&self) -> OidcAuthorizationRequest {
+        let state = generate_random_string();
+        let nonce = generate_random_string();
+        let url = self.oidc_provider().authorization_url(&state, &nonce);
+
+        OidcAuthorizationRequest { url, state, nonce }
+    }
+
+    async fn login(&self, code: &str, nonce: &str) -> Result<User> {
+        let claims = self.oidc_provider().exchange_code(code).await?;
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(OidcNonceMismatch);
+        }
+
+        let config = self.oidc_user_mapping_config();
+        let user_code = match claims.claim_str(&config.code_claim) {
+            Some(user_code) if !user_code.is_empty() => user_code.to_string(),
+            _ => return Err(OidcInvalidUserCodeClaim),
+        };
+
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let user_repo = self.user_repo();
+        let code = UserCode::new(user_code.clone().into());
+        if let Some(user) = user_repo.find_by_code(&mut conn, &code).await? {
+            if !user.is_active() {
+                return Err(UserDeactivated);
+            }
+            return Ok(user);
+        }
+
+        if !config.jit_provisioning {
+            return Err(OidcUserNotFound);
+        }
+        if user_code.chars().count() > USER_CODE_MAX_LENGTH {
+            return Err(OidcInvalidUserCodeClaim);
+        }
+
+        // IdP経由でのみログインするユーザーなので、推測できないパスワードを設定しておく
+        let hashed_password = self.password_hasher().hash(&generate_random_string())?;
+        let user = User {
+            id: UserID::new(util::new_ulid().await),
+            code: code.clone(),
+            name: claims
+                .claim_str(&config.name_claim)
+                .unwrap_or(&user_code)
+                .to_string(),
+            hashed_password: hashed_password.into_bytes(),
+            type_: config.user_type(&claims),
+            deactivated_at: None,
+        };
+        match user_repo.create(&mut conn, &user).await {
+            Ok(()) => Ok(user),
+            // 同時にログインした別のリクエストが先に作成した場合はそのユーザーを使う
+            Err(ReposError::UserDuplicate) => user_repo
+                .find_by_code(&mut conn, &code)
+                .await?
+                .ok_or(OidcUserNotFound),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: OidcLoginServiceImpl> OidcLoginService for S {
+    fn is_enabled(&self) -> bool {
+        OidcLoginServiceImpl::is_enabled(self)
+    }
+
+    fn authorization_request(&self) -> OidcAuthorizationRequest {
+        OidcLoginServiceImpl::authorization_request(self)
+    }
+
+    async fn login(&self, code: &str, nonce: &str) -> Result<User> {
+        OidcLoginServiceImpl::login(self, code, nonce).await
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::identity_providers::oidc_provider::IdTokenClaims;
+    use crate::models::user::User;
+    use crate::models::user_type::UserType;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::oidc_login_service::OidcLoginService;
+    use fake::{Fake, Faker};
+
+    fn claims(sub: &str, nonce: &str, extra: serde_json::Value) -> IdTokenClaims {
+        IdTokenClaims {
+            sub: sub.to_string(),
+            nonce: Some(nonce.to_string()),
+            extra: extra.as_object().unwrap().clone(),
+        }
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "OidcNonceMismatch")]
+    async fn nonce_mismatch_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .oidc_provider
+            .expect_exchange_code()
+            .withf(|code| code == "code")
+            .returning(|_| Ok(claims("S00001", "other", serde_json::json!({}))));
+        service.user_repo.expect_find_by_code().never();
+
+        service.login("code", "nonce").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn existing_user_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+        service.oidc_user_mapping_config.code_claim = "preferred_username".to_string();
+
+        service.oidc_provider.expect_exchange_code().returning(|_| {
+            Ok(claims(
+                "subject",
+                "nonce",
+                serde_json::json!({"preferred_username": "S00001"}),
+            ))
+        });
+        let user: User = Faker.fake();
+        let u = user.clone();
+        service
+            .user_repo
+            .expect_find_by_code()
+            .withf(|_, code| code.inner().to_string() == "S00001")
+            .returning(move |_, _| Ok(Some(u.clone())));
+        service.user_repo.expect_create().never();
+
+        let got = service.login("code", "nonce").await.unwrap();
+        assert_eq!(got, user);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "OidcUserNotFound")]
+    async fn not_found_without_jit_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .oidc_provider
+            .expect_exchange_code()
+            .returning(|_| Ok(claims("S00001", "nonce", serde_json::json!({}))));
+        service
+            .user_repo
+            .expect_find_by_code()
+            .returning(|_, _| Ok(None));
+        service.user_repo.expect_create().never();
+
+        service.login("code", "nonce").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn jit_provisioning_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+        service.oidc_user_mapping_config.jit_provisioning = true;
+
+        service.oidc_provider.expect_exchange_code().returning(|_| {
+            Ok(claims(
+                "T00001",
+                "nonce",
+                serde_json::json!({"name": "Teacher", "groups": ["staff", "teacher"]}),
+            ))
+        });
+        service
+            .user_repo
+            .expect_find_by_code()
+            .returning(|_, _| Ok(None));
+        // パスワードもポリシーで指定されたアルゴリズムでハッシュ化する
+        service
+            .password_hasher
+            .expect_hash()
+            .times(1)
+            .returning(|_| Ok("hashed".to_string()));
+        service
+            .user_repo
+            .expect_create()
+            .withf(|_, user| {
+                user.code.inner().to_string() == "T00001"
+                    && user.name == "Teacher"
+                    && user.type_ == UserType::Teacher
+                    && user.hashed_password == b"hashed"
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let got = service.login("code", "nonce").await.unwrap();
+        assert_eq!(got.type_, UserType::Teacher);
+        assert_eq!(got.name, "Teacher");
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "OidcInvalidUserCodeClaim")]
+    async fn jit_provisioning_too_long_code_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+        service.oidc_user_mapping_config.jit_provisioning = true;
+
+        service
+            .oidc_provider
+            .expect_exchange_code()
+            .returning(|_| Ok(claims("0123456789abcdef", "nonce", serde_json::json!({}))));
+        service
+            .user_repo
+            .expect_find_by_code()
+            .returning(|_, _| Ok(None));
+        service.user_repo.expect_create().never();
+
+        service.login("code", "nonce").await.unwrap();
+    }
+}
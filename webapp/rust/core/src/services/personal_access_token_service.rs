@@ -0,0 +1,156 @@
+use crate::models::personal_access_token::{
+    CreatePersonalAccessToken, PersonalAccessToken, PersonalAccessTokenID,
+};
+use crate::models::user::{User, UserID};
+use crate::repos::personal_access_token_repository::{
+    HavePersonalAccessTokenRepository, PersonalAccessTokenRepository,
+};
+use crate::repos::user_repository::{HaveUserRepository, UserRepository};
+use crate::services::error::Error::PersonalAccessTokenNotFound;
+use crate::services::error::Result;
+use crate::services::HaveDBPool;
+use crate::util;
+use async_trait::async_trait;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+mod authenticate;
+mod create;
+
+const TOKEN_BYTES: usize = 32;
+
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+fn generate_token() -> String {
+    let mut buf = [0u8; TOKEN_BYTES];
+    rand::thread_rng().fill_bytes(&mut buf);
+    hex::encode(buf)
+}
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait PersonalAccessTokenService: Sync {
+    async fn create(
+        &self,
+        user_id: &UserID,
+        req: &CreatePersonalAccessToken,
+    ) -> Result<(PersonalAccessToken, String)>;
+    async fn find_all_by_user_id(&self, user_id: &UserID) -> Result<Vec<PersonalAccessToken>>;
+    async fn delete(&self, user_id: &UserID, id: &PersonalAccessTokenID) -> Result<()>;
+    async fn authenticate(&self, token: &str) -> Result<Option<(User, PersonalAccessToken)>>;
+}
+
+pub trait HavePersonalAccessTokenService {
+    type Service: PersonalAccessTokenService;
+    fn personal_access_token_service(&self) -> &Self::Service;
+}
+
+#[async_trait]
+pub trait PersonalAccessTokenServiceImpl:
+    Sync + HaveDBPool + HavePersonalAccessTokenRepository + HaveUserRepository
+{
+    // 発行したトークンはレスポンスでのみ返し、DBにはハッシュ値を保存する
+    async fn create(
+        &self,
+        user_id: &UserID,
+        req: &CreatePersonalAccessToken,
+    ) -> Result<(PersonalAccessToken, String)> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let token = generate_token();
+        let now = chrono::Utc::now().naive_utc();
+        let personal_access_token = PersonalAccessToken {
+            id: PersonalAccessTokenID::new(util::new_ulid().await),
+            user_id: user_id.clone(),
+            name: req.name.clone(),
+            hashed_token: hash_token(&token),
+            scope: req.scope.clone(),
+            expires_at: now + chrono::Duration::days(req.expires_in_days),
+            created_at: now,
+        };
+        self.personal_access_token_repo()
+            .create(&mut conn, &personal_access_token)
+            .await?;
+
+        Ok((personal_access_token, token))
+    }
+
+    async fn find_all_by_user_id(&self, user_id: &UserID) -> Result<Vec<PersonalAccessToken>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let tokens = self
+            .personal_access_token_repo()
+            .find_all_by_user_id(&mut conn, user_id)
+            .await?;
+
+        Ok(tokens)
+    }
+
+    async fn delete(&self, user_id: &UserID, id: &PersonalAccessTokenID) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let is_deleted = self
+            .personal_access_token_repo()
+            .delete_by_id_and_user_id(&mut conn, id, user_id)
+            .await?;
+        if !is_deleted {
+            return Err(PersonalAccessTokenNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn authenticate(&self, token: &str) -> Result<Option<(User, PersonalAccessToken)>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let now = chrono::Utc::now().naive_utc();
+        let personal_access_token = self
+            .personal_access_token_repo()
+            .find_unexpired_by_hashed_token(&mut conn, &hash_token(token), &now)
+            .await?;
+        let personal_access_token = match personal_access_token {
+            None => return Ok(None),
+            Some(personal_access_token) => personal_access_token,
+        };
+
+        let user = self
+            .user_repo()
+            .find(&mut conn, &personal_access_token.user_id)
+            .await?;
+        // 無効化されたユーザーのトークンは使えない
+        if !user.is_active() {
+            return Ok(None);
+        }
+
+        Ok(Some((user, personal_access_token)))
+    }
+}
+
+#[async_trait]
+impl<S: PersonalAccessTokenServiceImpl> PersonalAccessTokenService for S {
+    async fn create(
+        &self,
+        user_id: &UserID,
+        req: &CreatePersonalAccessToken,
+    ) -> Result<(PersonalAccessToken, String)> {
+        PersonalAccessTokenServiceImpl::create(self, user_id, req).await
+    }
+
+    async fn find_all_by_user_id(&self, user_id: &UserID) -> Result<Vec<PersonalAccessToken>> {
+        PersonalAccessTokenServiceImpl::find_all_by_user_id(self, user_id).await
+    }
+
+    async fn delete(&self, user_id: &UserID, id: &PersonalAccessTokenID) -> Result<()> {
+        PersonalAccessTokenServiceImpl::delete(self, user_id, id).await
+    }
+
+    async fn authenticate(&self, token: &str) -> Result<Option<(User, PersonalAccessToken)>> {
+        PersonalAccessTokenServiceImpl::authenticate(self, token).await
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::personal_access_token::PersonalAccessToken;
+    use crate::models::user::User;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::personal_access_token_service::{hash_token, PersonalAccessTokenService};
+    use fake::{Fake, Faker};
+
+    #[tokio::test]
+    async fn not_found_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let hashed_token = hash_token("token");
+        service
+            .personal_access_token_repo
+            .expect_find_unexpired_by_hashed_token()
+            .withf(move |_, token, _| token == hashed_token)
+            .returning(|_, _, _| Ok(None));
+
+        let result = service.authenticate("token").await.unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let user: User = Faker.fake();
+        let mut token: PersonalAccessToken = Faker.fake();
+        token.user_id = user.id.clone();
+        token.hashed_token = hash_token("token");
+
+        let hashed_token = token.hashed_token.clone();
+        let t = token.clone();
+        service
+            .personal_access_token_repo
+            .expect_find_unexpired_by_hashed_token()
+            .withf(move |_, token, _| token == hashed_token)
+            .returning(move |_, _, _| Ok(Some(t.clone())));
+
+        let uid = user.id.clone();
+        let u = user.clone();
+        service
+            .user_repo
+            .expect_find()
+            .withf(move |_, user_id| user_id == &uid)
+            .returning(move |_, _| Ok(u.clone()));
+
+        let (got_user, got_token) = service.authenticate("token").await.unwrap().unwrap();
+
+        assert_eq!(got_user, user);
+        assert_eq!(got_token, token);
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::personal_access_token::CreatePersonalAccessToken;
+    use crate::models::personal_access_token_scope::PersonalAccessTokenScope;
+    use crate::models::user::UserID;
+    use crate::repos::error::ReposError::TestError;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::personal_access_token_service::{hash_token, PersonalAccessTokenService};
+    use fake::{Fake, Faker};
+
+    #[tokio::test]
+    #[should_panic(expected = "ReposError(TestError)")]
+    async fn error_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .personal_access_token_repo
+            .expect_create()
+            .returning(|_, _| Err(TestError));
+
+        let user_id: UserID = Faker.fake();
+        let req = CreatePersonalAccessToken {
+            name: "script".to_string(),
+            scope: PersonalAccessTokenScope::Read,
+            expires_in_days: 30,
+        };
+        service.create(&user_id, &req).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let user_id: UserID = Faker.fake();
+        let uid = user_id.clone();
+        service
+            .personal_access_token_repo
+            .expect_create()
+            .withf(move |_, token| {
+                token.user_id == uid
+                    && token.name == "script"
+                    && token.scope == PersonalAccessTokenScope::Write
+                    && token.expires_at - token.created_at == chrono::Duration::days(30)
+            })
+            .returning(|_, _| Ok(()));
+
+        let req = CreatePersonalAccessToken {
+            name: "script".to_string(),
+            scope: PersonalAccessTokenScope::Write,
+            expires_in_days: 30,
+        };
+        let (token, plain_token) = service.create(&user_id, &req).await.unwrap();
+
+        assert_ne!(token.hashed_token, plain_token);
+        assert_eq!(token.hashed_token, hash_token(&plain_token));
+    }
+}
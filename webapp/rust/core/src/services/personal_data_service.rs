@@ -0,0 +1,246 @@
+use crate::models::audit_log::CreateAuditLog;
+use crate::models::personal_data::PersonalData;
+use crate::models::user::{UserCode, UserID};
+use crate::password_hashers::{HavePasswordHasher, PasswordHasher};
+use crate::repos::audit_log_repository::{AuditLogRepository, HaveAuditLogRepository};
+use crate::repos::credit_limit_repository::{CreditLimitRepository, HaveCreditLimitRepository};
+use crate::repos::login_attempt_repository::{HaveLoginAttemptRepository, LoginAttemptRepository};
+use crate::repos::lottery_preference_repository::{
+    HaveLotteryPreferenceRepository, LotteryPreferenceRepository,
+};
+use crate::repos::personal_access_token_repository::{
+    HavePersonalAccessTokenRepository, PersonalAccessTokenRepository,
+};
+use crate::repos::registration_course_repository::{
+    HaveRegistrationCourseRepository, RegistrationCourseRepository,
+};
+use crate::repos::registration_request_repository::{
+    HaveRegistrationRequestRepository, RegistrationRequestRepository,
+};
+use crate::repos::submission_repository::{HaveSubmissionRepository, SubmissionRepository};
+use crate::repos::unread_announcement_repository::{
+    HaveUnreadAnnouncementRepository, UnreadAnnouncementRepository,
+};
+use crate::repos::user_profile_repository::{HaveUserProfileRepository, UserProfileRepository};
+use crate::repos::user_recovery_code_repository::{
+    HaveUserRecoveryCodeRepository, UserRecoveryCodeRepository,
+};
+use crate::repos::user_repository::{HaveUserRepository, UserRepository};
+use crate::repos::user_totp_secret_repository::{
+    HaveUserTotpSecretRepository, UserTotpSecretRepository,
+};
+use crate::repos::waitlist_repository::{HaveWaitlistRepository, WaitlistRepository};
+use crate::services::audit_log_service::create_audit_log;
+use crate::services::error::Error::UserNotFound;
+use crate::services::error::Result;
+use crate::services::HaveDBPool;
+use crate::storages::personal_data_storage::{HavePersonalDataStorage, PersonalDataStorage};
+use async_trait::async_trait;
+use rand::distributions::{Alphanumeric, DistString};
+
+mod erase;
+mod export;
+
+const ERASED_USER_NAME: &str = "erased user";
+const ERASED_FILE_NAME: &str = "erased.pdf";
+
+// 仮名化したユーザーコード。英数字以外で始まるため、通常のユーザーコードとは衝突しない
+fn new_erased_user_code() -> UserCode {
+    let suffix = Alphanumeric.sample_string(&mut rand::thread_rng(), 5);
+    UserCode::new(format!("~{}", suffix).into())
+}
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait PersonalDataService: Sync {
+    async fn export(&self, user_id: &UserID) -> Result<String>;
+    async fn erase(&self, user_id: &UserID, audit_log: &CreateAuditLog) -> Result<()>;
+}
+
+pub trait HavePersonalDataService {
+    type Service: PersonalDataService;
+    fn personal_data_service(&self) -> &Self::Service;
+}
+
+#[async_trait]
+pub trait PersonalDataServiceImpl:
+    Sync
+    + HaveDBPool
+    + HaveUserRepository
+    + HaveUserProfileRepository
+    + HaveRegistrationCourseRepository
+    + HaveSubmissionRepository
+    + HaveUnreadAnnouncementRepository
+    + HaveUserTotpSecretRepository
+    + HaveUserRecoveryCodeRepository
+    + HavePersonalAccessTokenRepository
+    + HaveLoginAttemptRepository
+    + HaveWaitlistRepository
+    + HaveLotteryPreferenceRepository
+    + HaveRegistrationRequestRepository
+    + HaveCreditLimitRepository
+    + HavePasswordHasher
+    + HavePersonalDataStorage
+    + HaveAuditLogRepository
+{
+    async fn export(&self, user_id: &UserID) -> Result<String> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let user_repo = self.user_repo();
+        let code = match user_repo.find_code_by_id(&mut conn, user_id).await? {
+            Some(code) => code,
+            None => return Err(UserNotFound),
+        };
+        let user = user_repo.find(&mut conn, user_id).await?;
+        let profile = self
+            .user_profile_repo()
+            .find_by_user_id(&mut conn, user_id)
+            .await?;
+        let registered_courses = self
+            .registration_course_repo()
+            .find_courses_by_user_id(&mut conn, user_id)
+            .await?;
+        let submissions = self
+            .submission_repo()
+            .find_all_by_user_id(&mut conn, user_id)
+            .await?;
+        let announcements = self
+            .unread_announcement_repo()
+            .find_all_by_user_id(&mut conn, user_id)
+            .await?;
+        let waitlisted_course_ids = self
+            .waitlist_repo()
+            .find_course_ids_by_user_id(&mut conn, user_id)
+            .await?;
+        let lottery_preferences = self
+            .lottery_preference_repo()
+            .find_by_user_id(&mut conn, user_id)
+            .await?;
+        let registration_request_course_ids = self
+            .registration_request_repo()
+            .find_course_ids_by_user_id(&mut conn, user_id)
+            .await?;
+        let credit_limit = self
+            .credit_limit_repo()
+            .find_by_user_id(&mut conn, user_id)
+            .await?;
+        let personal_access_tokens = self
+            .personal_access_token_repo()
+            .find_all_by_user_id(&mut conn, user_id)
+            .await?;
+        let login_attempts = self
+            .login_attempt_repo()
+            .find_all_by_user_code(&mut conn, &code.inner().to_string())
+            .await?;
+        let audit_logs = self
+            .audit_log_repo()
+            .find_all_by_target_user_id(&mut conn, user_id)
+            .await?;
+
+        let document = serde_json::to_vec_pretty(&PersonalData {
+            user: user.into(),
+            profile,
+            registered_courses: registered_courses.into_iter().map(Into::into).collect(),
+            submissions: submissions.clone(),
+            announcements,
+            waitlisted_course_ids,
+            lottery_preferences,
+            registration_request_course_ids,
+            credit_limit,
+            personal_access_tokens: personal_access_tokens.into_iter().map(Into::into).collect(),
+            login_attempts: login_attempts.into_iter().map(Into::into).collect(),
+            audit_logs: audit_logs.into_iter().map(Into::into).collect(),
+        })?;
+
+        let zip_file_path = self
+            .personal_data_storage()
+            .create_personal_data_zip(user_id, &document, &submissions)
+            .await?;
+
+        Ok(zip_file_path)
+    }
+
+    // 成績の集計に使う履修登録・スコア・既読状態は残し、個人を識別できる情報のみ取り除く。
+    // キャンセル待ち・抽選の希望・履修申請・単位数の上限は成績に関わらないため削除する。
+    // 監査ログは本人のIDのみを保持し、要約には個人を識別できる情報を書かないため残す
+    async fn erase(&self, user_id: &UserID, audit_log: &CreateAuditLog) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let user_repo = self.user_repo();
+        let code = match user_repo.find_code_by_id(&mut tx, user_id).await? {
+            Some(code) => code,
+            None => return Err(UserNotFound),
+        };
+        let submission_repo = self.submission_repo();
+        let submissions = submission_repo
+            .find_all_by_user_id(&mut tx, user_id)
+            .await?;
+
+        // 元のパスワードではログインできないように、無作為なパスワードのハッシュ値で置き換える
+        let password = Alphanumeric.sample_string(&mut rand::thread_rng(), 32);
+        let hashed_password = self.password_hasher().hash(&password)?;
+        user_repo
+            .anonymize_by_id(
+                &mut tx,
+                user_id,
+                &new_erased_user_code(),
+                ERASED_USER_NAME,
+                hashed_password.as_bytes(),
+                &chrono::Utc::now().naive_utc(),
+            )
+            .await?;
+        submission_repo
+            .update_file_name_by_user_id(&mut tx, user_id, ERASED_FILE_NAME)
+            .await?;
+        self.user_profile_repo()
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        self.user_totp_secret_repo()
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        self.user_recovery_code_repo()
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        self.personal_access_token_repo()
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        self.login_attempt_repo()
+            .delete_by_user_code(&mut tx, &code.inner().to_string())
+            .await?;
+        self.waitlist_repo()
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        self.lottery_preference_repo()
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        self.registration_request_repo()
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        self.credit_limit_repo()
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        // ファイルの削除は取り消せないため、DBの更新が確定してから行う
+        self.personal_data_storage()
+            .delete_submission_files(user_id, &submissions)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: PersonalDataServiceImpl> PersonalDataService for S {
+    async fn export(&self, user_id: &UserID) -> Result<String> {
+        PersonalDataServiceImpl::export(self, user_id).await
+    }
+
+    async fn erase(&self, user_id: &UserID, audit_log: &CreateAuditLog) -> Result<()> {
+        PersonalDataServiceImpl::erase(self, user_id, audit_log).await
+    }
+}
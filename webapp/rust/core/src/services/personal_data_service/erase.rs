@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::user::{UserCode, UserID};
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::personal_data_service::PersonalDataService;
+    use fake::{Fake, Faker};
+
+    #[tokio::test]
+    #[should_panic(expected = "UserNotFound")]
+    async fn not_found_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(None));
+        service.user_repo.expect_anonymize_by_id().never();
+        service
+            .personal_data_storage
+            .expect_delete_submission_files()
+            .never();
+
+        service
+            .erase(&UserID::new("user".to_string()), &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(Some(UserCode::new("S00001".to_string().into()))));
+        service
+            .submission_repo
+            .expect_find_all_by_user_id()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .password_hasher
+            .expect_hash()
+            .returning(|_| Ok("hashed".to_string()));
+        service
+            .user_repo
+            .expect_anonymize_by_id()
+            .withf(|_, id, code, name, hashed_password, _| {
+                id.inner() == "user"
+                    && code.inner().to_string().starts_with('~')
+                    && name != "Student"
+                    && hashed_password == b"hashed"
+            })
+            .times(1)
+            .returning(|_, _, _, _, _, _| Ok(()));
+        service
+            .submission_repo
+            .expect_update_file_name_by_user_id()
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service
+            .user_profile_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .user_totp_secret_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .user_recovery_code_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .personal_access_token_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .login_attempt_repo
+            .expect_delete_by_user_code()
+            .withf(|_, user_code| user_code == "S00001")
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .waitlist_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .lottery_preference_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .registration_request_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .credit_limit_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .personal_data_storage
+            .expect_delete_submission_files()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        service
+            .erase(&UserID::new("user".to_string()), &Faker.fake())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::class::ClassID;
+    use crate::models::course::CourseID;
+    use crate::models::personal_access_token::PersonalAccessToken;
+    use crate::models::submission::UserSubmission;
+    use crate::models::user::{User, UserCode, UserID};
+    use crate::models::user_type::UserType;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::personal_data_service::PersonalDataService;
+    use fake::{Fake, Faker};
+
+    #[tokio::test]
+    #[should_panic(expected = "UserNotFound")]
+    async fn not_found_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(None));
+        service
+            .personal_data_storage
+            .expect_create_personal_data_zip()
+            .never();
+
+        service
+            .export(&UserID::new("user".to_string()))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(Some(UserCode::new("S00001".to_string().into()))));
+        service.user_repo.expect_find().returning(|_, _| {
+            Ok(User {
+                id: UserID::new("user".to_string()),
+                code: UserCode::new("S00001".to_string().into()),
+                name: "Student".to_string(),
+                hashed_password: b"hashed".to_vec(),
+                type_: UserType::Student,
+                deactivated_at: None,
+            })
+        });
+        service
+            .user_profile_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(None));
+        service
+            .registration_course_repo
+            .expect_find_courses_by_user_id()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .submission_repo
+            .expect_find_all_by_user_id()
+            .returning(|_, _| {
+                Ok(vec![UserSubmission {
+                    class_id: ClassID::new("class".to_string()),
+                    course_id: CourseID::new("course".to_string()),
+                    file_name: "report.pdf".to_string(),
+                    score: Some(80),
+                }])
+            });
+        service
+            .unread_announcement_repo
+            .expect_find_all_by_user_id()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .waitlist_repo
+            .expect_find_course_ids_by_user_id()
+            .returning(|_, _| Ok(vec![CourseID::new("waitlisted-course".to_string())]));
+        service
+            .lottery_preference_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_request_repo
+            .expect_find_course_ids_by_user_id()
+            .returning(|_, _| Ok(vec![CourseID::new("requested-course".to_string())]));
+        service
+            .credit_limit_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(Some(20)));
+        service
+            .personal_access_token_repo
+            .expect_find_all_by_user_id()
+            .returning(|_, _| {
+                let mut token: PersonalAccessToken = Faker.fake();
+                token.name = "ci-token".to_string();
+                token.hashed_token = "hashed-token".to_string();
+                Ok(vec![token])
+            });
+        service
+            .login_attempt_repo
+            .expect_find_all_by_user_code()
+            .withf(|_, user_code| user_code == "S00001")
+            .returning(|_, _| Ok(vec![]));
+        service
+            .audit_log_repo
+            .expect_find_all_by_target_user_id()
+            .returning(|_, _| Ok(vec![]));
+        // パスワードやトークンのハッシュ値はエクスポートに含めない
+        service
+            .personal_data_storage
+            .expect_create_personal_data_zip()
+            .withf(|user_id, document, submissions| {
+                let document = String::from_utf8_lossy(document);
+                user_id.inner() == "user"
+                    && document.contains("S00001")
+                    && document.contains("report.pdf")
+                    && document.contains("waitlisted-course")
+                    && document.contains("requested-course")
+                    && document.contains("ci-token")
+                    && !document.contains("hashed")
+                    && submissions.len() == 1
+            })
+            .times(1)
+            .returning(|_, _, _| Ok("personal-data-user.zip".to_string()));
+
+        let got = service
+            .export(&UserID::new("user".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(got, "personal-data-user.zip");
+    }
+}
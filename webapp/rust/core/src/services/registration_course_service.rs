@@ -1,20 +1,186 @@
+use crate::db::DBConn;
+use crate::models::announcement::{Announcement, AnnouncementID};
+use crate::models::audit_log::CreateAuditLog;
 use crate::models::course::{Course, CourseID};
+use crate::models::course_prerequisite::CoursePrerequisite;
 use crate::models::course_status::CourseStatus;
+use crate::models::drop_outcome::DropOutcome;
+use crate::models::lottery_outcome::{
+    LotteryDrawReport, LotteryOutcome, LotteryResult, LotteryStudentReport,
+};
+use crate::models::lottery_preference::LotteryPreference;
+use crate::models::registration_request::RegistrationRequest;
+use crate::models::registration_window::{
+    CreateRegistrationWindow, RegistrationWindow, RegistrationWindowID,
+};
 use crate::models::user::UserID;
+use crate::repos::announcement_repository::{AnnouncementRepository, HaveAnnouncementRepository};
+use crate::repos::approval_required_course_repository::{
+    ApprovalRequiredCourseRepository, HaveApprovalRequiredCourseRepository,
+};
+use crate::repos::audit_log_repository::HaveAuditLogRepository;
+use crate::repos::course_prerequisite_repository::{
+    CoursePrerequisiteRepository, HaveCoursePrerequisiteRepository,
+};
 use crate::repos::course_repository::{CourseRepository, HaveCourseRepository};
+use crate::repos::credit_limit_repository::{CreditLimitRepository, HaveCreditLimitRepository};
+use crate::repos::lottery_course_repository::{
+    HaveLotteryCourseRepository, LotteryCourseRepository,
+};
+use crate::repos::lottery_preference_repository::{
+    HaveLotteryPreferenceRepository, LotteryPreferenceRepository,
+};
 use crate::repos::registration_course_repository::{
     HaveRegistrationCourseRepository, RegistrationCourseRepository,
 };
 use crate::repos::registration_repository::{HaveRegistrationRepository, RegistrationRepository};
-use crate::services::error::{Error, RegistrationCourseValidationError, Result};
+use crate::repos::registration_request_repository::{
+    HaveRegistrationRequestRepository, RegistrationRequestRepository,
+};
+use crate::repos::registration_window_repository::{
+    HaveRegistrationWindowRepository, RegistrationWindowRepository,
+};
+use crate::repos::unread_announcement_repository::{
+    HaveUnreadAnnouncementRepository, UnreadAnnouncementRepository,
+};
+use crate::repos::user_repository::{HaveUserRepository, UserRepository};
+use crate::repos::waitlist_repository::{HaveWaitlistRepository, WaitlistRepository};
+use crate::services::audit_log_service::create_audit_log;
+use crate::services::error::Error::{
+    AlreadyRegistered, CourseNotDroppable, CourseNotFound, CourseNotFull, CourseNotRegistrable,
+    InvalidCourseCapacity, InvalidCreditLimit, InvalidLotteryPreference, InvalidRegistrationWindow,
+    NotCourseTeacher, RegistrationAlready, RegistrationClosed, RegistrationRequestNotFound,
+    RegistrationWindowNotFound, UserNotFound,
+};
+use crate::services::error::{
+    CreditLimitExceeded, Error, RegistrationCourseValidationError, Result, ScheduleConflict,
+};
 use crate::services::HaveDBPool;
+use crate::util;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashMap;
+
+mod approve_registration_request;
+mod create;
+mod create_registration_window;
+mod draw_lottery;
+mod drop_course;
+mod join_waitlist;
+mod update_capacity;
+mod update_credit_limit;
+mod update_lottery_preferences;
+mod validate;
+
+fn total_credits(courses: &[Course]) -> u32 {
+    courses.iter().map(|course| u32::from(course.credit)).sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreditLimitConfig {
+    // 1学期に履修登録できる単位数の上限。学生ごとに上書きできる。
+    // 設定されていない場合は、上書きのない学生の単位数を制限しない
+    pub credits_per_term: Option<u32>,
+}
+
+impl CreditLimitConfig {
+    pub fn from_env() -> Self {
+        Self {
+            credits_per_term: std::env::var("CREDIT_LIMIT_PER_TERM")
+                .ok()
+                .and_then(|v| v.parse().ok()),
+        }
+    }
+}
+
+pub trait HaveCreditLimitConfig {
+    fn credit_limit_config(&self) -> &CreditLimitConfig;
+}
 
 #[cfg_attr(any(test, feature = "test"), mockall::automock)]
 #[async_trait]
 pub trait RegistrationCourseService {
     async fn find_courses_by_user_id(&self, user_id: &UserID) -> Result<Vec<Course>>;
     async fn create(&self, user_id: &UserID, course_ids: &[CourseID]) -> Result<()>;
+    async fn validate(
+        &self,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<RegistrationCourseValidationError>;
+    async fn drop_course(&self, user_id: &UserID, course_id: &CourseID) -> Result<DropOutcome>;
+    async fn join_waitlist(&self, user_id: &UserID, course_id: &CourseID) -> Result<i64>;
+    async fn leave_waitlist(&self, user_id: &UserID, course_id: &CourseID) -> Result<()>;
+    async fn update_capacity(
+        &self,
+        course_id: &CourseID,
+        capacity: Option<u32>,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()>;
+    async fn update_credit_limit(
+        &self,
+        user_id: &UserID,
+        credit_limit: Option<u32>,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()>;
+    async fn is_registration_open(&self, user_id: &UserID) -> Result<bool>;
+    async fn find_registration_windows(&self) -> Result<Vec<RegistrationWindow>>;
+    async fn create_registration_window(
+        &self,
+        req: &CreateRegistrationWindow,
+        audit_log: &CreateAuditLog,
+    ) -> Result<RegistrationWindow>;
+    async fn delete_registration_window(
+        &self,
+        id: &RegistrationWindowID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()>;
+    async fn update_lottery(
+        &self,
+        course_id: &CourseID,
+        enabled: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()>;
+    async fn find_lottery_preferences(&self, user_id: &UserID) -> Result<Vec<LotteryPreference>>;
+    async fn find_registration_request_course_ids(&self, user_id: &UserID)
+        -> Result<Vec<CourseID>>;
+    async fn update_lottery_preferences(
+        &self,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<()>;
+    async fn draw_lottery(
+        &self,
+        seed: Option<u64>,
+        audit_log: &CreateAuditLog,
+    ) -> Result<LotteryDrawReport>;
+    async fn update_approval_required(
+        &self,
+        course_id: &CourseID,
+        required: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()>;
+    async fn find_registration_requests(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+    ) -> Result<Vec<RegistrationRequest>>;
+    async fn approve_registration_request(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+        user_id: &UserID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()>;
+    async fn deny_registration_request(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+        user_id: &UserID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()>;
 }
 
 pub trait HaveRegistrationCourseService {
@@ -29,7 +195,20 @@ pub trait RegistrationCourseServiceImpl:
     + HaveDBPool
     + HaveRegistrationCourseRepository
     + HaveRegistrationRepository
+    + HaveRegistrationWindowRepository
     + HaveCourseRepository
+    + HaveCoursePrerequisiteRepository
+    + HaveCreditLimitRepository
+    + HaveCreditLimitConfig
+    + HaveLotteryCourseRepository
+    + HaveLotteryPreferenceRepository
+    + HaveApprovalRequiredCourseRepository
+    + HaveRegistrationRequestRepository
+    + HaveUserRepository
+    + HaveWaitlistRepository
+    + HaveAnnouncementRepository
+    + HaveUnreadAnnouncementRepository
+    + HaveAuditLogRepository
 {
     async fn find_courses_by_user_id(&self, user_id: &UserID) -> Result<Vec<Course>> {
         let pool = self.get_db_pool();
@@ -46,68 +225,1101 @@ pub trait RegistrationCourseServiceImpl:
         let pool = self.get_db_pool();
         let mut tx = pool.begin().await?;
 
+        // 定員のある科目は、空席を数えてから登録するまでを他の登録と直列にするため、先に排他ロックを取る。
+        // 共有ロックのまま数えると、最後の1席を同時に取り合った登録同士がデッドロックする
         let course_repo = self.course_repo();
-        let registration_course_repo = self.registration_course_repo();
+        let capacity_limited_ids = course_repo
+            .find_capacity_limited_ids_by_ids(&mut tx, course_ids)
+            .await?;
+        if !capacity_limited_ids.is_empty() {
+            course_repo
+                .for_update_by_ids(&mut tx, &capacity_limited_ids)
+                .await?;
+        }
+
+        let (newly_added, errors) = self
+            .validate_registration(&mut tx, user_id, course_ids)
+            .await?;
+        if !errors.is_empty() {
+            return Err(Error::RegistrationCourseValidationError(Box::new(errors)));
+        }
+
+        let newly_added_ids = newly_added
+            .iter()
+            .map(|course| course.id.clone())
+            .collect::<Vec<_>>();
+        let approval_required_ids = self
+            .approval_required_course_repo()
+            .find_course_ids_by_course_ids(&mut tx, &newly_added_ids)
+            .await?;
+
+        let waitlist_repo = self.waitlist_repo();
+        let mut registered_course_ids = Vec::with_capacity(newly_added.len());
+        for course in newly_added {
+            // 承認が必要な科目は履修登録せず、担当教員への申請とする
+            if approval_required_ids.contains(&course.id) {
+                self.registration_request_repo()
+                    .create(&mut tx, &course.id, user_id)
+                    .await?;
+                continue;
+            }
+            // キャンセル待ちをしていた科目に直接登録できた場合は、待ちを取り消す
+            if course.capacity.is_some() {
+                waitlist_repo.delete(&mut tx, &course.id, user_id).await?;
+            }
+            registered_course_ids.push(course.id);
+        }
+        self.registration_repo()
+            .create_or_update_all(&mut tx, user_id, &registered_course_ids)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn validate(
+        &self,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<RegistrationCourseValidationError> {
+        let pool = self.get_db_pool();
+        // 書き込みは行わないので、トランザクションはコミットせずに破棄する
+        let mut tx = pool.begin().await?;
+
+        let (_, errors) = self
+            .validate_registration(&mut tx, user_id, course_ids)
+            .await?;
+
+        Ok(errors)
+    }
+
+    async fn drop_course(&self, user_id: &UserID, course_id: &CourseID) -> Result<DropOutcome> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let course_repo = self.course_repo();
+        if !course_repo.for_update_by_id(&mut tx, course_id).await? {
+            return Err(CourseNotFound);
+        }
+        let course = match course_repo
+            .find_for_share_lock_by_id(&mut tx, course_id)
+            .await?
+        {
+            Some(course) => course,
+            None => return Err(CourseNotFound),
+        };
+
         let registration_repo = self.registration_repo();
+        if !registration_repo
+            .exist_by_user_id_and_course_id(&mut tx, user_id, course_id)
+            .await?
+        {
+            return Err(RegistrationAlready);
+        }
 
-        let mut errors = RegistrationCourseValidationError::default();
-        let mut newly_added = Vec::new();
-        for course_id in course_ids {
+        let outcome = match self.drop_policy(&course) {
+            Some(outcome) => outcome,
+            None => return Err(CourseNotDroppable),
+        };
+        match outcome {
+            DropOutcome::Dropped => {
+                registration_repo
+                    .delete(&mut tx, user_id, course_id)
+                    .await?;
+                // 空いた席をキャンセル待ちの学生に回す
+                self.promote_waitlist(&mut tx, &course).await?;
+            }
+            DropOutcome::Withdrawn => {
+                registration_repo
+                    .withdraw(&mut tx, user_id, course_id, &chrono::Utc::now().naive_utc())
+                    .await?;
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(outcome)
+    }
+
+    // 科目のステータスに応じた履修取り消しの扱い。Noneの場合は取り消せない
+    fn drop_policy(&self, course: &Course) -> Option<DropOutcome> {
+        match course.status {
+            CourseStatus::Registration => Some(DropOutcome::Dropped),
+            // 開講後の取り消しは成績の記録を残すため、履修中止として扱う
+            CourseStatus::InProgress => Some(DropOutcome::Withdrawn),
+            CourseStatus::Closed => None,
+        }
+    }
+
+    async fn join_waitlist(&self, user_id: &UserID, course_id: &CourseID) -> Result<i64> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let course = match self
+            .course_repo()
+            .find_for_share_lock_by_id(&mut tx, course_id)
+            .await?
+        {
+            Some(course) => course,
+            None => return Err(CourseNotFound),
+        };
+        if course.status != CourseStatus::Registration {
+            return Err(CourseNotRegistrable);
+        }
+        // 抽選科目は抽選でのみ履修者を決める
+        if self
+            .lottery_course_repo()
+            .exist_by_course_id(&mut tx, course_id)
+            .await?
+        {
+            return Err(CourseNotRegistrable);
+        }
+
+        let registration_repo = self.registration_repo();
+        if registration_repo
+            .exist_by_user_id_and_course_id(&mut tx, user_id, course_id)
+            .await?
+        {
+            return Err(AlreadyRegistered);
+        }
+        // 空席がある科目は直接履修登録させる
+        let is_full = match course.capacity {
+            Some(capacity) => {
+                registration_repo
+                    .count_for_share_lock_by_course_id(&mut tx, course_id)
+                    .await?
+                    >= i64::from(capacity)
+            }
+            None => false,
+        };
+        if !is_full {
+            return Err(CourseNotFull);
+        }
+
+        let waitlist_repo = self.waitlist_repo();
+        waitlist_repo.create(&mut tx, course_id, user_id).await?;
+        let position = waitlist_repo
+            .find_position(&mut tx, course_id, user_id)
+            .await?
+            .unwrap_or_default();
+
+        tx.commit().await?;
+
+        Ok(position)
+    }
+
+    async fn leave_waitlist(&self, user_id: &UserID, course_id: &CourseID) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        self.waitlist_repo()
+            .delete(&mut tx, course_id, user_id)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn update_capacity(
+        &self,
+        course_id: &CourseID,
+        capacity: Option<u32>,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        if capacity == Some(0) {
+            return Err(InvalidCourseCapacity);
+        }
+
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let course_repo = self.course_repo();
+        if !course_repo.for_update_by_id(&mut tx, course_id).await? {
+            return Err(CourseNotFound);
+        }
+        // 定員を現在の履修者数より減らしても、登録済みの履修者はそのまま残す
+        course_repo
+            .update_capacity_by_id(&mut tx, course_id, capacity)
+            .await?;
+        let course = match course_repo
+            .find_for_share_lock_by_id(&mut tx, course_id)
+            .await?
+        {
+            Some(course) => course,
+            None => return Err(CourseNotFound),
+        };
+        self.promote_waitlist(&mut tx, &course).await?;
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn update_credit_limit(
+        &self,
+        user_id: &UserID,
+        credit_limit: Option<u32>,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        if credit_limit == Some(0) {
+            return Err(InvalidCreditLimit);
+        }
+
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        if self
+            .user_repo()
+            .find_code_by_id(&mut tx, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(UserNotFound);
+        }
+        // Noneの場合は上書きをやめて設定値に戻す
+        let credit_limit_repo = self.credit_limit_repo();
+        match credit_limit {
+            Some(credit_limit) => {
+                credit_limit_repo
+                    .create_or_update(&mut tx, user_id, credit_limit)
+                    .await?
+            }
+            None => {
+                credit_limit_repo
+                    .delete_by_user_id(&mut tx, user_id)
+                    .await?
+            }
+        }
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn is_registration_open(&self, user_id: &UserID) -> Result<bool> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        self.is_registration_open_at(&mut conn, user_id, &chrono::Utc::now().naive_utc())
+            .await
+    }
+
+    async fn find_registration_windows(&self) -> Result<Vec<RegistrationWindow>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+        let windows = self.registration_window_repo().find_all(&mut conn).await?;
+
+        Ok(windows)
+    }
+
+    async fn create_registration_window(
+        &self,
+        req: &CreateRegistrationWindow,
+        audit_log: &CreateAuditLog,
+    ) -> Result<RegistrationWindow> {
+        if req.term.is_empty()
+            || req.term.chars().count() > 255
+            || req.cohort.as_deref().is_some_and(str::is_empty)
+            || req.starts_at >= req.ends_at
+        {
+            return Err(InvalidRegistrationWindow);
+        }
+
+        let window = RegistrationWindow {
+            id: RegistrationWindowID::new(util::new_ulid().await),
+            term: req.term.clone(),
+            cohort: req.cohort.clone(),
+            starts_at: req.starts_at,
+            ends_at: req.ends_at,
+        };
+
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+        self.registration_window_repo()
+            .create(&mut tx, &window)
+            .await?;
+
+        let mut audit_log = audit_log.clone();
+        audit_log.after_summary = Some(
+            serde_json::json!({
+                "id": window.id,
+                "term": window.term,
+                "cohort": window.cohort,
+                "starts_at": window.starts_at,
+                "ends_at": window.ends_at,
+            })
+            .to_string(),
+        );
+        create_audit_log(self.audit_log_repo(), &mut tx, &audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(window)
+    }
+
+    async fn delete_registration_window(
+        &self,
+        id: &RegistrationWindowID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+        let is_deleted = self
+            .registration_window_repo()
+            .delete_by_id(&mut tx, id)
+            .await?;
+        if !is_deleted {
+            return Err(RegistrationWindowNotFound);
+        }
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn update_lottery(
+        &self,
+        course_id: &CourseID,
+        enabled: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let course_repo = self.course_repo();
+        if !course_repo.for_update_by_id(&mut tx, course_id).await? {
+            return Err(CourseNotFound);
+        }
+
+        let lottery_course_repo = self.lottery_course_repo();
+        if enabled {
             let course = course_repo
                 .find_for_share_lock_by_id(&mut tx, course_id)
+                .await?
+                .ok_or(CourseNotFound)?;
+            if course.status != CourseStatus::Registration {
+                return Err(CourseNotRegistrable);
+            }
+            lottery_course_repo.create(&mut tx, course_id).await?;
+        } else {
+            // 抽選をやめた科目は先着順に戻し、集めた希望は破棄する
+            lottery_course_repo.delete(&mut tx, course_id).await?;
+            self.lottery_preference_repo()
+                .delete_by_course_id(&mut tx, course_id)
                 .await?;
-            if course.is_none() {
-                errors.course_not_found.push(course_id.clone());
+        }
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find_lottery_preferences(&self, user_id: &UserID) -> Result<Vec<LotteryPreference>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+        let preferences = self
+            .lottery_preference_repo()
+            .find_by_user_id(&mut conn, user_id)
+            .await?;
+
+        Ok(preferences)
+    }
+
+    async fn find_registration_request_course_ids(
+        &self,
+        user_id: &UserID,
+    ) -> Result<Vec<CourseID>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+        let course_ids = self
+            .registration_request_repo()
+            .find_course_ids_by_user_id(&mut conn, user_id)
+            .await?;
+
+        Ok(course_ids)
+    }
+
+    // 希望は並び順を順位として、毎回すべて置き換える
+    async fn update_lottery_preferences(
+        &self,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        if !self
+            .is_registration_open_at(&mut tx, user_id, &chrono::Utc::now().naive_utc())
+            .await?
+        {
+            return Err(RegistrationClosed);
+        }
+
+        let course_repo = self.course_repo();
+        let lottery_course_repo = self.lottery_course_repo();
+        for (i, course_id) in course_ids.iter().enumerate() {
+            if course_ids[..i].contains(course_id) {
+                return Err(InvalidLotteryPreference);
+            }
+            let course = course_repo
+                .find_for_share_lock_by_id(&mut tx, course_id)
+                .await?
+                .ok_or(InvalidLotteryPreference)?;
+            if course.status != CourseStatus::Registration
+                || !lottery_course_repo
+                    .exist_by_course_id(&mut tx, course_id)
+                    .await?
+            {
+                return Err(InvalidLotteryPreference);
+            }
+        }
+
+        let lottery_preference_repo = self.lottery_preference_repo();
+        lottery_preference_repo
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        for (i, course_id) in course_ids.iter().enumerate() {
+            let preference = LotteryPreference {
+                user_id: user_id.clone(),
+                course_id: course_id.clone(),
+                rank: i as u32 + 1,
+            };
+            lottery_preference_repo.create(&mut tx, &preference).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // 履修登録を受付中の抽選科目について、seedから決まる順番で学生を並べ、
+    // 第一希望から順に1人1科目ずつ割り当てる。定員、時間割の重複、単位数の上限を満たさない希望は落選とする。
+    // seedを指定しない場合は乱数で決め、結果に含めて返す
+    async fn draw_lottery(
+        &self,
+        seed: Option<u64>,
+        audit_log: &CreateAuditLog,
+    ) -> Result<LotteryDrawReport> {
+        let seed = seed.unwrap_or_else(rand::random);
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let course_repo = self.course_repo();
+        let registration_repo = self.registration_repo();
+        let lottery_preference_repo = self.lottery_preference_repo();
+
+        let course_ids = self
+            .lottery_course_repo()
+            .find_all_course_ids(&mut tx)
+            .await?;
+        let mut courses = HashMap::new();
+        let mut remaining = HashMap::new();
+        let mut preferences = Vec::new();
+        for course_id in course_ids {
+            if !course_repo.for_update_by_id(&mut tx, &course_id).await? {
                 continue;
             }
-            let course = course.unwrap();
+            let course = match course_repo
+                .find_for_share_lock_by_id(&mut tx, &course_id)
+                .await?
+            {
+                Some(course) if course.status == CourseStatus::Registration => course,
+                _ => continue,
+            };
+            if let Some(capacity) = course.capacity {
+                let registered = registration_repo
+                    .count_for_share_lock_by_course_id(&mut tx, &course_id)
+                    .await?;
+                remaining.insert(course_id.clone(), i64::from(capacity) - registered);
+            }
+            preferences.extend(
+                lottery_preference_repo
+                    .find_by_course_id(&mut tx, &course_id)
+                    .await?,
+            );
+            courses.insert(course_id, course);
+        }
+
+        // シャッフル前の並びを固定するため、学生はIDの順に並べておく
+        preferences.sort_by(|x, y| {
+            x.user_id
+                .inner()
+                .cmp(y.user_id.inner())
+                .then(x.rank.cmp(&y.rank))
+        });
+        let mut students: Vec<(UserID, Vec<LotteryPreference>)> = Vec::new();
+        for preference in preferences {
+            match students.last_mut() {
+                Some((user_id, user_preferences)) if *user_id == preference.user_id => {
+                    user_preferences.push(preference)
+                }
+                _ => students.push((preference.user_id.clone(), vec![preference])),
+            }
+        }
+        students.shuffle(&mut StdRng::seed_from_u64(seed));
+
+        let mut registered_courses = Vec::with_capacity(students.len());
+        let mut credit_limits = Vec::with_capacity(students.len());
+        for (user_id, _) in &students {
+            registered_courses.push(
+                self.registration_course_repo()
+                    .find_open_courses_by_user_id(&mut tx, user_id)
+                    .await?,
+            );
+            credit_limits.push(self.find_credit_limit(&mut tx, user_id).await?);
+        }
+
+        let mut reports: Vec<LotteryStudentReport> = students
+            .iter()
+            .map(|(user_id, _)| LotteryStudentReport {
+                user_id: user_id.clone(),
+                results: Vec::new(),
+            })
+            .collect();
+        let max_round = students
+            .iter()
+            .map(|(_, user_preferences)| user_preferences.len())
+            .max()
+            .unwrap_or_default();
+        let waitlist_repo = self.waitlist_repo();
+        for round in 0..max_round {
+            for (i, (user_id, user_preferences)) in students.iter().enumerate() {
+                let preference = match user_preferences.get(round) {
+                    Some(preference) => preference,
+                    None => continue,
+                };
+                let course = &courses[&preference.course_id];
+                let registered = &mut registered_courses[i];
+
+                let outcome = if registered.iter().any(|c| c.id == course.id) {
+                    LotteryOutcome::AlreadyRegistered
+                } else if remaining.get(&course.id).is_some_and(|seats| *seats <= 0) {
+                    LotteryOutcome::CourseFull
+                } else if registered
+                    .iter()
+                    .any(|c| c.period == course.period && c.day_of_week == course.day_of_week)
+                {
+                    LotteryOutcome::ScheduleConflict
+                } else if credit_limits[i].is_some_and(|credit_limit| {
+                    total_credits(registered) + u32::from(course.credit) > credit_limit
+                }) {
+                    LotteryOutcome::CreditLimitExceeded
+                } else {
+                    registration_repo
+                        .create_or_update(&mut tx, user_id, &course.id)
+                        .await?;
+                    if course.capacity.is_some() {
+                        waitlist_repo.delete(&mut tx, &course.id, user_id).await?;
+                    }
+                    if let Some(seats) = remaining.get_mut(&course.id) {
+                        *seats -= 1;
+                    }
+                    registered.push(course.clone());
+                    LotteryOutcome::Won
+                };
+                reports[i].results.push(LotteryResult {
+                    course_id: course.id.clone(),
+                    rank: preference.rank,
+                    outcome,
+                });
+            }
+        }
+
+        // 抽選に使った希望は消費済みとして削除する
+        for course_id in courses.keys() {
+            lottery_preference_repo
+                .delete_by_course_id(&mut tx, course_id)
+                .await?;
+        }
+
+        let mut audit_log = audit_log.clone();
+        audit_log.after_summary =
+            Some(serde_json::json!({ "seed": seed, "students": reports.len() }).to_string());
+        create_audit_log(self.audit_log_repo(), &mut tx, &audit_log).await?;
+
+        tx.commit().await?;
+
+        reports.sort_by(|x, y| x.user_id.inner().cmp(y.user_id.inner()));
+        Ok(LotteryDrawReport {
+            seed,
+            students: reports,
+        })
+    }
+
+    async fn update_approval_required(
+        &self,
+        course_id: &CourseID,
+        required: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        if !self
+            .course_repo()
+            .for_update_by_id(&mut tx, course_id)
+            .await?
+        {
+            return Err(CourseNotFound);
+        }
+
+        let approval_required_course_repo = self.approval_required_course_repo();
+        if required {
+            approval_required_course_repo
+                .create(&mut tx, course_id)
+                .await?;
+        } else {
+            // 承認が不要になった科目は、承認待ちの申請を破棄する
+            approval_required_course_repo
+                .delete(&mut tx, course_id)
+                .await?;
+            self.registration_request_repo()
+                .delete_by_course_id(&mut tx, course_id)
+                .await?;
+        }
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // 履修申請の一覧と承認、却下は科目の担当教員にのみ許可する
+    async fn find_registration_requests(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+    ) -> Result<Vec<RegistrationRequest>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let course = self
+            .course_repo()
+            .find_for_share_lock_by_id(&mut conn, course_id)
+            .await?
+            .ok_or(CourseNotFound)?;
+        if course.teacher_id != *teacher_id {
+            return Err(NotCourseTeacher);
+        }
+        let requests = self
+            .registration_request_repo()
+            .find_by_course_id(&mut conn, course_id)
+            .await?;
+
+        Ok(requests)
+    }
+
+    // 申請からの状況の変化に備えて、承認時に定員、時間割の重複、単位数の上限を確認し直す
+    async fn approve_registration_request(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+        user_id: &UserID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let course_repo = self.course_repo();
+        if !course_repo.for_update_by_id(&mut tx, course_id).await? {
+            return Err(CourseNotFound);
+        }
+        let course = course_repo
+            .find_for_share_lock_by_id(&mut tx, course_id)
+            .await?
+            .ok_or(CourseNotFound)?;
+        if course.teacher_id != *teacher_id {
+            return Err(NotCourseTeacher);
+        }
+        if !self
+            .registration_request_repo()
+            .delete(&mut tx, course_id, user_id)
+            .await?
+        {
+            return Err(RegistrationRequestNotFound);
+        }
+        if course.status != CourseStatus::Registration {
+            return Err(CourseNotRegistrable);
+        }
+
+        let registration_repo = self.registration_repo();
+        if registration_repo
+            .exist_by_user_id_and_course_id(&mut tx, user_id, course_id)
+            .await?
+        {
+            return Err(AlreadyRegistered);
+        }
+
+        let mut errors = RegistrationCourseValidationError::default();
+        if let Some(capacity) = course.capacity {
+            let registered = registration_repo
+                .count_for_share_lock_by_course_id(&mut tx, course_id)
+                .await?;
+            if registered >= i64::from(capacity) {
+                errors.course_full.push(course.id.clone());
+            }
+        }
+
+        let registered_courses = self
+            .registration_course_repo()
+            .find_open_courses_by_user_id(&mut tx, user_id)
+            .await?;
+        let conflicts_with = registered_courses
+            .iter()
+            .filter(|registered| {
+                registered.period == course.period && registered.day_of_week == course.day_of_week
+            })
+            .map(|registered| registered.id.clone())
+            .collect::<Vec<_>>();
+        if !conflicts_with.is_empty() {
+            errors.schedule_conflict.push(course.id.clone());
+            errors.schedule_conflict_details.push(ScheduleConflict {
+                course_id: course.id.clone(),
+                conflicts_with,
+            });
+        }
+
+        if let Some(credit_limit) = self.find_credit_limit(&mut tx, user_id).await? {
+            let registered_credits = total_credits(&registered_courses);
+            let requested_credits = u32::from(course.credit);
+            if registered_credits + requested_credits > credit_limit {
+                errors.credit_limit_exceeded = Some(CreditLimitExceeded {
+                    credit_limit,
+                    registered_credits,
+                    requested_credits,
+                });
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::RegistrationCourseValidationError(Box::new(errors)));
+        }
+
+        registration_repo
+            .create_or_update(&mut tx, user_id, course_id)
+            .await?;
+        if course.capacity.is_some() {
+            self.waitlist_repo()
+                .delete(&mut tx, course_id, user_id)
+                .await?;
+        }
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn deny_registration_request(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+        user_id: &UserID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let course = self
+            .course_repo()
+            .find_for_share_lock_by_id(&mut tx, course_id)
+            .await?
+            .ok_or(CourseNotFound)?;
+        if course.teacher_id != *teacher_id {
+            return Err(NotCourseTeacher);
+        }
+        let is_deleted = self
+            .registration_request_repo()
+            .delete(&mut tx, course_id, user_id)
+            .await?;
+        if !is_deleted {
+            return Err(RegistrationRequestNotFound);
+        }
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // 学生に適用される履修登録期間は、学年を指定しない期間と学生の学年向けの期間の両方とし、
+    // そのいずれかの最中のみ受け付ける。学年を指定した期間はその学年の学生だけを制限するため、
+    // 適用される期間が1つもない学生は、科目のステータスのみで履修登録を受け付ける。
+    // 科目には学期がないため、期間のtermは管理用のラベルとして扱い、判定には使わない
+    async fn is_registration_open_at(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        now: &NaiveDateTime,
+    ) -> Result<bool> {
+        let windows = self.registration_window_repo().find_all(conn).await?;
+        if windows.is_empty() {
+            return Ok(true);
+        }
+
+        let user_code = self.user_repo().find_code_by_id(conn, user_id).await?;
+        let user_code = match user_code {
+            Some(user_code) => user_code.inner().to_string(),
+            None => return Ok(false),
+        };
+
+        let mut applicable = windows
+            .iter()
+            .filter(|window| window.is_applicable_to(&user_code))
+            .peekable();
+        if applicable.peek().is_none() {
+            return Ok(true);
+        }
+
+        Ok(applicable.any(|window| window.contains(now)))
+    }
+
+    // 履修登録のチェックを行い、新たに登録する科目とエラーを返す。書き込みは行わない
+    async fn validate_registration(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<(Vec<Course>, RegistrationCourseValidationError)> {
+        let course_repo = self.course_repo();
+        let registration_course_repo = self.registration_course_repo();
+        let registration_repo = self.registration_repo();
+
+        let is_open = self
+            .is_registration_open_at(conn, user_id, &chrono::Utc::now().naive_utc())
+            .await?;
+
+        // 科目と履修登録済みかどうかはまとめて取得し、エラーは指定された科目の順に並べる
+        let courses: HashMap<CourseID, Course> = course_repo
+            .find_for_share_lock_by_ids(conn, course_ids)
+            .await?
+            .into_iter()
+            .map(|course| (course.id.clone(), course))
+            .collect();
+        let registered_course_ids = registration_repo
+            .find_course_ids_by_user_id_and_course_ids(conn, user_id, course_ids)
+            .await?;
+        let lottery_course_ids = self
+            .lottery_course_repo()
+            .find_course_ids_by_course_ids(conn, course_ids)
+            .await?;
+        let capacity_limited_ids = course_ids
+            .iter()
+            .filter(|course_id| {
+                courses
+                    .get(*course_id)
+                    .is_some_and(|course| course.capacity.is_some())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        let registration_counts: HashMap<CourseID, i64> = registration_repo
+            .count_for_share_lock_by_course_ids(conn, &capacity_limited_ids)
+            .await?
+            .into_iter()
+            .collect();
+        let mut prerequisites_by_course_id: HashMap<CourseID, Vec<CoursePrerequisite>> =
+            HashMap::new();
+        for prerequisite in self
+            .course_prerequisite_repo()
+            .find_by_course_ids(conn, course_ids)
+            .await?
+        {
+            prerequisites_by_course_id
+                .entry(prerequisite.course_id.clone())
+                .or_default()
+                .push(prerequisite);
+        }
+
+        let mut errors = RegistrationCourseValidationError::default();
+        let mut newly_added = Vec::new();
+        let mut completed = None;
+        for course_id in course_ids {
+            let course = match courses.get(course_id) {
+                Some(course) => course.clone(),
+                None => {
+                    errors.course_not_found.push(course_id.clone());
+                    continue;
+                }
+            };
 
             if course.status != CourseStatus::Registration {
                 errors.not_registrable_status.push(course.id);
                 continue;
             }
 
+            if !is_open {
+                errors.registration_closed.push(course.id);
+                continue;
+            }
+
+            if lottery_course_ids.contains(course_id) {
+                errors.lottery_only.push(course.id);
+                continue;
+            }
+
             // すでに履修登録済みの科目は無視する
-            let is_exist = registration_repo
-                .exist_by_user_id_and_course_id(&mut tx, user_id, course_id)
-                .await?;
-            if is_exist {
+            if registered_course_ids.contains(course_id) {
                 continue;
             }
 
+            if let Some(capacity) = course.capacity {
+                let registered = registration_counts.get(course_id).copied().unwrap_or(0);
+                if registered >= i64::from(capacity) {
+                    errors.course_full.push(course.id);
+                    continue;
+                }
+            }
+
+            let prerequisites = prerequisites_by_course_id
+                .get(course_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            if !prerequisites.is_empty() {
+                // 修了済み科目の成績は事前科目のある科目が含まれる場合にだけ取得する
+                if completed.is_none() {
+                    completed = Some(
+                        registration_course_repo
+                            .find_closed_total_scores_by_user_id(conn, user_id)
+                            .await?,
+                    );
+                }
+                let completed = completed.as_deref().unwrap_or_default();
+                let is_met = prerequisites.iter().all(|prerequisite| {
+                    completed.iter().any(|result| {
+                        result.code == prerequisite.code
+                            && result.total_score >= i64::from(prerequisite.min_score)
+                    })
+                });
+                if !is_met {
+                    errors.prerequisite_not_met.push(course.id);
+                    continue;
+                }
+            }
+
             newly_added.push(course);
         }
 
         let already_registered = registration_course_repo
-            .find_open_courses_by_user_id(&mut tx, user_id)
+            .find_open_courses_by_user_id(conn, user_id)
             .await?;
 
         for course1 in &newly_added {
-            for course2 in already_registered.iter().chain(newly_added.iter()) {
-                if course1.id != course2.id
-                    && course1.period == course2.period
-                    && course1.day_of_week == course2.day_of_week
-                {
-                    errors.schedule_conflict.push(course1.id.to_owned());
-                    break;
+            let conflicts_with = already_registered
+                .iter()
+                .chain(newly_added.iter())
+                .filter(|course2| {
+                    course1.id != course2.id
+                        && course1.period == course2.period
+                        && course1.day_of_week == course2.day_of_week
+                })
+                .map(|course2| course2.id.to_owned())
+                .collect::<Vec<_>>();
+            if !conflicts_with.is_empty() {
+                errors.schedule_conflict.push(course1.id.to_owned());
+                errors.schedule_conflict_details.push(ScheduleConflict {
+                    course_id: course1.id.to_owned(),
+                    conflicts_with,
+                });
+            }
+        }
+
+        if !newly_added.is_empty() {
+            if let Some(credit_limit) = self.find_credit_limit(conn, user_id).await? {
+                let registered_credits = total_credits(&already_registered);
+                let requested_credits = total_credits(&newly_added);
+                if registered_credits + requested_credits > credit_limit {
+                    errors.credit_limit_exceeded = Some(CreditLimitExceeded {
+                        credit_limit,
+                        registered_credits,
+                        requested_credits,
+                    });
                 }
             }
         }
 
-        if !errors.course_not_found.is_empty()
-            || !errors.not_registrable_status.is_empty()
-            || !errors.schedule_conflict.is_empty()
-        {
-            return Err(Error::RegistrationCourseValidationError(errors));
+        Ok((newly_added, errors))
+    }
+
+    // 学生ごとの上書きがあればそれを、なければ設定値を単位数の上限とする。
+    // どちらもない場合は上限なしとしてNoneを返す
+    async fn find_credit_limit(&self, conn: &mut DBConn, user_id: &UserID) -> Result<Option<u32>> {
+        let credit_limit = self
+            .credit_limit_repo()
+            .find_by_user_id(conn, user_id)
+            .await?;
+
+        Ok(credit_limit.or(self.credit_limit_config().credits_per_term))
+    }
+
+    // 空席の数だけキャンセル待ちの先頭から履修登録し、繰り上がった学生に通知する。
+    // 呼び出し元は科目の行をロックしたトランザクション内で呼ぶこと
+    async fn promote_waitlist(&self, conn: &mut DBConn, course: &Course) -> Result<()> {
+        if course.status != CourseStatus::Registration {
+            return Ok(());
         }
 
-        for course in newly_added {
+        let registration_repo = self.registration_repo();
+        let waitlist_repo = self.waitlist_repo();
+        let mut registered = registration_repo
+            .count_for_share_lock_by_course_id(conn, &course.id)
+            .await?;
+        loop {
+            if let Some(capacity) = course.capacity {
+                if registered >= i64::from(capacity) {
+                    break;
+                }
+            }
+            let user_id = match waitlist_repo
+                .find_first_for_update_by_course_id(conn, &course.id)
+                .await?
+            {
+                Some(user_id) => user_id,
+                None => break,
+            };
+            waitlist_repo.delete(conn, &course.id, &user_id).await?;
+
+            // 待っている間に同じ時限の科目を履修登録した学生は繰り上げずに待ちから外す
+            let registered_courses = self
+                .registration_course_repo()
+                .find_open_courses_by_user_id(conn, &user_id)
+                .await?;
+            if registered_courses.iter().any(|registered| {
+                registered.id != course.id
+                    && registered.period == course.period
+                    && registered.day_of_week == course.day_of_week
+            }) {
+                continue;
+            }
+            // 繰り上げると単位数の上限を超える学生も待ちから外す
+            let credit_limit = self.find_credit_limit(conn, &user_id).await?;
+            if credit_limit.is_some_and(|credit_limit| {
+                total_credits(&registered_courses) + u32::from(course.credit) > credit_limit
+            }) {
+                continue;
+            }
+
             registration_repo
-                .create_or_update(&mut tx, user_id, &course.id)
+                .create_or_update(conn, &user_id, &course.id)
                 .await?;
-        }
+            registered += 1;
 
-        tx.commit().await?;
+            let announcement = Announcement {
+                id: AnnouncementID::new(util::new_ulid().await),
+                course_id: course.id.clone(),
+                title: format!("{}の履修登録が確定しました", course.name),
+                message: "キャンセル待ちから繰り上がり、履修登録が確定しました。".to_string(),
+            };
+            self.announcement_repo().create(conn, &announcement).await?;
+            self.unread_announcement_repo()
+                .create(conn, &announcement.id, &user_id)
+                .await?;
+        }
 
         Ok(())
     }
@@ -122,4 +1334,149 @@ impl<S: RegistrationCourseServiceImpl> RegistrationCourseService for S {
     async fn create(&self, user_id: &UserID, course_ids: &[CourseID]) -> Result<()> {
         RegistrationCourseServiceImpl::create(self, user_id, course_ids).await
     }
+
+    async fn validate(
+        &self,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<RegistrationCourseValidationError> {
+        RegistrationCourseServiceImpl::validate(self, user_id, course_ids).await
+    }
+
+    async fn drop_course(&self, user_id: &UserID, course_id: &CourseID) -> Result<DropOutcome> {
+        RegistrationCourseServiceImpl::drop_course(self, user_id, course_id).await
+    }
+
+    async fn join_waitlist(&self, user_id: &UserID, course_id: &CourseID) -> Result<i64> {
+        RegistrationCourseServiceImpl::join_waitlist(self, user_id, course_id).await
+    }
+
+    async fn leave_waitlist(&self, user_id: &UserID, course_id: &CourseID) -> Result<()> {
+        RegistrationCourseServiceImpl::leave_waitlist(self, user_id, course_id).await
+    }
+
+    async fn update_capacity(
+        &self,
+        course_id: &CourseID,
+        capacity: Option<u32>,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        RegistrationCourseServiceImpl::update_capacity(self, course_id, capacity, audit_log).await
+    }
+
+    async fn update_credit_limit(
+        &self,
+        user_id: &UserID,
+        credit_limit: Option<u32>,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        RegistrationCourseServiceImpl::update_credit_limit(self, user_id, credit_limit, audit_log)
+            .await
+    }
+
+    async fn is_registration_open(&self, user_id: &UserID) -> Result<bool> {
+        RegistrationCourseServiceImpl::is_registration_open(self, user_id).await
+    }
+
+    async fn find_registration_windows(&self) -> Result<Vec<RegistrationWindow>> {
+        RegistrationCourseServiceImpl::find_registration_windows(self).await
+    }
+
+    async fn create_registration_window(
+        &self,
+        req: &CreateRegistrationWindow,
+        audit_log: &CreateAuditLog,
+    ) -> Result<RegistrationWindow> {
+        RegistrationCourseServiceImpl::create_registration_window(self, req, audit_log).await
+    }
+
+    async fn delete_registration_window(
+        &self,
+        id: &RegistrationWindowID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        RegistrationCourseServiceImpl::delete_registration_window(self, id, audit_log).await
+    }
+
+    async fn update_lottery(
+        &self,
+        course_id: &CourseID,
+        enabled: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        RegistrationCourseServiceImpl::update_lottery(self, course_id, enabled, audit_log).await
+    }
+
+    async fn find_lottery_preferences(&self, user_id: &UserID) -> Result<Vec<LotteryPreference>> {
+        RegistrationCourseServiceImpl::find_lottery_preferences(self, user_id).await
+    }
+
+    async fn find_registration_request_course_ids(
+        &self,
+        user_id: &UserID,
+    ) -> Result<Vec<CourseID>> {
+        RegistrationCourseServiceImpl::find_registration_request_course_ids(self, user_id).await
+    }
+
+    async fn update_lottery_preferences(
+        &self,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<()> {
+        RegistrationCourseServiceImpl::update_lottery_preferences(self, user_id, course_ids).await
+    }
+
+    async fn draw_lottery(
+        &self,
+        seed: Option<u64>,
+        audit_log: &CreateAuditLog,
+    ) -> Result<LotteryDrawReport> {
+        RegistrationCourseServiceImpl::draw_lottery(self, seed, audit_log).await
+    }
+
+    async fn update_approval_required(
+        &self,
+        course_id: &CourseID,
+        required: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        RegistrationCourseServiceImpl::update_approval_required(
+            self, course_id, required, audit_log,
+        )
+        .await
+    }
+
+    async fn find_registration_requests(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+    ) -> Result<Vec<RegistrationRequest>> {
+        RegistrationCourseServiceImpl::find_registration_requests(self, course_id, teacher_id).await
+    }
+
+    async fn approve_registration_request(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+        user_id: &UserID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        RegistrationCourseServiceImpl::approve_registration_request(
+            self, course_id, teacher_id, user_id, audit_log,
+        )
+        .await
+    }
+
+    async fn deny_registration_request(
+        &self,
+        course_id: &CourseID,
+        teacher_id: &UserID,
+        user_id: &UserID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<()> {
+        RegistrationCourseServiceImpl::deny_registration_request(
+            self, course_id, teacher_id, user_id, audit_log,
+        )
+        .await
+    }
 }
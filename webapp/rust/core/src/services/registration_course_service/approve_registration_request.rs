@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::Course;
+    use crate::models::course_status::CourseStatus;
+    use crate::models::day_of_week::DayOfWeek;
+    use crate::models::user::UserID;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::error::{Error, ScheduleConflict};
+    use crate::services::registration_course_service::RegistrationCourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    fn requested_course(service: &mut MockRepositoryManager, registered: Vec<Course>) -> Course {
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.capacity = None;
+        course.credit = 2;
+        course.period = 1;
+        course.day_of_week = DayOfWeek::Monday;
+        course.teacher_id = UserID::new("teacher".to_string());
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service
+            .registration_request_repo
+            .expect_delete()
+            .returning(|_, _, _| Ok(true));
+        service
+            .registration_repo
+            .expect_exist_by_user_id_and_course_id()
+            .returning(|_, _, _| Ok(false));
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .returning(move |_, _| Ok(registered.clone()));
+        service
+            .credit_limit_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(None));
+        course
+    }
+
+    #[tokio::test]
+    async fn not_course_teacher_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course: Course = Faker.fake();
+        course.teacher_id = UserID::new("teacher".to_string());
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service.registration_request_repo.expect_delete().never();
+        service.registration_repo.expect_create_or_update().never();
+
+        let err = service
+            .approve_registration_request(
+                &course.id,
+                &UserID::new("other".to_string()),
+                &UserID::new("user".to_string()),
+                &Faker.fake(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::NotCourseTeacher));
+    }
+
+    #[tokio::test]
+    async fn not_found_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course: Course = Faker.fake();
+        course.teacher_id = UserID::new("teacher".to_string());
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service
+            .registration_request_repo
+            .expect_delete()
+            .returning(|_, _, _| Ok(false));
+        service.registration_repo.expect_create_or_update().never();
+
+        let err = service
+            .approve_registration_request(
+                &course.id,
+                &UserID::new("teacher".to_string()),
+                &UserID::new("user".to_string()),
+                &Faker.fake(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::RegistrationRequestNotFound));
+    }
+
+    #[tokio::test]
+    async fn schedule_conflict_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        // 申請後に同じ時限の科目を履修登録している
+        let mut registered: Course = Faker.fake();
+        registered.credit = 1;
+        registered.period = 1;
+        registered.day_of_week = DayOfWeek::Monday;
+        let course = requested_course(&mut service, vec![registered.clone()]);
+        service.registration_repo.expect_create_or_update().never();
+
+        let err = service
+            .approve_registration_request(
+                &course.id,
+                &UserID::new("teacher".to_string()),
+                &UserID::new("user".to_string()),
+                &Faker.fake(),
+            )
+            .await
+            .unwrap_err();
+        match err {
+            Error::RegistrationCourseValidationError(errors) => {
+                assert_eq!(errors.schedule_conflict, vec![course.id.clone()]);
+                assert_eq!(
+                    errors.schedule_conflict_details,
+                    vec![ScheduleConflict {
+                        course_id: course.id,
+                        conflicts_with: vec![registered.id],
+                    }]
+                );
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut registered: Course = Faker.fake();
+        registered.credit = 1;
+        registered.period = 2;
+        registered.day_of_week = DayOfWeek::Monday;
+        let course = requested_course(&mut service, vec![registered]);
+        let course_id = course.id.clone();
+        service
+            .registration_repo
+            .expect_create_or_update()
+            .withf(move |_, user_id, id| user_id.inner() == "user" && *id == course_id)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        service
+            .approve_registration_request(
+                &course.id,
+                &UserID::new("teacher".to_string()),
+                &UserID::new("user".to_string()),
+                &Faker.fake(),
+            )
+            .await
+            .unwrap();
+    }
+}
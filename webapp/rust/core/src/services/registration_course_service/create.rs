@@ -0,0 +1,318 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::{Course, CourseCode};
+    use crate::models::course_prerequisite::CoursePrerequisite;
+    use crate::models::course_result::CourseTotalScore;
+    use crate::models::course_status::CourseStatus;
+    use crate::models::day_of_week::DayOfWeek;
+    use crate::models::user::UserID;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::error::{CreditLimitExceeded, Error};
+    use crate::services::registration_course_service::RegistrationCourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    fn prerequisite_course(service: &mut MockRepositoryManager, min_score: u32) -> Course {
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.capacity = None;
+        service
+            .course_repo
+            .expect_find_capacity_limited_ids_by_ids()
+            .returning(|_, _| Ok(vec![]));
+        service.course_repo.expect_for_update_by_ids().never();
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_ids()
+            .times(1)
+            .returning(move |_, _| Ok(vec![found.clone()]));
+        service
+            .registration_window_repo
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        service
+            .lottery_course_repo
+            .expect_find_course_ids_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_find_course_ids_by_user_id_and_course_ids()
+            .times(1)
+            .returning(|_, _, _| Ok(vec![]));
+        let course_id = course.id.clone();
+        service
+            .course_prerequisite_repo
+            .expect_find_by_course_ids()
+            .returning(move |_, _| {
+                Ok(vec![CoursePrerequisite {
+                    course_id: course_id.clone(),
+                    code: CourseCode::new("L0001".to_string()),
+                    min_score,
+                }])
+            });
+        service
+            .registration_course_repo
+            .expect_find_closed_total_scores_by_user_id()
+            .times(1)
+            .returning(|_, _| {
+                Ok(vec![CourseTotalScore {
+                    code: CourseCode::new("L0001".to_string()),
+                    total_score: 70,
+                }])
+            });
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .returning(|_, _| Ok(vec![]));
+        course
+    }
+
+    #[tokio::test]
+    async fn prerequisite_not_met_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = prerequisite_course(&mut service, 80);
+        service
+            .registration_repo
+            .expect_create_or_update_all()
+            .never();
+
+        let err = service
+            .create(
+                &UserID::new("user".to_string()),
+                std::slice::from_ref(&course.id),
+            )
+            .await
+            .unwrap_err();
+        match err {
+            Error::RegistrationCourseValidationError(errors) => {
+                assert_eq!(errors.prerequisite_not_met, vec![course.id]);
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn prerequisite_met_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = prerequisite_course(&mut service, 60);
+        service
+            .credit_limit_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(None));
+        service
+            .approval_required_course_repo
+            .expect_find_course_ids_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        let course_id = course.id.clone();
+        service
+            .registration_repo
+            .expect_create_or_update_all()
+            .withf(move |_, _, course_ids| course_ids == [course_id.clone()])
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        service
+            .create(&UserID::new("user".to_string()), &[course.id])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn approval_required_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = prerequisite_course(&mut service, 60);
+        service
+            .credit_limit_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(None));
+        service
+            .approval_required_course_repo
+            .expect_find_course_ids_by_course_ids()
+            .returning(|_, course_ids| Ok(course_ids.to_vec()));
+        // 承認が必要な科目は履修登録せずに申請する
+        let course_id = course.id.clone();
+        service
+            .registration_request_repo
+            .expect_create()
+            .withf(move |_, id, user_id| *id == course_id && user_id.inner() == "user")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service
+            .registration_repo
+            .expect_create_or_update_all()
+            .withf(|_, _, course_ids| course_ids.is_empty())
+            .returning(|_, _, _| Ok(()));
+
+        service
+            .create(&UserID::new("user".to_string()), &[course.id])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn credit_limit_exceeded_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.capacity = None;
+        course.credit = 2;
+        course.period = 1;
+        course.day_of_week = DayOfWeek::Monday;
+        service
+            .course_repo
+            .expect_find_capacity_limited_ids_by_ids()
+            .returning(|_, _| Ok(vec![]));
+        service.course_repo.expect_for_update_by_ids().never();
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_ids()
+            .times(1)
+            .returning(move |_, _| Ok(vec![found.clone()]));
+        service
+            .registration_window_repo
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        service
+            .lottery_course_repo
+            .expect_find_course_ids_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_find_course_ids_by_user_id_and_course_ids()
+            .times(1)
+            .returning(|_, _, _| Ok(vec![]));
+        service
+            .course_prerequisite_repo
+            .expect_find_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        let mut registered: Course = Faker.fake();
+        registered.credit = 3;
+        registered.period = 2;
+        registered.day_of_week = DayOfWeek::Monday;
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .returning(move |_, _| Ok(vec![registered.clone()]));
+        // 学生ごとの上書きが設定値より優先される
+        service
+            .credit_limit_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(Some(4)));
+        service
+            .registration_repo
+            .expect_create_or_update_all()
+            .never();
+
+        let err = service
+            .create(&UserID::new("user".to_string()), &[course.id])
+            .await
+            .unwrap_err();
+        match err {
+            Error::RegistrationCourseValidationError(errors) => {
+                assert_eq!(
+                    errors.credit_limit_exceeded,
+                    Some(CreditLimitExceeded {
+                        credit_limit: 4,
+                        registered_credits: 3,
+                        requested_credits: 2,
+                    })
+                );
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn course_full_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.capacity = Some(1);
+        // 定員のある科目は空席を数える前に排他ロックを取る
+        let course_id = course.id.clone();
+        service
+            .course_repo
+            .expect_find_capacity_limited_ids_by_ids()
+            .times(1)
+            .returning(move |_, _| Ok(vec![course_id.clone()]));
+        let course_id = course.id.clone();
+        service
+            .course_repo
+            .expect_for_update_by_ids()
+            .withf(move |_, ids| ids == [course_id.clone()])
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_ids()
+            .times(1)
+            .returning(move |_, _| Ok(vec![found.clone()]));
+        service
+            .registration_window_repo
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        service
+            .lottery_course_repo
+            .expect_find_course_ids_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_find_course_ids_by_user_id_and_course_ids()
+            .times(1)
+            .returning(|_, _, _| Ok(vec![]));
+        let course_id = course.id.clone();
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_ids()
+            .withf(move |_, course_ids| course_ids == [course_id.clone()])
+            .times(1)
+            .returning(|_, course_ids| Ok(vec![(course_ids[0].clone(), 1)]));
+        service
+            .course_prerequisite_repo
+            .expect_find_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_create_or_update_all()
+            .never();
+
+        let err = service
+            .create(
+                &UserID::new("user".to_string()),
+                std::slice::from_ref(&course.id),
+            )
+            .await
+            .unwrap_err();
+        match err {
+            Error::RegistrationCourseValidationError(errors) => {
+                assert_eq!(errors.course_full, vec![course.id]);
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+}
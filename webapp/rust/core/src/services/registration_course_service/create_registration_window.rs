@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::registration_window::CreateRegistrationWindow;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::registration_course_service::RegistrationCourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    fn request() -> CreateRegistrationWindow {
+        let starts_at = chrono::Utc::now().naive_utc();
+        CreateRegistrationWindow {
+            term: "2026 spring".to_string(),
+            cohort: Some("S1".to_string()),
+            starts_at,
+            ends_at: starts_at + chrono::Duration::days(7),
+        }
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidRegistrationWindow")]
+    async fn ends_before_starts_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service.registration_window_repo.expect_create().never();
+
+        let mut req = request();
+        req.ends_at = req.starts_at - chrono::Duration::days(1);
+        service
+            .create_registration_window(&req, &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidRegistrationWindow")]
+    async fn empty_cohort_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service.registration_window_repo.expect_create().never();
+
+        let mut req = request();
+        req.cohort = Some(String::new());
+        service
+            .create_registration_window(&req, &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let req = request();
+        let expected = req.clone();
+        service
+            .registration_window_repo
+            .expect_create()
+            .withf(move |_, window| {
+                window.term == expected.term
+                    && window.cohort == expected.cohort
+                    && window.starts_at == expected.starts_at
+                    && window.ends_at == expected.ends_at
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .withf(|_, audit_log| {
+                audit_log
+                    .after_summary
+                    .as_deref()
+                    .is_some_and(|summary| summary.contains("2026 spring"))
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let window = service
+            .create_registration_window(&req, &Faker.fake())
+            .await
+            .unwrap();
+        assert_eq!(window.term, req.term);
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::Course;
+    use crate::models::course_status::CourseStatus;
+    use crate::models::day_of_week::DayOfWeek;
+    use crate::models::lottery_outcome::LotteryOutcome;
+    use crate::models::lottery_preference::LotteryPreference;
+    use crate::models::user::UserID;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::registration_course_service::RegistrationCourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    fn lottery_course(capacity: Option<u32>) -> Course {
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.capacity = capacity;
+        course.credit = 2;
+        course.period = 1;
+        course.day_of_week = DayOfWeek::Monday;
+        course
+    }
+
+    fn preference(user_id: &str, course: &Course, rank: u32) -> LotteryPreference {
+        LotteryPreference {
+            user_id: UserID::new(user_id.to_string()),
+            course_id: course.id.clone(),
+            rank,
+        }
+    }
+
+    fn expect_courses(service: &mut MockRepositoryManager, courses: Vec<Course>) {
+        let course_ids = courses.iter().map(|course| course.id.clone()).collect();
+        service
+            .lottery_course_repo
+            .expect_find_all_course_ids()
+            .returning(move |_| Ok(Vec::clone(&course_ids)));
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, course_id| {
+                Ok(courses
+                    .iter()
+                    .find(|course| course.id == *course_id)
+                    .cloned())
+            });
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .credit_limit_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(None));
+        service
+            .waitlist_repo
+            .expect_delete()
+            .returning(|_, _, _| Ok(()));
+        service
+            .lottery_preference_repo
+            .expect_delete_by_course_id()
+            .returning(|_, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .returning(|_, _| Ok(()));
+    }
+
+    #[tokio::test]
+    async fn capacity_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = lottery_course(Some(1));
+        let preferences = vec![
+            preference("student1", &course, 1),
+            preference("student2", &course, 1),
+        ];
+        expect_courses(&mut service, vec![course]);
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_id()
+            .returning(|_, _| Ok(0));
+        service
+            .lottery_preference_repo
+            .expect_find_by_course_id()
+            .returning(move |_, _| Ok(preferences.clone()));
+        service
+            .registration_repo
+            .expect_create_or_update()
+            .times(2)
+            .returning(|_, _, _| Ok(()));
+
+        let report = service.draw_lottery(Some(42), &Faker.fake()).await.unwrap();
+        assert_eq!(report.seed, 42);
+        assert_eq!(report.students.len(), 2);
+        let mut outcomes = report
+            .students
+            .iter()
+            .map(|student| student.results[0].outcome)
+            .collect::<Vec<_>>();
+        outcomes.sort_by_key(|outcome| *outcome != LotteryOutcome::Won);
+        assert_eq!(
+            outcomes,
+            vec![LotteryOutcome::Won, LotteryOutcome::CourseFull]
+        );
+
+        // 同じseedで引き直すと同じ結果になる
+        let again = service.draw_lottery(Some(42), &Faker.fake()).await.unwrap();
+        assert_eq!(again, report);
+    }
+
+    #[tokio::test]
+    async fn schedule_conflict_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let first = lottery_course(None);
+        let second = lottery_course(None);
+        let preferences = [
+            preference("student", &first, 1),
+            preference("student", &second, 2),
+        ];
+        expect_courses(&mut service, vec![first.clone(), second.clone()]);
+        service
+            .lottery_preference_repo
+            .expect_find_by_course_id()
+            .returning(move |_, course_id| {
+                Ok(preferences
+                    .iter()
+                    .filter(|preference| preference.course_id == *course_id)
+                    .cloned()
+                    .collect())
+            });
+        service
+            .registration_repo
+            .expect_create_or_update()
+            .withf(move |_, _, course_id| *course_id == first.id)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let report = service.draw_lottery(Some(1), &Faker.fake()).await.unwrap();
+        let results = &report.students[0].results;
+        assert_eq!(results[0].outcome, LotteryOutcome::Won);
+        assert_eq!(results[1].course_id, second.id);
+        assert_eq!(results[1].outcome, LotteryOutcome::ScheduleConflict);
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::Course;
+    use crate::models::course_status::CourseStatus;
+    use crate::models::drop_outcome::DropOutcome;
+    use crate::models::user::UserID;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::registration_course_service::RegistrationCourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    fn registered(service: &mut MockRepositoryManager, course: &Course) {
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service
+            .registration_repo
+            .expect_exist_by_user_id_and_course_id()
+            .returning(|_, _, _| Ok(true));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "RegistrationAlready")]
+    async fn not_registered_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service
+            .registration_repo
+            .expect_exist_by_user_id_and_course_id()
+            .returning(|_, _, _| Ok(false));
+        service.registration_repo.expect_delete().never();
+
+        service
+            .drop_course(&UserID::new("user".to_string()), &course.id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CourseNotDroppable")]
+    async fn closed_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Closed;
+        registered(&mut service, &course);
+        service.registration_repo.expect_delete().never();
+        service.registration_repo.expect_withdraw().never();
+
+        service
+            .drop_course(&UserID::new("user".to_string()), &course.id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn dropped_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.capacity = Some(10);
+        registered(&mut service, &course);
+        service
+            .registration_repo
+            .expect_delete()
+            .withf(|_, user_id, _| user_id.inner() == "user")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service.registration_repo.expect_withdraw().never();
+        // 空いた席はキャンセル待ちに回す
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_id()
+            .returning(|_, _| Ok(9));
+        service
+            .waitlist_repo
+            .expect_find_first_for_update_by_course_id()
+            .times(1)
+            .returning(|_, _| Ok(None));
+
+        let outcome = service
+            .drop_course(&UserID::new("user".to_string()), &course.id)
+            .await
+            .unwrap();
+        assert_eq!(outcome, DropOutcome::Dropped);
+    }
+
+    #[tokio::test]
+    async fn withdrawn_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::InProgress;
+        registered(&mut service, &course);
+        service.registration_repo.expect_delete().never();
+        service
+            .registration_repo
+            .expect_withdraw()
+            .withf(|_, user_id, _, _| user_id.inner() == "user")
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        service
+            .waitlist_repo
+            .expect_find_first_for_update_by_course_id()
+            .never();
+
+        let outcome = service
+            .drop_course(&UserID::new("user".to_string()), &course.id)
+            .await
+            .unwrap();
+        assert_eq!(outcome, DropOutcome::Withdrawn);
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::Course;
+    use crate::models::course_status::CourseStatus;
+    use crate::models::user::UserID;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::registration_course_service::RegistrationCourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    fn full_course() -> Course {
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.capacity = Some(1);
+        course
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CourseNotFull")]
+    async fn not_full_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = full_course();
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service
+            .lottery_course_repo
+            .expect_exist_by_course_id()
+            .returning(|_, _| Ok(false));
+        service
+            .registration_repo
+            .expect_exist_by_user_id_and_course_id()
+            .returning(|_, _, _| Ok(false));
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_id()
+            .returning(|_, _| Ok(0));
+        service.waitlist_repo.expect_create().never();
+
+        service
+            .join_waitlist(&UserID::new("user".to_string()), &course.id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyRegistered")]
+    async fn already_registered_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = full_course();
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service
+            .lottery_course_repo
+            .expect_exist_by_course_id()
+            .returning(|_, _| Ok(false));
+        service
+            .registration_repo
+            .expect_exist_by_user_id_and_course_id()
+            .returning(|_, _, _| Ok(true));
+        service.waitlist_repo.expect_create().never();
+
+        service
+            .join_waitlist(&UserID::new("user".to_string()), &course.id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CourseNotRegistrable")]
+    async fn lottery_course_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = full_course();
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service
+            .lottery_course_repo
+            .expect_exist_by_course_id()
+            .returning(|_, _| Ok(true));
+        service.waitlist_repo.expect_create().never();
+
+        service
+            .join_waitlist(&UserID::new("user".to_string()), &course.id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let course = full_course();
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        service
+            .lottery_course_repo
+            .expect_exist_by_course_id()
+            .returning(|_, _| Ok(false));
+        service
+            .registration_repo
+            .expect_exist_by_user_id_and_course_id()
+            .returning(|_, _, _| Ok(false));
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_id()
+            .returning(|_, _| Ok(1));
+        service
+            .waitlist_repo
+            .expect_create()
+            .withf(|_, _, user_id| user_id.inner() == "user")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service
+            .waitlist_repo
+            .expect_find_position()
+            .returning(|_, _, _| Ok(Some(3)));
+
+        let position = service
+            .join_waitlist(&UserID::new("user".to_string()), &course.id)
+            .await
+            .unwrap();
+        assert_eq!(position, 3);
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::Course;
+    use crate::models::course_status::CourseStatus;
+    use crate::models::day_of_week::DayOfWeek;
+    use crate::models::user::UserID;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::registration_course_service::RegistrationCourseServiceImpl;
+    use fake::{Fake, Faker};
+    use mockall::Sequence;
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidCourseCapacity")]
+    async fn zero_capacity_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service.course_repo.expect_update_capacity_by_id().never();
+
+        service
+            .update_capacity(&Faker.fake(), Some(0), &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CourseNotFound")]
+    async fn not_found_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(false));
+        service.course_repo.expect_update_capacity_by_id().never();
+
+        service
+            .update_capacity(&Faker.fake(), Some(10), &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn promote_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.period = 1;
+        course.day_of_week = DayOfWeek::Monday;
+        course.capacity = Some(3);
+
+        let conflicting = UserID::new("conflicting".to_string());
+        let promoted = UserID::new("promoted".to_string());
+        let mut conflict_course: Course = Faker.fake();
+        conflict_course.period = 1;
+        conflict_course.day_of_week = DayOfWeek::Monday;
+
+        service
+            .course_repo
+            .expect_for_update_by_id()
+            .returning(|_, _| Ok(true));
+        service
+            .course_repo
+            .expect_update_capacity_by_id()
+            .withf(|_, _, capacity| *capacity == Some(3))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        // 定員3に対して2人が登録済みなので、1人だけ繰り上げる
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_id()
+            .returning(|_, _| Ok(2));
+
+        let mut seq = Sequence::new();
+        let first = conflicting.clone();
+        service
+            .waitlist_repo
+            .expect_find_first_for_update_by_course_id()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(move |_, _| Ok(Some(first.clone())));
+        let second = promoted.clone();
+        service
+            .waitlist_repo
+            .expect_find_first_for_update_by_course_id()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(move |_, _| Ok(Some(second.clone())));
+        service
+            .waitlist_repo
+            .expect_delete()
+            .times(2)
+            .returning(|_, _, _| Ok(()));
+
+        // 同じ時限の科目を登録済みの学生は繰り上げない
+        let conflicting_id = conflicting.clone();
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .returning(move |_, user_id| {
+                if user_id == &conflicting_id {
+                    Ok(vec![conflict_course.clone()])
+                } else {
+                    Ok(vec![])
+                }
+            });
+        service
+            .credit_limit_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(None));
+        let promoted_id = promoted.clone();
+        service
+            .registration_repo
+            .expect_create_or_update()
+            .withf(move |_, user_id, _| user_id == &promoted_id)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service
+            .announcement_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let promoted_id = promoted.clone();
+        service
+            .unread_announcement_repo
+            .expect_create()
+            .withf(move |_, _, user_id| user_id == &promoted_id)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        service
+            .update_capacity(&course.id, Some(3), &Faker.fake())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::user::{UserCode, UserID};
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::registration_course_service::RegistrationCourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidCreditLimit")]
+    async fn zero_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service.credit_limit_repo.expect_create_or_update().never();
+
+        service
+            .update_credit_limit(&UserID::new("user".to_string()), Some(0), &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "UserNotFound")]
+    async fn user_not_found_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(None));
+        service.credit_limit_repo.expect_create_or_update().never();
+
+        service
+            .update_credit_limit(&UserID::new("user".to_string()), Some(30), &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn reset_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(Some(UserCode::new("S00001".to_string().into()))));
+        service.credit_limit_repo.expect_create_or_update().never();
+        service
+            .credit_limit_repo
+            .expect_delete_by_user_id()
+            .withf(|_, user_id| user_id.inner() == "user")
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        service
+            .update_credit_limit(&UserID::new("user".to_string()), None, &Faker.fake())
+            .await
+            .unwrap();
+    }
+}
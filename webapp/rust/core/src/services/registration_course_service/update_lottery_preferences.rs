@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::Course;
+    use crate::models::course_status::CourseStatus;
+    use crate::models::user::UserID;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::registration_course_service::RegistrationCourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    fn expect_course(service: &mut MockRepositoryManager, is_lottery: bool) {
+        service
+            .registration_window_repo
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_id()
+            .returning(|_, _| {
+                let mut course: Course = Faker.fake();
+                course.status = CourseStatus::Registration;
+                Ok(Some(course))
+            });
+        service
+            .lottery_course_repo
+            .expect_exist_by_course_id()
+            .returning(move |_, _| Ok(is_lottery));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidLotteryPreference")]
+    async fn duplicate_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        expect_course(&mut service, true);
+        service.lottery_preference_repo.expect_create().never();
+
+        let course: Course = Faker.fake();
+        service
+            .update_lottery_preferences(
+                &UserID::new("user".to_string()),
+                &[course.id.clone(), course.id],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidLotteryPreference")]
+    async fn not_lottery_course_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        expect_course(&mut service, false);
+        service.lottery_preference_repo.expect_create().never();
+
+        let course: Course = Faker.fake();
+        service
+            .update_lottery_preferences(&UserID::new("user".to_string()), &[course.id])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        expect_course(&mut service, true);
+        service
+            .lottery_preference_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let first: Course = Faker.fake();
+        let second: Course = Faker.fake();
+        let expected = [(first.id.clone(), 1), (second.id.clone(), 2)];
+        service
+            .lottery_preference_repo
+            .expect_create()
+            .withf(move |_, preference| {
+                expected.contains(&(preference.course_id.clone(), preference.rank))
+            })
+            .times(2)
+            .returning(|_, _| Ok(()));
+
+        service
+            .update_lottery_preferences(&UserID::new("user".to_string()), &[first.id, second.id])
+            .await
+            .unwrap();
+    }
+}
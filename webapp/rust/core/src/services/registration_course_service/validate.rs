@@ -0,0 +1,304 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::course::Course;
+    use crate::models::course_status::CourseStatus;
+    use crate::models::day_of_week::DayOfWeek;
+    use crate::models::registration_window::RegistrationWindow;
+    use crate::models::user::{UserCode, UserID};
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::error::{CreditLimitExceeded, ScheduleConflict};
+    use crate::services::registration_course_service::RegistrationCourseServiceImpl;
+    use fake::{Fake, Faker};
+
+    // 別の時限に59単位を履修済みの学生が3単位の科目を追加する
+    fn expect_heavy_load(service: &mut MockRepositoryManager) -> Course {
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.capacity = None;
+        course.credit = 3;
+        course.period = 6;
+        course.day_of_week = DayOfWeek::Friday;
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_ids()
+            .returning(move |_, _| Ok(vec![found.clone()]));
+        service
+            .registration_window_repo
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        service
+            .lottery_course_repo
+            .expect_find_course_ids_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_find_course_ids_by_user_id_and_course_ids()
+            .returning(|_, _, _| Ok(vec![]));
+        service
+            .course_prerequisite_repo
+            .expect_find_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .returning(|_, _| {
+                Ok((0..20)
+                    .map(|i| {
+                        let mut registered: Course = Faker.fake();
+                        registered.credit = if i == 0 { 2 } else { 3 };
+                        registered.period = 1 + i % 5;
+                        registered.day_of_week = DayOfWeek::Monday;
+                        registered
+                    })
+                    .collect())
+            });
+        service
+            .credit_limit_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(None));
+        course
+    }
+
+    #[tokio::test]
+    async fn credit_limit_not_configured_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        // 上限が設定されていなければ単位数を制限しない
+        let course = expect_heavy_load(&mut service);
+
+        let errors = service
+            .validate(&UserID::new("user".to_string()), &[course.id])
+            .await
+            .unwrap();
+        assert!(errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn credit_limit_configured_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service.credit_limit_config.credits_per_term = Some(60);
+        let course = expect_heavy_load(&mut service);
+
+        let errors = service
+            .validate(&UserID::new("user".to_string()), &[course.id])
+            .await
+            .unwrap();
+        assert_eq!(
+            errors.credit_limit_exceeded,
+            Some(CreditLimitExceeded {
+                credit_limit: 60,
+                registered_credits: 59,
+                requested_credits: 3,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn schedule_conflict_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.capacity = None;
+        course.credit = 1;
+        course.period = 1;
+        course.day_of_week = DayOfWeek::Monday;
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_ids()
+            .times(1)
+            .returning(move |_, _| Ok(vec![found.clone()]));
+        service
+            .registration_window_repo
+            .expect_find_all()
+            .returning(|_| Ok(vec![]));
+        service
+            .lottery_course_repo
+            .expect_find_course_ids_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_find_course_ids_by_user_id_and_course_ids()
+            .times(1)
+            .returning(|_, _, _| Ok(vec![]));
+        service
+            .course_prerequisite_repo
+            .expect_find_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        // 同じ時限の履修登録済み科目がすべて報告される
+        let registered = (0..2)
+            .map(|_| {
+                let mut registered: Course = Faker.fake();
+                registered.credit = 1;
+                registered.period = 1;
+                registered.day_of_week = DayOfWeek::Monday;
+                registered
+            })
+            .collect::<Vec<_>>();
+        let registered_ids = registered
+            .iter()
+            .map(|registered| registered.id.clone())
+            .collect::<Vec<_>>();
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .returning(move |_, _| Ok(registered.clone()));
+        service
+            .credit_limit_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(None));
+        // 検証のみで履修登録は行わない
+        service
+            .registration_repo
+            .expect_create_or_update_all()
+            .never();
+
+        let errors = service
+            .validate(
+                &UserID::new("user".to_string()),
+                std::slice::from_ref(&course.id),
+            )
+            .await
+            .unwrap();
+        assert_eq!(errors.schedule_conflict, vec![course.id.clone()]);
+        assert_eq!(
+            errors.schedule_conflict_details,
+            vec![ScheduleConflict {
+                course_id: course.id,
+                conflicts_with: registered_ids,
+            }]
+        );
+    }
+
+    // is_openがtrueなら受付中、falseなら終了済みの期間
+    fn window(cohort: Option<&str>, is_open: bool) -> RegistrationWindow {
+        let now = chrono::Utc::now().naive_utc();
+        let mut window: RegistrationWindow = Faker.fake();
+        window.cohort = cohort.map(|cohort| cohort.to_string());
+        window.starts_at = now - chrono::Duration::days(2);
+        window.ends_at = if is_open {
+            now + chrono::Duration::days(1)
+        } else {
+            now - chrono::Duration::days(1)
+        };
+        window
+    }
+
+    // 学籍番号がS20001の学生が、指定された履修登録期間のもとで科目を検証する
+    fn expect_windows(
+        service: &mut MockRepositoryManager,
+        windows: Vec<RegistrationWindow>,
+    ) -> Course {
+        let mut course: Course = Faker.fake();
+        course.status = CourseStatus::Registration;
+        course.capacity = None;
+        let found = course.clone();
+        service
+            .course_repo
+            .expect_find_for_share_lock_by_ids()
+            .times(1)
+            .returning(move |_, _| Ok(vec![found.clone()]));
+        service
+            .registration_window_repo
+            .expect_find_all()
+            .returning(move |_| Ok(windows.clone()));
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(Some(UserCode::new("S20001".to_string().into()))));
+        service
+            .registration_repo
+            .expect_find_course_ids_by_user_id_and_course_ids()
+            .returning(|_, _, _| Ok(vec![]));
+        service
+            .lottery_course_repo
+            .expect_find_course_ids_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_repo
+            .expect_count_for_share_lock_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .course_prerequisite_repo
+            .expect_find_by_course_ids()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .registration_course_repo
+            .expect_find_open_courses_by_user_id()
+            .returning(|_, _| Ok(vec![]));
+        service
+            .credit_limit_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(None));
+        course
+    }
+
+    #[tokio::test]
+    async fn registration_closed_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        // 自分の学年向けの期間は終了しており、受付中の期間は別の学年向けのもののみ
+        let course = expect_windows(
+            &mut service,
+            vec![window(Some("S2"), false), window(Some("S1"), true)],
+        );
+
+        let errors = service
+            .validate(
+                &UserID::new("user".to_string()),
+                std::slice::from_ref(&course.id),
+            )
+            .await
+            .unwrap();
+        assert_eq!(errors.registration_closed, vec![course.id]);
+    }
+
+    #[tokio::test]
+    async fn other_cohort_window_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        // 別の学年向けの期間は、その学年以外の学生を制限しない
+        let course = expect_windows(&mut service, vec![window(Some("S1"), false)]);
+
+        let errors = service
+            .validate(&UserID::new("user".to_string()), &[course.id])
+            .await
+            .unwrap();
+        assert!(errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn cohort_window_open_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        // 全学生向けの期間が終了していても、自分の学年向けの期間の最中であれば受け付ける
+        let course = expect_windows(
+            &mut service,
+            vec![window(None, false), window(Some("S2"), true)],
+        );
+
+        let errors = service
+            .validate(&UserID::new("user".to_string()), &[course.id])
+            .await
+            .unwrap();
+        assert!(errors.is_empty());
+    }
+}
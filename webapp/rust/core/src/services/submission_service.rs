@@ -10,25 +10,33 @@ pub trait SubmissionService {
         data: &mut B,
     ) -> Result<()>;
 
-    async fn download_submissions_zip(&self, class_id: &ClassID) -> Result<String>;
+    async fn download_submissions_zip(
+        &self,
+        class_id: &ClassID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<String>;
 
     async fn update_user_scores_by_class_id(
         &self,
         class_id: &ClassID,
         scores: &[Score],
+        audit_log: &CreateAuditLog,
     ) -> Result<()>;
 }
 
+use crate::models::audit_log::CreateAuditLog;
 use crate::models::class::ClassID;
 use crate::models::course::CourseID;
 use crate::models::course_status::CourseStatus;
 use crate::models::score::Score;
 use crate::models::submission::CreateSubmission;
 use crate::models::user::{UserCode, UserID};
+use crate::repos::audit_log_repository::HaveAuditLogRepository;
 use crate::repos::class_repository::{ClassRepository, HaveClassRepository};
 use crate::repos::course_repository::{CourseRepository, HaveCourseRepository};
 use crate::repos::registration_repository::{HaveRegistrationRepository, RegistrationRepository};
 use crate::repos::submission_repository::{HaveSubmissionRepository, SubmissionRepository};
+use crate::services::audit_log_service::create_audit_log;
 use crate::services::error::Error::{
     ClassIsNotSubmissionClosed, ClassNotFound, CourseIsNotInProgress, CourseNotFound,
     RegistrationAlready, SubmissionClosed,
@@ -53,6 +61,7 @@ pub trait SubmissionServiceImpl:
     + HaveCourseRepository
     + HaveRegistrationRepository
     + HaveSubmissionFileStorage
+    + HaveAuditLogRepository
 {
     async fn create_or_update<B: bytes::Buf + Send>(
         &self,
@@ -120,7 +129,11 @@ pub trait SubmissionServiceImpl:
         Ok(())
     }
 
-    async fn download_submissions_zip(&self, class_id: &ClassID) -> Result<String> {
+    async fn download_submissions_zip(
+        &self,
+        class_id: &ClassID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<String> {
         let pool = self.get_db_pool();
 
         let mut tx = pool.begin().await?;
@@ -143,6 +156,7 @@ pub trait SubmissionServiceImpl:
         class_repo
             .update_submission_closed_by_id(&mut tx, class_id)
             .await?;
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
 
         tx.commit().await?;
 
@@ -153,6 +167,7 @@ pub trait SubmissionServiceImpl:
         &self,
         class_id: &ClassID,
         scores: &[Score],
+        audit_log: &CreateAuditLog,
     ) -> Result<()> {
         let pool = self.get_db_pool();
         let mut tx = pool.begin().await?;
@@ -178,6 +193,7 @@ pub trait SubmissionServiceImpl:
                 .update_score_by_user_code_and_class_id(&mut tx, &user_code, class_id, score.score)
                 .await?;
         }
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
 
         tx.commit().await?;
 
@@ -199,15 +215,21 @@ impl<S: SubmissionServiceImpl> SubmissionService for S {
             .await
     }
 
-    async fn download_submissions_zip(&self, class_id: &ClassID) -> Result<String> {
-        SubmissionServiceImpl::download_submissions_zip(self, class_id).await
+    async fn download_submissions_zip(
+        &self,
+        class_id: &ClassID,
+        audit_log: &CreateAuditLog,
+    ) -> Result<String> {
+        SubmissionServiceImpl::download_submissions_zip(self, class_id, audit_log).await
     }
 
     async fn update_user_scores_by_class_id(
         &self,
         class_id: &ClassID,
         scores: &[Score],
+        audit_log: &CreateAuditLog,
     ) -> Result<()> {
-        SubmissionServiceImpl::update_user_scores_by_class_id(self, class_id, scores).await
+        SubmissionServiceImpl::update_user_scores_by_class_id(self, class_id, scores, audit_log)
+            .await
     }
 }
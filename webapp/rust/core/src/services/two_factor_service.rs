@@ -0,0 +1,384 @@
+use crate::db::DBConn;
+use crate::models::user::{User, UserID};
+use crate::models::user_recovery_code::{UserRecoveryCode, UserRecoveryCodeID};
+use crate::models::user_totp_secret::UserTotpSecret;
+use crate::models::user_type::UserType;
+use crate::repos::user_recovery_code_repository::{
+    HaveUserRecoveryCodeRepository, UserRecoveryCodeRepository,
+};
+use crate::repos::user_repository::{HaveUserRepository, UserRepository};
+use crate::repos::user_totp_secret_repository::{
+    HaveUserTotpSecretRepository, UserTotpSecretRepository,
+};
+use crate::services::error::Error::{
+    InvalidTotpCode, TotpAlreadyEnabled, TotpNotEnrolled, TotpRequired,
+};
+use crate::services::error::Result;
+use crate::services::personal_access_token_service::hash_token;
+use crate::services::HaveDBPool;
+use crate::util;
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha1::Sha1;
+
+mod complete_login;
+mod disable;
+mod totp_code;
+
+const TOTP_PERIOD_SECS: i64 = 30;
+const TOTP_DIGITS: u32 = 6;
+const TOTP_SECRET_BYTES: usize = 20;
+const RECOVERY_CODE_COUNT: usize = 10;
+
+// RFC 6238 (HMAC-SHA1, 6桁)
+pub fn totp_code(secret: &[u8], step: i64) -> String {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).unwrap();
+    mac.update(&step.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    format!(
+        "{:0width$}",
+        binary % 10u32.pow(TOTP_DIGITS),
+        width = TOTP_DIGITS as usize
+    )
+}
+
+// 端末との時刻のずれを考慮して前後1ステップまで許容し、一致したタイムステップを返す
+pub fn find_matching_step(secret: &str, code: &str, unix_time: i64) -> Option<i64> {
+    let secret = data_encoding::BASE32_NOPAD.decode(secret.as_bytes()).ok()?;
+    let code = code.trim();
+    let step = unix_time / TOTP_PERIOD_SECS;
+    (step - 1..=step + 1).find(|s| totp_code(&secret, *s) == code)
+}
+
+fn generate_secret() -> String {
+    let mut buf = [0u8; TOTP_SECRET_BYTES];
+    rand::thread_rng().fill_bytes(&mut buf);
+    data_encoding::BASE32_NOPAD.encode(&buf)
+}
+
+fn generate_recovery_code() -> String {
+    let mut buf = [0u8; 5];
+    rand::thread_rng().fill_bytes(&mut buf);
+    let code = hex::encode(buf);
+    format!("{}-{}", &code[..5], &code[5..])
+}
+
+fn hash_recovery_code(code: &str) -> String {
+    let normalized = code
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    hash_token(&normalized)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwoFactorConfig {
+    // trueの場合、教員は二要素認証を登録しないとログインを完了できない
+    pub required_for_teachers: bool,
+    pub issuer: String,
+}
+
+impl Default for TwoFactorConfig {
+    fn default() -> Self {
+        Self {
+            required_for_teachers: false,
+            issuer: "ISUCHOLAR".to_string(),
+        }
+    }
+}
+
+impl TwoFactorConfig {
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            required_for_teachers: std::env::var("TOTP_REQUIRED_FOR_TEACHERS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default.required_for_teachers),
+            issuer: std::env::var("TOTP_ISSUER").unwrap_or(default.issuer),
+        }
+    }
+
+    pub fn provisioning_uri(&self, account_name: &str, secret: &str) -> String {
+        format!(
+            "otpauth://totp/{issuer}:{account}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={digits}&period={period}",
+            issuer = urlencoding::encode(&self.issuer),
+            account = urlencoding::encode(account_name),
+            secret = secret,
+            digits = TOTP_DIGITS,
+            period = TOTP_PERIOD_SECS,
+        )
+    }
+}
+
+pub trait HaveTwoFactorConfig {
+    fn two_factor_config(&self) -> &TwoFactorConfig;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TwoFactorRequirement {
+    NotRequired,
+    // 登録済みのTOTPでの検証が必要
+    Verify,
+    // ポリシーにより、ログインを完了する前にTOTPの登録が必要
+    Enroll,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TotpEnrollment {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait TwoFactorService: Sync {
+    async fn requirement(&self, user: &User) -> Result<TwoFactorRequirement>;
+    async fn start_enrollment(&self, user_id: &UserID) -> Result<TotpEnrollment>;
+    async fn confirm_enrollment(&self, user_id: &UserID, code: &str) -> Result<Vec<String>>;
+    async fn complete_login(
+        &self,
+        user_id: &UserID,
+        code: &str,
+    ) -> Result<(User, Option<Vec<String>>)>;
+    async fn disable(&self, user_id: &UserID, code: &str) -> Result<()>;
+}
+
+pub trait HaveTwoFactorService {
+    type Service: TwoFactorService;
+    fn two_factor_service(&self) -> &Self::Service;
+}
+
+#[async_trait]
+pub trait TwoFactorServiceImpl:
+    Sync
+    + HaveDBPool
+    + HaveUserRepository
+    + HaveUserTotpSecretRepository
+    + HaveUserRecoveryCodeRepository
+    + HaveTwoFactorConfig
+{
+    async fn requirement(&self, user: &User) -> Result<TwoFactorRequirement> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let secret = self
+            .user_totp_secret_repo()
+            .find_by_user_id(&mut conn, &user.id)
+            .await?;
+        if matches!(secret, Some(ref secret) if secret.confirmed) {
+            return Ok(TwoFactorRequirement::Verify);
+        }
+        if self.two_factor_config().required_for_teachers && user.type_ == UserType::Teacher {
+            return Ok(TwoFactorRequirement::Enroll);
+        }
+
+        Ok(TwoFactorRequirement::NotRequired)
+    }
+
+    async fn start_enrollment(&self, user_id: &UserID) -> Result<TotpEnrollment> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let user = self.user_repo().find(&mut conn, user_id).await?;
+        let current = self
+            .user_totp_secret_repo()
+            .find_by_user_id(&mut conn, user_id)
+            .await?;
+        if matches!(current, Some(ref current) if current.confirmed) {
+            return Err(TotpAlreadyEnabled);
+        }
+
+        let secret = UserTotpSecret {
+            user_id: user_id.clone(),
+            secret: generate_secret(),
+            confirmed: false,
+            last_used_step: None,
+            created_at: chrono::Utc::now().naive_utc(),
+        };
+        self.user_totp_secret_repo()
+            .create_or_replace(&mut conn, &secret)
+            .await?;
+
+        let provisioning_uri = self
+            .two_factor_config()
+            .provisioning_uri(&user.code.inner().to_string(), &secret.secret);
+        Ok(TotpEnrollment {
+            secret: secret.secret,
+            provisioning_uri,
+        })
+    }
+
+    // コードを検証して登録を完了し、新しいリカバリーコードを返す
+    async fn confirm_enrollment(&self, user_id: &UserID, code: &str) -> Result<Vec<String>> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let totp_repo = self.user_totp_secret_repo();
+        let secret = match totp_repo.find_by_user_id(&mut tx, user_id).await? {
+            None => return Err(TotpNotEnrolled),
+            Some(secret) if secret.confirmed => return Err(TotpAlreadyEnabled),
+            Some(secret) => secret,
+        };
+        let now = chrono::Utc::now();
+        let step = match find_matching_step(&secret.secret, code, now.timestamp()) {
+            Some(step) => step,
+            None => return Err(InvalidTotpCode),
+        };
+        totp_repo.confirm_by_user_id(&mut tx, user_id).await?;
+        totp_repo
+            .update_last_used_step_by_user_id(&mut tx, user_id, step)
+            .await?;
+
+        let recovery_code_repo = self.user_recovery_code_repo();
+        recovery_code_repo
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        let mut recovery_codes = Vec::with_capacity(RECOVERY_CODE_COUNT);
+        for _ in 0..RECOVERY_CODE_COUNT {
+            let recovery_code = generate_recovery_code();
+            let code = UserRecoveryCode {
+                id: UserRecoveryCodeID::new(util::new_ulid().await),
+                user_id: user_id.clone(),
+                hashed_code: hash_recovery_code(&recovery_code),
+                used_at: None,
+            };
+            recovery_code_repo.create(&mut tx, &code).await?;
+            recovery_codes.push(recovery_code);
+        }
+
+        tx.commit().await?;
+
+        Ok(recovery_codes)
+    }
+
+    // ログインの2段階目。登録が済んでいなければ登録を完了し、リカバリーコードも返す
+    async fn complete_login(
+        &self,
+        user_id: &UserID,
+        code: &str,
+    ) -> Result<(User, Option<Vec<String>>)> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let user = self.user_repo().find(&mut conn, user_id).await?;
+        let secret = match self
+            .user_totp_secret_repo()
+            .find_by_user_id(&mut conn, user_id)
+            .await?
+        {
+            None => return Err(TotpNotEnrolled),
+            Some(secret) => secret,
+        };
+        if !secret.confirmed {
+            drop(conn);
+            let recovery_codes =
+                TwoFactorServiceImpl::confirm_enrollment(self, user_id, code).await?;
+            return Ok((user, Some(recovery_codes)));
+        }
+
+        if self.verify_code(&mut conn, &secret, code).await? {
+            return Ok((user, None));
+        }
+
+        Err(InvalidTotpCode)
+    }
+
+    // 有効化済みの場合は、本人であることをコードで確かめてから無効化する
+    async fn disable(&self, user_id: &UserID, code: &str) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let user = self.user_repo().find(&mut tx, user_id).await?;
+        if self.two_factor_config().required_for_teachers && user.type_ == UserType::Teacher {
+            return Err(TotpRequired);
+        }
+        let secret = self
+            .user_totp_secret_repo()
+            .find_by_user_id(&mut tx, user_id)
+            .await?;
+        if let Some(secret) = secret {
+            if secret.confirmed && !self.verify_code(&mut tx, &secret, code).await? {
+                return Err(InvalidTotpCode);
+            }
+        }
+        self.user_totp_secret_repo()
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+        self.user_recovery_code_repo()
+            .delete_by_user_id(&mut tx, user_id)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // TOTPのコードか未使用のリカバリーコードであれば、使用済みにしてtrueを返す。
+    // TOTPのコードは同じステップのものを二度使えない
+    async fn verify_code(
+        &self,
+        conn: &mut DBConn,
+        secret: &UserTotpSecret,
+        code: &str,
+    ) -> Result<bool> {
+        let now = chrono::Utc::now();
+        if let Some(step) = find_matching_step(&secret.secret, code, now.timestamp()) {
+            let is_updated = self
+                .user_totp_secret_repo()
+                .update_last_used_step_by_user_id(conn, &secret.user_id, step)
+                .await?;
+            return Ok(is_updated);
+        }
+
+        let is_used = self
+            .user_recovery_code_repo()
+            .use_by_user_id_and_hashed_code(
+                conn,
+                &secret.user_id,
+                &hash_recovery_code(code),
+                &now.naive_utc(),
+            )
+            .await?;
+
+        Ok(is_used)
+    }
+}
+
+#[async_trait]
+impl<S: TwoFactorServiceImpl> TwoFactorService for S {
+    async fn requirement(&self, user: &User) -> Result<TwoFactorRequirement> {
+        TwoFactorServiceImpl::requirement(self, user).await
+    }
+
+    async fn start_enrollment(&self, user_id: &UserID) -> Result<TotpEnrollment> {
+        TwoFactorServiceImpl::start_enrollment(self, user_id).await
+    }
+
+    async fn confirm_enrollment(&self, user_id: &UserID, code: &str) -> Result<Vec<String>> {
+        TwoFactorServiceImpl::confirm_enrollment(self, user_id, code).await
+    }
+
+    async fn complete_login(
+        &self,
+        user_id: &UserID,
+        code: &str,
+    ) -> Result<(User, Option<Vec<String>>)> {
+        TwoFactorServiceImpl::complete_login(self, user_id, code).await
+    }
+
+    async fn disable(&self, user_id: &UserID, code: &str) -> Result<()> {
+        TwoFactorServiceImpl::disable(self, user_id, code).await
+    }
+}
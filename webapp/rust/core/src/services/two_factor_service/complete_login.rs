@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::user::User;
+    use crate::models::user_totp_secret::UserTotpSecret;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::two_factor_service::{
+        hash_recovery_code, totp_code, TwoFactorService, TOTP_PERIOD_SECS,
+    };
+    use fake::{Fake, Faker};
+
+    const SECRET: &[u8] = b"12345678901234567890";
+
+    fn confirmed_secret(user: &User) -> UserTotpSecret {
+        UserTotpSecret {
+            user_id: user.id.clone(),
+            secret: data_encoding::BASE32_NOPAD.encode(SECRET),
+            confirmed: true,
+            last_used_step: None,
+            created_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TotpNotEnrolled")]
+    async fn not_enrolled_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let user: User = Faker.fake();
+        let u = user.clone();
+        service
+            .user_repo
+            .expect_find()
+            .returning(move |_, _| Ok(u.clone()));
+        service
+            .user_totp_secret_repo
+            .expect_find_by_user_id()
+            .returning(|_, _| Ok(None));
+
+        service.complete_login(&user.id, "000000").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn totp_code_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let user: User = Faker.fake();
+        let u = user.clone();
+        service
+            .user_repo
+            .expect_find()
+            .returning(move |_, _| Ok(u.clone()));
+        let secret = confirmed_secret(&user);
+        service
+            .user_totp_secret_repo
+            .expect_find_by_user_id()
+            .returning(move |_, _| Ok(Some(secret.clone())));
+        service
+            .user_totp_secret_repo
+            .expect_update_last_used_step_by_user_id()
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+        service
+            .user_recovery_code_repo
+            .expect_use_by_user_id_and_hashed_code()
+            .never();
+
+        let step = chrono::Utc::now().timestamp() / TOTP_PERIOD_SECS;
+        let (got, recovery_codes) = service
+            .complete_login(&user.id, &totp_code(SECRET, step))
+            .await
+            .unwrap();
+        assert_eq!(got, user);
+        assert!(recovery_codes.is_none());
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidTotpCode")]
+    async fn reused_totp_code_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let user: User = Faker.fake();
+        let u = user.clone();
+        service
+            .user_repo
+            .expect_find()
+            .returning(move |_, _| Ok(u.clone()));
+        let secret = confirmed_secret(&user);
+        service
+            .user_totp_secret_repo
+            .expect_find_by_user_id()
+            .returning(move |_, _| Ok(Some(secret.clone())));
+        service
+            .user_totp_secret_repo
+            .expect_update_last_used_step_by_user_id()
+            .returning(|_, _, _| Ok(false));
+
+        let step = chrono::Utc::now().timestamp() / TOTP_PERIOD_SECS;
+        service
+            .complete_login(&user.id, &totp_code(SECRET, step))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn recovery_code_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let user: User = Faker.fake();
+        let u = user.clone();
+        service
+            .user_repo
+            .expect_find()
+            .returning(move |_, _| Ok(u.clone()));
+        let secret = confirmed_secret(&user);
+        service
+            .user_totp_secret_repo
+            .expect_find_by_user_id()
+            .returning(move |_, _| Ok(Some(secret.clone())));
+        let hashed_code = hash_recovery_code("abcde12345");
+        service
+            .user_recovery_code_repo
+            .expect_use_by_user_id_and_hashed_code()
+            .withf(move |_, _, code, _| code == hashed_code)
+            .times(1)
+            .returning(|_, _, _, _| Ok(true));
+
+        let (got, _) = service
+            .complete_login(&user.id, "ABCDE-12345")
+            .await
+            .unwrap();
+        assert_eq!(got, user);
+    }
+}
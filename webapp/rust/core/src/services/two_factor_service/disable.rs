@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::user::User;
+    use crate::models::user_totp_secret::UserTotpSecret;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::two_factor_service::{
+        hash_recovery_code, totp_code, TwoFactorService, TOTP_PERIOD_SECS,
+    };
+    use fake::{Fake, Faker};
+
+    const SECRET: &[u8] = b"12345678901234567890";
+
+    fn expect_confirmed_secret(service: &mut MockRepositoryManager) -> User {
+        let user: User = Faker.fake();
+        let u = user.clone();
+        service
+            .user_repo
+            .expect_find()
+            .returning(move |_, _| Ok(u.clone()));
+        let secret = UserTotpSecret {
+            user_id: user.id.clone(),
+            secret: data_encoding::BASE32_NOPAD.encode(SECRET),
+            confirmed: true,
+            last_used_step: None,
+            created_at: chrono::Utc::now().naive_utc(),
+        };
+        service
+            .user_totp_secret_repo
+            .expect_find_by_user_id()
+            .returning(move |_, _| Ok(Some(secret.clone())));
+        user
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidTotpCode")]
+    async fn invalid_code_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let user = expect_confirmed_secret(&mut service);
+        service
+            .user_recovery_code_repo
+            .expect_use_by_user_id_and_hashed_code()
+            .returning(|_, _, _, _| Ok(false));
+        service
+            .user_totp_secret_repo
+            .expect_delete_by_user_id()
+            .never();
+
+        service.disable(&user.id, "000000").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn totp_code_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let user = expect_confirmed_secret(&mut service);
+        service
+            .user_totp_secret_repo
+            .expect_update_last_used_step_by_user_id()
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+        service
+            .user_totp_secret_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .user_recovery_code_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let step = chrono::Utc::now().timestamp() / TOTP_PERIOD_SECS;
+        service
+            .disable(&user.id, &totp_code(SECRET, step))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn recovery_code_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let user = expect_confirmed_secret(&mut service);
+        let hashed_code = hash_recovery_code("abcde12345");
+        service
+            .user_recovery_code_repo
+            .expect_use_by_user_id_and_hashed_code()
+            .withf(move |_, _, code, _| code == hashed_code)
+            .times(1)
+            .returning(|_, _, _, _| Ok(true));
+        service
+            .user_totp_secret_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .user_recovery_code_repo
+            .expect_delete_by_user_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        service.disable(&user.id, "ABCDE-12345").await.unwrap();
+    }
+}
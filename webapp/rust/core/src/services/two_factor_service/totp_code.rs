@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use crate::services::two_factor_service::{find_matching_step, totp_code};
+
+    // RFC 6238 Appendix B のテストベクタ(SHA1)の下6桁
+    #[test]
+    fn rfc6238_test_vector_case() {
+        let secret = b"12345678901234567890";
+
+        assert_eq!(totp_code(secret, 59 / 30), "287082");
+        assert_eq!(totp_code(secret, 1111111109 / 30), "081804");
+        assert_eq!(totp_code(secret, 1234567890 / 30), "005924");
+        assert_eq!(totp_code(secret, 2000000000 / 30), "279037");
+    }
+
+    #[test]
+    fn find_matching_step_case() {
+        let secret = data_encoding::BASE32_NOPAD.encode(b"12345678901234567890");
+
+        assert_eq!(
+            find_matching_step(&secret, "081804", 1111111109),
+            Some(1111111109 / 30)
+        );
+        // 1ステップ前後のずれは許容する
+        assert_eq!(
+            find_matching_step(&secret, "081804", 1111111109 + 30),
+            Some(1111111109 / 30)
+        );
+        assert_eq!(find_matching_step(&secret, "081804", 1111111109 + 60), None);
+        assert_eq!(find_matching_step(&secret, "000000", 1111111109), None);
+        assert_eq!(find_matching_step("invalid!", "081804", 1111111109), None);
+    }
+}
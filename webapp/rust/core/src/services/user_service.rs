@@ -1,15 +1,123 @@
-use crate::models::user::{User, UserCode, UserID};
-use crate::repos::user_repository::{HaveUserRepository, UserRepository};
+use crate::models::audit_log::CreateAuditLog;
+use crate::models::user::{CreateUser, UpdateUser, User, UserCode, UserID};
+use crate::models::user_import::{
+    ImportUserError, ImportUserResult, ImportUserRow, ImportUsersReport,
+};
+use crate::models::user_profile::{UpdateUserProfile, UserProfile};
+use crate::models::user_type::UserType;
+use crate::password_hashers::{HavePasswordHasher, PasswordHasher, PasswordHasherResult};
+use crate::repos::audit_log_repository::HaveAuditLogRepository;
+use crate::repos::error::ReposError;
+use crate::repos::user_profile_repository::{HaveUserProfileRepository, UserProfileRepository};
+use crate::repos::user_repository::{HaveUserRepository, SearchUsersQuery, UserRepository};
+use crate::services::audit_log_service::create_audit_log;
+use crate::services::error::Error::{
+    InvalidEmail, InvalidNameKana, InvalidUserCode, InvalidUserName, UserDuplicate, UserNotFound,
+};
 use crate::services::error::Result;
 use crate::services::HaveDBPool;
+use crate::util;
 use async_trait::async_trait;
+use rand::distributions::{Alphanumeric, DistString};
+use std::collections::HashSet;
+
+mod create;
+mod deactivate;
+mod import_users;
+mod update;
+mod update_profile;
+mod verify_password;
+
+const USER_CODE_LENGTH: usize = 6;
+const USER_NAME_MAX_LENGTH: usize = 255;
+const INITIAL_PASSWORD_LENGTH: usize = 16;
+const PROFILE_FIELD_MAX_LENGTH: usize = 255;
+const PASSWORD_MIN_LENGTH: usize = 8;
+// bcryptは72バイトを超える部分を無視するため、それ以上は受け付けない
+const PASSWORD_MAX_LENGTH: usize = 72;
+// 一括登録で1回のクエリに含める行数
+const IMPORT_BATCH_SIZE: usize = 500;
+// 一括登録時に1つのブロッキングタスクでハッシュ化するパスワードの件数
+const IMPORT_HASH_CHUNK_SIZE: usize = 16;
+
+fn validate_user_code(code: &str) -> Result<()> {
+    if code.len() != USER_CODE_LENGTH || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(InvalidUserCode);
+    }
+    Ok(())
+}
+
+fn validate_user_name(name: &str) -> Result<()> {
+    if name.trim().is_empty() || name.chars().count() > USER_NAME_MAX_LENGTH {
+        return Err(InvalidUserName);
+    }
+    Ok(())
+}
+
+// ひらがな・カタカナ・長音符・中黒と空白のみ受け付ける
+fn validate_name_kana(name_kana: &str) -> Result<()> {
+    if name_kana.chars().count() > PROFILE_FIELD_MAX_LENGTH
+        || !name_kana.chars().all(
+            |c| matches!(c, '\u{3041}'..='\u{3096}' | '\u{30a1}'..='\u{30fc}' | ' ' | '\u{3000}'),
+        )
+    {
+        return Err(InvalidNameKana);
+    }
+    Ok(())
+}
+
+fn validate_email(email: &str) -> Result<()> {
+    let valid = match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        }
+        None => false,
+    };
+    if !valid
+        || email.len() > PROFILE_FIELD_MAX_LENGTH
+        || email.chars().any(|c| c.is_whitespace() || c.is_control())
+    {
+        return Err(InvalidEmail);
+    }
+    Ok(())
+}
 
 #[cfg_attr(any(test, feature = "test"), mockall::automock)]
 #[async_trait]
 pub trait UserService: Sync {
     async fn find_by_code(&self, code: &UserCode) -> Result<Option<User>>;
     async fn find_code_by_id(&self, user_id: &UserID) -> Result<Option<UserCode>>;
-    fn verify_password(&self, user: &User, password: &str) -> Result<bool>;
+    async fn verify_password(&self, user: &User, password: &str) -> Result<bool>;
+    async fn create(&self, req: &CreateUser, audit_log: &CreateAuditLog) -> Result<(User, String)>;
+    async fn update(
+        &self,
+        user_id: &UserID,
+        req: &UpdateUser,
+        audit_log: &CreateAuditLog,
+    ) -> Result<User>;
+    async fn deactivate(&self, user_id: &UserID, audit_log: &CreateAuditLog) -> Result<()>;
+    async fn import_users(
+        &self,
+        rows: &[ImportUserRow],
+        dry_run: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<ImportUsersReport>;
+    async fn find_profile(&self, user_id: &UserID) -> Result<(User, UserProfile)>;
+    async fn update_profile(
+        &self,
+        user_id: &UserID,
+        req: &UpdateUserProfile,
+    ) -> Result<(User, UserProfile)>;
+    async fn find_all(
+        &self,
+        query: &SearchUsersQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>>;
 }
 
 pub trait HaveUserService {
@@ -18,7 +126,14 @@ pub trait HaveUserService {
 }
 
 #[async_trait]
-pub trait UserServiceImpl: Sync + HaveDBPool + HaveUserRepository {
+pub trait UserServiceImpl:
+    Sync
+    + HaveDBPool
+    + HaveUserRepository
+    + HaveUserProfileRepository
+    + HavePasswordHasher
+    + HaveAuditLogRepository
+{
     async fn find_by_code(&self, code: &UserCode) -> Result<Option<User>> {
         let pool = self.get_db_pool();
         let mut conn = pool.acquire().await?;
@@ -36,16 +151,358 @@ pub trait UserServiceImpl: Sync + HaveDBPool + HaveUserRepository {
         Ok(result)
     }
 
-    fn verify_password(&self, user: &User, password: &str) -> Result<bool> {
-        if !bcrypt::verify(
-            password,
-            &String::from_utf8(user.hashed_password.clone()).unwrap(),
-        )? {
+    // 検証に成功したとき、現在のポリシーより弱いハッシュであれば再ハッシュ化して保存する
+    async fn verify_password(&self, user: &User, password: &str) -> Result<bool> {
+        let hashed_password = String::from_utf8_lossy(&user.hashed_password);
+        let hasher = self.password_hasher();
+        if !hasher.verify(password, &hashed_password)? {
             return Ok(false);
         }
 
+        if hasher.needs_rehash(&hashed_password) {
+            let rehashed_password = hasher.hash(password)?;
+
+            let pool = self.get_db_pool();
+            let mut conn = pool.acquire().await?;
+            self.user_repo()
+                .update_hashed_password_by_id(&mut conn, &user.id, rehashed_password.as_bytes())
+                .await?;
+        }
+
         Ok(true)
     }
+
+    // 初期パスワードはレスポンスでのみ返し、DBにはハッシュ値を保存する
+    async fn create(&self, req: &CreateUser, audit_log: &CreateAuditLog) -> Result<(User, String)> {
+        validate_user_code(&req.code)?;
+        validate_user_name(&req.name)?;
+
+        let initial_password =
+            Alphanumeric.sample_string(&mut rand::thread_rng(), INITIAL_PASSWORD_LENGTH);
+        let hashed_password = self.password_hasher().hash(&initial_password)?;
+        let user = User {
+            id: UserID::new(util::new_ulid().await),
+            code: UserCode::new(req.code.clone().into()),
+            name: req.name.clone(),
+            hashed_password: hashed_password.into_bytes(),
+            type_: req.type_.clone(),
+            deactivated_at: None,
+        };
+
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+        match self.user_repo().create(&mut tx, &user).await {
+            Ok(_) => {}
+            Err(ReposError::UserDuplicate) => return Err(UserDuplicate),
+            Err(e) => return Err(e.into()),
+        }
+
+        let mut audit_log = audit_log.clone();
+        audit_log.target_user_id = Some(user.id.clone());
+        // 監査ログは個人データの削除後も残るため、学籍番号や氏名は書かない
+        audit_log.after_summary = Some(serde_json::json!({ "type": user.type_ }).to_string());
+        create_audit_log(self.audit_log_repo(), &mut tx, &audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok((user, initial_password))
+    }
+
+    async fn update(
+        &self,
+        user_id: &UserID,
+        req: &UpdateUser,
+        audit_log: &CreateAuditLog,
+    ) -> Result<User> {
+        if let Some(ref name) = req.name {
+            validate_user_name(name)?;
+        }
+
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let user_repo = self.user_repo();
+        if user_repo.find_code_by_id(&mut tx, user_id).await?.is_none() {
+            return Err(UserNotFound);
+        }
+        user_repo.update_by_id(&mut tx, user_id, req).await?;
+        let user = user_repo.find(&mut tx, user_id).await?;
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(user)
+    }
+
+    // 無効化済みのユーザーに対しては何もしない
+    async fn deactivate(&self, user_id: &UserID, audit_log: &CreateAuditLog) -> Result<()> {
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let user_repo = self.user_repo();
+        if user_repo.find_code_by_id(&mut tx, user_id).await?.is_none() {
+            return Err(UserNotFound);
+        }
+        user_repo
+            .deactivate_by_id(&mut tx, user_id, &chrono::Utc::now().naive_utc())
+            .await?;
+        create_audit_log(self.audit_log_repo(), &mut tx, audit_log).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // 全行を検証してから登録する。1行でもエラーがあれば、またはdry-runの場合は何も登録しない
+    async fn import_users(
+        &self,
+        rows: &[ImportUserRow],
+        dry_run: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<ImportUsersReport> {
+        let mut results = Vec::with_capacity(rows.len());
+        let mut types = Vec::with_capacity(rows.len());
+        let mut seen_codes = HashSet::new();
+        for row in rows {
+            let mut errors = Vec::new();
+            if validate_user_code(&row.code).is_err() {
+                errors.push(ImportUserError::InvalidCode);
+            } else if !seen_codes.insert(row.code.as_str()) {
+                errors.push(ImportUserError::DuplicateInFile);
+            }
+            if validate_user_name(&row.name).is_err() {
+                errors.push(ImportUserError::InvalidName);
+            }
+            let type_ = match row.type_.as_str() {
+                "student" => Some(UserType::Student),
+                "teacher" => Some(UserType::Teacher),
+                _ => {
+                    errors.push(ImportUserError::InvalidType);
+                    None
+                }
+            };
+            if let Some(ref password) = row.password {
+                if password.len() < PASSWORD_MIN_LENGTH || password.len() > PASSWORD_MAX_LENGTH {
+                    errors.push(ImportUserError::InvalidPassword);
+                }
+            }
+            if row.too_many_fields {
+                errors.push(ImportUserError::TooManyFields);
+            }
+
+            types.push(type_);
+            results.push(ImportUserResult {
+                line: row.line,
+                code: row.code.clone(),
+                errors,
+                user_id: None,
+                initial_password: None,
+            });
+        }
+
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let user_repo = self.user_repo();
+        let codes = seen_codes
+            .into_iter()
+            .map(|code| code.to_string())
+            .collect::<Vec<_>>();
+        let mut existing_codes = HashSet::new();
+        for chunk in codes.chunks(IMPORT_BATCH_SIZE) {
+            for code in user_repo.find_codes_in(&mut conn, chunk).await? {
+                existing_codes.insert(code.inner().to_string());
+            }
+        }
+        for result in results.iter_mut() {
+            if existing_codes.contains(&result.code) {
+                result.errors.push(ImportUserError::CodeAlreadyExists);
+            }
+        }
+
+        // ハッシュ化の間は接続を使わないため、プールへ返しておく
+        drop(conn);
+
+        if dry_run || results.iter().any(|result| !result.errors.is_empty()) {
+            return Ok(ImportUsersReport {
+                dry_run,
+                imported: false,
+                results,
+            });
+        }
+
+        let mut passwords = Vec::with_capacity(rows.len());
+        for (row, result) in rows.iter().zip(results.iter_mut()) {
+            let password = match row.password {
+                Some(ref password) => password.clone(),
+                None => {
+                    let initial_password = Alphanumeric
+                        .sample_string(&mut rand::thread_rng(), INITIAL_PASSWORD_LENGTH);
+                    result.initial_password = Some(initial_password.clone());
+                    initial_password
+                }
+            };
+            passwords.push(password);
+        }
+
+        // ハッシュ化は重いため、非同期ランタイムのスレッドを塞がないよう
+        // 一定件数ごとにブロッキング用のスレッドで行う
+        let mut hashed_passwords = Vec::with_capacity(passwords.len());
+        for chunk in passwords.chunks(IMPORT_HASH_CHUNK_SIZE) {
+            let hasher = self.password_hasher().clone();
+            let chunk = chunk.to_vec();
+            let hashed = tokio::task::spawn_blocking(move || {
+                chunk
+                    .iter()
+                    .map(|password| hasher.hash(password))
+                    .collect::<PasswordHasherResult<Vec<_>>>()
+            })
+            .await??;
+            hashed_passwords.extend(hashed);
+        }
+
+        let mut users = Vec::with_capacity(rows.len());
+        for (((row, type_), result), hashed_password) in rows
+            .iter()
+            .zip(types)
+            .zip(results.iter_mut())
+            .zip(hashed_passwords)
+        {
+            let user = User {
+                id: UserID::new(util::new_ulid().await),
+                code: UserCode::new(row.code.clone().into()),
+                name: row.name.clone(),
+                hashed_password: hashed_password.into_bytes(),
+                type_: type_.unwrap(),
+                deactivated_at: None,
+            };
+            result.user_id = Some(user.id.clone());
+            users.push(user);
+        }
+
+        let mut tx = pool.begin().await?;
+        for chunk in users.chunks(IMPORT_BATCH_SIZE) {
+            match user_repo.create_bulk(&mut tx, chunk).await {
+                Ok(_) => {}
+                Err(ReposError::UserDuplicate) => return Err(UserDuplicate),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        let mut audit_log = audit_log.clone();
+        audit_log.after_summary = Some(
+            serde_json::json!({
+                "user_ids": users.iter().map(|user| &user.id).collect::<Vec<_>>()
+            })
+            .to_string(),
+        );
+        create_audit_log(self.audit_log_repo(), &mut tx, &audit_log).await?;
+        tx.commit().await?;
+
+        Ok(ImportUsersReport {
+            dry_run,
+            imported: true,
+            results,
+        })
+    }
+
+    async fn find_profile(&self, user_id: &UserID) -> Result<(User, UserProfile)> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let user_repo = self.user_repo();
+        if user_repo
+            .find_code_by_id(&mut conn, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(UserNotFound);
+        }
+        let user = user_repo.find(&mut conn, user_id).await?;
+        let profile = self
+            .user_profile_repo()
+            .find_by_user_id(&mut conn, user_id)
+            .await?
+            .unwrap_or_else(|| UserProfile::new(user_id.clone()));
+
+        Ok((user, profile))
+    }
+
+    async fn update_profile(
+        &self,
+        user_id: &UserID,
+        req: &UpdateUserProfile,
+    ) -> Result<(User, UserProfile)> {
+        if let Some(ref name) = req.name {
+            validate_user_name(name)?;
+        }
+        if let Some(ref name_kana) = req.name_kana {
+            validate_name_kana(name_kana)?;
+        }
+        if let Some(ref email) = req.email {
+            if !email.is_empty() {
+                validate_email(email)?;
+            }
+        }
+
+        let pool = self.get_db_pool();
+        let mut tx = pool.begin().await?;
+
+        let user_repo = self.user_repo();
+        if user_repo.find_code_by_id(&mut tx, user_id).await?.is_none() {
+            return Err(UserNotFound);
+        }
+        if req.name.is_some() {
+            let update = UpdateUser {
+                name: req.name.clone(),
+                type_: None,
+            };
+            user_repo.update_by_id(&mut tx, user_id, &update).await?;
+        }
+
+        let user_profile_repo = self.user_profile_repo();
+        let mut profile = user_profile_repo
+            .find_by_user_id(&mut tx, user_id)
+            .await?
+            .unwrap_or_else(|| UserProfile::new(user_id.clone()));
+        if let Some(ref name_kana) = req.name_kana {
+            profile.name_kana = Some(name_kana.clone()).filter(|v| !v.is_empty());
+        }
+        if let Some(ref email) = req.email {
+            profile.email = Some(email.clone()).filter(|v| !v.is_empty());
+        }
+        if let Some(notify_announcements) = req.notify_announcements {
+            profile.notify_announcements = notify_announcements;
+        }
+        if let Some(notify_course_updates) = req.notify_course_updates {
+            profile.notify_course_updates = notify_course_updates;
+        }
+        user_profile_repo
+            .create_or_update(&mut tx, &profile)
+            .await?;
+
+        let user = user_repo.find(&mut tx, user_id).await?;
+
+        tx.commit().await?;
+
+        Ok((user, profile))
+    }
+
+    async fn find_all(
+        &self,
+        query: &SearchUsersQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>> {
+        let pool = self.get_db_pool();
+        let mut conn = pool.acquire().await?;
+
+        let users = self
+            .user_repo()
+            .find_all(&mut conn, query, limit, offset)
+            .await?;
+
+        Ok(users)
+    }
 }
 
 #[async_trait]
@@ -58,7 +515,54 @@ impl<S: UserServiceImpl> UserService for S {
         UserServiceImpl::find_code_by_id(self, user_id).await
     }
 
-    fn verify_password(&self, user: &User, password: &str) -> Result<bool> {
-        UserServiceImpl::verify_password(self, user, password)
+    async fn verify_password(&self, user: &User, password: &str) -> Result<bool> {
+        UserServiceImpl::verify_password(self, user, password).await
+    }
+
+    async fn create(&self, req: &CreateUser, audit_log: &CreateAuditLog) -> Result<(User, String)> {
+        UserServiceImpl::create(self, req, audit_log).await
+    }
+
+    async fn update(
+        &self,
+        user_id: &UserID,
+        req: &UpdateUser,
+        audit_log: &CreateAuditLog,
+    ) -> Result<User> {
+        UserServiceImpl::update(self, user_id, req, audit_log).await
+    }
+
+    async fn deactivate(&self, user_id: &UserID, audit_log: &CreateAuditLog) -> Result<()> {
+        UserServiceImpl::deactivate(self, user_id, audit_log).await
+    }
+
+    async fn import_users(
+        &self,
+        rows: &[ImportUserRow],
+        dry_run: bool,
+        audit_log: &CreateAuditLog,
+    ) -> Result<ImportUsersReport> {
+        UserServiceImpl::import_users(self, rows, dry_run, audit_log).await
+    }
+
+    async fn find_profile(&self, user_id: &UserID) -> Result<(User, UserProfile)> {
+        UserServiceImpl::find_profile(self, user_id).await
+    }
+
+    async fn update_profile(
+        &self,
+        user_id: &UserID,
+        req: &UpdateUserProfile,
+    ) -> Result<(User, UserProfile)> {
+        UserServiceImpl::update_profile(self, user_id, req).await
+    }
+
+    async fn find_all(
+        &self,
+        query: &SearchUsersQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>> {
+        UserServiceImpl::find_all(self, query, limit, offset).await
     }
 }
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::user::CreateUser;
+    use crate::models::user_type::UserType;
+    use crate::repos::error::ReposError;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::user_service::UserService;
+    use fake::{Fake, Faker};
+
+    fn req(code: &str) -> CreateUser {
+        CreateUser {
+            code: code.to_string(),
+            name: "student".to_string(),
+            type_: UserType::Student,
+        }
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidUserCode")]
+    async fn invalid_code_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service.user_repo.expect_create().never();
+
+        service.create(&req("S-0001"), &Faker.fake()).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "UserDuplicate")]
+    async fn duplicate_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .password_hasher
+            .expect_hash()
+            .returning(|_| Ok("hashed".to_string()));
+        service
+            .user_repo
+            .expect_create()
+            .returning(|_, _| Err(ReposError::UserDuplicate));
+
+        service.create(&req("S00001"), &Faker.fake()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .password_hasher
+            .expect_hash()
+            .withf(|password| password.len() == 16)
+            .returning(|_| Ok("hashed".to_string()));
+        service
+            .user_repo
+            .expect_create()
+            .withf(|_, user| {
+                user.code.inner().to_string() == "S00001"
+                    && user.hashed_password == b"hashed"
+                    && user.is_active()
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .withf(|_, audit_log| audit_log.target_user_id.is_some())
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let (user, initial_password) = service.create(&req("S00001"), &Faker.fake()).await.unwrap();
+        assert_eq!(user.name, "student");
+        assert_eq!(initial_password.len(), 16);
+    }
+}
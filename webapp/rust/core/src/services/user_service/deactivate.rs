@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::user::{UserCode, UserID};
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::user_service::UserService;
+    use fake::{Fake, Faker};
+
+    #[tokio::test]
+    #[should_panic(expected = "UserNotFound")]
+    async fn not_found_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(None));
+        service.user_repo.expect_deactivate_by_id().never();
+
+        service
+            .deactivate(&UserID::new("user".to_string()), &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(Some(UserCode::new("S00001".to_string().into()))));
+        service
+            .user_repo
+            .expect_deactivate_by_id()
+            .withf(|_, id, _| id.inner() == "user")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        service
+            .deactivate(&UserID::new("user".to_string()), &Faker.fake())
+            .await
+            .unwrap();
+    }
+}
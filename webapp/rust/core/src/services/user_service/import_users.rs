@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::user::UserCode;
+    use crate::models::user_import::{ImportUserError, ImportUserRow};
+    use crate::password_hashers::MockPasswordHasher;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::user_service::UserService;
+    use fake::{Fake, Faker};
+
+    fn row(line: u64, code: &str, type_: &str, password: Option<&str>) -> ImportUserRow {
+        ImportUserRow {
+            line,
+            code: code.to_string(),
+            name: "student".to_string(),
+            type_: type_.to_string(),
+            password: password.map(|password| password.to_string()),
+            too_many_fields: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_rows_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_codes_in()
+            .returning(|_, _| Ok(vec![UserCode::new("S00002".to_string().into())]));
+        service.password_hasher.expect_clone().never();
+        service.user_repo.expect_create_bulk().never();
+
+        let rows = vec![
+            row(2, "S00001", "student", None),
+            row(3, "S00001", "student", None),
+            row(4, "S00002", "teacher", None),
+            row(5, "S-0003", "admin", Some("short")),
+            ImportUserRow {
+                too_many_fields: true,
+                ..row(6, "S00004", "student", None)
+            },
+        ];
+        let report = service
+            .import_users(&rows, false, &Faker.fake())
+            .await
+            .unwrap();
+        assert!(!report.imported);
+        let errors = report
+            .results
+            .into_iter()
+            .map(|result| result.errors)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                vec![],
+                vec![ImportUserError::DuplicateInFile],
+                vec![ImportUserError::CodeAlreadyExists],
+                vec![
+                    ImportUserError::InvalidCode,
+                    ImportUserError::InvalidType,
+                    ImportUserError::InvalidPassword
+                ],
+                vec![ImportUserError::TooManyFields],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn dry_run_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_codes_in()
+            .returning(|_, _| Ok(vec![]));
+        service.user_repo.expect_create_bulk().never();
+        service.audit_log_repo.expect_create().never();
+
+        let rows = vec![row(2, "S00001", "student", None)];
+        let report = service
+            .import_users(&rows, true, &Faker.fake())
+            .await
+            .unwrap();
+        assert!(report.dry_run);
+        assert!(!report.imported);
+        assert!(report.results[0].errors.is_empty());
+        assert!(report.results[0].user_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_codes_in()
+            .returning(|_, _| Ok(vec![]));
+        service.password_hasher.expect_clone().returning(|| {
+            let mut hasher = MockPasswordHasher::new();
+            hasher
+                .expect_hash()
+                .returning(|password| Ok(format!("hashed-{}", password)));
+            hasher
+        });
+        service
+            .user_repo
+            .expect_create_bulk()
+            .withf(|_, users| users.len() == 2 && users[1].hashed_password == b"hashed-password1")
+            .times(1)
+            .returning(|_, _| Ok(()));
+        service
+            .audit_log_repo
+            .expect_create()
+            .withf(|_, audit_log| {
+                audit_log.after_summary.as_deref().is_some_and(|summary| {
+                    summary.contains("user_ids") && !summary.contains("T00001")
+                })
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let rows = vec![
+            row(2, "S00001", "student", None),
+            row(3, "T00001", "teacher", Some("password1")),
+        ];
+        let report = service
+            .import_users(&rows, false, &Faker.fake())
+            .await
+            .unwrap();
+        assert!(report.imported);
+        assert!(report.results.iter().all(|result| result.user_id.is_some()));
+        assert_eq!(
+            report.results[0]
+                .initial_password
+                .as_ref()
+                .map(|password| password.len()),
+            Some(16)
+        );
+        assert!(report.results[1].initial_password.is_none());
+    }
+}
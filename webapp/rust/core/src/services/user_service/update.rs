@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::user::{UpdateUser, UserID};
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::user_service::UserService;
+    use fake::{Fake, Faker};
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidUserName")]
+    async fn invalid_name_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service.user_repo.expect_update_by_id().never();
+
+        let req = UpdateUser {
+            name: Some(" ".to_string()),
+            type_: None,
+        };
+        service
+            .update(&UserID::new("user".to_string()), &req, &Faker.fake())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "UserNotFound")]
+    async fn not_found_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(None));
+        service.user_repo.expect_update_by_id().never();
+
+        service
+            .update(
+                &UserID::new("user".to_string()),
+                &UpdateUser::default(),
+                &Faker.fake(),
+            )
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::user::{User, UserCode, UserID};
+    use crate::models::user_profile::{UpdateUserProfile, UserProfile};
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::user_service::UserService;
+    use fake::{Fake, Faker};
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidNameKana")]
+    async fn invalid_name_kana_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service.user_profile_repo.expect_create_or_update().never();
+
+        let req = UpdateUserProfile {
+            name_kana: Some("Yamada".to_string()),
+            ..Default::default()
+        };
+        service
+            .update_profile(&UserID::new("user".to_string()), &req)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidEmail")]
+    async fn invalid_email_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service.user_profile_repo.expect_create_or_update().never();
+
+        let req = UpdateUserProfile {
+            email: Some("student@localhost".to_string()),
+            ..Default::default()
+        };
+        service
+            .update_profile(&UserID::new("user".to_string()), &req)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn success_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut user: User = Faker.fake();
+        user.id = UserID::new("user".to_string());
+        user.name = "やまだ".to_string();
+        let found = user.clone();
+
+        service
+            .user_repo
+            .expect_find_code_by_id()
+            .returning(|_, _| Ok(Some(UserCode::new("S00001".to_string().into()))));
+        service
+            .user_repo
+            .expect_update_by_id()
+            .withf(|_, _, req| req.name.as_deref() == Some("やまだ") && req.type_.is_none())
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        service
+            .user_repo
+            .expect_find()
+            .returning(move |_, _| Ok(found.clone()));
+        service
+            .user_profile_repo
+            .expect_find_by_user_id()
+            .returning(|_, user_id| {
+                let mut profile = UserProfile::new(user_id.clone());
+                profile.email = Some("old@example.com".to_string());
+                Ok(Some(profile))
+            });
+        service
+            .user_profile_repo
+            .expect_create_or_update()
+            .withf(|_, profile| {
+                profile.name_kana.as_deref() == Some("ヤマダ タロウ")
+                    && profile.email.is_none()
+                    && !profile.notify_announcements
+                    && profile.notify_course_updates
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let req = UpdateUserProfile {
+            name: Some("やまだ".to_string()),
+            name_kana: Some("ヤマダ タロウ".to_string()),
+            email: Some("".to_string()),
+            notify_announcements: Some(false),
+            notify_course_updates: None,
+        };
+        let (got, profile) = service.update_profile(&user.id, &req).await.unwrap();
+        assert_eq!(got, user);
+        assert!(profile.email.is_none());
+    }
+}
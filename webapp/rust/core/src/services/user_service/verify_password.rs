@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::db::get_test_db_conn;
+    use crate::models::user::User;
+    use crate::repos::manager::tests::MockRepositoryManager;
+    use crate::services::user_service::UserService;
+    use fake::{Fake, Faker};
+
+    #[tokio::test]
+    async fn wrong_password_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .password_hasher
+            .expect_verify()
+            .returning(|_, _| Ok(false));
+        service.password_hasher.expect_needs_rehash().never();
+        service
+            .user_repo
+            .expect_update_hashed_password_by_id()
+            .never();
+
+        let user: User = Faker.fake();
+        let result = service.verify_password(&user, "wrong").await.unwrap();
+        assert!(!result);
+    }
+
+    #[tokio::test]
+    async fn up_to_date_hash_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        service
+            .password_hasher
+            .expect_verify()
+            .returning(|_, _| Ok(true));
+        service
+            .password_hasher
+            .expect_needs_rehash()
+            .returning(|_| false);
+        service.password_hasher.expect_hash().never();
+        service
+            .user_repo
+            .expect_update_hashed_password_by_id()
+            .never();
+
+        let user: User = Faker.fake();
+        let result = service.verify_password(&user, "password").await.unwrap();
+        assert!(result);
+    }
+
+    #[tokio::test]
+    async fn rehash_case() {
+        let conn = get_test_db_conn().await.unwrap();
+        let mut service = MockRepositoryManager::new(conn);
+
+        let mut user: User = Faker.fake();
+        user.hashed_password = b"$2a$04$old".to_vec();
+
+        service
+            .password_hasher
+            .expect_verify()
+            .withf(|password, hashed_password| {
+                password == "password" && hashed_password == "$2a$04$old"
+            })
+            .returning(|_, _| Ok(true));
+        service
+            .password_hasher
+            .expect_needs_rehash()
+            .returning(|_| true);
+        service
+            .password_hasher
+            .expect_hash()
+            .returning(|_| Ok("$2b$10$new".to_string()));
+        let user_id = user.id.clone();
+        service
+            .user_repo
+            .expect_update_hashed_password_by_id()
+            .withf(move |_, id, hashed_password| id == &user_id && hashed_password == b"$2b$10$new")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let result = service.verify_password(&user, "password").await.unwrap();
+        assert!(result);
+    }
+}
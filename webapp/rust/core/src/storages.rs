@@ -1,5 +1,6 @@
 use thiserror::Error;
 
+pub mod personal_data_storage;
 pub mod submission_file_storage;
 
 #[derive(Debug, Error)]
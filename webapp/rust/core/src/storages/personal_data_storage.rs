@@ -0,0 +1,28 @@
+use crate::models::submission::UserSubmission;
+use crate::models::user::UserID;
+use crate::storages::StorageResult;
+use async_trait::async_trait;
+
+#[cfg_attr(any(test, feature = "test"), mockall::automock)]
+#[async_trait]
+pub trait PersonalDataStorage {
+    // 個人データのJSONと提出ファイルを1つのzipにまとめ、そのパスを返す
+    async fn create_personal_data_zip(
+        &self,
+        user_id: &UserID,
+        document: &[u8],
+        submissions: &[UserSubmission],
+    ) -> StorageResult<String>;
+
+    async fn delete_submission_files(
+        &self,
+        user_id: &UserID,
+        submissions: &[UserSubmission],
+    ) -> StorageResult<()>;
+}
+
+pub trait HavePersonalDataStorage {
+    type Storage: Sync + PersonalDataStorage;
+
+    fn personal_data_storage(&self) -> &Self::Storage;
+}
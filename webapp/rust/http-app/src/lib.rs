@@ -2,18 +2,29 @@ use crate::routes::initialize::initialize;
 use actix_session::config::PersistentSession;
 use actix_session::storage::CookieSessionStore;
 use actix_session::SessionMiddleware;
-use actix_web::body::{BoxBody, MessageBody};
+use actix_web::body::BoxBody;
 use actix_web::cookie::time::Duration;
-use actix_web::cookie::Key;
 use actix_web::dev::{ServiceFactory, ServiceRequest, ServiceResponse};
 use actix_web::{web, Error};
 use isucholar_core::db::DBPool;
-use isucholar_http_core::middleware::IsLoggedIn;
+use isucholar_http_core::middleware::{
+    BearerToken, CsrfProtection, Impersonation, IsLoggedIn, RequestIDAssigner, SessionKeyRotation,
+};
 use isucholar_http_core::routes::announcement_routes::get_announcement_routes;
+use isucholar_http_core::routes::audit_log_routes::get_audit_log_routes;
 use isucholar_http_core::routes::course_routes::get_course_routes;
+use isucholar_http_core::routes::impersonation_routes::get_impersonation_routes;
 use isucholar_http_core::routes::login::login;
+use isucholar_http_core::routes::login_attempt_routes::get_login_attempt_routes;
+use isucholar_http_core::routes::login_totp::login_totp;
+use isucholar_http_core::routes::login_totp_enrollment::login_totp_enrollment;
 use isucholar_http_core::routes::logout::logout;
+use isucholar_http_core::routes::lottery_routes::get_lottery_routes;
+use isucholar_http_core::routes::oidc_callback::oidc_callback;
+use isucholar_http_core::routes::oidc_login::oidc_login;
+use isucholar_http_core::routes::registration_window_routes::get_registration_window_routes;
 use isucholar_http_core::routes::user_routes::get_user_routes;
+use isucholar_http_core::session_keys::{SessionKeys, SESSION_COOKIE_NAME};
 use isucholar_infra::services::manager::ServiceManagerInfra;
 
 pub mod routes;
@@ -21,6 +32,7 @@ pub mod routes;
 pub fn create_app(
     pool: DBPool,
     service: ServiceManagerInfra,
+    session_keys: SessionKeys,
 ) -> actix_web::App<
     impl ServiceFactory<
         ServiceRequest,
@@ -33,27 +45,60 @@ pub fn create_app(
     let users_api = get_user_routes::<ServiceManagerInfra>();
     let courses_api = get_course_routes::<ServiceManagerInfra>();
     let announcements_api = get_announcement_routes::<ServiceManagerInfra>();
-
-    let session_key = env!("SESSION_KEY").try_into_bytes().unwrap().to_vec();
+    let login_attempts_api = get_login_attempt_routes::<ServiceManagerInfra>();
+    let impersonation_api = get_impersonation_routes::<ServiceManagerInfra>();
+    let audit_logs_api = get_audit_log_routes::<ServiceManagerInfra>();
+    let registration_windows_api = get_registration_window_routes::<ServiceManagerInfra>();
+    let lottery_api = get_lottery_routes::<ServiceManagerInfra>();
 
     actix_web::App::new()
         .app_data(web::Data::new(pool))
         .app_data(web::Data::new(service))
         // .wrap(actix_web::middleware::Logger::default())
         .wrap(
-            SessionMiddleware::builder(CookieSessionStore::default(), Key::from(&session_key))
-                .cookie_secure(false)
-                .session_lifecycle(PersistentSession::default().session_ttl(Duration::hours(1)))
-                .build(),
+            SessionMiddleware::builder(
+                CookieSessionStore::default(),
+                session_keys.current().clone(),
+            )
+            .cookie_name(SESSION_COOKIE_NAME.to_string())
+            .cookie_secure(false)
+            .session_lifecycle(PersistentSession::default().session_ttl(Duration::hours(1)))
+            .build(),
         )
+        .wrap(SessionKeyRotation::new(session_keys))
+        .wrap(RequestIDAssigner)
         .route("/initialize", web::post().to(initialize))
         .route("/login", web::post().to(login::<ServiceManagerInfra>))
-        .route("/logout", web::post().to(logout))
+        .route(
+            "/login/totp",
+            web::post().to(login_totp::<ServiceManagerInfra>),
+        )
+        .route(
+            "/login/totp/enrollment",
+            web::post().to(login_totp_enrollment::<ServiceManagerInfra>),
+        )
+        .route(
+            "/login/oidc",
+            web::get().to(oidc_login::<ServiceManagerInfra>),
+        )
+        .route(
+            "/login/oidc/callback",
+            web::get().to(oidc_callback::<ServiceManagerInfra>),
+        )
+        .route("/logout", web::post().to(logout::<ServiceManagerInfra>))
         .service(
             web::scope("/api")
                 .wrap(IsLoggedIn)
+                .wrap(Impersonation::<ServiceManagerInfra>::new())
+                .wrap(CsrfProtection::from_env())
+                .wrap(BearerToken::<ServiceManagerInfra>::new())
                 .service(users_api)
                 .service(courses_api)
-                .service(announcements_api),
+                .service(announcements_api)
+                .service(login_attempts_api)
+                .service(impersonation_api)
+                .service(audit_logs_api)
+                .service(registration_windows_api)
+                .service(lottery_api),
         )
 }
@@ -1,4 +1,5 @@
 use isucholar_http_app::create_app;
+use isucholar_http_core::session_keys::SessionKeys;
 
 use isucholar_infra::db::get_db_conn;
 use isucholar_infra::services::manager::ServiceManagerInfra;
@@ -8,11 +9,14 @@ async fn main() -> std::io::Result<()> {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info,sqlx=warn"))
         .init();
 
+    // 鍵がないと起動後にすべてのログインが失敗するため、起動時に検証する
+    let session_keys = SessionKeys::from_env().expect("failed to load session keys");
+
     let pool = get_db_conn().await.expect("failed to connect db");
     let service = ServiceManagerInfra::new(pool.clone());
 
     let server = actix_web::HttpServer::new(move || {
-        let app = create_app(pool.clone(), service.clone());
+        let app = create_app(pool.clone(), service.clone(), session_keys.clone());
         app.wrap(actix_web::middleware::Logger::default())
     });
     if let Some(l) = listenfd::ListenFd::from_env().take_tcp_listener(0)? {
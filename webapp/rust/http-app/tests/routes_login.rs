@@ -2,6 +2,7 @@ use actix_web::http::StatusCode;
 use actix_web::test;
 use isucholar_core::db::get_test_db_conn;
 use isucholar_http_app::create_app;
+use isucholar_http_core::session_keys::SessionKeys;
 use isucholar_infra::services::manager::ServiceManagerInfra;
 
 #[actix_web::test]
@@ -9,7 +10,7 @@ async fn user_not_login_case() {
     let db_pool = get_test_db_conn().await.unwrap();
     let service = ServiceManagerInfra::new(db_pool.clone());
 
-    let app = create_app(db_pool, service);
+    let app = create_app(db_pool, service, SessionKeys::from_env().unwrap());
 
     let app = test::init_service(app).await;
     let req = test::TestRequest::post().uri("/login").to_request();
@@ -17,3 +18,17 @@ async fn user_not_login_case() {
     let res = test::call_service(&app, req).await;
     assert_eq!(res.status(), StatusCode::BAD_REQUEST);
 }
+
+#[actix_web::test]
+async fn oidc_login_disabled_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let service = ServiceManagerInfra::new(db_pool.clone());
+
+    let app = create_app(db_pool, service, SessionKeys::from_env().unwrap());
+
+    let app = test::init_service(app).await;
+    let req = test::TestRequest::get().uri("/login/oidc").to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
@@ -4,6 +4,7 @@ use actix_web::http::StatusCode;
 use actix_web::test;
 use isucholar_core::db::get_test_db_conn;
 use isucholar_http_app::create_app;
+use isucholar_http_core::session_keys::SessionKeys;
 use isucholar_infra::services::manager::ServiceManagerInfra;
 
 #[actix_web::test]
@@ -11,7 +12,7 @@ async fn success_case() {
     let db_pool = get_test_db_conn().await.unwrap();
     let service = ServiceManagerInfra::new(db_pool.clone());
 
-    let app = create_app(db_pool, service);
+    let app = create_app(db_pool, service, SessionKeys::from_env().unwrap());
 
     let app = test::init_service(app).await;
     let req = test::TestRequest::post().uri("/logout").to_request();
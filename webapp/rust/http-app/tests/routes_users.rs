@@ -1,6 +1,7 @@
 use actix_web::test;
 use isucholar_core::db::get_test_db_conn;
 use isucholar_http_app::create_app;
+use isucholar_http_core::session_keys::SessionKeys;
 use isucholar_infra::services::manager::ServiceManagerInfra;
 
 #[actix_web::test]
@@ -9,7 +10,7 @@ async fn get_me() {
     let db_pool = get_test_db_conn().await.unwrap();
     let service = ServiceManagerInfra::new(db_pool.clone());
 
-    let app = create_app(db_pool, service);
+    let app = create_app(db_pool, service, SessionKeys::from_env().unwrap());
 
     let app = test::init_service(app).await;
     let req = test::TestRequest::get().uri("/api/users/me").to_request();
@@ -23,7 +24,7 @@ async fn get_me_courses() {
     let db_pool = get_test_db_conn().await.unwrap();
     let service = ServiceManagerInfra::new(db_pool.clone());
 
-    let app = create_app(db_pool, service);
+    let app = create_app(db_pool, service, SessionKeys::from_env().unwrap());
 
     let app = test::init_service(app).await;
     let req = test::TestRequest::get()
@@ -39,7 +40,7 @@ async fn put_me_courses() {
     let db_pool = get_test_db_conn().await.unwrap();
     let service = ServiceManagerInfra::new(db_pool.clone());
 
-    let app = create_app(db_pool, service);
+    let app = create_app(db_pool, service, SessionKeys::from_env().unwrap());
 
     let app = test::init_service(app).await;
     let req = test::TestRequest::put()
@@ -55,7 +56,7 @@ async fn get_me_grades() {
     let db_pool = get_test_db_conn().await.unwrap();
     let service = ServiceManagerInfra::new(db_pool.clone());
 
-    let app = create_app(db_pool, service);
+    let app = create_app(db_pool, service, SessionKeys::from_env().unwrap());
 
     let app = test::init_service(app).await;
     let req = test::TestRequest::get()
@@ -64,3 +65,20 @@ async fn get_me_grades() {
 
     test::call_service(&app, req).await;
 }
+
+#[actix_web::test]
+#[should_panic(expected = "The access token is invalid or expired.")]
+async fn get_me_with_invalid_token() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let service = ServiceManagerInfra::new(db_pool.clone());
+
+    let app = create_app(db_pool, service, SessionKeys::from_env().unwrap());
+
+    let app = test::init_service(app).await;
+    let req = test::TestRequest::get()
+        .uri("/api/users/me")
+        .insert_header(("Authorization", "Bearer invalid"))
+        .to_request();
+
+    test::call_service(&app, req).await;
+}
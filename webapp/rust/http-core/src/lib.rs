@@ -2,3 +2,6 @@ pub mod middleware;
 pub mod requests;
 pub mod responses;
 pub mod routes;
+pub mod session_keys;
+#[cfg(test)]
+mod test_util;
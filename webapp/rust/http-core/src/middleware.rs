@@ -1,6 +1,22 @@
 #![allow(clippy::type_complexity)]
 
+use crate::routes::util::{end_impersonation, get_impersonation};
+use crate::session_keys::{SessionKeys, SESSION_COOKIE_NAME};
+use actix_web::cookie::{Cookie, CookieJar};
+use actix_web::http::header::{HeaderMap, HeaderValue, COOKIE};
+use actix_web::HttpMessage;
 use futures::future;
+use isucholar_core::models::personal_access_token_scope::PersonalAccessTokenScope;
+use isucholar_core::models::user::UserID;
+use isucholar_core::models::user_type::UserType;
+use isucholar_core::services::impersonation_service::{
+    HaveImpersonationService, ImpersonationService,
+};
+use isucholar_core::services::personal_access_token_service::{
+    HavePersonalAccessTokenService, PersonalAccessTokenService,
+};
+use std::marker::PhantomData;
+use std::rc::Rc;
 
 // ログイン確認用middleware
 pub struct IsLoggedIn;
@@ -43,6 +59,10 @@ where
         use actix_session::SessionExt as _;
         use futures::FutureExt as _;
 
+        if req.extensions().get::<TokenUser>().is_some() {
+            return self.service.call(req).left_future();
+        }
+
         match req.get_session().get::<String>("userID") {
             Ok(Some(_)) => self.service.call(req).left_future(),
             Ok(None) => future::err(actix_web::error::ErrorUnauthorized(
@@ -95,6 +115,16 @@ where
         use actix_session::SessionExt as _;
         use futures::FutureExt as _;
 
+        let token_user_is_admin = req.extensions().get::<TokenUser>().map(|u| u.is_admin);
+        if let Some(is_admin) = token_user_is_admin {
+            return if is_admin {
+                self.service.call(req).left_future()
+            } else {
+                future::err(actix_web::error::ErrorForbidden("You are not admin user."))
+                    .right_future()
+            };
+        }
+
         match req.get_session().get::<bool>("isAdmin") {
             Ok(Some(true)) => self.service.call(req).left_future(),
             Ok(Some(false)) | Ok(None) => {
@@ -105,3 +135,892 @@ where
         }
     }
 }
+
+// Bearerトークンで認証されたユーザー
+#[derive(Debug, Clone)]
+pub struct TokenUser {
+    pub user_id: UserID,
+    pub user_name: String,
+    pub is_admin: bool,
+    pub scope: PersonalAccessTokenScope,
+}
+
+fn bearer_token(req: &actix_web::dev::ServiceRequest) -> Option<String> {
+    let value = req
+        .headers()
+        .get(actix_web::http::header::AUTHORIZATION)?
+        .to_str()
+        .ok()?;
+    value.strip_prefix("Bearer ").map(|v| v.trim().to_string())
+}
+
+// Authorizationヘッダーのトークンを検証し、TokenUserをリクエストに紐付けるmiddleware
+pub struct BearerToken<Service> {
+    _service: PhantomData<Service>,
+}
+impl<Service> BearerToken<Service> {
+    pub fn new() -> Self {
+        Self {
+            _service: PhantomData,
+        }
+    }
+}
+impl<Service> Default for BearerToken<Service> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<S, B, Service> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest>
+    for BearerToken<Service>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::error::Error,
+        > + 'static,
+    Service: HavePersonalAccessTokenService + 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = BearerTokenMiddleware<S, Service>;
+    type InitError = ();
+    type Future = future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        future::ok(BearerTokenMiddleware {
+            service: Rc::new(service),
+            _service: PhantomData,
+        })
+    }
+}
+pub struct BearerTokenMiddleware<S, Service> {
+    service: Rc<S>,
+    _service: PhantomData<Service>,
+}
+impl<S, B, Service> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for BearerTokenMiddleware<S, Service>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::error::Error,
+        > + 'static,
+    Service: HavePersonalAccessTokenService + 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use futures::FutureExt as _;
+
+        let token = match bearer_token(&req) {
+            Some(token) => token,
+            None => return self.service.call(req).boxed_local(),
+        };
+        let service = self.service.clone();
+
+        async move {
+            let app_service = req
+                .app_data::<actix_web::web::Data<Service>>()
+                .ok_or_else(|| {
+                    actix_web::error::ErrorInternalServerError("service is not registered")
+                })?
+                .clone();
+            let result = app_service
+                .personal_access_token_service()
+                .authenticate(&token)
+                .await
+                .map_err(|e| {
+                    log::error!("{}", e);
+                    actix_web::error::ErrorInternalServerError(e.to_string())
+                })?;
+            let (user, personal_access_token) = match result {
+                Some(result) => result,
+                None => {
+                    return Err(actix_web::error::ErrorUnauthorized(
+                        "The access token is invalid or expired.",
+                    ))
+                }
+            };
+            if !personal_access_token.scope.allows(req.method().as_str()) {
+                return Err(actix_web::error::ErrorForbidden(
+                    "The access token does not have the required scope.",
+                ));
+            }
+
+            req.extensions_mut().insert(TokenUser {
+                user_id: user.id,
+                user_name: user.name,
+                is_admin: user.type_ == UserType::Teacher,
+                scope: personal_access_token.scope,
+            });
+
+            service.call(req).await
+        }
+        .boxed_local()
+    }
+}
+
+// なりすましの終了はなりすまし中の書き込み制限の対象外にする
+const IMPERSONATION_PATH: &str = "/api/impersonation";
+
+// なりすまし中のセッションの有効期限と書き込みの制限を確認するmiddleware
+pub struct Impersonation<Service> {
+    _service: PhantomData<Service>,
+}
+impl<Service> Impersonation<Service> {
+    pub fn new() -> Self {
+        Self {
+            _service: PhantomData,
+        }
+    }
+}
+impl<Service> Default for Impersonation<Service> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<S, B, Service> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest>
+    for Impersonation<Service>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::error::Error,
+        > + 'static,
+    Service: HaveImpersonationService + 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Transform = ImpersonationMiddleware<S, Service>;
+    type InitError = ();
+    type Future = future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        future::ok(ImpersonationMiddleware {
+            service: Rc::new(service),
+            _service: PhantomData,
+        })
+    }
+}
+pub struct ImpersonationMiddleware<S, Service> {
+    service: Rc<S>,
+    _service: PhantomData<Service>,
+}
+impl<S, B, Service> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for ImpersonationMiddleware<S, Service>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::error::Error,
+        > + 'static,
+    Service: HaveImpersonationService + 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use actix_session::SessionExt as _;
+        use futures::FutureExt as _;
+
+        if req.extensions().get::<TokenUser>().is_some() {
+            return self
+                .service
+                .call(req)
+                .map(|res| res.map(actix_web::dev::ServiceResponse::map_into_left_body))
+                .boxed_local();
+        }
+        let service = self.service.clone();
+
+        async move {
+            let session = req.get_session();
+            let impersonation = match get_impersonation(&session)? {
+                Some(impersonation) => impersonation,
+                None => {
+                    return service
+                        .call(req)
+                        .await
+                        .map(actix_web::dev::ServiceResponse::map_into_left_body)
+                }
+            };
+            let app_service = req
+                .app_data::<actix_web::web::Data<Service>>()
+                .ok_or_else(|| {
+                    actix_web::error::ErrorInternalServerError("service is not registered")
+                })?
+                .clone();
+
+            // 期限が切れていれば管理者のセッションに戻す。
+            // 学生としての画面から送られたリクエストを管理者の権限で処理しないよう、処理は続けずに再読み込みを促す
+            if chrono::Utc::now().timestamp() >= impersonation.expires_at {
+                if let Some(user_id) = end_impersonation(&session, &impersonation)? {
+                    app_service
+                        .impersonation_service()
+                        .end(&impersonation.impersonator_id, &user_id, true)
+                        .await
+                        .map_err(|e| {
+                            log::error!("{}", e);
+                            actix_web::error::ErrorInternalServerError(e.to_string())
+                        })?;
+                }
+                // エラーにするとセッションの変更が保存されないため、レスポンスとして返す
+                return Ok(req
+                    .into_response(
+                        actix_web::HttpResponse::Conflict().body("The impersonation has expired."),
+                    )
+                    .map_into_right_body());
+            }
+
+            let is_read_only = matches!(req.method().as_str(), "GET" | "HEAD" | "OPTIONS");
+            if !is_read_only
+                && req.path() != IMPERSONATION_PATH
+                && !app_service.impersonation_service().allows_writes()
+            {
+                return Err(actix_web::error::ErrorForbidden(
+                    "Write operations are not allowed while impersonating.",
+                ));
+            }
+
+            service
+                .call(req)
+                .await
+                .map(actix_web::dev::ServiceResponse::map_into_left_body)
+        }
+        .boxed_local()
+    }
+}
+
+// セッションCookieで認証されたリクエストのOrigin(なければReferer)を検証し、CSRFを防ぐmiddleware。
+// Bearerトークンで認証されたリクエストはCookieに依存しないため対象外にする
+#[derive(Debug, Clone, Default)]
+pub struct CsrfProtection {
+    // 空の場合はHostヘッダーと同じオリジンのみ許可する
+    allowed_origins: Rc<Vec<String>>,
+}
+impl CsrfProtection {
+    pub fn new(allowed_origins: Vec<String>) -> Self {
+        Self {
+            allowed_origins: Rc::new(
+                allowed_origins
+                    .into_iter()
+                    .map(|origin| origin.trim_end_matches('/').to_ascii_lowercase())
+                    .collect(),
+            ),
+        }
+    }
+
+    // CSRF_ALLOWED_ORIGINSにカンマ区切りで許可するオリジン(例: https://example.com)を指定する
+    pub fn from_env() -> Self {
+        let allowed_origins = std::env::var("CSRF_ALLOWED_ORIGINS")
+            .map(|v| {
+                v.split(',')
+                    .map(|origin| origin.trim().to_string())
+                    .filter(|origin| !origin.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self::new(allowed_origins)
+    }
+}
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for CsrfProtection
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::error::Error,
+    >,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = CsrfProtectionMiddleware<S>;
+    type InitError = ();
+    type Future = future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        future::ok(CsrfProtectionMiddleware {
+            service,
+            allowed_origins: self.allowed_origins.clone(),
+        })
+    }
+}
+pub struct CsrfProtectionMiddleware<S> {
+    service: S,
+    allowed_origins: Rc<Vec<String>>,
+}
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for CsrfProtectionMiddleware<S>
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::error::Error,
+    >,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = future::Either<S::Future, future::Ready<Result<Self::Response, Self::Error>>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use futures::FutureExt as _;
+
+        if matches!(req.method().as_str(), "GET" | "HEAD" | "OPTIONS")
+            || req.extensions().get::<TokenUser>().is_some()
+        {
+            return self.service.call(req).left_future();
+        }
+
+        let source = req
+            .headers()
+            .get(actix_web::http::header::ORIGIN)
+            .or_else(|| req.headers().get(actix_web::http::header::REFERER))
+            .map(|v| v.to_str().ok().and_then(request_origin));
+        let is_allowed = match source {
+            // ブラウザはクロスオリジンの書き込みリクエストにOriginを付与するため、
+            // どちらのヘッダーもないリクエストはブラウザ以外のクライアントからのものとして許可する
+            None => true,
+            Some(None) => false,
+            Some(Some(origin)) => {
+                if self.allowed_origins.is_empty() {
+                    origin
+                        .split_once("://")
+                        .map(|(_, host)| host)
+                        .is_some_and(|host| host.eq_ignore_ascii_case(req.connection_info().host()))
+                } else {
+                    self.allowed_origins.contains(&origin)
+                }
+            }
+        };
+
+        if is_allowed {
+            self.service.call(req).left_future()
+        } else {
+            future::err(actix_web::error::ErrorForbidden(
+                "The request origin is not allowed.",
+            ))
+            .right_future()
+        }
+    }
+}
+
+// OriginまたはRefererヘッダーの値から、小文字化した`scheme://host[:port]`を取り出す
+fn request_origin(value: &str) -> Option<String> {
+    let (scheme, rest) = value.split_once("://")?;
+    let host = rest.split(['/', '?', '#']).next()?;
+    if scheme.is_empty() || host.is_empty() {
+        return None;
+    }
+    Some(format!("{}://{}", scheme, host).to_ascii_lowercase())
+}
+
+// リクエストを識別するID。監査ログなどに記録する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestID(pub String);
+
+const REQUEST_ID_HEADER: &str = "x-request-id";
+const REQUEST_ID_MAX_LEN: usize = 64;
+
+// リクエストIDを払い出し、レスポンスヘッダーにも付与するmiddleware。
+// 上流のプロキシなどが付与した妥当なX-Request-Idがあればそれを引き継ぐ
+pub struct RequestIDAssigner;
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RequestIDAssigner
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::error::Error,
+        > + 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = RequestIDAssignerMiddleware<S>;
+    type InitError = ();
+    type Future = future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        future::ok(RequestIDAssignerMiddleware { service })
+    }
+}
+pub struct RequestIDAssignerMiddleware<S> {
+    service: S,
+}
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for RequestIDAssignerMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::error::Error,
+        > + 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        use futures::FutureExt as _;
+
+        let request_id = req
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .filter(|v| is_valid_request_id(v))
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| ulid::Ulid::new().to_string());
+        req.extensions_mut().insert(RequestID(request_id.clone()));
+
+        let fut = self.service.call(req);
+        async move {
+            let mut res = fut.await?;
+            if let Ok(value) = HeaderValue::from_str(&request_id) {
+                res.headers_mut().insert(
+                    actix_web::http::header::HeaderName::from_static(REQUEST_ID_HEADER),
+                    value,
+                );
+            }
+            Ok(res)
+        }
+        .boxed_local()
+    }
+}
+
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= REQUEST_ID_MAX_LEN
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b == b'.')
+}
+
+// 古い鍵で暗号化されたセッションCookieを最新の鍵で暗号化し直し、SessionMiddlewareが読めるようにするmiddleware。
+// リクエストのCookieがパースされる前に書き換える必要があるため、SessionMiddlewareより外側に置く
+pub struct SessionKeyRotation {
+    keys: SessionKeys,
+}
+impl SessionKeyRotation {
+    pub fn new(keys: SessionKeys) -> Self {
+        Self { keys }
+    }
+}
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for SessionKeyRotation
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::error::Error,
+    >,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = SessionKeyRotationMiddleware<S>;
+    type InitError = ();
+    type Future = future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        future::ok(SessionKeyRotationMiddleware {
+            service,
+            keys: self.keys.clone(),
+        })
+    }
+}
+pub struct SessionKeyRotationMiddleware<S> {
+    service: S,
+    keys: SessionKeys,
+}
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for SessionKeyRotationMiddleware<S>
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::error::Error,
+    >,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = S::Future;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, mut req: actix_web::dev::ServiceRequest) -> Self::Future {
+        if !self.keys.previous().is_empty() {
+            if let Some(cookie_header) = rotate_session_cookie(req.headers(), &self.keys) {
+                req.headers_mut().insert(COOKIE, cookie_header);
+            }
+        }
+        self.service.call(req)
+    }
+}
+
+// セッションCookieが古い鍵で暗号化されていれば、最新の鍵で暗号化し直したCookieヘッダーを返す
+fn rotate_session_cookie(headers: &HeaderMap, keys: &SessionKeys) -> Option<HeaderValue> {
+    let mut is_rotated = false;
+    let mut pairs = Vec::new();
+    for value in headers.get_all(COOKIE) {
+        for pair in value.to_str().ok()?.split(';') {
+            let pair = pair.trim();
+            if pair.is_empty() {
+                continue;
+            }
+            if !is_rotated {
+                if let Ok(cookie) = Cookie::parse_encoded(pair) {
+                    if cookie.name() == SESSION_COOKIE_NAME {
+                        if let Some(rotated) = reencrypt_session_cookie(cookie.into_owned(), keys) {
+                            pairs.push(rotated);
+                            is_rotated = true;
+                            continue;
+                        }
+                    }
+                }
+            }
+            pairs.push(pair.to_string());
+        }
+    }
+    if !is_rotated {
+        return None;
+    }
+
+    HeaderValue::from_str(&pairs.join("; ")).ok()
+}
+
+fn reencrypt_session_cookie(cookie: Cookie<'static>, keys: &SessionKeys) -> Option<String> {
+    let mut jar = CookieJar::new();
+    jar.add_original(cookie);
+    if jar
+        .private(keys.current())
+        .get(SESSION_COOKIE_NAME)
+        .is_some()
+    {
+        return None;
+    }
+    let decrypted = keys
+        .previous()
+        .iter()
+        .find_map(|key| jar.private(key).get(SESSION_COOKIE_NAME))?;
+
+    let mut jar = CookieJar::new();
+    jar.private_mut(keys.current()).add(decrypted);
+    let encrypted = jar.get(SESSION_COOKIE_NAME)?;
+    Some(
+        Cookie::new(SESSION_COOKIE_NAME, encrypted.value().to_string())
+            .encoded()
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::middleware::{
+        CsrfProtection, Impersonation, RequestIDAssigner, SessionKeyRotation, TokenUser,
+    };
+    use crate::session_keys::{SessionKeys, SESSION_COOKIE_NAME};
+    use actix_session::storage::CookieSessionStore;
+    use actix_session::{Session, SessionMiddleware};
+    use actix_web::cookie::Key;
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web, App, HttpResponse};
+    use isucholar_core::models::personal_access_token_scope::PersonalAccessTokenScope;
+    use isucholar_core::models::user::UserID;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    const OLD_KEY: [u8; 64] = [1; 64];
+    const NEW_KEY: [u8; 64] = [2; 64];
+
+    async fn login(session: Session) -> HttpResponse {
+        session.insert("userID", "1").unwrap();
+        HttpResponse::Ok().finish()
+    }
+
+    async fn me(session: Session) -> HttpResponse {
+        match session.get::<String>("userID").unwrap() {
+            Some(user_id) => HttpResponse::Ok().body(user_id),
+            None => HttpResponse::Unauthorized().finish(),
+        }
+    }
+
+    async fn impersonate(session: Session) -> HttpResponse {
+        session.insert("userID", "2").unwrap();
+        session.insert("userName", "Student").unwrap();
+        session.insert("isAdmin", false).unwrap();
+        session.insert("impersonatorID", "1").unwrap();
+        session.insert("impersonatorName", "Teacher").unwrap();
+        session.insert("impersonationExpiresAt", 0).unwrap();
+        HttpResponse::Ok().finish()
+    }
+
+    fn session_middleware(key: &[u8]) -> SessionMiddleware<CookieSessionStore> {
+        SessionMiddleware::builder(CookieSessionStore::default(), Key::from(key))
+            .cookie_name(SESSION_COOKIE_NAME.to_string())
+            .build()
+    }
+
+    #[actix_web::test]
+    async fn test_previous_key_case() {
+        let old_app = test::init_service(
+            App::new()
+                .wrap(session_middleware(&OLD_KEY))
+                .route("/login", web::post().to(login)),
+        )
+        .await;
+        let res = test::call_service(
+            &old_app,
+            test::TestRequest::post().uri("/login").to_request(),
+        )
+        .await;
+        let cookie = res
+            .response()
+            .cookies()
+            .find(|c| c.name() == SESSION_COOKIE_NAME)
+            .unwrap()
+            .into_owned();
+
+        let keys = SessionKeys::new(vec![NEW_KEY.to_vec(), OLD_KEY.to_vec()]).unwrap();
+        let new_app = test::init_service(
+            App::new()
+                .wrap(session_middleware(keys.current().master()))
+                .wrap(SessionKeyRotation::new(keys))
+                .route("/me", web::get().to(me)),
+        )
+        .await;
+        let req = test::TestRequest::get()
+            .uri("/me")
+            .cookie(cookie)
+            .to_request();
+        let body = test::call_and_read_body(&new_app, req).await;
+        assert_eq!(body, "1");
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_key_case() {
+        let old_app = test::init_service(
+            App::new()
+                .wrap(session_middleware(&OLD_KEY))
+                .route("/login", web::post().to(login)),
+        )
+        .await;
+        let res = test::call_service(
+            &old_app,
+            test::TestRequest::post().uri("/login").to_request(),
+        )
+        .await;
+        let cookie = res
+            .response()
+            .cookies()
+            .find(|c| c.name() == SESSION_COOKIE_NAME)
+            .unwrap()
+            .into_owned();
+
+        // ローテーション期間が終わり古い鍵を外した後は受け付けない
+        let keys = SessionKeys::new(vec![NEW_KEY.to_vec(), [3; 64].to_vec()]).unwrap();
+        let new_app = test::init_service(
+            App::new()
+                .wrap(session_middleware(keys.current().master()))
+                .wrap(SessionKeyRotation::new(keys))
+                .route("/me", web::get().to(me)),
+        )
+        .await;
+        let req = test::TestRequest::get()
+            .uri("/me")
+            .cookie(cookie)
+            .to_request();
+        let res = test::call_service(&new_app, req).await;
+        assert_eq!(res.status(), actix_web::http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_impersonation_expired_case() {
+        let mut service = MockServiceManager::new();
+        service
+            .impersonation_service
+            .expect_end()
+            .withf(|admin_id, target_id, is_expired| {
+                admin_id.inner() == "1" && target_id.inner() == "2" && *is_expired
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(service))
+                .wrap(Impersonation::<MockServiceManager>::new())
+                .wrap(session_middleware(&NEW_KEY))
+                .route("/impersonate", web::post().to(impersonate))
+                .route("/me", web::get().to(me)),
+        )
+        .await;
+        let res = test::call_service(
+            &app,
+            test::TestRequest::post().uri("/impersonate").to_request(),
+        )
+        .await;
+        let cookie = res
+            .response()
+            .cookies()
+            .find(|c| c.name() == SESSION_COOKIE_NAME)
+            .unwrap()
+            .into_owned();
+
+        // 期限切れのリクエストは管理者の権限で処理せず、管理者のセッションに戻すだけにする
+        let req = test::TestRequest::get()
+            .uri("/me")
+            .cookie(cookie)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::CONFLICT);
+        let cookie = res
+            .response()
+            .cookies()
+            .find(|c| c.name() == SESSION_COOKIE_NAME)
+            .unwrap()
+            .into_owned();
+
+        let req = test::TestRequest::get()
+            .uri("/me")
+            .cookie(cookie)
+            .to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        assert_eq!(body, "1");
+    }
+
+    #[actix_web::test]
+    async fn test_request_id_forwarded_case() {
+        let app = test::init_service(
+            App::new()
+                .wrap(RequestIDAssigner)
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header(("X-Request-Id", "req-123"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.headers().get("x-request-id").unwrap(), "req-123");
+    }
+
+    #[actix_web::test]
+    async fn test_request_id_invalid_case() {
+        let app = test::init_service(
+            App::new()
+                .wrap(RequestIDAssigner)
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header(("X-Request-Id", "bad id\tvalue"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        let request_id = res.headers().get("x-request-id").unwrap().to_str().unwrap();
+        assert_eq!(request_id.len(), 26);
+    }
+
+    #[actix_web::test]
+    async fn test_csrf_same_origin_case() {
+        let app = test::init_service(
+            App::new()
+                .wrap(CsrfProtection::default())
+                .route("/", web::get().to(HttpResponse::Ok))
+                .route("/", web::post().to(HttpResponse::Ok)),
+        )
+        .await;
+        let req = test::TestRequest::post()
+            .uri("/")
+            .insert_header(("Host", "isucholar.example"))
+            .insert_header(("Origin", "http://isucholar.example"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_csrf_cross_origin_case() {
+        let app = test::init_service(
+            App::new()
+                .wrap(CsrfProtection::default())
+                .route("/", web::get().to(HttpResponse::Ok))
+                .route("/", web::post().to(HttpResponse::Ok)),
+        )
+        .await;
+        let req = test::TestRequest::post()
+            .uri("/")
+            .insert_header(("Host", "isucholar.example"))
+            .insert_header(("Origin", "http://evil.example"))
+            .to_request();
+        let res = test::try_call_service(&app, req).await;
+        assert_eq!(
+            res.err().unwrap().as_response_error().status_code(),
+            StatusCode::FORBIDDEN
+        );
+
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header(("Host", "isucholar.example"))
+            .insert_header(("Origin", "http://evil.example"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_csrf_referer_case() {
+        let app = test::init_service(
+            App::new()
+                .wrap(CsrfProtection::new(vec![
+                    "https://isucholar.example/".to_string()
+                ]))
+                .route("/", web::get().to(HttpResponse::Ok))
+                .route("/", web::post().to(HttpResponse::Ok)),
+        )
+        .await;
+        let req = test::TestRequest::post()
+            .uri("/")
+            .insert_header(("Referer", "https://isucholar.example/syllabus?id=1"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let req = test::TestRequest::post()
+            .uri("/")
+            .insert_header(("Referer", "http://isucholar.example/syllabus"))
+            .to_request();
+        let res = test::try_call_service(&app, req).await;
+        assert!(res.is_err());
+    }
+
+    #[actix_web::test]
+    async fn test_csrf_token_user_case() {
+        let app = test::init_service(
+            App::new()
+                .wrap(CsrfProtection::default())
+                .wrap_fn(|req, srv| {
+                    use actix_web::dev::Service as _;
+                    use actix_web::HttpMessage as _;
+                    req.extensions_mut().insert(TokenUser {
+                        user_id: UserID::new("1".to_string()),
+                        user_name: "student".to_string(),
+                        is_admin: false,
+                        scope: PersonalAccessTokenScope::Write,
+                    });
+                    srv.call(req)
+                })
+                .route("/", web::post().to(HttpResponse::Ok)),
+        )
+        .await;
+        let req = test::TestRequest::post()
+            .uri("/")
+            .insert_header(("Origin", "http://evil.example"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
@@ -3,3 +3,4 @@ pub mod get_course_detail_response;
 pub mod get_grade_response;
 pub mod get_registered_course_response;
 pub mod register_courses_error_response;
+pub mod search_course_response;
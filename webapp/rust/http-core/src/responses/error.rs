@@ -39,6 +39,24 @@ pub enum ResponseError {
     InvalidFile,
     #[error("Code or Password is wrong.")]
     Unauthorized,
+    #[error("Too many login attempts. Please try again later.")]
+    TooManyLoginAttempts(i64),
+    #[error("Invalid two-factor authentication code.")]
+    InvalidTotpCode,
+    #[error("No login is waiting for two-factor authentication.")]
+    TotpNotPending,
+    #[error("Two-factor authentication is already enabled.")]
+    TotpAlreadyEnabled,
+    #[error("Two-factor authentication enrollment has not been started.")]
+    TotpNotEnrolled,
+    #[error("Two-factor authentication is required for your account.")]
+    TotpRequired,
+    #[error("Failed to log in with the identity provider.")]
+    OidcUnauthorized,
+    #[error("Invalid state.")]
+    InvalidOidcState,
+    #[error("OIDC login is not enabled.")]
+    OidcNotEnabled,
     #[error("You are already logged in.")]
     AlreadyLogin,
     #[error("An announcement with the same id already exists.")]
@@ -55,8 +73,70 @@ pub enum ResponseError {
     CourseIsNotInProgress,
     #[error("A class with the same part already exists.")]
     CourseConflict,
+    #[error("Course name must not be empty, credit must be positive and period must be between 1 and 6.")]
+    InvalidCourse,
+    #[error("This course has already been closed.")]
+    CourseIsClosed,
+    #[error("The new schedule conflicts with other courses of registered students.")]
+    CourseScheduleConflict,
+    #[error("The course status cannot be changed in this order.")]
+    InvalidCourseStatusTransition,
+    #[error("Capacity must be a positive number.")]
+    InvalidCourseCapacity,
+    #[error("Credit limit must be a positive number.")]
+    InvalidCreditLimit,
+    #[error("Prerequisites must be other existing courses without duplicates.")]
+    InvalidCoursePrerequisite,
+    #[error("This course is not accepting registrations.")]
+    CourseNotRegistrable,
+    #[error("This course still has available seats.")]
+    CourseNotFull,
+    #[error("You have already registered for this course.")]
+    AlreadyRegistered,
+    #[error("This course can no longer be dropped.")]
+    CourseNotDroppable,
+    #[error("Registration window must have a term and end after it starts.")]
+    InvalidRegistrationWindow,
+    #[error("No such registration window.")]
+    RegistrationWindowNotFound,
+    #[error("Registration is not open for you now.")]
+    RegistrationClosed,
+    #[error("Lottery preferences must be distinct lottery courses accepting registrations.")]
+    InvalidLotteryPreference,
+    #[error("No such registration request.")]
+    RegistrationRequestNotFound,
     #[error("No such user.")]
     UserNotFound,
+    #[error("A user with the same code already exists.")]
+    UserConflict,
+    #[error("User code must be 6 alphanumeric characters.")]
+    InvalidUserCode,
+    #[error("User name must not be empty or longer than 255 characters.")]
+    InvalidUserName,
+    #[error("This user has been deactivated.")]
+    UserDeactivated,
+    #[error("Name reading must be written in kana and not longer than 255 characters.")]
+    InvalidNameKana,
+    #[error("Invalid email address.")]
+    InvalidEmail,
+    #[error("Reason for overriding the course status must not be empty.")]
+    InvalidStatusOverrideReason,
+    #[error("No such student.")]
+    ImpersonationTargetNotFound,
+    #[error("Only students can be impersonated.")]
+    ImpersonationTargetNotAllowed,
+    #[error("You are already impersonating another user.")]
+    ImpersonationAlreadyStarted,
+    #[error("You are not impersonating any user.")]
+    ImpersonationNotStarted,
+    #[error("No such personal access token.")]
+    PersonalAccessTokenNotFound,
+    #[error("Invalid personal access token name or expiration.")]
+    InvalidPersonalAccessToken,
+    #[error("Personal access tokens cannot be managed with a personal access token.")]
+    PersonalAccessTokenForbidden,
+    #[error("You are not a teacher of this course.")]
+    NotCourseTeacher,
     #[error("You have not taken this course.")]
     RegistrationAlready,
     #[error("Submission has been closed for this class.")]
@@ -66,27 +146,75 @@ pub enum ResponseError {
 impl actix_web::ResponseError for ResponseError {
     fn error_response(&self) -> HttpResponse {
         match self {
-            ResponseError::Unauthorized => HttpResponse::Unauthorized()
+            ResponseError::Unauthorized
+            | ResponseError::OidcUnauthorized
+            | ResponseError::InvalidTotpCode
+            | ResponseError::TotpNotPending => HttpResponse::Unauthorized()
+                .content_type(mime::TEXT_PLAIN)
+                .body(self.to_string()),
+            ResponseError::TooManyLoginAttempts(retry_after) => HttpResponse::TooManyRequests()
+                .insert_header((
+                    actix_web::http::header::RETRY_AFTER,
+                    retry_after.to_string(),
+                ))
                 .content_type(mime::TEXT_PLAIN)
                 .body(self.to_string()),
             ResponseError::AnnouncementNotFound
             | ResponseError::CourseNotFound
-            | ResponseError::ClassNotFound => HttpResponse::NotFound()
+            | ResponseError::ClassNotFound
+            | ResponseError::OidcNotEnabled
+            | ResponseError::ImpersonationTargetNotFound
+            | ResponseError::UserNotFound
+            | ResponseError::PersonalAccessTokenNotFound
+            | ResponseError::RegistrationWindowNotFound
+            | ResponseError::RegistrationRequestNotFound => HttpResponse::NotFound()
                 .content_type(mime::TEXT_PLAIN)
                 .body(self.to_string()),
             ResponseError::CourseIsNotInProgress
             | ResponseError::ClassIsNotSubmissionClosed
             | ResponseError::InvalidFile
             | ResponseError::InvalidPage
+            | ResponseError::InvalidOidcState
+            | ResponseError::InvalidPersonalAccessToken
             | ResponseError::RegistrationAlready
-            | ResponseError::SubmissionClosed => HttpResponse::BadRequest()
+            | ResponseError::SubmissionClosed
+            | ResponseError::TotpAlreadyEnabled
+            | ResponseError::TotpNotEnrolled
+            | ResponseError::ImpersonationTargetNotAllowed
+            | ResponseError::ImpersonationAlreadyStarted
+            | ResponseError::ImpersonationNotStarted
+            | ResponseError::InvalidUserCode
+            | ResponseError::InvalidUserName
+            | ResponseError::InvalidNameKana
+            | ResponseError::InvalidEmail
+            | ResponseError::InvalidCourse
+            | ResponseError::CourseIsClosed
+            | ResponseError::InvalidStatusOverrideReason
+            | ResponseError::InvalidCourseCapacity
+            | ResponseError::InvalidCreditLimit
+            | ResponseError::InvalidCoursePrerequisite
+            | ResponseError::CourseNotRegistrable
+            | ResponseError::CourseNotFull
+            | ResponseError::CourseNotDroppable
+            | ResponseError::InvalidRegistrationWindow
+            | ResponseError::RegistrationClosed
+            | ResponseError::InvalidLotteryPreference => HttpResponse::BadRequest()
+                .content_type(mime::TEXT_PLAIN)
+                .body(self.to_string()),
+            ResponseError::PersonalAccessTokenForbidden
+            | ResponseError::NotCourseTeacher
+            | ResponseError::TotpRequired
+            | ResponseError::UserDeactivated => HttpResponse::Forbidden()
+                .content_type(mime::TEXT_PLAIN)
+                .body(self.to_string()),
+            ResponseError::CourseConflict
+            | ResponseError::CourseScheduleConflict
+            | ResponseError::InvalidCourseStatusTransition
+            | ResponseError::AlreadyRegistered
+            | ResponseError::AnnouncementConflict
+            | ResponseError::UserConflict => HttpResponse::Conflict()
                 .content_type(mime::TEXT_PLAIN)
                 .body(self.to_string()),
-            ResponseError::CourseConflict | ResponseError::AnnouncementConflict => {
-                HttpResponse::Conflict()
-                    .content_type(mime::TEXT_PLAIN)
-                    .body(self.to_string())
-            }
             _ => {
                 log::error!("{}", self);
                 HttpResponse::InternalServerError()
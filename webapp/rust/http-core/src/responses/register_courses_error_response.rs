@@ -1,3 +1,8 @@
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::error::{
+    CreditLimitExceeded, RegistrationCourseValidationError, ScheduleConflict,
+};
+
 #[derive(Debug, Default, serde::Serialize)]
 pub struct RegisterCoursesErrorResponse {
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -6,4 +11,77 @@ pub struct RegisterCoursesErrorResponse {
     pub not_registrable_status: Vec<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub schedule_conflict: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub schedule_conflict_details: Vec<ScheduleConflictResponse>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub course_full: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub prerequisite_not_met: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub registration_closed: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub lottery_only: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_limit_exceeded: Option<CreditLimitExceededResponse>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ScheduleConflictResponse {
+    pub course_id: String,
+    pub conflicts_with: Vec<String>,
+}
+
+impl From<ScheduleConflict> for ScheduleConflictResponse {
+    fn from(conflict: ScheduleConflict) -> Self {
+        Self {
+            course_id: conflict.course_id.inner().to_string(),
+            conflicts_with: conflict
+                .conflicts_with
+                .iter()
+                .map(|id| id.inner().to_string())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CreditLimitExceededResponse {
+    pub credit_limit: u32,
+    pub registered_credits: u32,
+    pub requested_credits: u32,
+}
+
+impl From<CreditLimitExceeded> for CreditLimitExceededResponse {
+    fn from(exceeded: CreditLimitExceeded) -> Self {
+        Self {
+            credit_limit: exceeded.credit_limit,
+            registered_credits: exceeded.registered_credits,
+            requested_credits: exceeded.requested_credits,
+        }
+    }
+}
+
+impl From<RegistrationCourseValidationError> for RegisterCoursesErrorResponse {
+    fn from(errors: RegistrationCourseValidationError) -> Self {
+        let to_strings = |ids: Vec<CourseID>| -> Vec<String> {
+            ids.iter().map(|id| id.inner().to_string()).collect()
+        };
+        Self {
+            course_not_found: to_strings(errors.course_not_found),
+            not_registrable_status: to_strings(errors.not_registrable_status),
+            schedule_conflict: to_strings(errors.schedule_conflict),
+            schedule_conflict_details: errors
+                .schedule_conflict_details
+                .into_iter()
+                .map(ScheduleConflictResponse::from)
+                .collect(),
+            course_full: to_strings(errors.course_full),
+            prerequisite_not_met: to_strings(errors.prerequisite_not_met),
+            registration_closed: to_strings(errors.registration_closed),
+            lottery_only: to_strings(errors.lottery_only),
+            credit_limit_exceeded: errors
+                .credit_limit_exceeded
+                .map(CreditLimitExceededResponse::from),
+        }
+    }
 }
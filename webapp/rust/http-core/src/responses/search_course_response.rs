@@ -0,0 +1,9 @@
+use isucholar_core::models::course::CourseWithTeacher;
+
+#[derive(Debug, serde::Serialize)]
+pub struct SearchCourseResponse {
+    #[serde(flatten)]
+    pub course: CourseWithTeacher,
+    // 呼び出したユーザーがこの科目に現在履修登録できるか
+    pub registration_open: bool,
+}
@@ -1,6 +1,15 @@
 pub mod announcement_routes;
+pub mod audit_log_routes;
 pub mod course_routes;
+pub mod impersonation_routes;
 pub mod login;
+pub mod login_attempt_routes;
+pub mod login_totp;
+pub mod login_totp_enrollment;
 pub mod logout;
+pub mod lottery_routes;
+pub mod oidc_callback;
+pub mod oidc_login;
+pub mod registration_window_routes;
 pub mod user_routes;
 pub mod util;
@@ -1,7 +1,9 @@
 use crate::responses::error::ResponseError::{AnnouncementConflict, CourseNotFound};
 use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
 use actix_web::{web, HttpResponse};
 use isucholar_core::models::announcement::{Announcement, AnnouncementID};
+use isucholar_core::models::audit_action::AuditAction;
 use isucholar_core::models::course::CourseID;
 use isucholar_core::services::announcement_service::{
     AnnouncementService, HaveAnnouncementService,
@@ -19,6 +21,7 @@ pub struct AddAnnouncementRequest {
 // POST /api/announcements 新規お知らせ追加
 pub async fn add_announcement<Service: HaveAnnouncementService>(
     service: web::Data<Service>,
+    request: actix_web::HttpRequest,
     req: web::Json<AddAnnouncementRequest>,
 ) -> ResponseResult<HttpResponse> {
     let announcement = Announcement {
@@ -28,7 +31,15 @@ pub async fn add_announcement<Service: HaveAnnouncementService>(
         message: req.message.clone(),
     };
 
-    let result = service.announcement_service().create(&announcement).await;
+    let mut audit_log = new_audit_log(&request, AuditAction::AnnouncementCreate)?;
+    audit_log.target_course_id = Some(announcement.course_id.clone());
+    audit_log.target_announcement_id = Some(announcement.id.clone());
+    audit_log.after_summary = Some(serde_json::json!({ "title": announcement.title }).to_string());
+
+    let result = service
+        .announcement_service()
+        .create(&announcement, &audit_log)
+        .await;
     match result {
         Ok(_) => Ok(HttpResponse::Created().finish()),
         Err(e) => match e {
@@ -41,10 +52,12 @@ pub async fn add_announcement<Service: HaveAnnouncementService>(
 
 #[cfg(test)]
 mod tests {
+    use actix_session::SessionExt;
     use actix_web::http::StatusCode;
     use actix_web::test::TestRequest;
     use actix_web::web::{Data, Json};
     use isucholar_core::models::announcement::AnnouncementID;
+    use isucholar_core::models::audit_action::AuditAction;
     use isucholar_core::models::course::CourseID;
 
     use crate::routes::announcement_routes::add_announcement::{
@@ -62,12 +75,17 @@ mod tests {
         service
             .announcement_service
             .expect_create()
-            .returning(|_| Err(CourseNotFound));
+            .returning(|_, _| Err(CourseNotFound));
 
-        let _req = TestRequest::with_uri("/announcements").to_http_request();
+        let req = TestRequest::with_uri("/announcements").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "admin");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
 
         add_announcement(
             Data::new(service),
+            req,
             Json(AddAnnouncementRequest {
                 id: AnnouncementID::new("".to_string()),
                 course_id: CourseID::new("".to_string()),
@@ -86,12 +104,17 @@ mod tests {
         service
             .announcement_service
             .expect_create()
-            .returning(|_| Err(AnnouncementDuplicate));
+            .returning(|_, _| Err(AnnouncementDuplicate));
 
-        let _req = TestRequest::with_uri("/announcements").to_http_request();
+        let req = TestRequest::with_uri("/announcements").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "admin");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
 
         add_announcement(
             Data::new(service),
+            req,
             Json(AddAnnouncementRequest {
                 id: AnnouncementID::new("".to_string()),
                 course_id: CourseID::new("".to_string()),
@@ -110,12 +133,17 @@ mod tests {
         service
             .announcement_service
             .expect_create()
-            .returning(|_| Err(TestError));
+            .returning(|_, _| Err(TestError));
 
-        let _req = TestRequest::with_uri("/announcements").to_http_request();
+        let req = TestRequest::with_uri("/announcements").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "admin");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
 
         add_announcement(
             Data::new(service),
+            req,
             Json(AddAnnouncementRequest {
                 id: AnnouncementID::new("".to_string()),
                 course_id: CourseID::new("".to_string()),
@@ -133,14 +161,25 @@ mod tests {
         service
             .announcement_service
             .expect_create()
-            .returning(|_| Ok(()));
-
-        let _req = TestRequest::with_uri("/announcements").to_http_request();
+            .withf(|_, audit_log| {
+                audit_log.actor_id.inner() == "admin"
+                    && audit_log.action == AuditAction::AnnouncementCreate
+                    && audit_log.target_announcement_id.as_ref().unwrap().inner() == "announcement"
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let req = TestRequest::with_uri("/announcements").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "admin");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
 
         let result = add_announcement(
             Data::new(service),
+            req,
             Json(AddAnnouncementRequest {
-                id: AnnouncementID::new("".to_string()),
+                id: AnnouncementID::new("announcement".to_string()),
                 course_id: CourseID::new("".to_string()),
                 title: "".to_string(),
                 message: "".to_string(),
@@ -11,10 +11,10 @@ use isucholar_core::services::unread_announcement_service::{
 // GET /api/announcements/{announcement_id} お知らせ詳細取得
 pub async fn get_announcement_detail<Service: HaveUnreadAnnouncementService>(
     service: web::Data<Service>,
-    session: actix_session::Session,
+    request: actix_web::HttpRequest,
     announcement_id: web::Path<(String,)>,
 ) -> ResponseResult<HttpResponse> {
-    let (user_id, _, _) = get_user_info(session)?;
+    let (user_id, _, _) = get_user_info(&request)?;
 
     let announcement_id: &str = &announcement_id.0;
     let announcement_id = AnnouncementID::new(announcement_id.to_string());
@@ -70,7 +70,7 @@ mod tests {
         let _ = session.insert("userName", "1");
         let _ = session.insert("isAdmin", false);
 
-        get_announcement_detail(Data::new(service), session, announcement_id)
+        get_announcement_detail(Data::new(service), req, announcement_id)
             .await
             .unwrap();
     }
@@ -93,7 +93,7 @@ mod tests {
         let _ = session.insert("userName", "1");
         let _ = session.insert("isAdmin", false);
 
-        get_announcement_detail(Data::new(service), session, announcement_id)
+        get_announcement_detail(Data::new(service), req, announcement_id)
             .await
             .unwrap();
     }
@@ -134,7 +134,7 @@ mod tests {
         let _ = session.insert("userName", "1");
         let _ = session.insert("isAdmin", false);
 
-        let result = get_announcement_detail(Data::new(service), session, announcement_id)
+        let result = get_announcement_detail(Data::new(service), req, announcement_id)
             .await
             .unwrap();
 
@@ -23,11 +23,10 @@ struct GetAnnouncementsResponse {
 // GET /api/announcements お知らせ一覧取得
 pub async fn get_announcement_list<S: HaveUnreadAnnouncementService>(
     service: web::Data<S>,
-    session: actix_session::Session,
     params: web::Query<GetAnnouncementsQuery>,
     request: actix_web::HttpRequest,
 ) -> ResponseResult<HttpResponse> {
-    let (user_id, _, _) = get_user_info(session)?;
+    let (user_id, _, _) = get_user_info(&request)?;
 
     let mut course_id: Option<CourseID> = None;
     if let Some(ref c_id) = params.course_id {
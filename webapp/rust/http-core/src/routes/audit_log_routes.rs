@@ -0,0 +1,15 @@
+use crate::middleware::IsAdmin;
+use crate::routes::audit_log_routes::get_audit_logs::get_audit_logs;
+use actix_web::{web, Scope};
+use isucholar_core::services::manager::ServiceManager;
+
+mod get_audit_logs;
+
+pub fn get_audit_log_routes<Service: ServiceManager + 'static>() -> Scope {
+    web::scope("/audit_logs").service(
+        web::resource("")
+            .guard(actix_web::guard::Get())
+            .wrap(IsAdmin)
+            .to(get_audit_logs::<Service>),
+    )
+}
@@ -0,0 +1,210 @@
+use crate::responses::error::ResponseError::InvalidPage;
+use crate::responses::error::ResponseResult;
+use actix_web::{web, HttpResponse};
+use chrono::NaiveDateTime;
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::audit_log_repository::AuditLogQuery;
+use isucholar_core::services::audit_log_service::{AuditLogService, HaveAuditLogService};
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct GetAuditLogsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actor_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    course_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    until: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct AuditLogResponse {
+    id: String,
+    actor_id: String,
+    action: AuditAction,
+    target_user_id: Option<String>,
+    target_course_id: Option<String>,
+    target_class_id: Option<String>,
+    target_announcement_id: Option<String>,
+    request_id: Option<String>,
+    before: Option<serde_json::Value>,
+    after: Option<serde_json::Value>,
+    created_at: NaiveDateTime,
+}
+
+fn parse_summary(summary: Option<String>) -> Option<serde_json::Value> {
+    summary
+        .map(|summary| serde_json::from_str(&summary).unwrap_or(serde_json::Value::String(summary)))
+}
+
+// GET /api/audit_logs 管理者による操作の監査ログ一覧
+pub async fn get_audit_logs<Service: HaveAuditLogService>(
+    service: web::Data<Service>,
+    params: web::Query<GetAuditLogsQuery>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let page = if let Some(ref page_str) = params.page {
+        match page_str.parse() {
+            Ok(page) if page > 0 => page,
+            _ => return Err(InvalidPage),
+        }
+    } else {
+        1
+    };
+    let limit = 20;
+    let offset = limit * (page - 1);
+
+    let query = AuditLogQuery {
+        actor_id: params.actor_id.clone().map(UserID::new),
+        course_id: params.course_id.clone().map(CourseID::new),
+        since: params.since,
+        until: params.until,
+    };
+    let mut audit_logs = service
+        .audit_log_service()
+        .find_all(&query, limit, offset)
+        .await?;
+
+    let uri = request.uri();
+    let mut params = params.into_inner();
+    let mut links = Vec::new();
+    if page > 1 {
+        params.page = Some(format!("{}", page - 1));
+        links.push(format!(
+            "<{}?{}>; rel=\"prev\"",
+            uri.path(),
+            serde_urlencoded::to_string(&params)?
+        ));
+    }
+    if audit_logs.len() as i64 > limit {
+        params.page = Some(format!("{}", page + 1));
+        links.push(format!(
+            "<{}?{}>; rel=\"next\"",
+            uri.path(),
+            serde_urlencoded::to_string(&params)?
+        ));
+    }
+
+    if audit_logs.len() as i64 == limit + 1 {
+        audit_logs.truncate(audit_logs.len() - 1);
+    }
+
+    let res = audit_logs
+        .into_iter()
+        .map(|audit_log| AuditLogResponse {
+            id: audit_log.id.inner().to_string(),
+            actor_id: audit_log.actor_id.inner().to_string(),
+            action: audit_log.action,
+            target_user_id: audit_log.target_user_id.map(|id| id.inner().to_string()),
+            target_course_id: audit_log.target_course_id.map(|id| id.inner().to_string()),
+            target_class_id: audit_log.target_class_id.map(|id| id.inner().to_string()),
+            target_announcement_id: audit_log
+                .target_announcement_id
+                .map(|id| id.inner().to_string()),
+            request_id: audit_log.request_id,
+            before: parse_summary(audit_log.before_summary),
+            after: parse_summary(audit_log.after_summary),
+            created_at: audit_log.created_at,
+        })
+        .collect::<Vec<_>>();
+
+    let mut builder = HttpResponse::Ok();
+    if !links.is_empty() {
+        builder.insert_header((actix_web::http::header::LINK, links.join(",")));
+    }
+    Ok(builder.json(res))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::audit_log_routes::get_audit_logs::{get_audit_logs, GetAuditLogsQuery};
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::models::audit_log::{AuditLog, AuditLogID};
+    use isucholar_core::models::user::UserID;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+    use std::str::from_utf8;
+
+    #[actix_web::test]
+    #[should_panic(expected = "InvalidPage")]
+    async fn test_invalid_page_case() {
+        let service = MockServiceManager::new();
+
+        let req = TestRequest::with_uri("/api/audit_logs?page=0").to_http_request();
+        let params = web::Query(GetAuditLogsQuery {
+            actor_id: None,
+            course_id: None,
+            since: None,
+            until: None,
+            page: Some("0".to_string()),
+        });
+
+        get_audit_logs(web::Data::new(service), params, req)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_next_page_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .audit_log_service
+            .expect_find_all()
+            .withf(|query, limit, offset| {
+                query.course_id.as_ref().unwrap().inner() == "course"
+                    && query.actor_id.is_none()
+                    && *limit == 20
+                    && *offset == 0
+            })
+            .returning(|_, _, _| {
+                Ok((0..21)
+                    .map(|i| AuditLog {
+                        id: AuditLogID::new(format!("{:026}", i)),
+                        actor_id: UserID::new("admin".to_string()),
+                        action: AuditAction::CourseStatusUpdate,
+                        target_user_id: None,
+                        target_course_id: None,
+                        target_class_id: None,
+                        target_announcement_id: None,
+                        request_id: None,
+                        before_summary: None,
+                        after_summary: Some(r#"{"status":"closed"}"#.to_string()),
+                        created_at: chrono::DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+                    })
+                    .collect())
+            });
+
+        let req = TestRequest::with_uri("/api/audit_logs?course_id=course").to_http_request();
+        let params = web::Query(GetAuditLogsQuery {
+            actor_id: None,
+            course_id: Some("course".to_string()),
+            since: None,
+            until: None,
+            page: None,
+        });
+
+        let res = get_audit_logs(web::Data::new(service), params, req)
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(actix_web::http::header::LINK).unwrap(),
+            "</api/audit_logs?course_id=course&page=2>; rel=\"next\""
+        );
+
+        let body = to_bytes(res.into_body()).await.unwrap();
+        let audit_logs: Vec<serde_json::Value> =
+            serde_json::from_str(from_utf8(&body).unwrap()).unwrap();
+        assert_eq!(audit_logs.len(), 20);
+        assert_eq!(audit_logs[0]["after"]["status"], "closed");
+    }
+}
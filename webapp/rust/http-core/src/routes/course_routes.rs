@@ -1,25 +1,51 @@
 use crate::middleware::IsAdmin;
 use crate::routes::course_routes::add_class::add_class;
 use crate::routes::course_routes::add_course::add_course;
+use crate::routes::course_routes::approve_registration_request::approve_registration_request;
+use crate::routes::course_routes::deny_registration_request::deny_registration_request;
 use crate::routes::course_routes::download_submitted_assignments::download_submitted_assignments;
+use crate::routes::course_routes::export_course_students::export_course_students;
 use crate::routes::course_routes::get_classes::get_classes;
 use crate::routes::course_routes::get_course_detail::get_course_detail;
+use crate::routes::course_routes::get_course_prerequisites::get_course_prerequisites;
+use crate::routes::course_routes::get_course_students::get_course_students;
+use crate::routes::course_routes::get_registration_requests::get_registration_requests;
+use crate::routes::course_routes::join_waitlist::join_waitlist;
+use crate::routes::course_routes::leave_waitlist::leave_waitlist;
 use crate::routes::course_routes::register_scores::register_scores;
 use crate::routes::course_routes::search_courses::search_courses;
+use crate::routes::course_routes::set_course_approval::set_course_approval;
+use crate::routes::course_routes::set_course_capacity::set_course_capacity;
+use crate::routes::course_routes::set_course_lottery::set_course_lottery;
+use crate::routes::course_routes::set_course_prerequisites::set_course_prerequisites;
 use crate::routes::course_routes::set_course_status::set_course_status;
 use crate::routes::course_routes::submit_assignment::submit_assignment;
+use crate::routes::course_routes::update_course::update_course;
 use actix_web::{web, Scope};
 use isucholar_core::services::manager::ServiceManager;
 
 mod add_class;
 mod add_course;
+mod approve_registration_request;
+mod deny_registration_request;
 mod download_submitted_assignments;
+mod export_course_students;
 mod get_classes;
 mod get_course_detail;
+mod get_course_prerequisites;
+mod get_course_students;
+mod get_registration_requests;
+mod join_waitlist;
+mod leave_waitlist;
 mod register_scores;
 mod search_courses;
+mod set_course_approval;
+mod set_course_capacity;
+mod set_course_lottery;
+mod set_course_prerequisites;
 mod set_course_status;
 mod submit_assignment;
+mod update_course;
 
 pub fn get_course_routes<Service: ServiceManager + 'static>() -> Scope {
     web::scope("/courses")
@@ -31,12 +57,84 @@ pub fn get_course_routes<Service: ServiceManager + 'static>() -> Scope {
                 .to(add_course::<Service>),
         )
         .route("/{course_id}", web::get().to(get_course_detail::<Service>))
+        .service(
+            web::resource("/{course_id}")
+                .guard(actix_web::guard::Put())
+                .wrap(IsAdmin)
+                .to(update_course::<Service>),
+        )
         .service(
             web::resource("/{course_id}/status")
                 .guard(actix_web::guard::Put())
                 .wrap(IsAdmin)
                 .to(set_course_status::<Service>),
         )
+        .service(
+            web::resource("/{course_id}/capacity")
+                .guard(actix_web::guard::Put())
+                .wrap(IsAdmin)
+                .to(set_course_capacity::<Service>),
+        )
+        .route(
+            "/{course_id}/prerequisites",
+            web::get().to(get_course_prerequisites::<Service>),
+        )
+        .service(
+            web::resource("/{course_id}/prerequisites")
+                .guard(actix_web::guard::Put())
+                .wrap(IsAdmin)
+                .to(set_course_prerequisites::<Service>),
+        )
+        .service(
+            web::resource("/{course_id}/lottery")
+                .guard(actix_web::guard::Put())
+                .wrap(IsAdmin)
+                .to(set_course_lottery::<Service>),
+        )
+        .service(
+            web::resource("/{course_id}/approval")
+                .guard(actix_web::guard::Put())
+                .wrap(IsAdmin)
+                .to(set_course_approval::<Service>),
+        )
+        .service(
+            web::resource("/{course_id}/registration_requests")
+                .guard(actix_web::guard::Get())
+                .wrap(IsAdmin)
+                .to(get_registration_requests::<Service>),
+        )
+        .service(
+            web::resource("/{course_id}/registration_requests/{user_id}/approve")
+                .guard(actix_web::guard::Post())
+                .wrap(IsAdmin)
+                .to(approve_registration_request::<Service>),
+        )
+        .service(
+            web::resource("/{course_id}/registration_requests/{user_id}/deny")
+                .guard(actix_web::guard::Post())
+                .wrap(IsAdmin)
+                .to(deny_registration_request::<Service>),
+        )
+        .service(
+            web::resource("/{course_id}/students")
+                .guard(actix_web::guard::Get())
+                .wrap(IsAdmin)
+                .to(get_course_students::<Service>),
+        )
+        .service(
+            web::resource("/{course_id}/students/export")
+                .guard(actix_web::guard::Get())
+                .wrap(IsAdmin)
+                .to(export_course_students::<Service>),
+        )
+        .route(
+            "/{course_id}/waitlist",
+            web::post().to(join_waitlist::<Service>),
+        )
+        .route(
+            "/{course_id}/waitlist",
+            web::delete().to(leave_waitlist::<Service>),
+        )
         .route(
             "/{course_id}/classes",
             web::get().to(get_classes::<Service>),
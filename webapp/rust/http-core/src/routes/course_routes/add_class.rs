@@ -2,7 +2,9 @@ use crate::responses::error::ResponseError::{
     CourseConflict, CourseIsNotInProgress, CourseNotFound,
 };
 use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
 use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
 use isucholar_core::models::class::{ClassID, CreateClass};
 use isucholar_core::models::course::CourseID;
 use isucholar_core::services::class_service::{ClassService, HaveClassService};
@@ -24,6 +26,7 @@ struct AddClassResponse {
 pub async fn add_class<Service: HaveClassService>(
     service: web::Data<Service>,
     course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
     req: web::Json<AddClassRequest>,
 ) -> ResponseResult<HttpResponse> {
     let course_id = CourseID::new(course_id.0.to_string());
@@ -35,7 +38,12 @@ pub async fn add_class<Service: HaveClassService>(
         description: req.description.clone(),
     };
 
-    let result = service.class_service().create(&form).await;
+    let mut audit_log = new_audit_log(&request, AuditAction::ClassCreate)?;
+    audit_log.target_course_id = Some(course_id);
+    audit_log.after_summary =
+        Some(serde_json::json!({ "part": form.part, "title": form.title }).to_string());
+
+    let result = service.class_service().create(&form, &audit_log).await;
 
     match result {
         Ok(class_id) => Ok(HttpResponse::Created().json(AddClassResponse { class_id })),
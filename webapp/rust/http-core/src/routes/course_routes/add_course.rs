@@ -1,6 +1,7 @@
 use crate::responses::error::ResponseResult;
-use crate::routes::util::get_user_info;
+use crate::routes::util::{get_user_info, new_audit_log};
 use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
 use isucholar_core::models::course::{CourseCode, CourseID, CreateCourse};
 use isucholar_core::models::course_type::CourseType;
 use isucholar_core::models::day_of_week::DayOfWeek;
@@ -19,6 +20,7 @@ pub struct AddCourseRequest {
     period: u8,
     day_of_week: DayOfWeek,
     keywords: String,
+    capacity: Option<u32>,
 }
 
 impl AddCourseRequest {
@@ -34,6 +36,7 @@ impl AddCourseRequest {
             period: self.period,
             day_of_week: self.day_of_week.clone(),
             keywords: self.keywords.clone(),
+            capacity: self.capacity,
         }
     }
 }
@@ -46,15 +49,28 @@ pub struct AddCourseResponse {
 // POST /api/courses 新規科目登録
 pub async fn add_course<Service: HaveCourseService>(
     service: web::Data<Service>,
-    session: actix_session::Session,
+    request: actix_web::HttpRequest,
     req: web::Json<AddCourseRequest>,
 ) -> ResponseResult<HttpResponse> {
-    let (user_id, _, _) = get_user_info(session)?;
+    let (user_id, _, _) = get_user_info(&request)?;
 
     let course_id = util::new_ulid().await;
     let form = req.convert_create_course(course_id.clone(), user_id.clone());
 
-    let course_id = service.course_service().create(&form).await?;
+    let mut audit_log = new_audit_log(&request, AuditAction::CourseCreate)?;
+    audit_log.after_summary = Some(
+        serde_json::json!({
+            "code": form.code,
+            "type": form.type_,
+            "name": form.name,
+            "credit": form.credit,
+            "period": form.period,
+            "day_of_week": form.day_of_week,
+            "capacity": form.capacity,
+        })
+        .to_string(),
+    );
+    let course_id = service.course_service().create(&form, &audit_log).await?;
 
     Ok(HttpResponse::Created().json(AddCourseResponse { id: course_id }))
 }
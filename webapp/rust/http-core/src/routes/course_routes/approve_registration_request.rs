@@ -0,0 +1,143 @@
+use crate::responses::error::ResponseError::{
+    AlreadyRegistered, CourseNotFound, CourseNotRegistrable, NotCourseTeacher,
+    RegistrationRequestNotFound,
+};
+use crate::responses::error::ResponseResult;
+use crate::responses::register_courses_error_response::RegisterCoursesErrorResponse;
+use crate::routes::util::{get_user_info, new_audit_log};
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// POST /api/courses/{course_id}/registration_requests/{user_id}/approve 履修申請を承認して履修登録
+pub async fn approve_registration_request<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    path: web::Path<(String, String)>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (teacher_id, _, _) = get_user_info(&request)?;
+    let course_id = CourseID::new(path.0.to_string());
+    let user_id = UserID::new(path.1.to_string());
+
+    let mut audit_log = new_audit_log(&request, AuditAction::RegistrationRequestApprove)?;
+    audit_log.target_course_id = Some(course_id.clone());
+    audit_log.target_user_id = Some(user_id.clone());
+
+    let result = service
+        .registration_course_service()
+        .approve_registration_request(&course_id, &teacher_id, &user_id, &audit_log)
+        .await;
+    match result {
+        Ok(_) => {}
+        Err(Error::CourseNotFound) => return Err(CourseNotFound),
+        Err(Error::NotCourseTeacher) => return Err(NotCourseTeacher),
+        Err(Error::RegistrationRequestNotFound) => return Err(RegistrationRequestNotFound),
+        Err(Error::CourseNotRegistrable) => return Err(CourseNotRegistrable),
+        Err(Error::AlreadyRegistered) => return Err(AlreadyRegistered),
+        Err(Error::RegistrationCourseValidationError(errors)) => {
+            return Ok(HttpResponse::BadRequest().json(RegisterCoursesErrorResponse::from(*errors)));
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::approve_registration_request::approve_registration_request;
+    use crate::test_util::admin_request;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::web;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::models::course::CourseID;
+    use isucholar_core::services::error::{
+        Error, RegistrationCourseValidationError, ScheduleConflict,
+    };
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "NotCourseTeacher")]
+    async fn test_not_course_teacher_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_approve_registration_request()
+            .returning(|_, _, _, _| Err(Error::NotCourseTeacher));
+
+        approve_registration_request(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(), "student".to_string())),
+            admin_request("/api/courses/course/registration_requests/student/approve"),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_schedule_conflict_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_approve_registration_request()
+            .returning(|course_id, _, _, _| {
+                Err(Error::RegistrationCourseValidationError(Box::new(
+                    RegistrationCourseValidationError {
+                        schedule_conflict: vec![course_id.clone()],
+                        schedule_conflict_details: vec![ScheduleConflict {
+                            course_id: course_id.clone(),
+                            conflicts_with: vec![CourseID::new("other".to_string())],
+                        }],
+                        ..Default::default()
+                    },
+                )))
+            });
+
+        let res = approve_registration_request(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(), "student".to_string())),
+            admin_request("/api/courses/course/registration_requests/student/approve"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["schedule_conflict"], serde_json::json!(["course"]));
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_approve_registration_request()
+            .withf(|course_id, teacher_id, user_id, log| {
+                course_id.inner() == "course"
+                    && teacher_id.inner() == "admin"
+                    && user_id.inner() == "student"
+                    && log.action == AuditAction::RegistrationRequestApprove
+                    && log.target_user_id.as_ref().map(|id| id.inner().as_str()) == Some("student")
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let res = approve_registration_request(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(), "student".to_string())),
+            admin_request("/api/courses/course/registration_requests/student/approve"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
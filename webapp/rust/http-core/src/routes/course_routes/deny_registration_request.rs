@@ -0,0 +1,78 @@
+use crate::responses::error::ResponseError::{
+    CourseNotFound, NotCourseTeacher, RegistrationRequestNotFound,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::{get_user_info, new_audit_log};
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// POST /api/courses/{course_id}/registration_requests/{user_id}/deny 履修申請を却下
+pub async fn deny_registration_request<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    path: web::Path<(String, String)>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (teacher_id, _, _) = get_user_info(&request)?;
+    let course_id = CourseID::new(path.0.to_string());
+    let user_id = UserID::new(path.1.to_string());
+
+    let mut audit_log = new_audit_log(&request, AuditAction::RegistrationRequestDeny)?;
+    audit_log.target_course_id = Some(course_id.clone());
+    audit_log.target_user_id = Some(user_id.clone());
+
+    let result = service
+        .registration_course_service()
+        .deny_registration_request(&course_id, &teacher_id, &user_id, &audit_log)
+        .await;
+    match result {
+        Ok(_) => {}
+        Err(Error::CourseNotFound) => return Err(CourseNotFound),
+        Err(Error::NotCourseTeacher) => return Err(NotCourseTeacher),
+        Err(Error::RegistrationRequestNotFound) => return Err(RegistrationRequestNotFound),
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::deny_registration_request::deny_registration_request;
+    use actix_session::SessionExt;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::services::error::Error::RegistrationRequestNotFound;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "RegistrationRequestNotFound")]
+    async fn test_not_found_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_deny_registration_request()
+            .returning(|_, _, _, _| Err(RegistrationRequestNotFound));
+
+        let req = TestRequest::with_uri("/api/courses/course/registration_requests/student/deny")
+            .to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "admin");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
+
+        deny_registration_request(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(), "student".to_string())),
+            req,
+        )
+        .await
+        .unwrap();
+    }
+}
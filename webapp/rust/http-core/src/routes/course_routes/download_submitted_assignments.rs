@@ -1,7 +1,9 @@
 use crate::responses::error::ResponseError::ClassNotFound;
 use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
 use actix_web::web;
 use isucholar_core::models::assignment_path::AssignmentPath;
+use isucholar_core::models::audit_action::AuditAction;
 use isucholar_core::services::error::Error;
 use isucholar_core::services::submission_service::{HaveSubmissionService, SubmissionService};
 
@@ -9,12 +11,17 @@ use isucholar_core::services::submission_service::{HaveSubmissionService, Submis
 pub async fn download_submitted_assignments<Service: HaveSubmissionService>(
     service: web::Data<Service>,
     path: web::Path<AssignmentPath>,
+    request: actix_web::HttpRequest,
 ) -> ResponseResult<actix_files::NamedFile> {
     let class_id = path.class_id.clone();
 
+    let mut audit_log = new_audit_log(&request, AuditAction::AssignmentExport)?;
+    audit_log.target_course_id = Some(path.course_id.clone());
+    audit_log.target_class_id = Some(class_id.clone());
+
     let result = service
         .submission_service()
-        .download_submissions_zip(&class_id)
+        .download_submissions_zip(&class_id, &audit_log)
         .await;
     match result {
         Ok(zip_file_path) => Ok(actix_files::NamedFile::open(zip_file_path)?),
@@ -0,0 +1,161 @@
+use crate::responses::error::ResponseError::{CourseNotFound, NotCourseTeacher};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::{get_user_info, new_audit_log};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::course_student::CourseStudent;
+use isucholar_core::services::audit_log_service::{AuditLogService, HaveAuditLogService};
+use isucholar_core::services::course_service::{CourseService, HaveCourseService};
+use isucholar_core::services::error::Error;
+
+// カンマ、ダブルクォート、改行を含むフィールドはダブルクォートで囲み、中のダブルクォートは二重にする。
+// 表計算ソフトで数式として実行されないよう、数式の開始とみなされる文字で始まる値には'を前置する
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn students_csv(students: &[CourseStudent]) -> String {
+    let mut csv = String::from("code,name,registered_at,submission_count,total_score\r\n");
+    for student in students {
+        let row = [
+            csv_field(&student.code.inner().to_string()),
+            csv_field(&student.name),
+            student.registered_at.to_string(),
+            student.submission_count.to_string(),
+            student.total_score.to_string(),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+// GET /api/courses/{course_id}/students/export 科目の履修者一覧をCSV形式でダウンロード
+pub async fn export_course_students<Service: HaveCourseService + HaveAuditLogService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    let result = service
+        .course_service()
+        .find_students(&course_id, &user_id, None, 0)
+        .await;
+    let students = match result {
+        Ok(students) => students,
+        Err(Error::CourseNotFound) => return Err(CourseNotFound),
+        Err(Error::NotCourseTeacher) => return Err(NotCourseTeacher),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut audit_log = new_audit_log(&request, AuditAction::CourseStudentsExport)?;
+    audit_log.target_course_id = Some(course_id);
+    service.audit_log_service().record(&audit_log).await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename("students.csv".to_string())],
+        })
+        .body(students_csv(&students)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::export_course_students::{csv_field, export_course_students};
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use chrono::NaiveDate;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::models::course_student::CourseStudent;
+    use isucholar_core::models::user::{UserCode, UserID};
+    use isucholar_core::services::manager::tests::MockServiceManager;
+    use std::str::from_utf8;
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_find_students()
+            .withf(|_, teacher_id, limit, _| teacher_id.inner() == "teacher" && limit.is_none())
+            .returning(|_, _, _, _| {
+                Ok(vec![CourseStudent {
+                    user_id: UserID::new("student".to_string()),
+                    code: UserCode::new("S00001".to_string().into()),
+                    name: "Doe, \"Jo\"".to_string(),
+                    registered_at: NaiveDate::from_ymd_opt(2026, 4, 1)
+                        .unwrap()
+                        .and_hms_opt(9, 0, 0)
+                        .unwrap(),
+                    submission_count: 3,
+                    total_score: 210,
+                }])
+            });
+        service
+            .audit_log_service
+            .expect_record()
+            .withf(|log| log.action == AuditAction::CourseStudentsExport)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let req = TestRequest::with_uri("/api/courses/course/students/export").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "teacher");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
+
+        let res = export_course_students(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            req,
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers()
+                .get(actix_web::http::header::CONTENT_TYPE)
+                .unwrap(),
+            "text/csv; charset=utf-8"
+        );
+
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(
+            from_utf8(&body).unwrap(),
+            "code,name,registered_at,submission_count,total_score\r\nS00001,\"Doe, \"\"Jo\"\"\",2026-04-01 09:00:00,3,210\r\n"
+        );
+    }
+
+    #[test]
+    fn test_formula_case() {
+        assert_eq!(
+            csv_field("=HYPERLINK(\"https://example.com\")"),
+            "\"'=HYPERLINK(\"\"https://example.com\"\")\""
+        );
+        assert_eq!(csv_field("+81"), "'+81");
+        assert_eq!(csv_field("-1"), "'-1");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("\tName"), "'\tName");
+        assert_eq!(csv_field("\rName"), "\"'\rName\"");
+        assert_eq!(csv_field("Jo=Doe"), "Jo=Doe");
+    }
+}
@@ -18,10 +18,10 @@ struct GetClassResponse {
 // GET /api/courses/{course_id}/classes 科目に紐づく講義一覧の取得
 pub async fn get_classes<Service: HaveClassService>(
     service: web::Data<Service>,
-    session: actix_session::Session,
+    request: actix_web::HttpRequest,
     course_id: web::Path<(String,)>,
 ) -> ResponseResult<HttpResponse> {
-    let (user_id, _, _) = get_user_info(session)?;
+    let (user_id, _, _) = get_user_info(&request)?;
 
     let course_id = &course_id.0;
     let course_id = CourseID::new(course_id.to_string());
@@ -0,0 +1,27 @@
+use crate::responses::error::ResponseError::CourseNotFound;
+use crate::responses::error::ResponseResult;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::course_service::{CourseService, HaveCourseService};
+
+// GET /api/courses/{course_id}/prerequisites 科目の事前科目一覧取得
+pub async fn get_course_prerequisites<Service: HaveCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+) -> ResponseResult<HttpResponse> {
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    let course_service = service.course_service();
+    if course_service
+        .find_with_teacher_by_id(&course_id)
+        .await?
+        .is_none()
+    {
+        return Err(CourseNotFound);
+    }
+    let prerequisites = course_service
+        .find_prerequisites_by_course_id(&course_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(prerequisites))
+}
@@ -0,0 +1,174 @@
+use crate::responses::error::ResponseError::{CourseNotFound, InvalidPage, NotCourseTeacher};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::course_service::{CourseService, HaveCourseService};
+use isucholar_core::services::error::Error;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct GetCourseStudentsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<String>,
+}
+
+// GET /api/courses/{course_id}/students 科目の履修者一覧を学籍番号順に取得
+pub async fn get_course_students<Service: HaveCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    params: web::Query<GetCourseStudentsQuery>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    let page = if let Some(ref page_str) = params.page {
+        match page_str.parse() {
+            Ok(page) if page > 0 => page,
+            _ => return Err(InvalidPage),
+        }
+    } else {
+        1
+    };
+    let limit = 20;
+    let offset = limit * (page - 1);
+
+    let result = service
+        .course_service()
+        .find_students(&course_id, &user_id, Some(limit), offset)
+        .await;
+    let mut students = match result {
+        Ok(students) => students,
+        Err(Error::CourseNotFound) => return Err(CourseNotFound),
+        Err(Error::NotCourseTeacher) => return Err(NotCourseTeacher),
+        Err(e) => return Err(e.into()),
+    };
+
+    let uri = request.uri();
+    let mut params = params.into_inner();
+    let mut links = Vec::new();
+    if page > 1 {
+        params.page = Some(format!("{}", page - 1));
+        links.push(format!(
+            "<{}?{}>; rel=\"prev\"",
+            uri.path(),
+            serde_urlencoded::to_string(&params)?
+        ));
+    }
+    if students.len() as i64 > limit {
+        params.page = Some(format!("{}", page + 1));
+        links.push(format!(
+            "<{}?{}>; rel=\"next\"",
+            uri.path(),
+            serde_urlencoded::to_string(&params)?
+        ));
+    }
+
+    if students.len() as i64 == limit + 1 {
+        students.truncate(students.len() - 1);
+    }
+
+    let mut builder = HttpResponse::Ok();
+    if !links.is_empty() {
+        builder.insert_header((actix_web::http::header::LINK, links.join(",")));
+    }
+    Ok(builder.json(students))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::get_course_students::{
+        get_course_students, GetCourseStudentsQuery,
+    };
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use chrono::NaiveDate;
+    use isucholar_core::models::course_student::CourseStudent;
+    use isucholar_core::models::user::{UserCode, UserID};
+    use isucholar_core::services::error::Error::NotCourseTeacher;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    fn teacher_request(uri: &str) -> actix_web::HttpRequest {
+        let req = TestRequest::with_uri(uri).to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "teacher");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
+        req
+    }
+
+    fn student(code: &str) -> CourseStudent {
+        CourseStudent {
+            user_id: UserID::new(code.to_string()),
+            code: UserCode::new(code.to_string().into()),
+            name: "student".to_string(),
+            registered_at: NaiveDate::from_ymd_opt(2026, 4, 1)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap(),
+            submission_count: 2,
+            total_score: 150,
+        }
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "NotCourseTeacher")]
+    async fn test_not_course_teacher_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_find_students()
+            .returning(|_, _, _, _| Err(NotCourseTeacher));
+
+        get_course_students(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            web::Query(GetCourseStudentsQuery { page: None }),
+            teacher_request("/api/courses/course/students"),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_next_page_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_find_students()
+            .withf(|course_id, teacher_id, limit, offset| {
+                course_id.inner() == "course"
+                    && teacher_id.inner() == "teacher"
+                    && *limit == Some(20)
+                    && *offset == 0
+            })
+            .returning(|_, _, _, _| Ok((0..21).map(|i| student(&format!("S{:05}", i))).collect()));
+
+        let res = get_course_students(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            web::Query(GetCourseStudentsQuery { page: None }),
+            teacher_request("/api/courses/course/students"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(actix_web::http::header::LINK).unwrap(),
+            "</api/courses/course/students?page=2>; rel=\"next\""
+        );
+
+        let body = to_bytes(res.into_body()).await.unwrap();
+        let body: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body.len(), 20);
+        assert_eq!(
+            serde_json::to_string(&body[0]).unwrap(),
+            r#"{"code":"S00000","name":"student","registered_at":"2026-04-01T09:00:00","submission_count":2,"total_score":150}"#
+        );
+    }
+}
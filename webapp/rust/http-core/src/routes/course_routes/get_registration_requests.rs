@@ -0,0 +1,30 @@
+use crate::responses::error::ResponseError::{CourseNotFound, NotCourseTeacher};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// GET /api/courses/{course_id}/registration_requests 承認待ちの履修申請一覧を申請順に取得
+pub async fn get_registration_requests<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    let result = service
+        .registration_course_service()
+        .find_registration_requests(&course_id, &user_id)
+        .await;
+    match result {
+        Ok(requests) => Ok(HttpResponse::Ok().json(requests)),
+        Err(Error::CourseNotFound) => Err(CourseNotFound),
+        Err(Error::NotCourseTeacher) => Err(NotCourseTeacher),
+        Err(e) => Err(e.into()),
+    }
+}
@@ -0,0 +1,103 @@
+use crate::responses::error::ResponseError::{
+    AlreadyRegistered, CourseNotFound, CourseNotFull, CourseNotRegistrable,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+#[derive(Debug, serde::Serialize)]
+pub struct JoinWaitlistResponse {
+    position: i64,
+}
+
+// POST /api/courses/{course_id}/waitlist 満員の科目のキャンセル待ちに登録
+pub async fn join_waitlist<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    let result = service
+        .registration_course_service()
+        .join_waitlist(&user_id, &course_id)
+        .await;
+    match result {
+        Ok(position) => Ok(HttpResponse::Ok().json(JoinWaitlistResponse { position })),
+        Err(Error::CourseNotFound) => Err(CourseNotFound),
+        Err(Error::CourseNotRegistrable) => Err(CourseNotRegistrable),
+        Err(Error::AlreadyRegistered) => Err(AlreadyRegistered),
+        Err(Error::CourseNotFull) => Err(CourseNotFull),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::join_waitlist::join_waitlist;
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::{web, ResponseError};
+    use isucholar_core::services::error::Error::CourseNotFull;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    fn student_request() -> actix_web::HttpRequest {
+        let req = TestRequest::with_uri("/api/courses/course/waitlist").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "student");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+        req
+    }
+
+    #[actix_web::test]
+    async fn test_not_full_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_join_waitlist()
+            .returning(|_, _| Err(CourseNotFull));
+
+        let err = join_waitlist(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            student_request(),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.error_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_join_waitlist()
+            .withf(|user_id, course_id| {
+                user_id.inner() == "student" && course_id.inner() == "course"
+            })
+            .returning(|_, _| Ok(2));
+
+        let res = join_waitlist(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            student_request(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(body, r#"{"position":2}"#);
+    }
+}
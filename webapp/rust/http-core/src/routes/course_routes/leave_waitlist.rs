@@ -0,0 +1,24 @@
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// DELETE /api/courses/{course_id}/waitlist キャンセル待ちを取り消す
+pub async fn leave_waitlist<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    service
+        .registration_course_service()
+        .leave_waitlist(&user_id, &course_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
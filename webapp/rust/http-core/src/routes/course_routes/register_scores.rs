@@ -1,7 +1,9 @@
 use crate::responses::error::ResponseError::{ClassIsNotSubmissionClosed, ClassNotFound};
 use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
 use actix_web::{web, HttpResponse};
 use isucholar_core::models::assignment_path::AssignmentPath;
+use isucholar_core::models::audit_action::AuditAction;
 use isucholar_core::models::score::Score;
 use isucholar_core::services::error::Error;
 use isucholar_core::services::submission_service::{HaveSubmissionService, SubmissionService};
@@ -10,13 +12,28 @@ use isucholar_core::services::submission_service::{HaveSubmissionService, Submis
 pub async fn register_scores<Service: HaveSubmissionService>(
     service: web::Data<Service>,
     path: web::Path<AssignmentPath>,
+    request: actix_web::HttpRequest,
     req: web::Json<Vec<Score>>,
 ) -> ResponseResult<HttpResponse> {
     let class_id = path.class_id.clone();
 
+    let scores = req.into_inner();
+    let mut audit_log = new_audit_log(&request, AuditAction::ScoreRegister)?;
+    audit_log.target_course_id = Some(path.course_id.clone());
+    audit_log.target_class_id = Some(class_id.clone());
+    audit_log.after_summary = Some(
+        serde_json::json!({
+            "scores": scores
+                .iter()
+                .map(|score| serde_json::json!({ "user_code": score.user_code, "score": score.score }))
+                .collect::<Vec<_>>()
+        })
+        .to_string(),
+    );
+
     let result = service
         .submission_service()
-        .update_user_scores_by_class_id(&class_id, &req.into_inner())
+        .update_user_scores_by_class_id(&class_id, &scores, &audit_log)
         .await;
     match result {
         Ok(_) => Ok(HttpResponse::NoContent().finish()),
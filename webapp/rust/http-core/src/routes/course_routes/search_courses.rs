@@ -1,11 +1,17 @@
 use crate::responses::error::ResponseError::InvalidPage;
 use crate::responses::error::ResponseResult;
+use crate::responses::search_course_response::SearchCourseResponse;
+use crate::routes::util::get_user_info;
 use actix_web::{web, HttpResponse};
+use isucholar_core::models::course_status::CourseStatus;
 use isucholar_core::repos::course_repository::SearchCoursesQuery;
 use isucholar_core::services::course_service::{CourseService, HaveCourseService};
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
 
 // GET /api/courses 科目検索
-pub async fn search_courses<Service: HaveCourseService>(
+pub async fn search_courses<Service: HaveCourseService + HaveRegistrationCourseService>(
     service: web::Data<Service>,
     params: web::Query<SearchCoursesQuery>,
     request: actix_web::HttpRequest,
@@ -50,9 +56,99 @@ pub async fn search_courses<Service: HaveCourseService>(
         res.truncate(res.len() - 1);
     }
 
+    let (user_id, _, _) = get_user_info(&request)?;
+    let is_open = service
+        .registration_course_service()
+        .is_registration_open(&user_id)
+        .await?;
+    let res = res
+        .into_iter()
+        .map(|course| SearchCourseResponse {
+            registration_open: is_open && course.status == CourseStatus::Registration,
+            course,
+        })
+        .collect::<Vec<_>>();
+
     let mut builder = HttpResponse::Ok();
     if !links.is_empty() {
         builder.insert_header((actix_web::http::header::LINK, links.join(",")));
     }
     Ok(builder.json(res))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::search_courses::search_courses;
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::course::{CourseCode, CourseID, CourseWithTeacher};
+    use isucholar_core::models::course_status::CourseStatus;
+    use isucholar_core::models::day_of_week::DayOfWeek;
+    use isucholar_core::models::user::UserID;
+    use isucholar_core::repos::course_repository::SearchCoursesQuery;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    fn course(id: &str, status: CourseStatus) -> CourseWithTeacher {
+        CourseWithTeacher {
+            id: CourseID::new(id.to_string()),
+            code: CourseCode::new(id.to_string()),
+            type_: "liberal-arts".to_string(),
+            name: "course".to_string(),
+            description: "".to_string(),
+            credit: 1,
+            period: 1,
+            day_of_week: DayOfWeek::Monday,
+            teacher_id: UserID::new("teacher".to_string()),
+            keywords: "".to_string(),
+            status,
+            capacity: None,
+            teacher: "teacher".to_string(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_registration_open_case() {
+        let mut service = MockServiceManager::new();
+
+        let courses = vec![
+            course("registration", CourseStatus::Registration),
+            course("in_progress", CourseStatus::InProgress),
+        ];
+        service
+            .course_service
+            .expect_find_all_with_teacher()
+            .returning(move |_, _, _| Ok(courses.clone()));
+        service
+            .registration_course_service
+            .expect_is_registration_open()
+            .returning(|_| Ok(true));
+
+        let req = TestRequest::with_uri("/api/courses").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "student");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+
+        let query = SearchCoursesQuery {
+            type_: None,
+            credit: None,
+            teacher: None,
+            period: None,
+            day_of_week: None,
+            keywords: None,
+            status: None,
+            page: None,
+        };
+        let res = search_courses(web::Data::new(service), web::Query(query), req)
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        let body: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body[0]["registration_open"], true);
+        assert_eq!(body[1]["registration_open"], false);
+    }
+}
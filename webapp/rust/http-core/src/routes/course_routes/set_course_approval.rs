@@ -0,0 +1,101 @@
+use crate::responses::error::ResponseError::CourseNotFound;
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SetCourseApprovalRequest {
+    required: bool,
+}
+
+// PUT /api/courses/{course_id}/approval 科目の履修登録に担当教員の承認を必要とするか変更
+pub async fn set_course_approval<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+    req: web::Json<SetCourseApprovalRequest>,
+) -> ResponseResult<HttpResponse> {
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    let mut audit_log = new_audit_log(&request, AuditAction::CourseApprovalUpdate)?;
+    audit_log.target_course_id = Some(course_id.clone());
+    audit_log.after_summary = Some(serde_json::json!({ "required": req.required }).to_string());
+
+    let result = service
+        .registration_course_service()
+        .update_approval_required(&course_id, req.required, &audit_log)
+        .await;
+    match result {
+        Ok(_) => {}
+        Err(Error::CourseNotFound) => return Err(CourseNotFound),
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::set_course_approval::{
+        set_course_approval, SetCourseApprovalRequest,
+    };
+    use crate::test_util::admin_request;
+    use actix_web::http::StatusCode;
+    use actix_web::web;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::services::error::Error::CourseNotFound;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "CourseNotFound")]
+    async fn test_course_not_found_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_update_approval_required()
+            .returning(|_, _, _| Err(CourseNotFound));
+
+        set_course_approval(
+            web::Data::new(service),
+            web::Path::from(("1".to_string(),)),
+            admin_request("/api/courses/1/approval"),
+            web::Json(SetCourseApprovalRequest { required: true }),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_update_approval_required()
+            .withf(|course_id, required, log| {
+                course_id.inner() == "1"
+                    && !*required
+                    && log.action == AuditAction::CourseApprovalUpdate
+                    && log.after_summary.as_deref() == Some(r#"{"required":false}"#)
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let res = set_course_approval(
+            web::Data::new(service),
+            web::Path::from(("1".to_string(),)),
+            admin_request("/api/courses/1/approval"),
+            web::Json(SetCourseApprovalRequest { required: false }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
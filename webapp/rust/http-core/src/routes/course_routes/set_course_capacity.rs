@@ -0,0 +1,120 @@
+use crate::responses::error::ResponseError::{CourseNotFound, InvalidCourseCapacity};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::course_service::{CourseService, HaveCourseService};
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SetCourseCapacityRequest {
+    // nullの場合は定員なし
+    capacity: Option<u32>,
+}
+
+// PUT /api/courses/{course_id}/capacity 科目の定員を変更
+pub async fn set_course_capacity<Service: HaveCourseService + HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+    req: web::Json<SetCourseCapacityRequest>,
+) -> ResponseResult<HttpResponse> {
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    // 監査ログに変更前の定員を残すため、更新前に取得しておく
+    let before = service
+        .course_service()
+        .find_with_teacher_by_id(&course_id)
+        .await?;
+
+    let mut audit_log = new_audit_log(&request, AuditAction::CourseCapacityUpdate)?;
+    audit_log.target_course_id = Some(course_id.clone());
+    audit_log.before_summary =
+        before.map(|course| serde_json::json!({ "capacity": course.capacity }).to_string());
+    audit_log.after_summary = Some(serde_json::json!({ "capacity": req.capacity }).to_string());
+
+    let result = service
+        .registration_course_service()
+        .update_capacity(&course_id, req.capacity, &audit_log)
+        .await;
+    match result {
+        Ok(_) => {}
+        Err(Error::CourseNotFound) => return Err(CourseNotFound),
+        Err(Error::InvalidCourseCapacity) => return Err(InvalidCourseCapacity),
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::set_course_capacity::{
+        set_course_capacity, SetCourseCapacityRequest,
+    };
+    use crate::test_util::admin_request;
+    use actix_web::http::StatusCode;
+    use actix_web::{web, ResponseError};
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::services::error::Error::InvalidCourseCapacity;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    async fn test_invalid_capacity_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_find_with_teacher_by_id()
+            .returning(|_| Ok(None));
+        service
+            .registration_course_service
+            .expect_update_capacity()
+            .returning(|_, _, _| Err(InvalidCourseCapacity));
+
+        let err = set_course_capacity(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            admin_request("/api/courses/course/capacity"),
+            web::Json(SetCourseCapacityRequest { capacity: Some(0) }),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.error_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_find_with_teacher_by_id()
+            .returning(|_| Ok(None));
+        service
+            .registration_course_service
+            .expect_update_capacity()
+            .withf(|course_id, capacity, audit_log| {
+                course_id.inner() == "course"
+                    && *capacity == Some(30)
+                    && audit_log.action == AuditAction::CourseCapacityUpdate
+                    && audit_log.after_summary.as_deref() == Some(r#"{"capacity":30}"#)
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let res = set_course_capacity(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            admin_request("/api/courses/course/capacity"),
+            web::Json(SetCourseCapacityRequest { capacity: Some(30) }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::responses::error::ResponseError::{CourseNotFound, CourseNotRegistrable};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SetCourseLotteryRequest {
+    enabled: bool,
+}
+
+// PUT /api/courses/{course_id}/lottery 科目を抽選で履修者を決める科目にするか変更
+pub async fn set_course_lottery<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+    req: web::Json<SetCourseLotteryRequest>,
+) -> ResponseResult<HttpResponse> {
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    let mut audit_log = new_audit_log(&request, AuditAction::CourseLotteryUpdate)?;
+    audit_log.target_course_id = Some(course_id.clone());
+    audit_log.after_summary = Some(serde_json::json!({ "enabled": req.enabled }).to_string());
+
+    let result = service
+        .registration_course_service()
+        .update_lottery(&course_id, req.enabled, &audit_log)
+        .await;
+    match result {
+        Ok(_) => {}
+        Err(Error::CourseNotFound) => return Err(CourseNotFound),
+        Err(Error::CourseNotRegistrable) => return Err(CourseNotRegistrable),
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::set_course_lottery::{
+        set_course_lottery, SetCourseLotteryRequest,
+    };
+    use crate::test_util::admin_request;
+    use actix_web::http::StatusCode;
+    use actix_web::web;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::services::error::Error::CourseNotRegistrable;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "CourseNotRegistrable")]
+    async fn test_not_registrable_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_update_lottery()
+            .returning(|_, _, _| Err(CourseNotRegistrable));
+
+        set_course_lottery(
+            web::Data::new(service),
+            web::Path::from(("1".to_string(),)),
+            admin_request("/api/courses/1/lottery"),
+            web::Json(SetCourseLotteryRequest { enabled: true }),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_update_lottery()
+            .withf(|_, enabled, log| {
+                *enabled
+                    && log.action == AuditAction::CourseLotteryUpdate
+                    && log.target_course_id.as_ref().map(|id| id.inner().as_str()) == Some("1")
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let res = set_course_lottery(
+            web::Data::new(service),
+            web::Path::from(("1".to_string(),)),
+            admin_request("/api/courses/1/lottery"),
+            web::Json(SetCourseLotteryRequest { enabled: true }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
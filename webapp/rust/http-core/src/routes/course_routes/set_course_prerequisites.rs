@@ -0,0 +1,113 @@
+use crate::responses::error::ResponseError::{CourseNotFound, InvalidCoursePrerequisite};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::course_prerequisite::UpdateCoursePrerequisite;
+use isucholar_core::services::course_service::{CourseService, HaveCourseService};
+use isucholar_core::services::error::Error;
+
+// PUT /api/courses/{course_id}/prerequisites 科目の事前科目を設定
+pub async fn set_course_prerequisites<Service: HaveCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+    req: web::Json<Vec<UpdateCoursePrerequisite>>,
+) -> ResponseResult<HttpResponse> {
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    let mut audit_log = new_audit_log(&request, AuditAction::CoursePrerequisiteUpdate)?;
+    audit_log.target_course_id = Some(course_id.clone());
+    audit_log.after_summary = Some(
+        serde_json::json!(req
+            .iter()
+            .map(|prerequisite| serde_json::json!({
+                "code": prerequisite.code,
+                "min_score": prerequisite.min_score,
+            }))
+            .collect::<Vec<_>>())
+        .to_string(),
+    );
+
+    let result = service
+        .course_service()
+        .update_prerequisites(&course_id, &req, &audit_log)
+        .await;
+    match result {
+        Ok(_) => {}
+        Err(Error::CourseNotFound) => return Err(CourseNotFound),
+        Err(Error::InvalidCoursePrerequisite) => return Err(InvalidCoursePrerequisite),
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::set_course_prerequisites::set_course_prerequisites;
+    use crate::test_util::admin_request;
+    use actix_web::http::StatusCode;
+    use actix_web::{web, ResponseError};
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::models::course::CourseCode;
+    use isucholar_core::models::course_prerequisite::UpdateCoursePrerequisite;
+    use isucholar_core::services::error::Error::InvalidCoursePrerequisite;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    fn body() -> web::Json<Vec<UpdateCoursePrerequisite>> {
+        web::Json(vec![UpdateCoursePrerequisite {
+            code: CourseCode::new("L0001".to_string()),
+            min_score: 60,
+        }])
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_prerequisite_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_update_prerequisites()
+            .returning(|_, _, _| Err(InvalidCoursePrerequisite));
+
+        let err = set_course_prerequisites(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            admin_request("/api/courses/course/prerequisites"),
+            body(),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.error_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_update_prerequisites()
+            .withf(|course_id, prerequisites, audit_log| {
+                course_id.inner() == "course"
+                    && prerequisites.len() == 1
+                    && audit_log.action == AuditAction::CoursePrerequisiteUpdate
+                    && audit_log.after_summary.as_deref()
+                        == Some(r#"[{"code":"L0001","min_score":60}]"#)
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let res = set_course_prerequisites(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            admin_request("/api/courses/course/prerequisites"),
+            body(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
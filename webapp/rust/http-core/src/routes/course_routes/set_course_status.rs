@@ -1,26 +1,227 @@
+use crate::responses::error::ResponseError::{
+    CourseNotFound, InvalidCourseStatusTransition, InvalidStatusOverrideReason,
+};
 use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
 use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
 use isucholar_core::models::course::CourseID;
 use isucholar_core::models::course_status::CourseStatus;
 use isucholar_core::services::course_service::{CourseService, HaveCourseService};
+use isucholar_core::services::error::Error;
 
 #[derive(Debug, serde::Deserialize)]
 pub struct SetCourseStatusRequest {
     status: CourseStatus,
+    // 指定した場合は順序に沿わないステータスの変更も許可する
+    override_reason: Option<String>,
 }
 
 // PUT /api/courses/{course_id}/status 科目のステータスを変更
 pub async fn set_course_status<Service: HaveCourseService>(
     service: web::Data<Service>,
     course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
     req: web::Json<SetCourseStatusRequest>,
 ) -> ResponseResult<HttpResponse> {
     let course_id = CourseID::new(course_id.0.to_string());
+    if matches!(req.override_reason, Some(ref reason) if reason.trim().is_empty()) {
+        return Err(InvalidStatusOverrideReason);
+    }
 
-    service
+    // 監査ログに変更前のステータスを残すため、更新前に取得しておく
+    let before = service
         .course_service()
-        .update_status_by_id(&course_id, &req.status)
+        .find_with_teacher_by_id(&course_id)
         .await?;
 
+    let mut audit_log = new_audit_log(&request, AuditAction::CourseStatusUpdate)?;
+    audit_log.target_course_id = Some(course_id.clone());
+    audit_log.before_summary =
+        before.map(|course| serde_json::json!({ "status": course.status }).to_string());
+    audit_log.after_summary = Some(
+        match req.override_reason {
+            Some(ref reason) => {
+                serde_json::json!({ "status": req.status, "override_reason": reason })
+            }
+            None => serde_json::json!({ "status": req.status }),
+        }
+        .to_string(),
+    );
+
+    let result = service
+        .course_service()
+        .update_status_by_id(
+            &course_id,
+            &req.status,
+            req.override_reason.is_some(),
+            &audit_log,
+        )
+        .await;
+    match result {
+        Ok(_) => {}
+        Err(Error::CourseNotFound) => return Err(CourseNotFound),
+        Err(Error::InvalidCourseStatusTransition) => return Err(InvalidCourseStatusTransition),
+        Err(e) => return Err(e.into()),
+    }
+
     Ok(HttpResponse::Ok().finish())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::set_course_status::{
+        set_course_status, SetCourseStatusRequest,
+    };
+    use crate::test_util::admin_request;
+    use actix_web::http::StatusCode;
+    use actix_web::{web, ResponseError};
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::models::course::{CourseCode, CourseID, CourseWithTeacher};
+    use isucholar_core::models::course_status::CourseStatus;
+    use isucholar_core::models::day_of_week::DayOfWeek;
+    use isucholar_core::models::user::UserID;
+    use isucholar_core::services::error::Error::{CourseNotFound, InvalidCourseStatusTransition};
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "CourseNotFound")]
+    async fn test_course_not_found_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_find_with_teacher_by_id()
+            .returning(|_| Ok(None));
+        service
+            .course_service
+            .expect_update_status_by_id()
+            .returning(|_, _, _, _| Err(CourseNotFound));
+
+        set_course_status(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            admin_request("/api/courses/course/status"),
+            web::Json(SetCourseStatusRequest {
+                status: CourseStatus::InProgress,
+                override_reason: None,
+            }),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn success_case() {
+        let mut service = MockServiceManager::new();
+
+        let course = CourseWithTeacher {
+            id: CourseID::new("course".to_string()),
+            code: CourseCode::new("L0001".to_string()),
+            type_: "liberal-arts".to_string(),
+            name: "course".to_string(),
+            description: "".to_string(),
+            credit: 1,
+            period: 1,
+            day_of_week: DayOfWeek::Monday,
+            teacher_id: UserID::new("admin".to_string()),
+            keywords: "".to_string(),
+            status: CourseStatus::Registration,
+            capacity: None,
+            teacher: "teacher".to_string(),
+        };
+        service
+            .course_service
+            .expect_find_with_teacher_by_id()
+            .returning(move |_| Ok(Some(course.clone())));
+        service
+            .course_service
+            .expect_update_status_by_id()
+            .withf(|_, _, is_override, audit_log| {
+                !is_override
+                    && audit_log.actor_id.inner() == "admin"
+                    && audit_log.action == AuditAction::CourseStatusUpdate
+                    && audit_log.target_course_id.as_ref().unwrap().inner() == "course"
+                    && audit_log.before_summary.as_deref() == Some(r#"{"status":"registration"}"#)
+                    && audit_log.after_summary.as_deref() == Some(r#"{"status":"in-progress"}"#)
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let res = set_course_status(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            admin_request("/api/courses/course/status"),
+            web::Json(SetCourseStatusRequest {
+                status: CourseStatus::InProgress,
+                override_reason: None,
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_transition_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_find_with_teacher_by_id()
+            .returning(|_| Ok(None));
+        service
+            .course_service
+            .expect_update_status_by_id()
+            .returning(|_, _, _, _| Err(InvalidCourseStatusTransition));
+
+        let err = set_course_status(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            admin_request("/api/courses/course/status"),
+            web::Json(SetCourseStatusRequest {
+                status: CourseStatus::Registration,
+                override_reason: None,
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.error_response().status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_override_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_find_with_teacher_by_id()
+            .returning(|_| Ok(None));
+        service
+            .course_service
+            .expect_update_status_by_id()
+            .withf(|_, status, is_override, audit_log| {
+                *status == CourseStatus::Registration
+                    && *is_override
+                    && audit_log.after_summary.as_deref()
+                        == Some(
+                            r#"{"override_reason":"graded by mistake","status":"registration"}"#,
+                        )
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let res = set_course_status(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            admin_request("/api/courses/course/status"),
+            web::Json(SetCourseStatusRequest {
+                status: CourseStatus::Registration,
+                override_reason: Some("graded by mistake".to_string()),
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
@@ -13,11 +13,11 @@ use isucholar_core::services::submission_service::{HaveSubmissionService, Submis
 // POST /api/courses/{course_id}/classes/{class_id}/assignments 課題の提出
 pub async fn submit_assignment<Service: HaveSubmissionService>(
     service: web::Data<Service>,
-    session: actix_session::Session,
+    request: actix_web::HttpRequest,
     path: web::Path<AssignmentPath>,
     mut payload: actix_multipart::Multipart,
 ) -> ResponseResult<HttpResponse> {
-    let (user_id, _, _) = get_user_info(session)?;
+    let (user_id, _, _) = get_user_info(&request)?;
 
     let course_id = path.course_id.clone();
     let class_id = path.class_id.clone();
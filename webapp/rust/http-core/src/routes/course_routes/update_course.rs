@@ -0,0 +1,178 @@
+use crate::responses::error::ResponseError::{
+    CourseIsClosed, CourseNotFound, CourseScheduleConflict, InvalidCourse,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::course::{CourseID, UpdateCourse};
+use isucholar_core::models::day_of_week::DayOfWeek;
+use isucholar_core::services::course_service::{CourseService, HaveCourseService};
+use isucholar_core::services::error::Error;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct UpdateCourseRequest {
+    name: String,
+    description: String,
+    credit: u8,
+    period: u8,
+    day_of_week: DayOfWeek,
+    keywords: String,
+}
+
+// PUT /api/courses/{course_id} 科目情報の更新
+pub async fn update_course<Service: HaveCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+    req: web::Json<UpdateCourseRequest>,
+) -> ResponseResult<HttpResponse> {
+    let course_id = CourseID::new(course_id.0.to_string());
+    let form = UpdateCourse {
+        name: req.name.clone(),
+        description: req.description.clone(),
+        credit: req.credit,
+        period: req.period,
+        day_of_week: req.day_of_week.clone(),
+        keywords: req.keywords.clone(),
+    };
+
+    // 監査ログに変更前の値を残すため、更新前に取得しておく
+    let before = service
+        .course_service()
+        .find_with_teacher_by_id(&course_id)
+        .await?;
+
+    let mut audit_log = new_audit_log(&request, AuditAction::CourseUpdate)?;
+    audit_log.target_course_id = Some(course_id.clone());
+    audit_log.before_summary = before.map(|course| {
+        serde_json::json!({
+            "name": course.name,
+            "credit": course.credit,
+            "period": course.period,
+            "day_of_week": course.day_of_week,
+        })
+        .to_string()
+    });
+    audit_log.after_summary = Some(
+        serde_json::json!({
+            "name": form.name,
+            "credit": form.credit,
+            "period": form.period,
+            "day_of_week": form.day_of_week,
+        })
+        .to_string(),
+    );
+
+    match service
+        .course_service()
+        .update(&course_id, &form, &audit_log)
+        .await
+    {
+        Ok(_) => {}
+        Err(Error::CourseNotFound) => return Err(CourseNotFound),
+        Err(Error::InvalidCourse) => return Err(InvalidCourse),
+        Err(Error::CourseIsClosed) => return Err(CourseIsClosed),
+        Err(Error::CourseScheduleConflict) => return Err(CourseScheduleConflict),
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::course_routes::update_course::{update_course, UpdateCourseRequest};
+    use crate::test_util::admin_request;
+    use actix_web::http::StatusCode;
+    use actix_web::web;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::models::course::{Course, CourseCode, CourseID};
+    use isucholar_core::models::course_status::CourseStatus;
+    use isucholar_core::models::course_type::CourseType;
+    use isucholar_core::models::day_of_week::DayOfWeek;
+    use isucholar_core::models::user::UserID;
+    use isucholar_core::services::error::Error::CourseScheduleConflict;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    fn body() -> web::Json<UpdateCourseRequest> {
+        web::Json(UpdateCourseRequest {
+            name: "updated".to_string(),
+            description: "".to_string(),
+            credit: 2,
+            period: 3,
+            day_of_week: DayOfWeek::Friday,
+            keywords: "".to_string(),
+        })
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "CourseScheduleConflict")]
+    async fn test_schedule_conflict_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_find_with_teacher_by_id()
+            .returning(|_| Ok(None));
+        service
+            .course_service
+            .expect_update()
+            .returning(|_, _, _| Err(CourseScheduleConflict));
+
+        update_course(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            admin_request("/api/courses/course"),
+            body(),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .course_service
+            .expect_find_with_teacher_by_id()
+            .returning(|_| Ok(None));
+        service
+            .course_service
+            .expect_update()
+            .withf(|course_id, req, audit_log| {
+                course_id.inner() == "course"
+                    && req.name == "updated"
+                    && req.period == 3
+                    && audit_log.action == AuditAction::CourseUpdate
+                    && audit_log.target_course_id == Some(CourseID::new("course".to_string()))
+            })
+            .returning(|course_id, req, _| {
+                Ok(Course {
+                    id: course_id.clone(),
+                    code: CourseCode::new("L0001".to_string()),
+                    type_: CourseType::LiberalArts,
+                    name: req.name.clone(),
+                    description: req.description.clone(),
+                    credit: req.credit,
+                    period: req.period,
+                    day_of_week: req.day_of_week.clone(),
+                    teacher_id: UserID::new("admin".to_string()),
+                    keywords: req.keywords.clone(),
+                    status: CourseStatus::Registration,
+                    capacity: None,
+                })
+            });
+
+        let res = update_course(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            admin_request("/api/courses/course"),
+            body(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,20 @@
+use crate::middleware::IsAdmin;
+use crate::routes::impersonation_routes::end_impersonation::end_impersonation;
+use crate::routes::impersonation_routes::start_impersonation::start_impersonation;
+use actix_web::{web, Scope};
+use isucholar_core::services::manager::ServiceManager;
+
+mod end_impersonation;
+mod start_impersonation;
+
+pub fn get_impersonation_routes<Service: ServiceManager + 'static>() -> Scope {
+    web::scope("/impersonation")
+        .service(
+            web::resource("")
+                .guard(actix_web::guard::Post())
+                .wrap(IsAdmin)
+                .to(start_impersonation::<Service>),
+        )
+        // なりすまし中は管理者ではないため、IsAdminは付けない
+        .route("", web::delete().to(end_impersonation::<Service>))
+}
@@ -0,0 +1,87 @@
+use crate::responses::error::ResponseError::ImpersonationNotStarted;
+use crate::responses::error::ResponseResult;
+use crate::routes::util::{end_impersonation as end, get_impersonation};
+use actix_web::{web, HttpResponse};
+use isucholar_core::services::impersonation_service::{
+    HaveImpersonationService, ImpersonationService,
+};
+
+// DELETE /api/impersonation 学生としての閲覧を終了し、管理者に戻る
+pub async fn end_impersonation<Service: HaveImpersonationService>(
+    service: web::Data<Service>,
+    session: actix_session::Session,
+) -> ResponseResult<HttpResponse> {
+    let impersonation = match get_impersonation(&session)? {
+        Some(impersonation) => impersonation,
+        None => return Err(ImpersonationNotStarted),
+    };
+
+    if let Some(user_id) = end(&session, &impersonation)? {
+        service
+            .impersonation_service()
+            .end(&impersonation.impersonator_id, &user_id, false)
+            .await?;
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::impersonation_routes::end_impersonation::end_impersonation;
+    use actix_session::SessionExt;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "ImpersonationNotStarted")]
+    async fn test_not_started_case() {
+        let mut service = MockServiceManager::new();
+
+        service.impersonation_service.expect_end().never();
+
+        let req = TestRequest::with_uri("/impersonation").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+
+        end_impersonation(web::Data::new(service), session)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .impersonation_service
+            .expect_end()
+            .withf(|admin_id, user_id, is_expired| {
+                admin_id.inner() == "1" && user_id.inner() == "2" && !is_expired
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let req = TestRequest::with_uri("/impersonation").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "2");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+        let _ = session.insert("impersonatorID", "1");
+        let _ = session.insert("impersonatorName", "admin");
+        let _ = session.insert("impersonationExpiresAt", i64::MAX);
+
+        let res = end_impersonation(web::Data::new(service), session.clone())
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            session.get::<String>("userID").unwrap(),
+            Some("1".to_string())
+        );
+        assert_eq!(session.get::<bool>("isAdmin").unwrap(), Some(true));
+        assert_eq!(session.get::<String>("impersonatorID").unwrap(), None);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::responses::error::ResponseError::{
+    ImpersonationAlreadyStarted, ImpersonationTargetNotAllowed, ImpersonationTargetNotFound,
+    PersonalAccessTokenForbidden,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::{
+    get_impersonation, get_user_info, is_authenticated_by_token, start_impersonation as start,
+    ImpersonationSession,
+};
+use actix_web::{web, HttpResponse};
+use chrono::NaiveDateTime;
+use isucholar_core::models::user::UserCode;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::impersonation_service::{
+    HaveImpersonationService, ImpersonationService,
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct StartImpersonationRequest {
+    user_code: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct StartImpersonationResponse {
+    code: UserCode,
+    name: String,
+    expires_at: NaiveDateTime,
+}
+
+// POST /api/impersonation 学生としての閲覧を開始
+pub async fn start_impersonation<Service: HaveImpersonationService>(
+    service: web::Data<Service>,
+    session: actix_session::Session,
+    request: actix_web::HttpRequest,
+    req: web::Json<StartImpersonationRequest>,
+) -> ResponseResult<HttpResponse> {
+    if is_authenticated_by_token(&request) {
+        return Err(PersonalAccessTokenForbidden);
+    }
+    if get_impersonation(&session)?.is_some() {
+        return Err(ImpersonationAlreadyStarted);
+    }
+    let (user_id, user_name, _) = get_user_info(&request)?;
+
+    let target_code = UserCode::new(req.user_code.clone().into());
+    let result = service
+        .impersonation_service()
+        .start(&user_id, &target_code)
+        .await;
+    let impersonation = match result {
+        Ok(impersonation) => impersonation,
+        Err(Error::ImpersonationTargetNotFound) => return Err(ImpersonationTargetNotFound),
+        Err(Error::ImpersonationTargetNotAllowed) => return Err(ImpersonationTargetNotAllowed),
+        Err(e) => return Err(e.into()),
+    };
+
+    let impersonation_session = ImpersonationSession {
+        impersonator_id: user_id,
+        impersonator_name: user_name,
+        expires_at: impersonation.expires_at.and_utc().timestamp(),
+    };
+    start(
+        &session,
+        &impersonation_session,
+        &impersonation.user.id,
+        &impersonation.user.name,
+    )?;
+
+    Ok(HttpResponse::Ok().json(StartImpersonationResponse {
+        code: impersonation.user.code,
+        name: impersonation.user.name,
+        expires_at: impersonation.expires_at,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::impersonation_routes::start_impersonation::{
+        start_impersonation, StartImpersonationRequest,
+    };
+    use crate::routes::util::get_impersonation;
+    use actix_session::SessionExt;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::user::{User, UserCode, UserID};
+    use isucholar_core::models::user_type::UserType;
+    use isucholar_core::services::impersonation_service::Impersonation;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    fn body() -> web::Json<StartImpersonationRequest> {
+        web::Json(StartImpersonationRequest {
+            user_code: "S00001".to_string(),
+        })
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "ImpersonationAlreadyStarted")]
+    async fn test_already_started_case() {
+        let mut service = MockServiceManager::new();
+
+        service.impersonation_service.expect_start().never();
+
+        let req = TestRequest::with_uri("/impersonation").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "2");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+        let _ = session.insert("impersonatorID", "1");
+
+        start_impersonation(web::Data::new(service), session, req, body())
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        let expires_at = chrono::Utc::now().naive_utc() + chrono::Duration::minutes(30);
+        service
+            .impersonation_service
+            .expect_start()
+            .withf(|admin_id, code| admin_id.inner() == "1" && code.inner().to_string() == "S00001")
+            .returning(move |_, _| {
+                Ok(Impersonation {
+                    user: User {
+                        id: UserID::new("2".to_string()),
+                        code: UserCode::new("S00001".to_string().into()),
+                        name: "student".to_string(),
+                        hashed_password: vec![],
+                        type_: UserType::Student,
+                        deactivated_at: None,
+                    },
+                    expires_at,
+                })
+            });
+
+        let req = TestRequest::with_uri("/impersonation").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+        let _ = session.insert("userName", "admin");
+        let _ = session.insert("isAdmin", true);
+
+        let res = start_impersonation(web::Data::new(service), session.clone(), req, body())
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            session.get::<String>("userID").unwrap(),
+            Some("2".to_string())
+        );
+        assert_eq!(session.get::<bool>("isAdmin").unwrap(), Some(false));
+        let impersonation = get_impersonation(&session).unwrap().unwrap();
+        assert_eq!(impersonation.impersonator_id.inner(), "1");
+        assert_eq!(impersonation.impersonator_name, "admin");
+        assert_eq!(impersonation.expires_at, expires_at.and_utc().timestamp());
+    }
+}
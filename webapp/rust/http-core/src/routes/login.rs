@@ -1,45 +1,388 @@
-use crate::responses::error::ResponseError::{AlreadyLogin, Unauthorized};
+use crate::responses::error::ResponseError::{
+    AlreadyLogin, TooManyLoginAttempts, Unauthorized, UserDeactivated,
+};
 use crate::responses::error::ResponseResult;
+use crate::routes::util::{clear_impersonation, get_client_ip_address};
 use actix_web::{web, HttpResponse};
 use isucholar_core::models::user::UserCode;
 use isucholar_core::models::user_type::UserType;
+use isucholar_core::services::login_attempt_service::{
+    HaveLoginAttemptService, LoginAttemptService,
+};
+use isucholar_core::services::two_factor_service::{
+    HaveTwoFactorService, TwoFactorRequirement, TwoFactorService,
+};
 use isucholar_core::services::user_service::{HaveUserService, UserService};
 
+// login_attempts.user_code の長さ。これより長い学籍番号のユーザーは存在しない
+const USER_CODE_MAX_LENGTH: usize = 255;
+
 #[derive(Debug, serde::Deserialize)]
 pub struct LoginRequest {
     code: String,
     password: String,
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct LoginResponse {
+    two_factor: TwoFactorRequirement,
+}
+
 // POST /login ログイン
-pub async fn login<Service: HaveUserService>(
+pub async fn login<Service: HaveUserService + HaveLoginAttemptService + HaveTwoFactorService>(
     service: web::Data<Service>,
     session: actix_session::Session,
+    request: actix_web::HttpRequest,
     req: web::Json<LoginRequest>,
 ) -> ResponseResult<HttpResponse> {
+    let ip_address = get_client_ip_address(&request);
+
+    // 試行として記録できない長さの学籍番号は、照合も記録もせずに拒否する
+    if req.code.chars().count() > USER_CODE_MAX_LENGTH {
+        return Err(Unauthorized);
+    }
+
+    // ロックアウト中の試行はパスワードを検証せず、失敗回数にも数えない
+    if let Some(retry_after) = service
+        .login_attempt_service()
+        .find_retry_after(&req.code, &ip_address)
+        .await?
+    {
+        return Err(TooManyLoginAttempts(retry_after));
+    }
+
     let code = UserCode::new(req.code.to_string().into());
     let user = service.user_service().find_by_code(&code).await?;
 
     if user.is_none() {
+        service
+            .login_attempt_service()
+            .record(&req.code, &ip_address, false)
+            .await?;
         return Err(Unauthorized);
     }
     let user = user.unwrap();
 
     let is_valid_password = service
         .user_service()
-        .verify_password(&user, &req.password)?;
+        .verify_password(&user, &req.password)
+        .await?;
     if !is_valid_password {
+        service
+            .login_attempt_service()
+            .record(&req.code, &ip_address, false)
+            .await?;
         return Err(Unauthorized);
     }
 
+    // 無効化されたユーザーはパスワードが正しくてもログインさせない
+    if !user.is_active() {
+        service
+            .login_attempt_service()
+            .record(&req.code, &ip_address, false)
+            .await?;
+        return Err(UserDeactivated);
+    }
+
+    clear_impersonation(&session);
     if let Some(user_id) = session.get::<String>("userID")? {
         if user_id == user.id.inner().to_string() {
             return Err(AlreadyLogin);
         }
     }
 
+    // 二要素認証が必要な場合はセッションを未認証のまま保留し、POST /login/totp で完了させる。
+    // 成功の記録もそちらで行い、パスワードだけで失敗回数がリセットされないようにする
+    let requirement = service.two_factor_service().requirement(&user).await?;
+    if requirement != TwoFactorRequirement::NotRequired {
+        session.remove("userID");
+        session.remove("userName");
+        session.remove("isAdmin");
+        session.insert("pendingUserID", user.id)?;
+        return Ok(HttpResponse::Ok().json(LoginResponse {
+            two_factor: requirement,
+        }));
+    }
+
+    service
+        .login_attempt_service()
+        .record(&req.code, &ip_address, true)
+        .await?;
+
     session.insert("userID", user.id)?;
     session.insert("userName", user.name)?;
     session.insert("isAdmin", user.type_ == UserType::Teacher)?;
     Ok(HttpResponse::Ok().finish())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::responses::error::ResponseError::TooManyLoginAttempts;
+    use crate::routes::login::{login, LoginRequest};
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::{web, ResponseError};
+    use isucholar_core::models::user::{User, UserCode, UserID};
+    use isucholar_core::models::user_type::UserType;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+    use isucholar_core::services::two_factor_service::TwoFactorRequirement;
+
+    #[actix_web::test]
+    async fn test_locked_out_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .login_attempt_service
+            .expect_find_retry_after()
+            .withf(|code, ip_address| code == "S00001" && ip_address == "192.0.2.1")
+            .returning(|_, _| Ok(Some(30)));
+        service.user_service.expect_find_by_code().never();
+        service.login_attempt_service.expect_record().never();
+
+        // 信頼するプロキシ以外からのX-Forwarded-Forは無視して、接続元のアドレスで制限する
+        let req = TestRequest::with_uri("/login")
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "198.51.100.1"))
+            .to_http_request();
+        let session = req.get_session();
+        let body = web::Json(LoginRequest {
+            code: "S00001".to_string(),
+            password: "password".to_string(),
+        });
+
+        let err = login(web::Data::new(service), session, req, body)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TooManyLoginAttempts(30)));
+
+        let res = err.error_response();
+        assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            res.headers()
+                .get(actix_web::http::header::RETRY_AFTER)
+                .unwrap(),
+            "30"
+        );
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "Unauthorized")]
+    async fn test_unknown_user_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .login_attempt_service
+            .expect_find_retry_after()
+            .returning(|_, _| Ok(None));
+        service
+            .user_service
+            .expect_find_by_code()
+            .returning(|_| Ok(None));
+        service
+            .login_attempt_service
+            .expect_record()
+            .withf(|code, ip_address, succeeded| {
+                code == "S00001" && ip_address == "192.0.2.1" && !succeeded
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let req = TestRequest::with_uri("/login")
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .to_http_request();
+        let session = req.get_session();
+        let body = web::Json(LoginRequest {
+            code: "S00001".to_string(),
+            password: "password".to_string(),
+        });
+
+        login(web::Data::new(service), session, req, body)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "Unauthorized")]
+    async fn test_too_long_code_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .login_attempt_service
+            .expect_find_retry_after()
+            .never();
+        service.user_service.expect_find_by_code().never();
+        service.login_attempt_service.expect_record().never();
+
+        let req = TestRequest::with_uri("/login")
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .to_http_request();
+        let session = req.get_session();
+        let body = web::Json(LoginRequest {
+            code: "S".repeat(256),
+            password: "password".to_string(),
+        });
+
+        login(web::Data::new(service), session, req, body)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_two_factor_pending_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .login_attempt_service
+            .expect_find_retry_after()
+            .returning(|_, _| Ok(None));
+        service.user_service.expect_find_by_code().returning(|_| {
+            Ok(Some(User {
+                id: UserID::new("1".to_string()),
+                code: UserCode::new("T00001".to_string().into()),
+                name: "Teacher".to_string(),
+                hashed_password: vec![],
+                type_: UserType::Teacher,
+                deactivated_at: None,
+            }))
+        });
+        service
+            .user_service
+            .expect_verify_password()
+            .returning(|_, _| Ok(true));
+        service
+            .two_factor_service
+            .expect_requirement()
+            .returning(|_| Ok(TwoFactorRequirement::Verify));
+        // パスワードだけでは成功として記録しない
+        service.login_attempt_service.expect_record().never();
+
+        let req = TestRequest::with_uri("/login")
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .to_http_request();
+        let session = req.get_session();
+        let body = web::Json(LoginRequest {
+            code: "T00001".to_string(),
+            password: "password".to_string(),
+        });
+
+        let res = login(web::Data::new(service), session.clone(), req, body)
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(body, r#"{"two_factor":"verify"}"#);
+        assert_eq!(
+            session.get::<String>("pendingUserID").unwrap(),
+            Some("1".to_string())
+        );
+        assert_eq!(session.get::<String>("userID").unwrap(), None);
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "UserDeactivated")]
+    async fn test_deactivated_user_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .login_attempt_service
+            .expect_find_retry_after()
+            .returning(|_, _| Ok(None));
+        service.user_service.expect_find_by_code().returning(|_| {
+            Ok(Some(User {
+                id: UserID::new("1".to_string()),
+                code: UserCode::new("S00001".to_string().into()),
+                name: "Student".to_string(),
+                hashed_password: vec![],
+                type_: UserType::Student,
+                deactivated_at: Some(chrono::DateTime::from_timestamp(0, 0).unwrap().naive_utc()),
+            }))
+        });
+        service
+            .user_service
+            .expect_verify_password()
+            .returning(|_, _| Ok(true));
+        service.two_factor_service.expect_requirement().never();
+        service
+            .login_attempt_service
+            .expect_record()
+            .withf(|_, _, succeeded| !succeeded)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let req = TestRequest::with_uri("/login")
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .to_http_request();
+        let session = req.get_session();
+        let body = web::Json(LoginRequest {
+            code: "S00001".to_string(),
+            password: "password".to_string(),
+        });
+
+        login(web::Data::new(service), session, req, body)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_impersonation_cleared_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .login_attempt_service
+            .expect_find_retry_after()
+            .returning(|_, _| Ok(None));
+        service.user_service.expect_find_by_code().returning(|_| {
+            Ok(Some(User {
+                id: UserID::new("2".to_string()),
+                code: UserCode::new("S00002".to_string().into()),
+                name: "Student".to_string(),
+                hashed_password: vec![],
+                type_: UserType::Student,
+                deactivated_at: None,
+            }))
+        });
+        service
+            .user_service
+            .expect_verify_password()
+            .returning(|_, _| Ok(true));
+        service
+            .two_factor_service
+            .expect_requirement()
+            .returning(|_| Ok(TwoFactorRequirement::NotRequired));
+        service
+            .login_attempt_service
+            .expect_record()
+            .withf(|_, _, succeeded| *succeeded)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let req = TestRequest::with_uri("/login")
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .to_http_request();
+        // 管理者がなりすましたままログアウトせずに離れたセッション
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+        let _ = session.insert("userName", "Student");
+        let _ = session.insert("isAdmin", false);
+        let _ = session.insert("impersonatorID", "admin");
+        let _ = session.insert("impersonatorName", "Teacher");
+        let _ = session.insert("impersonationExpiresAt", 0);
+        let body = web::Json(LoginRequest {
+            code: "S00002".to_string(),
+            password: "password".to_string(),
+        });
+
+        let res = login(web::Data::new(service), session.clone(), req, body)
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            session.get::<String>("userID").unwrap(),
+            Some("2".to_string())
+        );
+        assert_eq!(session.get::<String>("impersonatorID").unwrap(), None);
+        assert_eq!(session.get::<i64>("impersonationExpiresAt").unwrap(), None);
+    }
+}
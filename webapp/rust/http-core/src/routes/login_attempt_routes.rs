@@ -0,0 +1,15 @@
+use crate::middleware::IsAdmin;
+use crate::routes::login_attempt_routes::get_login_attempts::get_login_attempts;
+use actix_web::{web, Scope};
+use isucholar_core::services::manager::ServiceManager;
+
+mod get_login_attempts;
+
+pub fn get_login_attempt_routes<Service: ServiceManager + 'static>() -> Scope {
+    web::scope("/login_attempts").service(
+        web::resource("")
+            .guard(actix_web::guard::Get())
+            .wrap(IsAdmin)
+            .to(get_login_attempts::<Service>),
+    )
+}
@@ -0,0 +1,160 @@
+use crate::responses::error::ResponseError::InvalidPage;
+use crate::responses::error::ResponseResult;
+use actix_web::{web, HttpResponse};
+use chrono::NaiveDateTime;
+use isucholar_core::services::login_attempt_service::{
+    HaveLoginAttemptService, LoginAttemptService,
+};
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct GetLoginAttemptsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct LoginAttemptResponse {
+    id: String,
+    user_code: String,
+    ip_address: String,
+    created_at: NaiveDateTime,
+}
+
+// GET /api/login_attempts 失敗したログイン試行の一覧
+pub async fn get_login_attempts<Service: HaveLoginAttemptService>(
+    service: web::Data<Service>,
+    params: web::Query<GetLoginAttemptsQuery>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let page = if let Some(ref page_str) = params.page {
+        match page_str.parse() {
+            Ok(page) if page > 0 => page,
+            _ => return Err(InvalidPage),
+        }
+    } else {
+        1
+    };
+    let limit = 20;
+    let offset = limit * (page - 1);
+
+    let mut attempts = service
+        .login_attempt_service()
+        .find_all_failed(params.user_code.clone(), limit, offset)
+        .await?;
+
+    let uri = request.uri();
+    let mut params = params.into_inner();
+    let mut links = Vec::new();
+    if page > 1 {
+        params.page = Some(format!("{}", page - 1));
+        links.push(format!(
+            "<{}?{}>; rel=\"prev\"",
+            uri.path(),
+            serde_urlencoded::to_string(&params)?
+        ));
+    }
+    if attempts.len() as i64 > limit {
+        params.page = Some(format!("{}", page + 1));
+        links.push(format!(
+            "<{}?{}>; rel=\"next\"",
+            uri.path(),
+            serde_urlencoded::to_string(&params)?
+        ));
+    }
+
+    if attempts.len() as i64 == limit + 1 {
+        attempts.truncate(attempts.len() - 1);
+    }
+
+    let res = attempts
+        .into_iter()
+        .map(|attempt| LoginAttemptResponse {
+            id: attempt.id.inner().to_string(),
+            user_code: attempt.user_code,
+            ip_address: attempt.ip_address,
+            created_at: attempt.created_at,
+        })
+        .collect::<Vec<_>>();
+
+    let mut builder = HttpResponse::Ok();
+    if !links.is_empty() {
+        builder.insert_header((actix_web::http::header::LINK, links.join(",")));
+    }
+    Ok(builder.json(res))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::login_attempt_routes::get_login_attempts::{
+        get_login_attempts, GetLoginAttemptsQuery,
+    };
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::login_attempt::{LoginAttempt, LoginAttemptID};
+    use isucholar_core::services::manager::tests::MockServiceManager;
+    use std::str::from_utf8;
+
+    #[actix_web::test]
+    #[should_panic(expected = "InvalidPage")]
+    async fn test_invalid_page_case() {
+        let service = MockServiceManager::new();
+
+        let req = TestRequest::with_uri("/api/login_attempts?page=0").to_http_request();
+        let params = web::Query(GetLoginAttemptsQuery {
+            user_code: None,
+            page: Some("0".to_string()),
+        });
+
+        get_login_attempts(web::Data::new(service), params, req)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_next_page_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .login_attempt_service
+            .expect_find_all_failed()
+            .withf(|user_code, limit, offset| {
+                user_code.as_deref() == Some("S00001") && *limit == 20 && *offset == 0
+            })
+            .returning(|_, _, _| {
+                Ok((0..21)
+                    .map(|i| LoginAttempt {
+                        id: LoginAttemptID::new(format!("{:026}", i)),
+                        user_code: "S00001".to_string(),
+                        ip_address: "192.0.2.1".to_string(),
+                        succeeded: false,
+                        created_at: chrono::DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+                    })
+                    .collect())
+            });
+
+        let req = TestRequest::with_uri("/api/login_attempts?user_code=S00001").to_http_request();
+        let params = web::Query(GetLoginAttemptsQuery {
+            user_code: Some("S00001".to_string()),
+            page: None,
+        });
+
+        let res = get_login_attempts(web::Data::new(service), params, req)
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(actix_web::http::header::LINK).unwrap(),
+            "</api/login_attempts?user_code=S00001&page=2>; rel=\"next\""
+        );
+
+        let body = to_bytes(res.into_body()).await.unwrap();
+        let attempts: Vec<serde_json::Value> =
+            serde_json::from_str(from_utf8(&body).unwrap()).unwrap();
+        assert_eq!(attempts.len(), 20);
+        assert_eq!(attempts[0]["created_at"], "1970-01-01T00:00:00");
+    }
+}
@@ -0,0 +1,215 @@
+use crate::responses::error::ResponseError::{
+    InvalidTotpCode, TooManyLoginAttempts, TotpNotPending,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::{clear_impersonation, get_client_ip_address};
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::user::UserID;
+use isucholar_core::models::user_type::UserType;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::login_attempt_service::{
+    HaveLoginAttemptService, LoginAttemptService,
+};
+use isucholar_core::services::two_factor_service::{HaveTwoFactorService, TwoFactorService};
+use isucholar_core::services::user_service::{HaveUserService, UserService};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct LoginTotpRequest {
+    code: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct LoginTotpResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recovery_codes: Option<Vec<String>>,
+}
+
+// POST /login/totp 二要素認証のコードを検証してログインを完了
+pub async fn login_totp<
+    Service: HaveUserService + HaveLoginAttemptService + HaveTwoFactorService,
+>(
+    service: web::Data<Service>,
+    session: actix_session::Session,
+    request: actix_web::HttpRequest,
+    req: web::Json<LoginTotpRequest>,
+) -> ResponseResult<HttpResponse> {
+    let user_id = match session.get::<String>("pendingUserID")? {
+        Some(user_id) => UserID::new(user_id),
+        None => return Err(TotpNotPending),
+    };
+    let user_code = match service.user_service().find_code_by_id(&user_id).await? {
+        Some(user_code) => user_code.inner().to_string(),
+        None => return Err(TotpNotPending),
+    };
+    let ip_address = get_client_ip_address(&request);
+
+    // コードの総当たりもパスワードと同じ失敗回数で制限する
+    if let Some(retry_after) = service
+        .login_attempt_service()
+        .find_retry_after(&user_code, &ip_address)
+        .await?
+    {
+        return Err(TooManyLoginAttempts(retry_after));
+    }
+
+    let result = service
+        .two_factor_service()
+        .complete_login(&user_id, &req.code)
+        .await;
+    let (user, recovery_codes) = match result {
+        Ok(result) => result,
+        Err(Error::InvalidTotpCode) => {
+            service
+                .login_attempt_service()
+                .record(&user_code, &ip_address, false)
+                .await?;
+            return Err(InvalidTotpCode);
+        }
+        Err(Error::TotpNotEnrolled) => return Err(TotpNotPending),
+        Err(e) => return Err(e.into()),
+    };
+
+    service
+        .login_attempt_service()
+        .record(&user_code, &ip_address, true)
+        .await?;
+
+    session.remove("pendingUserID");
+    clear_impersonation(&session);
+    session.renew();
+    session.insert("userID", user.id)?;
+    session.insert("userName", user.name)?;
+    session.insert("isAdmin", user.type_ == UserType::Teacher)?;
+    Ok(HttpResponse::Ok().json(LoginTotpResponse { recovery_codes }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::responses::error::ResponseError::{InvalidTotpCode, TotpNotPending};
+    use crate::routes::login_totp::{login_totp, LoginTotpRequest};
+    use actix_session::SessionExt;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::user::{User, UserCode, UserID};
+    use isucholar_core::models::user_type::UserType;
+    use isucholar_core::services::error::Error;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    fn body(code: &str) -> web::Json<LoginTotpRequest> {
+        web::Json(LoginTotpRequest {
+            code: code.to_string(),
+        })
+    }
+
+    #[actix_web::test]
+    async fn test_not_pending_case() {
+        let mut service = MockServiceManager::new();
+
+        service.two_factor_service.expect_complete_login().never();
+
+        let req = TestRequest::with_uri("/login/totp").to_http_request();
+        let session = req.get_session();
+
+        let err = login_totp(web::Data::new(service), session, req, body("123456"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TotpNotPending));
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_code_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .user_service
+            .expect_find_code_by_id()
+            .returning(|_| Ok(Some(UserCode::new("T00001".to_string().into()))));
+        service
+            .login_attempt_service
+            .expect_find_retry_after()
+            .withf(|code, _| code == "T00001")
+            .returning(|_, _| Ok(None));
+        service
+            .two_factor_service
+            .expect_complete_login()
+            .returning(|_, _| Err(Error::InvalidTotpCode));
+        service
+            .login_attempt_service
+            .expect_record()
+            .withf(|code, _, succeeded| code == "T00001" && !succeeded)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let req = TestRequest::with_uri("/login/totp").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("pendingUserID", "1");
+
+        let err = login_totp(
+            web::Data::new(service),
+            session.clone(),
+            req,
+            body("000000"),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, InvalidTotpCode));
+        assert_eq!(session.get::<String>("userID").unwrap(), None);
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .user_service
+            .expect_find_code_by_id()
+            .returning(|_| Ok(Some(UserCode::new("T00001".to_string().into()))));
+        service
+            .login_attempt_service
+            .expect_find_retry_after()
+            .returning(|_, _| Ok(None));
+        service
+            .two_factor_service
+            .expect_complete_login()
+            .withf(|user_id, code| user_id.inner() == "1" && code == "123456")
+            .returning(|_, _| {
+                Ok((
+                    User {
+                        id: UserID::new("1".to_string()),
+                        code: UserCode::new("T00001".to_string().into()),
+                        name: "Teacher".to_string(),
+                        hashed_password: vec![],
+                        type_: UserType::Teacher,
+                        deactivated_at: None,
+                    },
+                    None,
+                ))
+            });
+        service
+            .login_attempt_service
+            .expect_record()
+            .withf(|_, _, succeeded| *succeeded)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let req = TestRequest::with_uri("/login/totp").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("pendingUserID", "1");
+
+        let res = login_totp(
+            web::Data::new(service),
+            session.clone(),
+            req,
+            body("123456"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            session.get::<String>("userID").unwrap(),
+            Some("1".to_string())
+        );
+        assert_eq!(session.get::<String>("pendingUserID").unwrap(), None);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::responses::error::ResponseError::{TotpAlreadyEnabled, TotpNotPending};
+use crate::responses::error::ResponseResult;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::user::UserID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::two_factor_service::{HaveTwoFactorService, TwoFactorService};
+
+#[derive(Debug, serde::Serialize)]
+pub struct TotpEnrollmentResponse {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+// POST /login/totp/enrollment ログイン途中で二要素認証の登録を開始
+pub async fn login_totp_enrollment<Service: HaveTwoFactorService>(
+    service: web::Data<Service>,
+    session: actix_session::Session,
+) -> ResponseResult<HttpResponse> {
+    let user_id = match session.get::<String>("pendingUserID")? {
+        Some(user_id) => UserID::new(user_id),
+        None => return Err(TotpNotPending),
+    };
+
+    // 登録済みの場合に秘密鍵を差し替えられると二要素認証を迂回できてしまうため、未登録の場合のみ許可する
+    let result = service
+        .two_factor_service()
+        .start_enrollment(&user_id)
+        .await;
+    match result {
+        Ok(enrollment) => Ok(HttpResponse::Ok().json(TotpEnrollmentResponse {
+            secret: enrollment.secret,
+            provisioning_uri: enrollment.provisioning_uri,
+        })),
+        Err(Error::TotpAlreadyEnabled) => Err(TotpAlreadyEnabled),
+        Err(e) => Err(e.into()),
+    }
+}
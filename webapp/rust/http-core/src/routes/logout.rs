@@ -1,7 +1,26 @@
-use actix_web::HttpResponse;
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_impersonation;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::user::UserID;
+use isucholar_core::services::impersonation_service::{
+    HaveImpersonationService, ImpersonationService,
+};
 
 // POST /logout ログアウト
-pub async fn logout(session: actix_session::Session) -> actix_web::Result<HttpResponse> {
+pub async fn logout<Service: HaveImpersonationService>(
+    service: web::Data<Service>,
+    session: actix_session::Session,
+) -> ResponseResult<HttpResponse> {
+    // なりすまし中のログアウトはなりすましの終了として記録する
+    if let Some(impersonation) = get_impersonation(&session)? {
+        if let Some(user_id) = session.get::<String>("userID")? {
+            service
+                .impersonation_service()
+                .end(&impersonation.impersonator_id, &UserID::new(user_id), false)
+                .await?;
+        }
+    }
+
     session.renew();
     session.purge();
     Ok(HttpResponse::Ok().finish())
@@ -13,9 +32,15 @@ mod tests {
     use actix_session::SessionExt;
     use actix_web::http::StatusCode;
     use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::services::manager::tests::MockServiceManager;
 
     #[actix_web::test]
     async fn success_case() {
+        let mut service = MockServiceManager::new();
+
+        service.impersonation_service.expect_end().never();
+
         let req = TestRequest::with_uri("/logout").to_http_request();
 
         let session = req.get_session();
@@ -23,7 +48,32 @@ mod tests {
         let _ = session.insert("userName", "1");
         let _ = session.insert("isAdmin", false);
 
-        let res = logout(session).await.unwrap();
+        let res = logout(web::Data::new(service), session).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn impersonation_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .impersonation_service
+            .expect_end()
+            .withf(|admin_id, user_id, is_expired| {
+                admin_id.inner() == "1" && user_id.inner() == "2" && !is_expired
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let req = TestRequest::with_uri("/logout").to_http_request();
+
+        let session = req.get_session();
+        let _ = session.insert("userID", "2");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+        let _ = session.insert("impersonatorID", "1");
+
+        let res = logout(web::Data::new(service), session).await.unwrap();
         assert_eq!(res.status(), StatusCode::OK);
     }
 }
@@ -0,0 +1,15 @@
+use crate::middleware::IsAdmin;
+use crate::routes::lottery_routes::draw_lottery::draw_lottery;
+use actix_web::{web, Scope};
+use isucholar_core::services::manager::ServiceManager;
+
+mod draw_lottery;
+
+pub fn get_lottery_routes<Service: ServiceManager + 'static>() -> Scope {
+    web::scope("/lottery").service(
+        web::resource("/draw")
+            .guard(actix_web::guard::Post())
+            .wrap(IsAdmin)
+            .to(draw_lottery::<Service>),
+    )
+}
@@ -0,0 +1,89 @@
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct DrawLotteryRequest {
+    // 省略した場合はランダムに決める。同じseedを指定すると抽選を再現できる
+    seed: Option<u64>,
+}
+
+// POST /api/lottery/draw 抽選科目の履修者を抽選で決定
+pub async fn draw_lottery<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+    req: web::Json<DrawLotteryRequest>,
+) -> ResponseResult<HttpResponse> {
+    let audit_log = new_audit_log(&request, AuditAction::LotteryDraw)?;
+    let report = service
+        .registration_course_service()
+        .draw_lottery(req.seed, &audit_log)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::lottery_routes::draw_lottery::{draw_lottery, DrawLotteryRequest};
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::models::course::CourseID;
+    use isucholar_core::models::lottery_outcome::{
+        LotteryDrawReport, LotteryOutcome, LotteryResult, LotteryStudentReport,
+    };
+    use isucholar_core::models::user::UserID;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_draw_lottery()
+            .withf(|seed, log| *seed == Some(7) && log.action == AuditAction::LotteryDraw)
+            .times(1)
+            .returning(|seed, _| {
+                Ok(LotteryDrawReport {
+                    seed: seed.unwrap(),
+                    students: vec![LotteryStudentReport {
+                        user_id: UserID::new("student".to_string()),
+                        results: vec![LotteryResult {
+                            course_id: CourseID::new("course".to_string()),
+                            rank: 1,
+                            outcome: LotteryOutcome::Won,
+                        }],
+                    }],
+                })
+            });
+
+        let req = TestRequest::with_uri("/api/lottery/draw").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "admin");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
+
+        let res = draw_lottery(
+            web::Data::new(service),
+            req,
+            web::Json(DrawLotteryRequest { seed: Some(7) }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(
+            body,
+            r#"{"seed":7,"students":[{"user_id":"student","results":[{"course_id":"course","rank":1,"outcome":"won"}]}]}"#
+        );
+    }
+}
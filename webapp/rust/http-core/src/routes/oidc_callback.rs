@@ -0,0 +1,267 @@
+use crate::responses::error::ResponseError::{
+    InvalidOidcState, OidcNotEnabled, OidcUnauthorized, UserDeactivated,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::clear_impersonation;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::user_type::UserType;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::oidc_login_service::{HaveOidcLoginService, OidcLoginService};
+use isucholar_core::services::two_factor_service::{
+    HaveTwoFactorService, TwoFactorRequirement, TwoFactorService,
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct OidcCallbackQuery {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+}
+
+// GET /login/oidc/callback IdPからの認可コードを受け取りログイン
+pub async fn oidc_callback<Service: HaveOidcLoginService + HaveTwoFactorService>(
+    service: web::Data<Service>,
+    session: actix_session::Session,
+    params: web::Query<OidcCallbackQuery>,
+) -> ResponseResult<HttpResponse> {
+    let oidc_login_service = service.oidc_login_service();
+    if !oidc_login_service.is_enabled() {
+        return Err(OidcNotEnabled);
+    }
+
+    // stateとnonceは一度しか使えないように、検証の前にセッションから取り除く
+    let expected_state = session.remove_as::<String>("oidcState");
+    let nonce = session.remove_as::<String>("oidcNonce");
+    let (expected_state, nonce) = match (expected_state, nonce) {
+        (Some(Ok(expected_state)), Some(Ok(nonce))) => (expected_state, nonce),
+        _ => return Err(InvalidOidcState),
+    };
+    if params.state.as_deref() != Some(expected_state.as_str()) {
+        return Err(InvalidOidcState);
+    }
+
+    if let Some(ref error) = params.error {
+        log::warn!("identity provider returned an error: {}", error);
+        return Err(OidcUnauthorized);
+    }
+    let code = match params.code {
+        Some(ref code) => code,
+        None => return Err(OidcUnauthorized),
+    };
+
+    let user = match oidc_login_service.login(code, &nonce).await {
+        Ok(user) => user,
+        Err(
+            e @ (Error::IdentityProviderError(_)
+            | Error::OidcNonceMismatch
+            | Error::OidcInvalidUserCodeClaim
+            | Error::OidcUserNotFound),
+        ) => {
+            log::warn!("failed to log in with the identity provider: {:?}", e);
+            return Err(OidcUnauthorized);
+        }
+        Err(Error::UserDeactivated) => return Err(UserDeactivated),
+        Err(e) => return Err(e.into()),
+    };
+
+    clear_impersonation(&session);
+    session.renew();
+
+    // IdP経由でもパスワードログインと同じく二要素認証を要求し、POST /login/totp で完了させる
+    let requirement = service.two_factor_service().requirement(&user).await?;
+    // 二要素認証が完了するまでは、それまでログインしていたユーザーとしても扱わない
+    if requirement != TwoFactorRequirement::NotRequired {
+        session.remove("userID");
+        session.remove("userName");
+        session.remove("isAdmin");
+    }
+    let location = match requirement {
+        TwoFactorRequirement::NotRequired => {
+            session.insert("userID", user.id)?;
+            session.insert("userName", user.name)?;
+            session.insert("isAdmin", user.type_ == UserType::Teacher)?;
+            "/"
+        }
+        TwoFactorRequirement::Verify => {
+            session.insert("pendingUserID", user.id)?;
+            "/login?two_factor=verify"
+        }
+        TwoFactorRequirement::Enroll => {
+            session.insert("pendingUserID", user.id)?;
+            "/login?two_factor=enroll"
+        }
+    };
+    Ok(HttpResponse::Found()
+        .insert_header((actix_web::http::header::LOCATION, location))
+        .finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::oidc_callback::{oidc_callback, OidcCallbackQuery};
+    use actix_session::SessionExt;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::user::{User, UserCode, UserID};
+    use isucholar_core::models::user_type::UserType;
+    use isucholar_core::services::error::Error::OidcUserNotFound;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+    use isucholar_core::services::two_factor_service::TwoFactorRequirement;
+
+    fn query(code: &str, state: &str) -> web::Query<OidcCallbackQuery> {
+        web::Query(OidcCallbackQuery {
+            code: Some(code.to_string()),
+            state: Some(state.to_string()),
+            error: None,
+        })
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "InvalidOidcState")]
+    async fn test_state_mismatch_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .oidc_login_service
+            .expect_is_enabled()
+            .returning(|| true);
+        service.oidc_login_service.expect_login().never();
+
+        let req = TestRequest::with_uri("/login/oidc/callback").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("oidcState", "state");
+        let _ = session.insert("oidcNonce", "nonce");
+
+        oidc_callback(web::Data::new(service), session, query("code", "other"))
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "OidcUnauthorized")]
+    async fn test_user_not_found_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .oidc_login_service
+            .expect_is_enabled()
+            .returning(|| true);
+        service
+            .oidc_login_service
+            .expect_login()
+            .returning(|_, _| Err(OidcUserNotFound));
+
+        let req = TestRequest::with_uri("/login/oidc/callback").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("oidcState", "state");
+        let _ = session.insert("oidcNonce", "nonce");
+
+        oidc_callback(web::Data::new(service), session, query("code", "state"))
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .oidc_login_service
+            .expect_is_enabled()
+            .returning(|| true);
+        service
+            .oidc_login_service
+            .expect_login()
+            .withf(|code, nonce| code == "code" && nonce == "nonce")
+            .returning(|_, _| {
+                Ok(User {
+                    id: UserID::new("1".to_string()),
+                    code: UserCode::new("T00001".to_string().into()),
+                    name: "Teacher".to_string(),
+                    hashed_password: vec![],
+                    type_: UserType::Teacher,
+                    deactivated_at: None,
+                })
+            });
+        service
+            .two_factor_service
+            .expect_requirement()
+            .returning(|_| Ok(TwoFactorRequirement::NotRequired));
+
+        let req = TestRequest::with_uri("/login/oidc/callback").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("oidcState", "state");
+        let _ = session.insert("oidcNonce", "nonce");
+
+        let res = oidc_callback(
+            web::Data::new(service),
+            session.clone(),
+            query("code", "state"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::FOUND);
+        assert_eq!(
+            session.get::<String>("userID").unwrap(),
+            Some("1".to_string())
+        );
+        assert_eq!(session.get::<bool>("isAdmin").unwrap(), Some(true));
+        assert_eq!(session.get::<String>("oidcState").unwrap(), None);
+    }
+
+    #[actix_web::test]
+    async fn test_two_factor_pending_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .oidc_login_service
+            .expect_is_enabled()
+            .returning(|| true);
+        service.oidc_login_service.expect_login().returning(|_, _| {
+            Ok(User {
+                id: UserID::new("1".to_string()),
+                code: UserCode::new("T00001".to_string().into()),
+                name: "Teacher".to_string(),
+                hashed_password: vec![],
+                type_: UserType::Teacher,
+                deactivated_at: None,
+            })
+        });
+        service
+            .two_factor_service
+            .expect_requirement()
+            .returning(|_| Ok(TwoFactorRequirement::Verify));
+
+        let req = TestRequest::with_uri("/login/oidc/callback").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("oidcState", "state");
+        let _ = session.insert("oidcNonce", "nonce");
+        // 別のユーザーでログイン済みのセッション
+        let _ = session.insert("userID", "2");
+        let _ = session.insert("userName", "Admin");
+        let _ = session.insert("isAdmin", true);
+
+        let res = oidc_callback(
+            web::Data::new(service),
+            session.clone(),
+            query("code", "state"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::FOUND);
+        assert_eq!(
+            res.headers()
+                .get(actix_web::http::header::LOCATION)
+                .unwrap(),
+            "/login?two_factor=verify"
+        );
+        assert_eq!(
+            session.get::<String>("pendingUserID").unwrap(),
+            Some("1".to_string())
+        );
+        assert_eq!(session.get::<String>("userID").unwrap(), None);
+        assert_eq!(session.get::<String>("userName").unwrap(), None);
+        assert_eq!(session.get::<bool>("isAdmin").unwrap(), None);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::responses::error::ResponseError::OidcNotEnabled;
+use crate::responses::error::ResponseResult;
+use actix_web::{web, HttpResponse};
+use isucholar_core::services::oidc_login_service::{HaveOidcLoginService, OidcLoginService};
+
+// GET /login/oidc IdPの認可エンドポイントへリダイレクト
+pub async fn oidc_login<Service: HaveOidcLoginService>(
+    service: web::Data<Service>,
+    session: actix_session::Session,
+) -> ResponseResult<HttpResponse> {
+    let oidc_login_service = service.oidc_login_service();
+    if !oidc_login_service.is_enabled() {
+        return Err(OidcNotEnabled);
+    }
+
+    let req = oidc_login_service.authorization_request();
+    session.insert("oidcState", req.state)?;
+    session.insert("oidcNonce", req.nonce)?;
+
+    Ok(HttpResponse::Found()
+        .insert_header((actix_web::http::header::LOCATION, req.url))
+        .finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::oidc_login::oidc_login;
+    use actix_session::SessionExt;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+    use isucholar_core::services::oidc_login_service::OidcAuthorizationRequest;
+
+    #[actix_web::test]
+    #[should_panic(expected = "OidcNotEnabled")]
+    async fn test_disabled_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .oidc_login_service
+            .expect_is_enabled()
+            .returning(|| false);
+
+        let req = TestRequest::with_uri("/login/oidc").to_http_request();
+        let session = req.get_session();
+
+        oidc_login(web::Data::new(service), session).await.unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .oidc_login_service
+            .expect_is_enabled()
+            .returning(|| true);
+        service
+            .oidc_login_service
+            .expect_authorization_request()
+            .returning(|| OidcAuthorizationRequest {
+                url: "http://idp.example/authorize?state=state&nonce=nonce".to_string(),
+                state: "state".to_string(),
+                nonce: "nonce".to_string(),
+            });
+
+        let req = TestRequest::with_uri("/login/oidc").to_http_request();
+        let session = req.get_session();
+
+        let res = oidc_login(web::Data::new(service), session.clone())
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::FOUND);
+        assert_eq!(
+            res.headers()
+                .get(actix_web::http::header::LOCATION)
+                .unwrap(),
+            "http://idp.example/authorize?state=state&nonce=nonce"
+        );
+        assert_eq!(
+            session.get::<String>("oidcState").unwrap(),
+            Some("state".to_string())
+        );
+        assert_eq!(
+            session.get::<String>("oidcNonce").unwrap(),
+            Some("nonce".to_string())
+        );
+    }
+}
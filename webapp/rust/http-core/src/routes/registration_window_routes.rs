@@ -0,0 +1,32 @@
+use crate::middleware::IsAdmin;
+use crate::routes::registration_window_routes::add_registration_window::add_registration_window;
+use crate::routes::registration_window_routes::delete_registration_window::delete_registration_window;
+use crate::routes::registration_window_routes::get_registration_windows::get_registration_windows;
+use actix_web::{web, Scope};
+use isucholar_core::services::manager::ServiceManager;
+
+mod add_registration_window;
+mod delete_registration_window;
+mod get_registration_windows;
+
+pub fn get_registration_window_routes<Service: ServiceManager + 'static>() -> Scope {
+    web::scope("/registration_windows")
+        .service(
+            web::resource("")
+                .guard(actix_web::guard::Get())
+                .wrap(IsAdmin)
+                .to(get_registration_windows::<Service>),
+        )
+        .service(
+            web::resource("")
+                .guard(actix_web::guard::Post())
+                .wrap(IsAdmin)
+                .to(add_registration_window::<Service>),
+        )
+        .service(
+            web::resource("/{window_id}")
+                .guard(actix_web::guard::Delete())
+                .wrap(IsAdmin)
+                .to(delete_registration_window::<Service>),
+        )
+}
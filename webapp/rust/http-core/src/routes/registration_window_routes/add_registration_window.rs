@@ -0,0 +1,102 @@
+use crate::responses::error::ResponseError::InvalidRegistrationWindow;
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::registration_window::CreateRegistrationWindow;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// POST /api/registration_windows 履修登録期間を追加
+pub async fn add_registration_window<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+    req: web::Json<CreateRegistrationWindow>,
+) -> ResponseResult<HttpResponse> {
+    let audit_log = new_audit_log(&request, AuditAction::RegistrationWindowCreate)?;
+    let result = service
+        .registration_course_service()
+        .create_registration_window(&req, &audit_log)
+        .await;
+    let window = match result {
+        Ok(window) => window,
+        Err(Error::InvalidRegistrationWindow) => return Err(InvalidRegistrationWindow),
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(HttpResponse::Created().json(window))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::registration_window_routes::add_registration_window::add_registration_window;
+    use crate::test_util::admin_request;
+    use actix_web::http::StatusCode;
+    use actix_web::web;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::models::registration_window::{
+        CreateRegistrationWindow, RegistrationWindow, RegistrationWindowID,
+    };
+    use isucholar_core::services::error::Error::InvalidRegistrationWindow;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    fn request_body() -> CreateRegistrationWindow {
+        let starts_at = chrono::Utc::now().naive_utc();
+        CreateRegistrationWindow {
+            term: "2026 spring".to_string(),
+            cohort: None,
+            starts_at,
+            ends_at: starts_at + chrono::Duration::days(7),
+        }
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "InvalidRegistrationWindow")]
+    async fn test_invalid_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_create_registration_window()
+            .returning(|_, _| Err(InvalidRegistrationWindow));
+
+        add_registration_window(
+            web::Data::new(service),
+            admin_request("/api/registration_windows"),
+            web::Json(request_body()),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_create_registration_window()
+            .withf(|_, log| log.action == AuditAction::RegistrationWindowCreate)
+            .times(1)
+            .returning(|req, _| {
+                Ok(RegistrationWindow {
+                    id: RegistrationWindowID::new("window".to_string()),
+                    term: req.term.clone(),
+                    cohort: req.cohort.clone(),
+                    starts_at: req.starts_at,
+                    ends_at: req.ends_at,
+                })
+            });
+
+        let res = add_registration_window(
+            web::Data::new(service),
+            admin_request("/api/registration_windows"),
+            web::Json(request_body()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::CREATED);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::responses::error::ResponseError::RegistrationWindowNotFound;
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::registration_window::RegistrationWindowID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// DELETE /api/registration_windows/{window_id} 履修登録期間を削除
+pub async fn delete_registration_window<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    window_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let window_id = RegistrationWindowID::new(window_id.0.to_string());
+
+    let mut audit_log = new_audit_log(&request, AuditAction::RegistrationWindowDelete)?;
+    audit_log.before_summary = Some(serde_json::json!({ "id": window_id }).to_string());
+
+    let result = service
+        .registration_course_service()
+        .delete_registration_window(&window_id, &audit_log)
+        .await;
+    match result {
+        Ok(_) => {}
+        Err(Error::RegistrationWindowNotFound) => return Err(RegistrationWindowNotFound),
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::registration_window_routes::delete_registration_window::delete_registration_window;
+    use actix_session::SessionExt;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::services::error::Error::RegistrationWindowNotFound;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "RegistrationWindowNotFound")]
+    async fn test_not_found_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_delete_registration_window()
+            .returning(|_, _| Err(RegistrationWindowNotFound));
+
+        let req = TestRequest::with_uri("/api/registration_windows/1").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "admin");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
+
+        delete_registration_window(
+            web::Data::new(service),
+            web::Path::from(("1".to_string(),)),
+            req,
+        )
+        .await
+        .unwrap();
+    }
+}
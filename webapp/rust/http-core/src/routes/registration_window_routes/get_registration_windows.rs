@@ -0,0 +1,17 @@
+use crate::responses::error::ResponseResult;
+use actix_web::{web, HttpResponse};
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// GET /api/registration_windows 履修登録期間の一覧
+pub async fn get_registration_windows<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+) -> ResponseResult<HttpResponse> {
+    let windows = service
+        .registration_course_service()
+        .find_registration_windows()
+        .await?;
+
+    Ok(HttpResponse::Ok().json(windows))
+}
@@ -1,22 +1,157 @@
+use crate::middleware::IsAdmin;
+use crate::routes::user_routes::confirm_totp_enrollment::confirm_totp_enrollment;
+use crate::routes::user_routes::create_personal_access_token::create_personal_access_token;
+use crate::routes::user_routes::create_user::create_user;
+use crate::routes::user_routes::deactivate_user::deactivate_user;
+use crate::routes::user_routes::delete_personal_access_token::delete_personal_access_token;
+use crate::routes::user_routes::disable_totp::disable_totp;
+use crate::routes::user_routes::drop_course::drop_course;
+use crate::routes::user_routes::erase_personal_data::erase_personal_data;
+use crate::routes::user_routes::export_personal_data::export_personal_data;
 use crate::routes::user_routes::get_grades::get_grades;
+use crate::routes::user_routes::get_lottery_preferences::get_lottery_preferences;
 use crate::routes::user_routes::get_me::get_me;
+use crate::routes::user_routes::get_my_profile::get_my_profile;
+use crate::routes::user_routes::get_personal_access_tokens::get_personal_access_tokens;
 use crate::routes::user_routes::get_registered_courses::get_registered_courses;
+use crate::routes::user_routes::get_registration_requests::get_registration_requests;
+use crate::routes::user_routes::get_users::get_users;
+use crate::routes::user_routes::import_users::import_users;
 use crate::routes::user_routes::register_courses::register_courses;
+use crate::routes::user_routes::set_credit_limit::set_credit_limit;
+use crate::routes::user_routes::start_totp_enrollment::start_totp_enrollment;
+use crate::routes::user_routes::update_lottery_preferences::update_lottery_preferences;
+use crate::routes::user_routes::update_my_profile::update_my_profile;
+use crate::routes::user_routes::update_user::update_user;
+use crate::routes::user_routes::validate_courses::validate_courses;
 use actix_web::{web, Scope};
 use isucholar_core::services::manager::ServiceManager;
 
+mod confirm_totp_enrollment;
+mod create_personal_access_token;
+mod create_user;
+mod deactivate_user;
+mod delete_personal_access_token;
+mod disable_totp;
+mod drop_course;
+mod erase_personal_data;
+mod export_personal_data;
 mod get_grades;
+mod get_lottery_preferences;
 mod get_me;
+mod get_my_profile;
+mod get_personal_access_tokens;
 mod get_registered_courses;
+mod get_registration_requests;
+mod get_users;
+mod import_users;
 mod register_courses;
+mod set_credit_limit;
+mod start_totp_enrollment;
+mod update_lottery_preferences;
+mod update_my_profile;
+mod update_user;
+mod validate_courses;
+
+const IMPORT_USERS_PAYLOAD_LIMIT: usize = 16 * 1024 * 1024;
 
 pub fn get_user_routes<Service: ServiceManager + 'static>() -> Scope {
     web::scope("/users")
         .route("/me", web::get().to(get_me::<Service>))
+        .route("/me", web::patch().to(update_my_profile::<Service>))
+        .route("/me/profile", web::get().to(get_my_profile::<Service>))
         .route(
             "/me/courses",
             web::get().to(get_registered_courses::<Service>),
         )
         .route("/me/courses", web::put().to(register_courses::<Service>))
+        .route(
+            "/me/courses/validate",
+            web::post().to(validate_courses::<Service>),
+        )
+        .route(
+            "/me/courses/{course_id}",
+            web::delete().to(drop_course::<Service>),
+        )
+        .route(
+            "/me/lottery_preferences",
+            web::get().to(get_lottery_preferences::<Service>),
+        )
+        .route(
+            "/me/lottery_preferences",
+            web::put().to(update_lottery_preferences::<Service>),
+        )
+        .route(
+            "/me/registration_requests",
+            web::get().to(get_registration_requests::<Service>),
+        )
         .route("/me/grades", web::get().to(get_grades::<Service>))
+        .route(
+            "/me/tokens",
+            web::get().to(get_personal_access_tokens::<Service>),
+        )
+        .route(
+            "/me/tokens",
+            web::post().to(create_personal_access_token::<Service>),
+        )
+        .route(
+            "/me/tokens/{token_id}",
+            web::delete().to(delete_personal_access_token::<Service>),
+        )
+        .route("/me/totp", web::post().to(start_totp_enrollment::<Service>))
+        .route(
+            "/me/totp",
+            web::put().to(confirm_totp_enrollment::<Service>),
+        )
+        .route("/me/totp", web::delete().to(disable_totp::<Service>))
+        .service(
+            web::resource("")
+                .guard(actix_web::guard::Get())
+                .wrap(IsAdmin)
+                .to(get_users::<Service>),
+        )
+        .service(
+            web::resource("")
+                .guard(actix_web::guard::Post())
+                .wrap(IsAdmin)
+                .to(create_user::<Service>),
+        )
+        .service(
+            web::resource("/import")
+                .guard(actix_web::guard::Post())
+                // 数千人分のCSVを受け付けられるようにする
+                .app_data(web::PayloadConfig::new(IMPORT_USERS_PAYLOAD_LIMIT))
+                .wrap(IsAdmin)
+                .to(import_users::<Service>),
+        )
+        .service(
+            web::resource("/{user_id}")
+                .guard(actix_web::guard::Patch())
+                .wrap(IsAdmin)
+                .to(update_user::<Service>),
+        )
+        .service(
+            web::resource("/{user_id}/deactivate")
+                .guard(actix_web::guard::Post())
+                .wrap(IsAdmin)
+                .to(deactivate_user::<Service>),
+        )
+        .service(
+            web::resource("/{user_id}/credit_limit")
+                .guard(actix_web::guard::Put())
+                .wrap(IsAdmin)
+                .to(set_credit_limit::<Service>),
+        )
+        .service(
+            web::resource("/{user_id}/personal_data")
+                .guard(actix_web::guard::Get())
+                .wrap(IsAdmin)
+                .to(export_personal_data::<Service>),
+        )
+        .service(
+            web::resource("/{user_id}/erase")
+                .guard(actix_web::guard::Post())
+                .wrap(IsAdmin)
+                .to(erase_personal_data::<Service>),
+        )
 }
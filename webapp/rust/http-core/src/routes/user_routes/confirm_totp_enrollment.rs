@@ -0,0 +1,109 @@
+use crate::responses::error::ResponseError::{
+    InvalidTotpCode, PersonalAccessTokenForbidden, TotpAlreadyEnabled, TotpNotEnrolled,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::{get_user_info, is_authenticated_by_token};
+use actix_web::{web, HttpResponse};
+use isucholar_core::services::error::Error;
+use isucholar_core::services::two_factor_service::{HaveTwoFactorService, TwoFactorService};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ConfirmTotpEnrollmentRequest {
+    code: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConfirmTotpEnrollmentResponse {
+    recovery_codes: Vec<String>,
+}
+
+// PUT /api/users/me/totp コードを検証して二要素認証を有効化
+pub async fn confirm_totp_enrollment<Service: HaveTwoFactorService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+    req: web::Json<ConfirmTotpEnrollmentRequest>,
+) -> ResponseResult<HttpResponse> {
+    if is_authenticated_by_token(&request) {
+        return Err(PersonalAccessTokenForbidden);
+    }
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    let result = service
+        .two_factor_service()
+        .confirm_enrollment(&user_id, &req.code)
+        .await;
+    match result {
+        Ok(recovery_codes) => {
+            Ok(HttpResponse::Ok().json(ConfirmTotpEnrollmentResponse { recovery_codes }))
+        }
+        Err(Error::InvalidTotpCode) => Err(InvalidTotpCode),
+        Err(Error::TotpNotEnrolled) => Err(TotpNotEnrolled),
+        Err(Error::TotpAlreadyEnabled) => Err(TotpAlreadyEnabled),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::confirm_totp_enrollment::{
+        confirm_totp_enrollment, ConfirmTotpEnrollmentRequest,
+    };
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::services::error::Error;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "InvalidTotpCode")]
+    async fn test_invalid_code_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .two_factor_service
+            .expect_confirm_enrollment()
+            .returning(|_, _| Err(Error::InvalidTotpCode));
+
+        let req = TestRequest::with_uri("/users/me/totp").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+        let _ = session.insert("userName", "1");
+        let _ = session.insert("isAdmin", false);
+
+        let body = web::Json(ConfirmTotpEnrollmentRequest {
+            code: "000000".to_string(),
+        });
+        confirm_totp_enrollment(web::Data::new(service), req, body)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .two_factor_service
+            .expect_confirm_enrollment()
+            .withf(|user_id, code| user_id.inner() == "1" && code == "123456")
+            .returning(|_, _| Ok(vec!["abcde-12345".to_string()]));
+
+        let req = TestRequest::with_uri("/users/me/totp").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+        let _ = session.insert("userName", "1");
+        let _ = session.insert("isAdmin", false);
+
+        let body = web::Json(ConfirmTotpEnrollmentRequest {
+            code: "123456".to_string(),
+        });
+        let result = confirm_totp_enrollment(web::Data::new(service), req, body)
+            .await
+            .unwrap();
+        assert_eq!(result.status(), StatusCode::OK);
+        let body = to_bytes(result.into_body()).await.unwrap();
+        assert_eq!(body, r#"{"recovery_codes":["abcde-12345"]}"#);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::responses::error::ResponseError::{
+    InvalidPersonalAccessToken, PersonalAccessTokenForbidden,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::{get_user_info, is_authenticated_by_token};
+use actix_web::{web, HttpResponse};
+use chrono::NaiveDateTime;
+use isucholar_core::models::personal_access_token::{
+    CreatePersonalAccessToken, PersonalAccessTokenID,
+};
+use isucholar_core::models::personal_access_token_scope::PersonalAccessTokenScope;
+use isucholar_core::services::personal_access_token_service::{
+    HavePersonalAccessTokenService, PersonalAccessTokenService,
+};
+
+const MAX_EXPIRES_IN_DAYS: i64 = 365;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct CreatePersonalAccessTokenRequest {
+    name: String,
+    scope: PersonalAccessTokenScope,
+    expires_in_days: i64,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CreatePersonalAccessTokenResponse {
+    id: PersonalAccessTokenID,
+    name: String,
+    scope: PersonalAccessTokenScope,
+    expires_at: NaiveDateTime,
+    token: String,
+}
+
+// POST /api/users/me/tokens パーソナルアクセストークンを発行
+pub async fn create_personal_access_token<Service: HavePersonalAccessTokenService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+    req: web::Json<CreatePersonalAccessTokenRequest>,
+) -> ResponseResult<HttpResponse> {
+    // トークンから別のトークンを発行できないようにする
+    if is_authenticated_by_token(&request) {
+        return Err(PersonalAccessTokenForbidden);
+    }
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    if req.name.is_empty()
+        || req.name.chars().count() > 255
+        || req.expires_in_days < 1
+        || req.expires_in_days > MAX_EXPIRES_IN_DAYS
+    {
+        return Err(InvalidPersonalAccessToken);
+    }
+
+    let form = CreatePersonalAccessToken {
+        name: req.name.clone(),
+        scope: req.scope.clone(),
+        expires_in_days: req.expires_in_days,
+    };
+    let (personal_access_token, token) = service
+        .personal_access_token_service()
+        .create(&user_id, &form)
+        .await?;
+
+    Ok(
+        HttpResponse::Created().json(CreatePersonalAccessTokenResponse {
+            id: personal_access_token.id,
+            name: personal_access_token.name,
+            scope: personal_access_token.scope,
+            expires_at: personal_access_token.expires_at,
+            token,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::middleware::TokenUser;
+    use crate::routes::user_routes::create_personal_access_token::{
+        create_personal_access_token, CreatePersonalAccessTokenRequest,
+    };
+    use actix_session::SessionExt;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::{web, HttpMessage};
+    use isucholar_core::models::personal_access_token::{
+        PersonalAccessToken, PersonalAccessTokenID,
+    };
+    use isucholar_core::models::personal_access_token_scope::PersonalAccessTokenScope;
+    use isucholar_core::models::user::UserID;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "InvalidPersonalAccessToken")]
+    async fn test_invalid_expiration_case() {
+        let service = MockServiceManager::new();
+
+        let req = TestRequest::with_uri("/users/me/tokens").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+        let _ = session.insert("userName", "1");
+        let _ = session.insert("isAdmin", false);
+
+        let body = web::Json(CreatePersonalAccessTokenRequest {
+            name: "script".to_string(),
+            scope: PersonalAccessTokenScope::Read,
+            expires_in_days: 0,
+        });
+        create_personal_access_token(web::Data::new(service), req, body)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "PersonalAccessTokenForbidden")]
+    async fn test_token_user_case() {
+        let service = MockServiceManager::new();
+
+        let req = TestRequest::with_uri("/users/me/tokens").to_http_request();
+        req.extensions_mut().insert(TokenUser {
+            user_id: UserID::new("1".to_string()),
+            user_name: "1".to_string(),
+            is_admin: false,
+            scope: PersonalAccessTokenScope::Write,
+        });
+
+        let body = web::Json(CreatePersonalAccessTokenRequest {
+            name: "script".to_string(),
+            scope: PersonalAccessTokenScope::Read,
+            expires_in_days: 30,
+        });
+        create_personal_access_token(web::Data::new(service), req, body)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        let now = chrono::Utc::now().naive_utc();
+        let token = PersonalAccessToken {
+            id: PersonalAccessTokenID::new("1".to_string()),
+            user_id: UserID::new("1".to_string()),
+            name: "script".to_string(),
+            hashed_token: "hashed".to_string(),
+            scope: PersonalAccessTokenScope::Read,
+            expires_at: now + chrono::Duration::days(30),
+            created_at: now,
+        };
+        let t = token.clone();
+        service
+            .personal_access_token_service
+            .expect_create()
+            .withf(|uid, req| {
+                uid.inner() == "1" && req.name == "script" && req.expires_in_days == 30
+            })
+            .returning(move |_, _| Ok((t.clone(), "secret".to_string())));
+
+        let req = TestRequest::with_uri("/users/me/tokens").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+        let _ = session.insert("userName", "1");
+        let _ = session.insert("isAdmin", false);
+
+        let body = web::Json(CreatePersonalAccessTokenRequest {
+            name: "script".to_string(),
+            scope: PersonalAccessTokenScope::Read,
+            expires_in_days: 30,
+        });
+        let result = create_personal_access_token(web::Data::new(service), req, body)
+            .await
+            .unwrap();
+        assert_eq!(result.status(), StatusCode::CREATED);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::responses::error::ResponseError::{InvalidUserCode, InvalidUserName, UserConflict};
+use crate::responses::error::ResponseResult;
+use crate::routes::user_routes::get_users::UserResponse;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::user::CreateUser;
+use isucholar_core::models::user_type::UserType;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::user_service::{HaveUserService, UserService};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct CreateUserRequest {
+    code: String,
+    name: String,
+    #[serde(rename = "type")]
+    type_: UserType,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CreateUserResponse {
+    #[serde(flatten)]
+    user: UserResponse,
+    // 初期パスワードはこのレスポンスでのみ確認できる
+    initial_password: String,
+}
+
+// POST /api/users 新規ユーザー登録
+pub async fn create_user<Service: HaveUserService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+    req: web::Json<CreateUserRequest>,
+) -> ResponseResult<HttpResponse> {
+    let form = CreateUser {
+        code: req.code.clone(),
+        name: req.name.clone(),
+        type_: req.type_.clone(),
+    };
+
+    let audit_log = new_audit_log(&request, AuditAction::UserCreate)?;
+    let result = service.user_service().create(&form, &audit_log).await;
+    let (user, initial_password) = match result {
+        Ok(result) => result,
+        Err(Error::InvalidUserCode) => return Err(InvalidUserCode),
+        Err(Error::InvalidUserName) => return Err(InvalidUserName),
+        Err(Error::UserDuplicate) => return Err(UserConflict),
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(HttpResponse::Created().json(CreateUserResponse {
+        user: user.into(),
+        initial_password,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::create_user::{create_user, CreateUserRequest};
+    use crate::test_util::admin_request;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::web;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::models::user::{User, UserCode, UserID};
+    use isucholar_core::models::user_type::UserType;
+    use isucholar_core::services::error::Error::UserDuplicate;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+    use std::str::from_utf8;
+
+    fn body() -> web::Json<CreateUserRequest> {
+        web::Json(CreateUserRequest {
+            code: "S00001".to_string(),
+            name: "student".to_string(),
+            type_: UserType::Student,
+        })
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "UserConflict")]
+    async fn test_conflict_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .user_service
+            .expect_create()
+            .returning(|_, _| Err(UserDuplicate));
+
+        create_user(web::Data::new(service), admin_request("/api/users"), body())
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .user_service
+            .expect_create()
+            .withf(|_, audit_log| audit_log.action == AuditAction::UserCreate)
+            .times(1)
+            .returning(|req, _| {
+                Ok((
+                    User {
+                        id: UserID::new("1".to_string()),
+                        code: UserCode::new(req.code.clone().into()),
+                        name: req.name.clone(),
+                        hashed_password: b"hashed".to_vec(),
+                        type_: req.type_.clone(),
+                        deactivated_at: None,
+                    },
+                    "initialpassword0".to_string(),
+                ))
+            });
+
+        let res = create_user(web::Data::new(service), admin_request("/api/users"), body())
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::CREATED);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(
+            from_utf8(&body).unwrap(),
+            r#"{"id":"1","code":"S00001","name":"student","type":"student","deactivated_at":null,"initial_password":"initialpassword0"}"#
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crate::responses::error::ResponseError::{
+    PersonalAccessTokenForbidden, PersonalAccessTokenNotFound,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::{get_user_info, is_authenticated_by_token};
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::personal_access_token::PersonalAccessTokenID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::personal_access_token_service::{
+    HavePersonalAccessTokenService, PersonalAccessTokenService,
+};
+
+// DELETE /api/users/me/tokens/{token_id} パーソナルアクセストークンを失効
+pub async fn delete_personal_access_token<Service: HavePersonalAccessTokenService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+    token_id: web::Path<(String,)>,
+) -> ResponseResult<HttpResponse> {
+    if is_authenticated_by_token(&request) {
+        return Err(PersonalAccessTokenForbidden);
+    }
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    let token_id = PersonalAccessTokenID::new(token_id.0.to_string());
+
+    let result = service
+        .personal_access_token_service()
+        .delete(&user_id, &token_id)
+        .await;
+    match result {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(Error::PersonalAccessTokenNotFound) => Err(PersonalAccessTokenNotFound),
+        Err(e) => Err(e.into()),
+    }
+}
@@ -0,0 +1,98 @@
+use crate::responses::error::ResponseError::{
+    InvalidTotpCode, PersonalAccessTokenForbidden, TotpRequired,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::{get_user_info, is_authenticated_by_token};
+use actix_web::{web, HttpResponse};
+use isucholar_core::services::error::Error;
+use isucholar_core::services::two_factor_service::{HaveTwoFactorService, TwoFactorService};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct DisableTotpRequest {
+    // TOTPのコードかリカバリーコード
+    code: String,
+}
+
+// DELETE /api/users/me/totp 二要素認証を無効化
+pub async fn disable_totp<Service: HaveTwoFactorService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+    req: web::Json<DisableTotpRequest>,
+) -> ResponseResult<HttpResponse> {
+    if is_authenticated_by_token(&request) {
+        return Err(PersonalAccessTokenForbidden);
+    }
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    let result = service
+        .two_factor_service()
+        .disable(&user_id, &req.code)
+        .await;
+    match result {
+        Ok(_) => Ok(HttpResponse::NoContent().finish()),
+        Err(Error::TotpRequired) => Err(TotpRequired),
+        Err(Error::InvalidTotpCode) => Err(InvalidTotpCode),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::disable_totp::{disable_totp, DisableTotpRequest};
+    use actix_session::SessionExt;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::services::error::Error;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "InvalidTotpCode")]
+    async fn test_invalid_code_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .two_factor_service
+            .expect_disable()
+            .returning(|_, _| Err(Error::InvalidTotpCode));
+
+        let req = TestRequest::with_uri("/users/me/totp").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+        let _ = session.insert("userName", "1");
+        let _ = session.insert("isAdmin", false);
+
+        let body = web::Json(DisableTotpRequest {
+            code: "000000".to_string(),
+        });
+        disable_totp(web::Data::new(service), req, body)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .two_factor_service
+            .expect_disable()
+            .withf(|user_id, code| user_id.inner() == "1" && code == "123456")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let req = TestRequest::with_uri("/users/me/totp").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+        let _ = session.insert("userName", "1");
+        let _ = session.insert("isAdmin", false);
+
+        let body = web::Json(DisableTotpRequest {
+            code: "123456".to_string(),
+        });
+        let result = disable_totp(web::Data::new(service), req, body)
+            .await
+            .unwrap();
+        assert_eq!(result.status(), StatusCode::NO_CONTENT);
+    }
+}
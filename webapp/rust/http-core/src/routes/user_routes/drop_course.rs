@@ -0,0 +1,104 @@
+use crate::responses::error::ResponseError::{
+    CourseNotDroppable, CourseNotFound, RegistrationAlready,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::drop_outcome::DropOutcome;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+#[derive(Debug, serde::Serialize)]
+pub struct DropCourseResponse {
+    outcome: DropOutcome,
+}
+
+// DELETE /api/users/me/courses/{course_id} 履修登録の取り消し
+pub async fn drop_course<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    course_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+    let course_id = CourseID::new(course_id.0.to_string());
+
+    let result = service
+        .registration_course_service()
+        .drop_course(&user_id, &course_id)
+        .await;
+    match result {
+        Ok(outcome) => Ok(HttpResponse::Ok().json(DropCourseResponse { outcome })),
+        Err(Error::CourseNotFound) => Err(CourseNotFound),
+        Err(Error::RegistrationAlready) => Err(RegistrationAlready),
+        Err(Error::CourseNotDroppable) => Err(CourseNotDroppable),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::drop_course::drop_course;
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::{web, ResponseError};
+    use isucholar_core::models::drop_outcome::DropOutcome;
+    use isucholar_core::services::error::Error::CourseNotDroppable;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    fn student_request() -> actix_web::HttpRequest {
+        let req = TestRequest::with_uri("/api/users/me/courses/course").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "student");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+        req
+    }
+
+    #[actix_web::test]
+    async fn test_not_droppable_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_drop_course()
+            .returning(|_, _| Err(CourseNotDroppable));
+
+        let err = drop_course(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            student_request(),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.error_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_withdrawn_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_drop_course()
+            .withf(|user_id, course_id| {
+                user_id.inner() == "student" && course_id.inner() == "course"
+            })
+            .returning(|_, _| Ok(DropOutcome::Withdrawn));
+
+        let res = drop_course(
+            web::Data::new(service),
+            web::Path::from(("course".to_string(),)),
+            student_request(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(body, r#"{"outcome":"withdrawn"}"#);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::responses::error::ResponseError::UserNotFound;
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::user::UserID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::personal_data_service::{
+    HavePersonalDataService, PersonalDataService,
+};
+
+// POST /api/users/{user_id}/erase ユーザーの個人データの消去
+pub async fn erase_personal_data<Service: HavePersonalDataService>(
+    service: web::Data<Service>,
+    user_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let user_id = UserID::new(user_id.0.to_string());
+
+    let mut audit_log = new_audit_log(&request, AuditAction::PersonalDataErase)?;
+    audit_log.target_user_id = Some(user_id.clone());
+
+    match service
+        .personal_data_service()
+        .erase(&user_id, &audit_log)
+        .await
+    {
+        Ok(_) => {}
+        Err(Error::UserNotFound) => return Err(UserNotFound),
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::erase_personal_data::erase_personal_data;
+    use actix_session::SessionExt;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::services::error::Error::UserNotFound;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "UserNotFound")]
+    async fn test_not_found_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .personal_data_service
+            .expect_erase()
+            .returning(|_, _| Err(UserNotFound));
+
+        let req = TestRequest::with_uri("/api/users/1/erase").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "admin");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
+        erase_personal_data(
+            web::Data::new(service),
+            web::Path::from(("1".to_string(),)),
+            req,
+        )
+        .await
+        .unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+use crate::responses::error::ResponseError::UserNotFound;
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::web;
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::user::UserID;
+use isucholar_core::services::audit_log_service::{AuditLogService, HaveAuditLogService};
+use isucholar_core::services::error::Error;
+use isucholar_core::services::personal_data_service::{
+    HavePersonalDataService, PersonalDataService,
+};
+
+// GET /api/users/{user_id}/personal_data ユーザーの個人データをzip形式でダウンロード
+pub async fn export_personal_data<Service: HavePersonalDataService + HaveAuditLogService>(
+    service: web::Data<Service>,
+    user_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<actix_files::NamedFile> {
+    let user_id = UserID::new(user_id.0.to_string());
+
+    let zip_file_path = match service.personal_data_service().export(&user_id).await {
+        Ok(zip_file_path) => zip_file_path,
+        Err(Error::UserNotFound) => return Err(UserNotFound),
+        Err(e) => return Err(e.into()),
+    };
+    let file = actix_files::NamedFile::open(zip_file_path)?;
+
+    let mut audit_log = new_audit_log(&request, AuditAction::PersonalDataExport)?;
+    audit_log.target_user_id = Some(user_id);
+    service.audit_log_service().record(&audit_log).await?;
+
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::export_personal_data::export_personal_data;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::services::error::Error::UserNotFound;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "UserNotFound")]
+    async fn test_not_found_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .personal_data_service
+            .expect_export()
+            .returning(|_| Err(UserNotFound));
+        service.audit_log_service.expect_record().never();
+
+        let req = TestRequest::with_uri("/api/users/1/personal_data").to_http_request();
+        export_personal_data(
+            web::Data::new(service),
+            web::Path::from(("1".to_string(),)),
+            req,
+        )
+        .await
+        .unwrap();
+    }
+}
@@ -15,9 +15,9 @@ pub async fn get_grades<
     Service: HaveClassService + HaveRegistrationCourseService + HaveGradeSummaryService,
 >(
     service: web::Data<Service>,
-    session: actix_session::Session,
+    request: actix_web::HttpRequest,
 ) -> ResponseResult<HttpResponse> {
-    let (user_id, _, _) = get_user_info(session)?;
+    let (user_id, _, _) = get_user_info(&request)?;
 
     let registered_courses = service
         .registration_course_service()
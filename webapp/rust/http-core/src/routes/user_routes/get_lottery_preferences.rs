@@ -0,0 +1,21 @@
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// GET /api/users/me/lottery_preferences 抽選科目の希望順位を取得
+pub async fn get_lottery_preferences<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    let preferences = service
+        .registration_course_service()
+        .find_lottery_preferences(&user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(preferences))
+}
@@ -1,6 +1,7 @@
 use crate::responses::error::ResponseError::UserNotFound;
 use crate::responses::error::ResponseResult;
-use crate::routes::util::get_user_info;
+use crate::routes::util::{get_impersonation, get_user_info, is_authenticated_by_token};
+use actix_session::SessionExt;
 use actix_web::{web, HttpResponse};
 use isucholar_core::models::user::UserCode;
 use isucholar_core::services::user_service::{HaveUserService, UserService};
@@ -10,14 +11,31 @@ pub struct GetMeResponse {
     code: UserCode,
     name: String,
     is_admin: bool,
+    // 管理者が学生としての閲覧中であれば設定する
+    impersonation: Option<GetMeImpersonation>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct GetMeImpersonation {
+    impersonator_name: String,
+    expires_at: i64,
 }
 
 // GET /api/users/me 自身の情報を取得
 pub async fn get_me<Service: HaveUserService>(
     service: web::Data<Service>,
-    session: actix_session::Session,
+    request: actix_web::HttpRequest,
 ) -> ResponseResult<HttpResponse> {
-    let (user_id, user_name, is_admin) = get_user_info(session)?;
+    let (user_id, user_name, is_admin) = get_user_info(&request)?;
+
+    let impersonation = if is_authenticated_by_token(&request) {
+        None
+    } else {
+        get_impersonation(&request.get_session())?.map(|impersonation| GetMeImpersonation {
+            impersonator_name: impersonation.impersonator_name,
+            expires_at: impersonation.expires_at,
+        })
+    };
 
     let user_code = service.user_service().find_code_by_id(&user_id).await?;
 
@@ -27,19 +45,22 @@ pub async fn get_me<Service: HaveUserService>(
             code: user_code,
             name: user_name,
             is_admin,
+            impersonation,
         })),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::routes::user_routes::get_me::{get_me, GetMeResponse};
+    use crate::middleware::TokenUser;
+    use crate::routes::user_routes::get_me::{get_me, GetMeImpersonation, GetMeResponse};
     use actix_session::SessionExt;
     use actix_web::body::to_bytes;
     use actix_web::http::StatusCode;
     use actix_web::test::TestRequest;
-    use actix_web::web;
-    use isucholar_core::models::user::UserCode;
+    use actix_web::{web, HttpMessage};
+    use isucholar_core::models::personal_access_token_scope::PersonalAccessTokenScope;
+    use isucholar_core::models::user::{UserCode, UserID};
     use isucholar_core::services::error::Error::TestError;
     use isucholar_core::services::manager::tests::MockServiceManager;
     use std::str::from_utf8;
@@ -61,7 +82,7 @@ mod tests {
         let _ = session.insert("userName", "1");
         let _ = session.insert("isAdmin", false);
 
-        get_me(web::Data::new(service), session).await.unwrap();
+        get_me(web::Data::new(service), req).await.unwrap();
     }
 
     #[actix_web::test]
@@ -81,7 +102,7 @@ mod tests {
         let _ = session.insert("userName", "1");
         let _ = session.insert("isAdmin", false);
 
-        get_me(web::Data::new(service), session).await.unwrap();
+        get_me(web::Data::new(service), req).await.unwrap();
     }
 
     #[actix_web::test]
@@ -100,12 +121,81 @@ mod tests {
         let _ = session.insert("userName", "1");
         let _ = session.insert("isAdmin", false);
 
-        let result = get_me(web::Data::new(service), session).await.unwrap();
+        let result = get_me(web::Data::new(service), req).await.unwrap();
         assert_eq!(result.status(), StatusCode::OK);
         let expected = GetMeResponse {
             code: UserCode::new("abc".to_string().into()),
             name: "1".to_string(),
             is_admin: false,
+            impersonation: None,
+        };
+        let expected = serde_json::to_string(&expected).unwrap();
+        let body = to_bytes(result.into_body()).await.unwrap();
+
+        assert_eq!(from_utf8(&body).unwrap(), expected)
+    }
+
+    #[actix_web::test]
+    async fn test_token_user_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .user_service
+            .expect_find_code_by_id()
+            .withf(|uid| uid.inner() == "2")
+            .returning(|_| Ok(Some(UserCode::new("def".to_string().into()))));
+
+        let req = TestRequest::with_uri("/user/me").to_http_request();
+        req.extensions_mut().insert(TokenUser {
+            user_id: UserID::new("2".to_string()),
+            user_name: "2".to_string(),
+            is_admin: true,
+            scope: PersonalAccessTokenScope::Read,
+        });
+
+        let result = get_me(web::Data::new(service), req).await.unwrap();
+        assert_eq!(result.status(), StatusCode::OK);
+        let expected = GetMeResponse {
+            code: UserCode::new("def".to_string().into()),
+            name: "2".to_string(),
+            is_admin: true,
+            impersonation: None,
+        };
+        let expected = serde_json::to_string(&expected).unwrap();
+        let body = to_bytes(result.into_body()).await.unwrap();
+
+        assert_eq!(from_utf8(&body).unwrap(), expected)
+    }
+
+    #[actix_web::test]
+    async fn test_impersonation_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .user_service
+            .expect_find_code_by_id()
+            .withf(|uid| uid.inner() == "2")
+            .returning(|_| Ok(Some(UserCode::new("S00001".to_string().into()))));
+
+        let req = TestRequest::with_uri("/user/me").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "2");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+        let _ = session.insert("impersonatorID", "1");
+        let _ = session.insert("impersonatorName", "admin");
+        let _ = session.insert("impersonationExpiresAt", 1700000000i64);
+
+        let result = get_me(web::Data::new(service), req).await.unwrap();
+        assert_eq!(result.status(), StatusCode::OK);
+        let expected = GetMeResponse {
+            code: UserCode::new("S00001".to_string().into()),
+            name: "student".to_string(),
+            is_admin: false,
+            impersonation: Some(GetMeImpersonation {
+                impersonator_name: "admin".to_string(),
+                expires_at: 1700000000,
+            }),
         };
         let expected = serde_json::to_string(&expected).unwrap();
         let body = to_bytes(result.into_body()).await.unwrap();
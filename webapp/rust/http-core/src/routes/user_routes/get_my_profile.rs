@@ -0,0 +1,52 @@
+use crate::responses::error::ResponseError::UserNotFound;
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::user::{User, UserCode};
+use isucholar_core::models::user_profile::UserProfile;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::user_service::{HaveUserService, UserService};
+
+#[derive(Debug, serde::Serialize)]
+pub struct NotificationPreferences {
+    pub announcements: bool,
+    pub course_updates: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ProfileResponse {
+    code: UserCode,
+    name: String,
+    name_kana: Option<String>,
+    email: Option<String>,
+    notification_preferences: NotificationPreferences,
+}
+
+impl ProfileResponse {
+    pub fn new(user: User, profile: UserProfile) -> Self {
+        Self {
+            code: user.code,
+            name: user.name,
+            name_kana: profile.name_kana,
+            email: profile.email,
+            notification_preferences: NotificationPreferences {
+                announcements: profile.notify_announcements,
+                course_updates: profile.notify_course_updates,
+            },
+        }
+    }
+}
+
+// GET /api/users/me/profile 自身のプロフィールを取得
+pub async fn get_my_profile<Service: HaveUserService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    match service.user_service().find_profile(&user_id).await {
+        Ok((user, profile)) => Ok(HttpResponse::Ok().json(ProfileResponse::new(user, profile))),
+        Err(Error::UserNotFound) => Err(UserNotFound),
+        Err(e) => Err(e.into()),
+    }
+}
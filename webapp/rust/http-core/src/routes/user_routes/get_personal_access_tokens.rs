@@ -0,0 +1,45 @@
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use chrono::NaiveDateTime;
+use isucholar_core::models::personal_access_token::PersonalAccessTokenID;
+use isucholar_core::models::personal_access_token_scope::PersonalAccessTokenScope;
+use isucholar_core::services::personal_access_token_service::{
+    HavePersonalAccessTokenService, PersonalAccessTokenService,
+};
+
+#[derive(Debug, serde::Serialize)]
+pub struct GetPersonalAccessTokenResponseContent {
+    id: PersonalAccessTokenID,
+    name: String,
+    scope: PersonalAccessTokenScope,
+    expires_at: NaiveDateTime,
+    created_at: NaiveDateTime,
+}
+
+// GET /api/users/me/tokens 発行済みのパーソナルアクセストークン一覧
+pub async fn get_personal_access_tokens<Service: HavePersonalAccessTokenService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    let tokens = service
+        .personal_access_token_service()
+        .find_all_by_user_id(&user_id)
+        .await?;
+
+    // 結果が0件の時は空配列を返却
+    let res = tokens
+        .into_iter()
+        .map(|token| GetPersonalAccessTokenResponseContent {
+            id: token.id,
+            name: token.name,
+            scope: token.scope,
+            expires_at: token.expires_at,
+            created_at: token.created_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(HttpResponse::Ok().json(res))
+}
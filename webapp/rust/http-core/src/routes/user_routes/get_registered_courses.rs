@@ -7,9 +7,9 @@ use isucholar_core::services::course_service::{CourseService, HaveCourseService}
 // GET /api/users/me/courses 履修中の科目一覧取得
 pub async fn get_registered_courses<Service: HaveCourseService>(
     service: web::Data<Service>,
-    session: actix_session::Session,
+    request: actix_web::HttpRequest,
 ) -> ResponseResult<HttpResponse> {
-    let (user_id, _, _) = get_user_info(session)?;
+    let (user_id, _, _) = get_user_info(&request)?;
 
     let course_with_teachers = service
         .course_service()
@@ -60,7 +60,7 @@ mod tests {
         let _ = session.insert("userName", "1");
         let _ = session.insert("isAdmin", false);
 
-        get_registered_courses(web::Data::new(service), session)
+        get_registered_courses(web::Data::new(service), req)
             .await
             .unwrap();
     }
@@ -81,7 +81,7 @@ mod tests {
         let _ = session.insert("userName", "1");
         let _ = session.insert("isAdmin", false);
 
-        let result = get_registered_courses(web::Data::new(service), session)
+        let result = get_registered_courses(web::Data::new(service), req)
             .await
             .unwrap();
         assert_eq!(result.status(), StatusCode::OK);
@@ -0,0 +1,57 @@
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// GET /api/users/me/registration_requests 承認待ちの履修申請の科目IDを取得
+pub async fn get_registration_requests<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    let course_ids = service
+        .registration_course_service()
+        .find_registration_request_course_ids(&user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(course_ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::get_registration_requests::get_registration_requests;
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::course::CourseID;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_find_registration_request_course_ids()
+            .withf(|user_id| user_id.inner() == "1")
+            .returning(|_| Ok(vec![CourseID::new("course".to_string())]));
+
+        let req = TestRequest::with_uri("/api/users/me/registration_requests").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+        let _ = session.insert("userName", "Student");
+        let _ = session.insert("isAdmin", false);
+
+        let res = get_registration_requests(web::Data::new(service), req)
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(body, r#"["course"]"#);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::responses::error::ResponseError::InvalidPage;
+use crate::responses::error::ResponseResult;
+use actix_web::{web, HttpResponse};
+use chrono::NaiveDateTime;
+use isucholar_core::models::user::{User, UserCode, UserID};
+use isucholar_core::models::user_type::UserType;
+use isucholar_core::repos::user_repository::SearchUsersQuery;
+use isucholar_core::services::user_service::{HaveUserService, UserService};
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct GetUsersQuery {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    type_: Option<UserType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct UserResponse {
+    pub id: UserID,
+    pub code: UserCode,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: UserType,
+    pub deactivated_at: Option<NaiveDateTime>,
+}
+
+impl From<User> for UserResponse {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            code: user.code,
+            name: user.name,
+            type_: user.type_,
+            deactivated_at: user.deactivated_at,
+        }
+    }
+}
+
+// GET /api/users ユーザー一覧
+pub async fn get_users<Service: HaveUserService>(
+    service: web::Data<Service>,
+    params: web::Query<GetUsersQuery>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    let page = if let Some(ref page_str) = params.page {
+        match page_str.parse() {
+            Ok(page) if page > 0 => page,
+            _ => return Err(InvalidPage),
+        }
+    } else {
+        1
+    };
+    let limit = 20;
+    let offset = limit * (page - 1);
+
+    let query = SearchUsersQuery {
+        type_: params.type_.clone(),
+        code_prefix: params.code_prefix.clone(),
+    };
+    let mut users = service
+        .user_service()
+        .find_all(&query, limit, offset)
+        .await?;
+
+    let uri = request.uri();
+    let mut params = params.into_inner();
+    let mut links = Vec::new();
+    if page > 1 {
+        params.page = Some(format!("{}", page - 1));
+        links.push(format!(
+            "<{}?{}>; rel=\"prev\"",
+            uri.path(),
+            serde_urlencoded::to_string(&params)?
+        ));
+    }
+    if users.len() as i64 > limit {
+        params.page = Some(format!("{}", page + 1));
+        links.push(format!(
+            "<{}?{}>; rel=\"next\"",
+            uri.path(),
+            serde_urlencoded::to_string(&params)?
+        ));
+    }
+
+    if users.len() as i64 == limit + 1 {
+        users.truncate(users.len() - 1);
+    }
+
+    let res = users
+        .into_iter()
+        .map(UserResponse::from)
+        .collect::<Vec<_>>();
+
+    let mut builder = HttpResponse::Ok();
+    if !links.is_empty() {
+        builder.insert_header((actix_web::http::header::LINK, links.join(",")));
+    }
+    Ok(builder.json(res))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::get_users::{get_users, GetUsersQuery};
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::user::{User, UserCode, UserID};
+    use isucholar_core::models::user_type::UserType;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+    use std::str::from_utf8;
+
+    #[actix_web::test]
+    async fn test_filter_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .user_service
+            .expect_find_all()
+            .withf(|query, limit, offset| {
+                query.type_ == Some(UserType::Student)
+                    && query.code_prefix.as_deref() == Some("S0")
+                    && *limit == 20
+                    && *offset == 20
+            })
+            .returning(|_, _, _| {
+                Ok(vec![User {
+                    id: UserID::new("1".to_string()),
+                    code: UserCode::new("S00001".to_string().into()),
+                    name: "student".to_string(),
+                    hashed_password: b"hashed".to_vec(),
+                    type_: UserType::Student,
+                    deactivated_at: None,
+                }])
+            });
+
+        let req = TestRequest::with_uri("/api/users?type=student&code_prefix=S0&page=2")
+            .to_http_request();
+        let params = web::Query(GetUsersQuery {
+            type_: Some(UserType::Student),
+            code_prefix: Some("S0".to_string()),
+            page: Some("2".to_string()),
+        });
+
+        let res = get_users(web::Data::new(service), params, req)
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(actix_web::http::header::LINK).unwrap(),
+            "</api/users?type=student&code_prefix=S0&page=1>; rel=\"prev\""
+        );
+
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(
+            from_utf8(&body).unwrap(),
+            r#"[{"id":"1","code":"S00001","name":"student","type":"student","deactivated_at":null}]"#
+        );
+    }
+}
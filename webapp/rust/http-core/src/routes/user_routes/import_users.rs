@@ -0,0 +1,236 @@
+use crate::responses::error::ResponseError::InvalidFile;
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::user_import::ImportUserRow;
+use isucholar_core::services::user_service::{HaveUserService, UserService};
+
+const CSV_HEADER: [&str; 4] = ["code", "name", "type", "password"];
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ImportUsersQuery {
+    #[serde(default)]
+    dry_run: bool,
+}
+
+// RFC 4180形式のCSVを、レコードの開始行番号とフィールドの組に分解する
+fn parse_csv(body: &str) -> Option<Vec<(u64, Vec<String>)>> {
+    let body = body.strip_prefix('\u{feff}').unwrap_or(body);
+
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            '"' => return None,
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                // 空行は読み飛ばす
+                if fields.len() > 1 || !fields[0].is_empty() {
+                    records.push((record_line, std::mem::take(&mut fields)));
+                }
+                fields.clear();
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return None;
+    }
+    if !fields.is_empty() || !field.is_empty() {
+        fields.push(field);
+        records.push((record_line, fields));
+    }
+
+    Some(records)
+}
+
+// POST /api/users/import CSVによるユーザーの一括登録
+pub async fn import_users<Service: HaveUserService>(
+    service: web::Data<Service>,
+    params: web::Query<ImportUsersQuery>,
+    request: actix_web::HttpRequest,
+    body: web::Bytes,
+) -> ResponseResult<HttpResponse> {
+    let body = std::str::from_utf8(&body).map_err(|_| InvalidFile)?;
+    let mut records = parse_csv(body).ok_or(InvalidFile)?.into_iter();
+
+    // 1行目はヘッダーで、passwordの列は省略できる
+    let header = records.next().ok_or(InvalidFile)?.1;
+    if header.len() < 3
+        || header.len() > CSV_HEADER.len()
+        || header
+            .iter()
+            .zip(CSV_HEADER)
+            .any(|(column, expected)| column.trim() != expected)
+    {
+        return Err(InvalidFile);
+    }
+
+    let rows = records
+        .map(|(line, mut fields)| {
+            let too_many_fields = fields.len() > header.len();
+            fields.resize(CSV_HEADER.len(), String::new());
+            let mut fields = fields.into_iter();
+            ImportUserRow {
+                line,
+                code: fields.next().unwrap(),
+                name: fields.next().unwrap(),
+                type_: fields.next().unwrap(),
+                password: fields.next().filter(|password| !password.is_empty()),
+                too_many_fields,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // 監査ログは実際に登録した場合のみ記録される
+    let audit_log = new_audit_log(&request, AuditAction::UserImport)?;
+    let report = service
+        .user_service()
+        .import_users(&rows, params.dry_run, &audit_log)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::import_users::{import_users, parse_csv, ImportUsersQuery};
+    use actix_session::SessionExt;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::models::user_import::{ImportUserResult, ImportUsersReport};
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[test]
+    fn test_parse_csv() {
+        let body = "\u{feff}code,name,type\r\nS00001,\"Doe, \"\"John\"\"\",student\r\n\r\nS00002,\"multi\nline\",student";
+        assert_eq!(
+            parse_csv(body).unwrap(),
+            vec![
+                (
+                    1,
+                    vec!["code".to_string(), "name".to_string(), "type".to_string()]
+                ),
+                (
+                    2,
+                    vec![
+                        "S00001".to_string(),
+                        "Doe, \"John\"".to_string(),
+                        "student".to_string()
+                    ]
+                ),
+                (
+                    4,
+                    vec![
+                        "S00002".to_string(),
+                        "multi\nline".to_string(),
+                        "student".to_string()
+                    ]
+                ),
+            ]
+        );
+        assert!(parse_csv("code,\"name").is_none());
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "InvalidFile")]
+    async fn test_invalid_header_case() {
+        let mut service = MockServiceManager::new();
+
+        service.user_service.expect_import_users().never();
+
+        let req = TestRequest::with_uri("/api/users/import").to_http_request();
+        import_users(
+            web::Data::new(service),
+            web::Query(ImportUsersQuery { dry_run: false }),
+            req,
+            web::Bytes::from_static(b"name,code,type\nS00001,student,student\n"),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .user_service
+            .expect_import_users()
+            .withf(|rows, dry_run, audit_log| {
+                rows.len() == 3
+                    && rows[0].line == 2
+                    && rows[0].password.is_none()
+                    && !rows[0].too_many_fields
+                    && rows[1].password.as_deref() == Some("password1")
+                    && !rows[1].too_many_fields
+                    && rows[2].too_many_fields
+                    && !dry_run
+                    && audit_log.action == AuditAction::UserImport
+            })
+            .times(1)
+            .returning(|rows, dry_run, _| {
+                Ok(ImportUsersReport {
+                    dry_run,
+                    imported: true,
+                    results: rows
+                        .iter()
+                        .map(|row| ImportUserResult {
+                            line: row.line,
+                            code: row.code.clone(),
+                            errors: vec![],
+                            user_id: None,
+                            initial_password: None,
+                        })
+                        .collect(),
+                })
+            });
+
+        let req = TestRequest::with_uri("/api/users/import").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "admin");
+        let _ = session.insert("userName", "teacher");
+        let _ = session.insert("isAdmin", true);
+
+        let res = import_users(
+            web::Data::new(service),
+            web::Query(ImportUsersQuery { dry_run: false }),
+            req,
+            web::Bytes::from_static(
+                b"code,name,type,password\nS00001,student,student,\nT00001,teacher,teacher,password1\nS00002,student,student,password2,extra\n",
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), actix_web::http::StatusCode::OK);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::requests::register_course_request::RegisterCourseRequestContent;
 use crate::responses::error::ResponseResult;
+use crate::responses::register_courses_error_response::RegisterCoursesErrorResponse;
 use crate::routes::util::get_user_info;
 use actix_web::{web, HttpResponse};
 use isucholar_core::models::course::CourseID;
@@ -11,10 +12,10 @@ use isucholar_core::services::registration_course_service::{
 // PUT /api/users/me/courses 履修登録
 pub async fn register_courses<Service: HaveRegistrationCourseService>(
     service: web::Data<Service>,
-    session: actix_session::Session,
+    request: actix_web::HttpRequest,
     req: web::Json<Vec<RegisterCourseRequestContent>>,
 ) -> ResponseResult<HttpResponse> {
-    let (user_id, _, _) = get_user_info(session)?;
+    let (user_id, _, _) = get_user_info(&request)?;
 
     let mut req = req.into_inner();
     req.sort_by(|x, y| x.id.cmp(&y.id));
@@ -29,9 +30,104 @@ pub async fn register_courses<Service: HaveRegistrationCourseService>(
         Ok(_) => Ok(HttpResponse::Ok().finish()),
         Err(e) => match e {
             Error::RegistrationCourseValidationError(errors) => {
-                Ok(HttpResponse::BadRequest().json(errors))
+                Ok(HttpResponse::BadRequest().json(RegisterCoursesErrorResponse::from(*errors)))
             }
             _ => Err(e.into()),
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::requests::register_course_request::RegisterCourseRequestContent;
+    use crate::routes::user_routes::register_courses::register_courses;
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::course::CourseID;
+    use isucholar_core::services::error::{
+        Error, RegistrationCourseValidationError, ScheduleConflict,
+    };
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    async fn test_prerequisite_not_met_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_create()
+            .returning(|_, _| {
+                Err(Error::RegistrationCourseValidationError(Box::new(
+                    RegistrationCourseValidationError {
+                        prerequisite_not_met: vec![CourseID::new("course".to_string())],
+                        ..Default::default()
+                    },
+                )))
+            });
+
+        let req = TestRequest::with_uri("/api/users/me/courses").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "student");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+
+        let res = register_courses(
+            web::Data::new(service),
+            req,
+            web::Json(vec![RegisterCourseRequestContent {
+                id: "course".to_string(),
+            }]),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(body, r#"{"prerequisite_not_met":["course"]}"#);
+    }
+
+    #[actix_web::test]
+    async fn test_schedule_conflict_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_create()
+            .returning(|_, _| {
+                Err(Error::RegistrationCourseValidationError(Box::new(
+                    RegistrationCourseValidationError {
+                        schedule_conflict: vec![CourseID::new("course".to_string())],
+                        schedule_conflict_details: vec![ScheduleConflict {
+                            course_id: CourseID::new("course".to_string()),
+                            conflicts_with: vec![CourseID::new("registered".to_string())],
+                        }],
+                        ..Default::default()
+                    },
+                )))
+            });
+
+        let req = TestRequest::with_uri("/api/users/me/courses").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "student");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+
+        let res = register_courses(
+            web::Data::new(service),
+            req,
+            web::Json(vec![RegisterCourseRequestContent {
+                id: "course".to_string(),
+            }]),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(
+            body,
+            r#"{"schedule_conflict":["course"],"schedule_conflict_details":[{"course_id":"course","conflicts_with":["registered"]}]}"#
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::responses::error::ResponseError::{InvalidCreditLimit, UserNotFound};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::user::UserID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SetCreditLimitRequest {
+    // nullの場合は設定値に戻す
+    credit_limit: Option<u32>,
+}
+
+// PUT /api/users/{user_id}/credit_limit 学生ごとの履修単位数の上限を変更
+pub async fn set_credit_limit<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    user_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+    req: web::Json<SetCreditLimitRequest>,
+) -> ResponseResult<HttpResponse> {
+    let user_id = UserID::new(user_id.0.to_string());
+
+    let mut audit_log = new_audit_log(&request, AuditAction::UserCreditLimitUpdate)?;
+    audit_log.target_user_id = Some(user_id.clone());
+    audit_log.after_summary =
+        Some(serde_json::json!({ "credit_limit": req.credit_limit }).to_string());
+
+    let result = service
+        .registration_course_service()
+        .update_credit_limit(&user_id, req.credit_limit, &audit_log)
+        .await;
+    match result {
+        Ok(_) => {}
+        Err(Error::UserNotFound) => return Err(UserNotFound),
+        Err(Error::InvalidCreditLimit) => return Err(InvalidCreditLimit),
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::set_credit_limit::{set_credit_limit, SetCreditLimitRequest};
+    use crate::test_util::admin_request;
+    use actix_web::http::StatusCode;
+    use actix_web::web;
+    use isucholar_core::models::audit_action::AuditAction;
+    use isucholar_core::services::error::Error::UserNotFound;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    #[should_panic(expected = "UserNotFound")]
+    async fn test_not_found_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_update_credit_limit()
+            .returning(|_, _, _| Err(UserNotFound));
+
+        set_credit_limit(
+            web::Data::new(service),
+            web::Path::from(("1".to_string(),)),
+            admin_request("/api/users/1/credit_limit"),
+            web::Json(SetCreditLimitRequest {
+                credit_limit: Some(30),
+            }),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_update_credit_limit()
+            .withf(|user_id, credit_limit, audit_log| {
+                user_id.inner() == "1"
+                    && *credit_limit == Some(30)
+                    && audit_log.action == AuditAction::UserCreditLimitUpdate
+                    && audit_log.target_user_id.as_ref().unwrap().inner() == "1"
+                    && audit_log.after_summary.as_deref() == Some(r#"{"credit_limit":30}"#)
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let res = set_credit_limit(
+            web::Data::new(service),
+            web::Path::from(("1".to_string(),)),
+            admin_request("/api/users/1/credit_limit"),
+            web::Json(SetCreditLimitRequest {
+                credit_limit: Some(30),
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
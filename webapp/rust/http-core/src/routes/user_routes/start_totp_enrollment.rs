@@ -0,0 +1,31 @@
+use crate::responses::error::ResponseError::{PersonalAccessTokenForbidden, TotpAlreadyEnabled};
+use crate::responses::error::ResponseResult;
+use crate::routes::login_totp_enrollment::TotpEnrollmentResponse;
+use crate::routes::util::{get_user_info, is_authenticated_by_token};
+use actix_web::{web, HttpResponse};
+use isucholar_core::services::error::Error;
+use isucholar_core::services::two_factor_service::{HaveTwoFactorService, TwoFactorService};
+
+// POST /api/users/me/totp 二要素認証の登録を開始
+pub async fn start_totp_enrollment<Service: HaveTwoFactorService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+) -> ResponseResult<HttpResponse> {
+    if is_authenticated_by_token(&request) {
+        return Err(PersonalAccessTokenForbidden);
+    }
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    let result = service
+        .two_factor_service()
+        .start_enrollment(&user_id)
+        .await;
+    match result {
+        Ok(enrollment) => Ok(HttpResponse::Ok().json(TotpEnrollmentResponse {
+            secret: enrollment.secret,
+            provisioning_uri: enrollment.provisioning_uri,
+        })),
+        Err(Error::TotpAlreadyEnabled) => Err(TotpAlreadyEnabled),
+        Err(e) => Err(e.into()),
+    }
+}
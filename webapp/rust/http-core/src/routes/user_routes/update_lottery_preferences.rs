@@ -0,0 +1,111 @@
+use crate::requests::register_course_request::RegisterCourseRequestContent;
+use crate::responses::error::ResponseError::{InvalidLotteryPreference, RegistrationClosed};
+use crate::responses::error::ResponseResult;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// PUT /api/users/me/lottery_preferences 抽選科目の希望順位を登録。並び順が希望順位になる
+pub async fn update_lottery_preferences<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+    req: web::Json<Vec<RegisterCourseRequestContent>>,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    let course_ids: Vec<CourseID> = req.iter().map(|i| CourseID::new(i.id.clone())).collect();
+
+    let result = service
+        .registration_course_service()
+        .update_lottery_preferences(&user_id, &course_ids)
+        .await;
+    match result {
+        Ok(_) => Ok(HttpResponse::Ok().finish()),
+        Err(Error::InvalidLotteryPreference) => Err(InvalidLotteryPreference),
+        Err(Error::RegistrationClosed) => Err(RegistrationClosed),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::requests::register_course_request::RegisterCourseRequestContent;
+    use crate::routes::user_routes::update_lottery_preferences::update_lottery_preferences;
+    use actix_session::SessionExt;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::services::error::Error::RegistrationClosed;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    fn student_request() -> actix_web::HttpRequest {
+        let req = TestRequest::with_uri("/api/users/me/lottery_preferences").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "student");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+        req
+    }
+
+    fn request_body() -> Vec<RegisterCourseRequestContent> {
+        vec![
+            RegisterCourseRequestContent {
+                id: "second".to_string(),
+            },
+            RegisterCourseRequestContent {
+                id: "first".to_string(),
+            },
+        ]
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "RegistrationClosed")]
+    async fn test_registration_closed_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_update_lottery_preferences()
+            .returning(|_, _| Err(RegistrationClosed));
+
+        update_lottery_preferences(
+            web::Data::new(service),
+            student_request(),
+            web::Json(request_body()),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        // 科目IDで並べ替えず、送られた順を希望順位とする
+        service
+            .registration_course_service
+            .expect_update_lottery_preferences()
+            .withf(|user_id, course_ids| {
+                user_id.inner() == "student"
+                    && course_ids
+                        .iter()
+                        .map(|id| id.inner().as_str())
+                        .eq(["second", "first"])
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let res = update_lottery_preferences(
+            web::Data::new(service),
+            student_request(),
+            web::Json(request_body()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
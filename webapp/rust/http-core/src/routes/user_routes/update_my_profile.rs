@@ -0,0 +1,167 @@
+use crate::responses::error::ResponseError::{
+    InvalidEmail, InvalidNameKana, InvalidUserName, UserNotFound,
+};
+use crate::responses::error::ResponseResult;
+use crate::routes::user_routes::get_my_profile::ProfileResponse;
+use crate::routes::util::{get_user_info, is_authenticated_by_token};
+use actix_session::SessionExt;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::user_profile::UpdateUserProfile;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::user_service::{HaveUserService, UserService};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct UpdateNotificationPreferences {
+    announcements: Option<bool>,
+    course_updates: Option<bool>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct UpdateMyProfileRequest {
+    name: Option<String>,
+    name_kana: Option<String>,
+    email: Option<String>,
+    notification_preferences: Option<UpdateNotificationPreferences>,
+}
+
+// PATCH /api/users/me 自身のプロフィールを更新
+pub async fn update_my_profile<Service: HaveUserService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+    req: web::Json<UpdateMyProfileRequest>,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    let req = req.into_inner();
+    let (notify_announcements, notify_course_updates) = match req.notification_preferences {
+        Some(preferences) => (preferences.announcements, preferences.course_updates),
+        None => (None, None),
+    };
+    let form = UpdateUserProfile {
+        name: req.name,
+        name_kana: req.name_kana,
+        email: req.email,
+        notify_announcements,
+        notify_course_updates,
+    };
+
+    let result = service.user_service().update_profile(&user_id, &form).await;
+    let (user, profile) = match result {
+        Ok(result) => result,
+        Err(Error::UserNotFound) => return Err(UserNotFound),
+        Err(Error::InvalidUserName) => return Err(InvalidUserName),
+        Err(Error::InvalidNameKana) => return Err(InvalidNameKana),
+        Err(Error::InvalidEmail) => return Err(InvalidEmail),
+        Err(e) => return Err(e.into()),
+    };
+
+    // ログイン時にセッションへ保存した表示名を新しいものに置き換える
+    if !is_authenticated_by_token(&request) {
+        request.get_session().insert("userName", &user.name)?;
+    }
+
+    Ok(HttpResponse::Ok().json(ProfileResponse::new(user, profile)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::user_routes::update_my_profile::{
+        update_my_profile, UpdateMyProfileRequest, UpdateNotificationPreferences,
+    };
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::user::{User, UserCode, UserID};
+    use isucholar_core::models::user_profile::UserProfile;
+    use isucholar_core::models::user_type::UserType;
+    use isucholar_core::services::error::Error::InvalidEmail;
+    use isucholar_core::services::manager::tests::MockServiceManager;
+    use std::str::from_utf8;
+
+    fn request() -> actix_web::HttpRequest {
+        let req = TestRequest::with_uri("/api/users/me").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "1");
+        let _ = session.insert("userName", "old");
+        let _ = session.insert("isAdmin", false);
+        req
+    }
+
+    #[actix_web::test]
+    #[should_panic(expected = "InvalidEmail")]
+    async fn test_invalid_email_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .user_service
+            .expect_update_profile()
+            .returning(|_, _| Err(InvalidEmail));
+
+        let body = web::Json(UpdateMyProfileRequest {
+            name: None,
+            name_kana: None,
+            email: Some("invalid".to_string()),
+            notification_preferences: None,
+        });
+        update_my_profile(web::Data::new(service), request(), body)
+            .await
+            .unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_success_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .user_service
+            .expect_update_profile()
+            .withf(|user_id, req| {
+                user_id.inner() == "1"
+                    && req.name.as_deref() == Some("new")
+                    && req.notify_announcements == Some(false)
+                    && req.notify_course_updates.is_none()
+            })
+            .returning(|user_id, req| {
+                let mut profile = UserProfile::new(user_id.clone());
+                profile.notify_announcements = false;
+                Ok((
+                    User {
+                        id: UserID::new("1".to_string()),
+                        code: UserCode::new("S00001".to_string().into()),
+                        name: req.name.clone().unwrap(),
+                        hashed_password: vec![],
+                        type_: UserType::Student,
+                        deactivated_at: None,
+                    },
+                    profile,
+                ))
+            });
+
+        let req = request();
+        let session = req.get_session();
+        let body = web::Json(UpdateMyProfileRequest {
+            name: Some("new".to_string()),
+            name_kana: None,
+            email: None,
+            notification_preferences: Some(UpdateNotificationPreferences {
+                announcements: Some(false),
+                course_updates: None,
+            }),
+        });
+        let res = update_my_profile(web::Data::new(service), req, body)
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            session.get::<String>("userName").unwrap(),
+            Some("new".to_string())
+        );
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(
+            from_utf8(&body).unwrap(),
+            r#"{"code":"S00001","name":"new","name_kana":null,"email":null,"notification_preferences":{"announcements":false,"course_updates":true}}"#
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::responses::error::ResponseError::{InvalidUserName, UserNotFound};
+use crate::responses::error::ResponseResult;
+use crate::routes::user_routes::get_users::UserResponse;
+use crate::routes::util::new_audit_log;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::user::{UpdateUser, UserID};
+use isucholar_core::models::user_type::UserType;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::user_service::{HaveUserService, UserService};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct UpdateUserRequest {
+    name: Option<String>,
+    #[serde(rename = "type")]
+    type_: Option<UserType>,
+}
+
+// PATCH /api/users/{user_id} ユーザー情報の更新
+pub async fn update_user<Service: HaveUserService>(
+    service: web::Data<Service>,
+    user_id: web::Path<(String,)>,
+    request: actix_web::HttpRequest,
+    req: web::Json<UpdateUserRequest>,
+) -> ResponseResult<HttpResponse> {
+    let user_id = UserID::new(user_id.0.to_string());
+    let form = UpdateUser {
+        name: req.name.clone(),
+        type_: req.type_.clone(),
+    };
+
+    let mut audit_log = new_audit_log(&request, AuditAction::UserUpdate)?;
+    audit_log.target_user_id = Some(user_id.clone());
+    // 監査ログは個人データの削除後も残るため、氏名そのものは書かない
+    audit_log.after_summary = Some(
+        serde_json::json!({ "name_changed": form.name.is_some(), "type": form.type_ }).to_string(),
+    );
+
+    let result = service
+        .user_service()
+        .update(&user_id, &form, &audit_log)
+        .await;
+    let user = match result {
+        Ok(user) => user,
+        Err(Error::UserNotFound) => return Err(UserNotFound),
+        Err(Error::InvalidUserName) => return Err(InvalidUserName),
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(HttpResponse::Ok().json(UserResponse::from(user)))
+}
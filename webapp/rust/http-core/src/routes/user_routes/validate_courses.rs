@@ -0,0 +1,87 @@
+use crate::requests::register_course_request::RegisterCourseRequestContent;
+use crate::responses::error::ResponseResult;
+use crate::responses::register_courses_error_response::RegisterCoursesErrorResponse;
+use crate::routes::util::get_user_info;
+use actix_web::{web, HttpResponse};
+use isucholar_core::models::course::CourseID;
+use isucholar_core::services::registration_course_service::{
+    HaveRegistrationCourseService, RegistrationCourseService,
+};
+
+// POST /api/users/me/courses/validate 履修登録の事前チェック。
+// エラーは PUT /api/users/me/courses と同じ形式で返し、問題がなければ空のオブジェクトを返す
+pub async fn validate_courses<Service: HaveRegistrationCourseService>(
+    service: web::Data<Service>,
+    request: actix_web::HttpRequest,
+    req: web::Json<Vec<RegisterCourseRequestContent>>,
+) -> ResponseResult<HttpResponse> {
+    let (user_id, _, _) = get_user_info(&request)?;
+
+    let mut req = req.into_inner();
+    req.sort_by(|x, y| x.id.cmp(&y.id));
+
+    let course_ids: Vec<CourseID> = req.iter().map(|i| CourseID::new(i.id.clone())).collect();
+
+    let errors = service
+        .registration_course_service()
+        .validate(&user_id, &course_ids)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(RegisterCoursesErrorResponse::from(errors)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::requests::register_course_request::RegisterCourseRequestContent;
+    use crate::routes::user_routes::validate_courses::validate_courses;
+    use actix_session::SessionExt;
+    use actix_web::body::to_bytes;
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use isucholar_core::models::course::CourseID;
+    use isucholar_core::services::error::{RegistrationCourseValidationError, ScheduleConflict};
+    use isucholar_core::services::manager::tests::MockServiceManager;
+
+    #[actix_web::test]
+    async fn test_schedule_conflict_case() {
+        let mut service = MockServiceManager::new();
+
+        service
+            .registration_course_service
+            .expect_validate()
+            .returning(|_, _| {
+                Ok(RegistrationCourseValidationError {
+                    schedule_conflict: vec![CourseID::new("course".to_string())],
+                    schedule_conflict_details: vec![ScheduleConflict {
+                        course_id: CourseID::new("course".to_string()),
+                        conflicts_with: vec![CourseID::new("registered".to_string())],
+                    }],
+                    ..Default::default()
+                })
+            });
+        service.registration_course_service.expect_create().never();
+
+        let req = TestRequest::with_uri("/api/users/me/courses/validate").to_http_request();
+        let session = req.get_session();
+        let _ = session.insert("userID", "student");
+        let _ = session.insert("userName", "student");
+        let _ = session.insert("isAdmin", false);
+
+        let res = validate_courses(
+            web::Data::new(service),
+            req,
+            web::Json(vec![RegisterCourseRequestContent {
+                id: "course".to_string(),
+            }]),
+        )
+        .await
+        .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert_eq!(
+            body,
+            r#"{"schedule_conflict":["course"],"schedule_conflict_details":[{"course_id":"course","conflicts_with":["registered"]}]}"#
+        );
+    }
+}
@@ -1,6 +1,34 @@
+use crate::middleware::{RequestID, TokenUser};
+use actix_session::{Session, SessionExt, SessionGetError, SessionInsertError};
+use actix_web::HttpMessage;
+use isucholar_core::models::audit_action::AuditAction;
+use isucholar_core::models::audit_log::CreateAuditLog;
 use isucholar_core::models::user::UserID;
+use std::net::IpAddr;
 
-pub fn get_user_info(session: actix_session::Session) -> actix_web::Result<(UserID, String, bool)> {
+lazy_static::lazy_static! {
+    // TRUSTED_PROXIESにカンマ区切りで指定したプロキシからの接続に限り、X-Forwarded-Forを信頼する
+    static ref TRUSTED_PROXIES: Vec<IpAddr> = std::env::var("TRUSTED_PROXIES")
+        .map(|v| {
+            v.split(',')
+                .filter_map(|proxy| proxy.trim().parse().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+}
+
+pub fn get_user_info(
+    request: &actix_web::HttpRequest,
+) -> actix_web::Result<(UserID, String, bool)> {
+    if let Some(token_user) = request.extensions().get::<TokenUser>() {
+        return Ok((
+            token_user.user_id.clone(),
+            token_user.user_name.clone(),
+            token_user.is_admin,
+        ));
+    }
+
+    let session = request.get_session();
     let user_id = session.get("userID")?;
     if user_id.is_none() {
         return Err(actix_web::error::ErrorInternalServerError(
@@ -25,3 +53,152 @@ pub fn get_user_info(session: actix_session::Session) -> actix_web::Result<(User
         is_admin.unwrap(),
     ))
 }
+
+pub fn get_request_id(request: &actix_web::HttpRequest) -> Option<String> {
+    request
+        .extensions()
+        .get::<RequestID>()
+        .map(|request_id| request_id.0.clone())
+}
+
+// ログイン中のユーザーを操作者とした監査ログを作る
+pub fn new_audit_log(
+    request: &actix_web::HttpRequest,
+    action: AuditAction,
+) -> actix_web::Result<CreateAuditLog> {
+    let (user_id, _, _) = get_user_info(request)?;
+    Ok(CreateAuditLog::new(
+        user_id,
+        action,
+        get_request_id(request),
+    ))
+}
+
+// ログイン試行の制限に使う接続元のIPアドレス。
+// X-Forwarded-Forはクライアントが自由に付けられるため、信頼するプロキシから接続された場合のみ、
+// 末尾からたどって最初に現れる信頼するプロキシ以外のアドレスを接続元とする
+pub fn get_client_ip_address(request: &actix_web::HttpRequest) -> String {
+    client_ip_address(request, &TRUSTED_PROXIES)
+}
+
+fn client_ip_address(request: &actix_web::HttpRequest, trusted_proxies: &[IpAddr]) -> String {
+    let peer = match request.peer_addr() {
+        Some(peer) => peer.ip(),
+        None => return String::new(),
+    };
+    if !trusted_proxies.contains(&peer) {
+        return peer.to_string();
+    }
+
+    let forwarded_for = request
+        .headers()
+        .get_all("X-Forwarded-For")
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|v| v.trim())
+        .collect::<Vec<_>>();
+    for address in forwarded_for.into_iter().rev() {
+        match address.parse::<IpAddr>() {
+            Ok(ip) if trusted_proxies.contains(&ip) => continue,
+            Ok(ip) => return ip.to_string(),
+            // 解釈できない値は偽装の可能性があるため、それより前の値は信頼しない
+            Err(_) => break,
+        }
+    }
+    peer.to_string()
+}
+
+pub fn is_authenticated_by_token(request: &actix_web::HttpRequest) -> bool {
+    request.extensions().get::<TokenUser>().is_some()
+}
+
+// なりすまし中のセッションに保存する、なりすましを開始した管理者の情報
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImpersonationSession {
+    pub impersonator_id: UserID,
+    pub impersonator_name: String,
+    // UNIX時間(秒)
+    pub expires_at: i64,
+}
+
+pub fn get_impersonation(
+    session: &Session,
+) -> Result<Option<ImpersonationSession>, SessionGetError> {
+    let impersonator_id = match session.get::<String>("impersonatorID")? {
+        Some(impersonator_id) => impersonator_id,
+        None => return Ok(None),
+    };
+    Ok(Some(ImpersonationSession {
+        impersonator_id: UserID::new(impersonator_id),
+        impersonator_name: session.get("impersonatorName")?.unwrap_or_default(),
+        expires_at: session.get("impersonationExpiresAt")?.unwrap_or_default(),
+    }))
+}
+
+pub fn start_impersonation(
+    session: &Session,
+    impersonation: &ImpersonationSession,
+    user_id: &UserID,
+    user_name: &str,
+) -> Result<(), SessionInsertError> {
+    session.insert("impersonatorID", &impersonation.impersonator_id)?;
+    session.insert("impersonatorName", &impersonation.impersonator_name)?;
+    session.insert("impersonationExpiresAt", impersonation.expires_at)?;
+    session.insert("userID", user_id)?;
+    session.insert("userName", user_name)?;
+    session.insert("isAdmin", false)?;
+    Ok(())
+}
+
+// なりすましの情報をセッションから取り除く。
+// ログイン時に残っていると、期限切れの際に別のユーザーのセッションが管理者に戻されてしまう
+pub fn clear_impersonation(session: &Session) {
+    session.remove("impersonatorID");
+    session.remove("impersonatorName");
+    session.remove("impersonationExpiresAt");
+}
+
+// 管理者のセッションに戻し、なりすましていたユーザーのIDを返す
+pub fn end_impersonation(
+    session: &Session,
+    impersonation: &ImpersonationSession,
+) -> Result<Option<UserID>, actix_web::Error> {
+    let user_id = session.get::<String>("userID")?.map(UserID::new);
+    clear_impersonation(session);
+    session.insert("userID", &impersonation.impersonator_id)?;
+    session.insert("userName", &impersonation.impersonator_name)?;
+    session.insert("isAdmin", true)?;
+    Ok(user_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::util::client_ip_address;
+    use actix_web::test::TestRequest;
+    use std::net::IpAddr;
+
+    #[test]
+    fn untrusted_peer_case() {
+        let req = TestRequest::default()
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "198.51.100.1"))
+            .to_http_request();
+        assert_eq!(client_ip_address(&req, &[]), "192.0.2.1");
+    }
+
+    #[test]
+    fn trusted_proxy_case() {
+        let trusted_proxies: Vec<IpAddr> = vec!["127.0.0.1".parse().unwrap()];
+        // 先頭の値はクライアントが付けたもので、プロキシが末尾に接続元を追記している
+        let req = TestRequest::default()
+            .peer_addr("127.0.0.1:12345".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "198.51.100.1, 192.0.2.1"))
+            .to_http_request();
+        assert_eq!(client_ip_address(&req, &trusted_proxies), "192.0.2.1");
+
+        let req = TestRequest::default()
+            .peer_addr("127.0.0.1:12345".parse().unwrap())
+            .to_http_request();
+        assert_eq!(client_ip_address(&req, &trusted_proxies), "127.0.0.1");
+    }
+}
@@ -0,0 +1,108 @@
+use actix_web::cookie::Key;
+use std::sync::Arc;
+use thiserror::Error;
+
+pub const SESSION_COOKIE_NAME: &str = "id";
+
+#[derive(Debug, Error)]
+pub enum SessionKeyError {
+    #[error("session key is not configured. set SESSION_KEY or SESSION_KEY_FILE")]
+    NotConfigured,
+    #[error("failed to read session key file {0}: {1}")]
+    ReadFileError(String, std::io::Error),
+    #[error("session key #{0} is too short. each key must be at least 64 bytes")]
+    KeyTooShort(usize),
+}
+
+// セッションCookieの暗号化に使う鍵。先頭が最新の鍵で、新しいCookieは常にこの鍵で暗号化する。
+// 2番目以降はローテーション期間中に古いCookieを受け付けるための鍵
+#[derive(Clone)]
+pub struct SessionKeys {
+    keys: Arc<Vec<Key>>,
+}
+
+impl SessionKeys {
+    pub fn new(keys: Vec<Vec<u8>>) -> Result<Self, SessionKeyError> {
+        if keys.is_empty() {
+            return Err(SessionKeyError::NotConfigured);
+        }
+        let keys = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                Key::try_from(key.as_slice()).map_err(|_| SessionKeyError::KeyTooShort(i))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            keys: Arc::new(keys),
+        })
+    }
+
+    // SESSION_KEY_FILEが指定されていれば1行に1つずつ新しい順に書かれた鍵を読み込む。
+    // 指定されていなければSESSION_KEYを最新の鍵、SESSION_PREVIOUS_KEYS(カンマ区切り)を古い鍵として使う
+    pub fn from_env() -> Result<Self, SessionKeyError> {
+        let keys = match std::env::var("SESSION_KEY_FILE") {
+            Ok(path) => {
+                let content = std::fs::read_to_string(&path)
+                    .map_err(|e| SessionKeyError::ReadFileError(path, e))?;
+                parse_key_lines(&content)
+            }
+            Err(_) => {
+                let current = std::env::var("SESSION_KEY").unwrap_or_default();
+                let previous = std::env::var("SESSION_PREVIOUS_KEYS").unwrap_or_default();
+                std::iter::once(current.as_str())
+                    .chain(previous.split(','))
+                    .map(str::trim)
+                    .filter(|key| !key.is_empty())
+                    .map(|key| key.as_bytes().to_vec())
+                    .collect()
+            }
+        };
+
+        Self::new(keys)
+    }
+
+    pub fn current(&self) -> &Key {
+        &self.keys[0]
+    }
+
+    pub fn previous(&self) -> &[Key] {
+        &self.keys[1..]
+    }
+}
+
+fn parse_key_lines(content: &str) -> Vec<Vec<u8>> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.as_bytes().to_vec())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::session_keys::{parse_key_lines, SessionKeyError, SessionKeys};
+
+    #[test]
+    fn empty_case() {
+        let result = SessionKeys::new(vec![]);
+        assert!(matches!(result, Err(SessionKeyError::NotConfigured)));
+    }
+
+    #[test]
+    fn too_short_key_case() {
+        let result = SessionKeys::new(vec![vec![b'a'; 64], vec![b'b'; 32]]);
+        assert!(matches!(result, Err(SessionKeyError::KeyTooShort(1))));
+    }
+
+    #[test]
+    fn parse_key_lines_case() {
+        let content = "# newest first\nfirst\n\n  second  \n";
+        assert_eq!(
+            parse_key_lines(content),
+            vec![b"first".to_vec(), b"second".to_vec()]
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use actix_session::SessionExt;
+use actix_web::test::TestRequest;
+
+// 管理者(教員)としてログイン済みのセッションを持つリクエストを作る
+pub fn admin_request(uri: &str) -> actix_web::HttpRequest {
+    let req = TestRequest::with_uri(uri).to_http_request();
+    let session = req.get_session();
+    let _ = session.insert("userID", "admin");
+    let _ = session.insert("userName", "teacher");
+    let _ = session.insert("isAdmin", true);
+    req
+}
@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use base64::Engine;
+use isucholar_core::identity_providers::oidc_provider::{IdTokenClaims, OidcProvider};
+use isucholar_core::identity_providers::{IdentityProviderError, IdentityProviderResult};
+
+#[derive(Debug, Clone)]
+pub struct OidcProviderConfig {
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_uri: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub scope: String,
+}
+
+impl OidcProviderConfig {
+    // OIDC_ISSUERが未設定の場合はOIDCによるログインを無効にする
+    pub fn from_env() -> Option<Self> {
+        let issuer = std::env::var("OIDC_ISSUER").ok()?;
+        let issuer = issuer.trim_end_matches('/').to_string();
+        Some(Self {
+            client_id: std::env::var("OIDC_CLIENT_ID").unwrap_or_default(),
+            client_secret: std::env::var("OIDC_CLIENT_SECRET").unwrap_or_default(),
+            redirect_uri: std::env::var("OIDC_REDIRECT_URI").unwrap_or_default(),
+            authorization_endpoint: std::env::var("OIDC_AUTHORIZATION_ENDPOINT")
+                .unwrap_or_else(|_| format!("{}/authorize", issuer)),
+            token_endpoint: std::env::var("OIDC_TOKEN_ENDPOINT")
+                .unwrap_or_else(|_| format!("{}/token", issuer)),
+            scope: std::env::var("OIDC_SCOPE").unwrap_or_else(|_| "openid profile".to_string()),
+            issuer,
+        })
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+enum Audience {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct RegisteredClaims {
+    iss: String,
+    aud: Audience,
+    exp: i64,
+}
+
+#[derive(Clone)]
+pub struct OidcProviderHttp {
+    config: Option<OidcProviderConfig>,
+    client: reqwest::Client,
+}
+
+impl OidcProviderHttp {
+    pub fn new(config: Option<OidcProviderConfig>) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn config(&self) -> IdentityProviderResult<&OidcProviderConfig> {
+        self.config.as_ref().ok_or_else(|| {
+            IdentityProviderError::RequestError("OIDC is not configured".to_string())
+        })
+    }
+
+    // IDトークンはトークンエンドポイントからTLSで直接受け取るので、署名の代わりに発行者と宛先、有効期限を検証する
+    // (OpenID Connect Core 1.0 3.1.3.7)
+    pub fn verify_id_token(&self, id_token: &str) -> IdentityProviderResult<IdTokenClaims> {
+        let config = self.config()?;
+
+        let payload = id_token
+            .split('.')
+            .nth(1)
+            .ok_or_else(|| IdentityProviderError::InvalidIdToken("malformed token".to_string()))?;
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(payload.trim_end_matches('='))
+            .map_err(|e| IdentityProviderError::InvalidIdToken(e.to_string()))?;
+
+        let registered: RegisteredClaims = serde_json::from_slice(&payload)
+            .map_err(|e| IdentityProviderError::InvalidIdToken(e.to_string()))?;
+        if registered.iss.trim_end_matches('/') != config.issuer {
+            return Err(IdentityProviderError::InvalidIdToken(
+                "issuer mismatch".to_string(),
+            ));
+        }
+        let is_valid_audience = match registered.aud {
+            Audience::Single(aud) => aud == config.client_id,
+            Audience::Multiple(aud) => aud.contains(&config.client_id),
+        };
+        if !is_valid_audience {
+            return Err(IdentityProviderError::InvalidIdToken(
+                "audience mismatch".to_string(),
+            ));
+        }
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        if registered.exp <= now {
+            return Err(IdentityProviderError::InvalidIdToken(
+                "token expired".to_string(),
+            ));
+        }
+
+        serde_json::from_slice(&payload)
+            .map_err(|e| IdentityProviderError::InvalidIdToken(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl OidcProvider for OidcProviderHttp {
+    fn is_enabled(&self) -> bool {
+        self.config.is_some()
+    }
+
+    fn authorization_url(&self, state: &str, nonce: &str) -> String {
+        let config = match self.config {
+            Some(ref config) => config,
+            None => return String::new(),
+        };
+        let mut url = match url::Url::parse(&config.authorization_endpoint) {
+            Ok(url) => url,
+            Err(_) => return String::new(),
+        };
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &config.client_id)
+            .append_pair("redirect_uri", &config.redirect_uri)
+            .append_pair("scope", &config.scope)
+            .append_pair("state", state)
+            .append_pair("nonce", nonce);
+        url.to_string()
+    }
+
+    async fn exchange_code(&self, code: &str) -> IdentityProviderResult<IdTokenClaims> {
+        let config = self.config()?;
+
+        let res = self
+            .client
+            .post(&config.token_endpoint)
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", &config.redirect_uri),
+                ("client_id", &config.client_id),
+                ("client_secret", &config.client_secret),
+            ])
+            .send()
+            .await
+            .map_err(|e| IdentityProviderError::RequestError(e.to_string()))?;
+        if !res.status().is_success() {
+            return Err(IdentityProviderError::RequestError(format!(
+                "token endpoint returned {}",
+                res.status()
+            )));
+        }
+        let token: TokenResponse = res
+            .json()
+            .await
+            .map_err(|e| IdentityProviderError::RequestError(e.to_string()))?;
+
+        self.verify_id_token(&token.id_token)
+    }
+}
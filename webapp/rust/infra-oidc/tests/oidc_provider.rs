@@ -0,0 +1,141 @@
+use actix_web::{web, HttpResponse};
+use base64::Engine;
+use isucholar_core::identity_providers::oidc_provider::OidcProvider;
+use isucholar_infra_oidc::oidc_provider::{OidcProviderConfig, OidcProviderHttp};
+
+#[derive(serde::Deserialize)]
+struct TokenRequest {
+    grant_type: String,
+    code: String,
+    client_id: String,
+    client_secret: String,
+}
+
+fn encode_id_token(claims: serde_json::Value) -> String {
+    let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    format!(
+        "{}.{}.signature",
+        engine.encode(r#"{"alg":"RS256","typ":"JWT"}"#),
+        engine.encode(claims.to_string())
+    )
+}
+
+fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+// 認可コードごとに決まったIDトークンを返すモックIdP
+async fn token(form: web::Form<TokenRequest>) -> HttpResponse {
+    if form.grant_type != "authorization_code"
+        || form.client_id != "client"
+        || form.client_secret != "secret"
+    {
+        return HttpResponse::BadRequest().json(serde_json::json!({"error": "invalid_client"}));
+    }
+
+    let claims = match form.code.as_str() {
+        "valid" => serde_json::json!({
+            "iss": "http://idp.example",
+            "aud": "client",
+            "exp": now() + 60,
+            "sub": "S00001",
+            "nonce": "nonce",
+            "groups": ["teacher"],
+        }),
+        "expired" => serde_json::json!({
+            "iss": "http://idp.example",
+            "aud": ["client"],
+            "exp": now() - 60,
+            "sub": "S00001",
+        }),
+        "other_audience" => serde_json::json!({
+            "iss": "http://idp.example",
+            "aud": "other",
+            "exp": now() + 60,
+            "sub": "S00001",
+        }),
+        _ => return HttpResponse::BadRequest().json(serde_json::json!({"error": "invalid_grant"})),
+    };
+
+    HttpResponse::Ok().json(serde_json::json!({
+        "access_token": "access",
+        "token_type": "Bearer",
+        "id_token": encode_id_token(claims),
+    }))
+}
+
+fn start_mock_idp() -> OidcProviderHttp {
+    let server =
+        actix_web::HttpServer::new(|| actix_web::App::new().route("/token", web::post().to(token)))
+            .workers(1)
+            .bind(("127.0.0.1", 0))
+            .unwrap();
+    let addr = server.addrs()[0];
+    actix_web::rt::spawn(server.run());
+
+    OidcProviderHttp::new(Some(OidcProviderConfig {
+        issuer: "http://idp.example".to_string(),
+        client_id: "client".to_string(),
+        client_secret: "secret".to_string(),
+        redirect_uri: "http://localhost/login/oidc/callback".to_string(),
+        authorization_endpoint: "http://idp.example/authorize".to_string(),
+        token_endpoint: format!("http://{}/token", addr),
+        scope: "openid profile".to_string(),
+    }))
+}
+
+#[actix_web::test]
+async fn authorization_url() {
+    let provider = start_mock_idp();
+
+    let url = provider.authorization_url("state", "nonce");
+    assert_eq!(
+        url,
+        "http://idp.example/authorize?response_type=code&client_id=client&redirect_uri=http%3A%2F%2Flocalhost%2Flogin%2Foidc%2Fcallback&scope=openid+profile&state=state&nonce=nonce"
+    );
+}
+
+#[actix_web::test]
+async fn exchange_code_success() {
+    let provider = start_mock_idp();
+
+    let claims = provider.exchange_code("valid").await.unwrap();
+    assert_eq!(claims.sub, "S00001");
+    assert_eq!(claims.nonce.as_deref(), Some("nonce"));
+    assert!(claims.claim_contains("groups", "teacher"));
+}
+
+#[actix_web::test]
+#[should_panic(expected = "RequestError")]
+async fn exchange_code_invalid_grant() {
+    let provider = start_mock_idp();
+
+    provider.exchange_code("unknown").await.unwrap();
+}
+
+#[actix_web::test]
+#[should_panic(expected = "token expired")]
+async fn exchange_code_expired() {
+    let provider = start_mock_idp();
+
+    provider.exchange_code("expired").await.unwrap();
+}
+
+#[actix_web::test]
+#[should_panic(expected = "audience mismatch")]
+async fn exchange_code_other_audience() {
+    let provider = start_mock_idp();
+
+    provider.exchange_code("other_audience").await.unwrap();
+}
+
+#[actix_web::test]
+async fn disabled() {
+    let provider = OidcProviderHttp::new(None);
+
+    assert!(!provider.is_enabled());
+    assert!(provider.exchange_code("valid").await.is_err());
+}
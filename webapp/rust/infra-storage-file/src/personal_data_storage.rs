@@ -0,0 +1,102 @@
+use crate::submission_file_storage::SubmissionFileStorageFile;
+use async_trait::async_trait;
+use isucholar_core::models::submission::UserSubmission;
+use isucholar_core::models::user::UserID;
+use isucholar_core::storages::personal_data_storage::PersonalDataStorage;
+use isucholar_core::storages::StorageResult;
+use isucholar_core::ASSIGNMENTS_DIRECTORY;
+
+const PERSONAL_DATA_FILE_NAME: &str = "personal_data.json";
+
+#[derive(Default)]
+pub struct PersonalDataStorageFile {
+    submission_file_storage: SubmissionFileStorageFile,
+}
+
+#[async_trait]
+impl PersonalDataStorage for PersonalDataStorageFile {
+    async fn create_personal_data_zip(
+        &self,
+        user_id: &UserID,
+        document: &[u8],
+        submissions: &[UserSubmission],
+    ) -> StorageResult<String> {
+        let zip_file_path = format!(
+            "{}personal-data-{}.zip",
+            ASSIGNMENTS_DIRECTORY,
+            user_id.inner()
+        );
+
+        let tmp_dir = format!(
+            "{}personal-data-{}/",
+            ASSIGNMENTS_DIRECTORY,
+            user_id.inner()
+        );
+        tokio::process::Command::new("rm")
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .arg("-rf")
+            .arg(&tmp_dir)
+            .arg(&zip_file_path)
+            .status()
+            .await?;
+        tokio::fs::create_dir(&tmp_dir).await?;
+
+        tokio::fs::write(format!("{}{}", tmp_dir, PERSONAL_DATA_FILE_NAME), document).await?;
+
+        // ファイル名は課題一括ダウンロードと同様に、講義IDを前置して重複を避ける
+        for submission in submissions {
+            tokio::process::Command::new("cp")
+                .stdin(std::process::Stdio::null())
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .arg(
+                    self.submission_file_storage
+                        .get_filename(&submission.class_id, user_id),
+                )
+                .arg(format!(
+                    "{}{}-{}",
+                    tmp_dir,
+                    submission.class_id.inner(),
+                    submission.file_name
+                ))
+                .status()
+                .await?;
+        }
+
+        tokio::process::Command::new("zip")
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .arg("-j")
+            .arg("-r")
+            .arg(&zip_file_path)
+            .arg(&tmp_dir)
+            .status()
+            .await?;
+
+        tokio::fs::remove_dir_all(&tmp_dir).await?;
+
+        Ok(zip_file_path)
+    }
+
+    async fn delete_submission_files(
+        &self,
+        user_id: &UserID,
+        submissions: &[UserSubmission],
+    ) -> StorageResult<()> {
+        for submission in submissions {
+            let file_name = self
+                .submission_file_storage
+                .get_filename(&submission.class_id, user_id);
+            match tokio::fs::remove_file(&file_name).await {
+                Ok(_) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use isucholar_core::identity_providers::oidc_provider::{IdTokenClaims, OidcProvider};
+use isucholar_core::identity_providers::IdentityProviderResult;
+use isucholar_infra_oidc::oidc_provider::{OidcProviderConfig, OidcProviderHttp};
+
+#[derive(Clone)]
+pub struct OidcProviderInfra {
+    provider: OidcProviderHttp,
+}
+
+impl Default for OidcProviderInfra {
+    fn default() -> Self {
+        Self {
+            provider: OidcProviderHttp::new(OidcProviderConfig::from_env()),
+        }
+    }
+}
+
+#[async_trait]
+impl OidcProvider for OidcProviderInfra {
+    fn is_enabled(&self) -> bool {
+        self.provider.is_enabled()
+    }
+
+    fn authorization_url(&self, state: &str, nonce: &str) -> String {
+        self.provider.authorization_url(state, nonce)
+    }
+
+    async fn exchange_code(&self, code: &str) -> IdentityProviderResult<IdTokenClaims> {
+        self.provider.exchange_code(code).await
+    }
+}
@@ -1,4 +1,5 @@
 pub mod db;
+pub mod identity_providers;
 pub mod repos;
 pub mod services;
 pub mod storages;
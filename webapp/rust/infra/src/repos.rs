@@ -1,8 +1,22 @@
 pub mod announcement_repository;
+pub mod approval_required_course_repository;
+pub mod audit_log_repository;
 pub mod class_repository;
+pub mod course_prerequisite_repository;
 pub mod course_repository;
+pub mod credit_limit_repository;
+pub mod login_attempt_repository;
+pub mod lottery_course_repository;
+pub mod lottery_preference_repository;
+pub mod personal_access_token_repository;
 pub mod registration_course_repository;
 pub mod registration_repository;
+pub mod registration_request_repository;
+pub mod registration_window_repository;
 pub mod submission_repository;
 pub mod unread_announcement_repository;
+pub mod user_profile_repository;
+pub mod user_recovery_code_repository;
 pub mod user_repository;
+pub mod user_totp_secret_repository;
+pub mod waitlist_repository;
@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::audit_log::AuditLog;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::audit_log_repository::{AuditLogQuery, AuditLogRepository};
+use isucholar_core::repos::error::Result;
+use sqlx::Arguments;
+
+#[cfg(test)]
+mod create;
+#[cfg(test)]
+mod find_all;
+#[cfg(test)]
+mod find_all_by_target_user_id;
+
+#[derive(Clone)]
+pub struct AuditLogRepositoryInfra {}
+
+#[async_trait]
+impl AuditLogRepository for AuditLogRepositoryInfra {
+    async fn create(&self, conn: &mut DBConn, audit_log: &AuditLog) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO `audit_logs` (`id`, `actor_id`, `action`, `target_user_id`, `target_course_id`, `target_class_id`, `target_announcement_id`, `request_id`, `before_summary`, `after_summary`, `created_at`) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            &audit_log.id,
+            &audit_log.actor_id,
+            &audit_log.action,
+            &audit_log.target_user_id,
+            &audit_log.target_course_id,
+            &audit_log.target_class_id,
+            &audit_log.target_announcement_id,
+            &audit_log.request_id,
+            &audit_log.before_summary,
+            &audit_log.after_summary,
+            &audit_log.created_at,
+        )
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_all(
+        &self,
+        conn: &mut DBConn,
+        q: &AuditLogQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AuditLog>> {
+        let query = "SELECT * FROM `audit_logs` WHERE 1=1";
+        let mut condition = String::new();
+        let mut args = sqlx::mysql::MySqlArguments::default();
+
+        if let Some(ref actor_id) = q.actor_id {
+            condition.push_str(" AND `actor_id` = ?");
+            args.add(actor_id);
+        }
+
+        if let Some(ref course_id) = q.course_id {
+            condition.push_str(" AND `target_course_id` = ?");
+            args.add(course_id);
+        }
+
+        if let Some(ref since) = q.since {
+            condition.push_str(" AND `created_at` >= ?");
+            args.add(since);
+        }
+
+        if let Some(ref until) = q.until {
+            condition.push_str(" AND `created_at` < ?");
+            args.add(until);
+        }
+
+        condition.push_str(" ORDER BY `created_at` DESC, `id` DESC");
+
+        // limitより多く上限を設定し、実際にlimitより多くレコードが取得できた場合は次のページが存在する
+        condition.push_str(" LIMIT ? OFFSET ?");
+        args.add(limit + 1);
+        args.add(offset);
+
+        let audit_logs: Vec<AuditLog> =
+            sqlx::query_as_with(&format!("{}{}", query, condition), args)
+                .fetch_all(conn)
+                .await?;
+
+        Ok(audit_logs)
+    }
+
+    async fn find_all_by_target_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<AuditLog>> {
+        let audit_logs: Vec<AuditLog> = sqlx::query_as(
+            "SELECT * FROM `audit_logs` WHERE `target_user_id` = ? ORDER BY `created_at`, `id`",
+        )
+        .bind(user_id)
+        .fetch_all(conn)
+        .await?;
+
+        Ok(audit_logs)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::audit_log::AuditLog;
+use isucholar_core::repos::audit_log_repository::AuditLogRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = AuditLogRepositoryInfra {};
+    let mut audit_log: AuditLog = Faker.fake();
+    audit_log.after_summary = Some(r#"{"reason":"ended"}"#.to_string());
+    audit_log.created_at = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &audit_log).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let count = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM `audit_logs` WHERE `id` = ? AND `actor_id` = ?",
+        &audit_log.id,
+        &audit_log.actor_id
+    )
+    .fetch_one(conn)
+    .await
+    .unwrap();
+    assert_eq!(count, 1);
+}
@@ -0,0 +1,87 @@
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::audit_log::AuditLog;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::audit_log_repository::{AuditLogQuery, AuditLogRepository};
+use sqlx::Acquire;
+
+fn audit_log(
+    actor_id: &str,
+    course_id: Option<&str>,
+    created_at: chrono::NaiveDateTime,
+) -> AuditLog {
+    let mut audit_log: AuditLog = Faker.fake();
+    audit_log.actor_id = UserID::new(actor_id.to_string());
+    audit_log.target_course_id = course_id.map(|id| CourseID::new(id.to_string()));
+    audit_log.created_at = created_at;
+    audit_log
+}
+
+#[tokio::test]
+async fn filter_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = AuditLogRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let matched = audit_log("admin-a", Some("course-a"), now);
+    let other_actor = audit_log("admin-b", Some("course-a"), now);
+    let other_course = audit_log("admin-a", Some("course-b"), now);
+    let too_old = audit_log(
+        "admin-a",
+        Some("course-a"),
+        now - chrono::Duration::hours(2),
+    );
+    for input in [&matched, &other_actor, &other_course, &too_old] {
+        let conn = tx.acquire().await.unwrap();
+        repo.create(conn, input).await.unwrap();
+    }
+
+    let conn = tx.acquire().await.unwrap();
+    let query = AuditLogQuery {
+        actor_id: Some(UserID::new("admin-a".to_string())),
+        course_id: Some(CourseID::new("course-a".to_string())),
+        since: Some(now - chrono::Duration::hours(1)),
+        until: Some(now + chrono::Duration::hours(1)),
+    };
+    let got = repo.find_all(conn, &query, 20, 0).await.unwrap();
+    assert_eq!(got, vec![matched]);
+}
+
+#[tokio::test]
+async fn fetch_one_more_than_limit_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = AuditLogRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    for i in 0..3 {
+        let conn = tx.acquire().await.unwrap();
+        let input = audit_log("admin-c", None, now - chrono::Duration::seconds(i));
+        repo.create(conn, &input).await.unwrap();
+    }
+
+    let conn = tx.acquire().await.unwrap();
+    let query = AuditLogQuery {
+        actor_id: Some(UserID::new("admin-c".to_string())),
+        ..Default::default()
+    };
+    let got = repo.find_all(conn, &query, 1, 0).await.unwrap();
+    assert_eq!(got.len(), 2);
+    assert_eq!(got[0].created_at, now);
+}
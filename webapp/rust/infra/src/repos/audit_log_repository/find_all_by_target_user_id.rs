@@ -0,0 +1,44 @@
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::audit_log::AuditLog;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::audit_log_repository::AuditLogRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = AuditLogRepositoryInfra {};
+    let user_id: UserID = Faker.fake();
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let mut newer: AuditLog = Faker.fake();
+    newer.target_user_id = Some(user_id.clone());
+    newer.created_at = now;
+    let mut older: AuditLog = Faker.fake();
+    older.target_user_id = Some(user_id.clone());
+    older.created_at = now - chrono::Duration::hours(1);
+    let mut other: AuditLog = Faker.fake();
+    other.target_user_id = Some(Faker.fake());
+    other.created_at = now;
+    for input in [&newer, &older, &other] {
+        let conn = tx.acquire().await.unwrap();
+        repo.create(conn, input).await.unwrap();
+    }
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_all_by_target_user_id(conn, &user_id)
+        .await
+        .unwrap();
+    assert_eq!(got, vec![older, newer]);
+}
@@ -21,6 +21,8 @@ mod find_submission_closed_by_id_with_shared_lock;
 #[cfg(test)]
 mod for_update_by_id;
 #[cfg(test)]
+mod update_submission_closed_by_course_id;
+#[cfg(test)]
 mod update_submission_closed_by_id;
 
 #[derive(Clone)]
@@ -83,6 +85,21 @@ impl ClassRepository for ClassRepositoryInfra {
         Ok(())
     }
 
+    async fn update_submission_closed_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE `classes` SET `submission_closed` = true WHERE `course_id` = ?",
+            course_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
     async fn find_submission_closed_by_id_with_shared_lock(
         &self,
         conn: &mut DBConn,
@@ -0,0 +1,63 @@
+use crate::repos::class_repository::ClassRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::class::Class;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::repos::class_repository::ClassRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let course_id: CourseID = Faker.fake();
+    let mut classes: Vec<Class> = Vec::new();
+    for part in 1..=2 {
+        let mut class: Class = Faker.fake();
+        class.course_id = course_id.clone();
+        class.part = part;
+        class.submission_closed = false;
+        classes.push(class);
+    }
+    // 別の科目の講義は締め切らない
+    let mut other: Class = Faker.fake();
+    other.submission_closed = false;
+    classes.push(other);
+
+    for class in &classes {
+        let conn = tx.acquire().await.unwrap();
+        sqlx::query!("INSERT INTO classes (id, course_id, part, title, description, submission_closed) VALUES (?,?,?,?,?,?)",
+            &class.id,
+            &class.course_id,
+            &class.part,
+            &class.title,
+            &class.description,
+            &class.submission_closed,
+        ).execute(conn).await.unwrap();
+    }
+
+    let repo = ClassRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    repo.update_submission_closed_by_course_id(conn, &course_id)
+        .await
+        .unwrap();
+
+    for class in &classes {
+        let conn = tx.acquire().await.unwrap();
+        let got = sqlx::query_scalar!(
+            "SELECT submission_closed AS `submission_closed:bool` FROM classes WHERE id = ?",
+            &class.id
+        )
+        .fetch_one(conn)
+        .await
+        .unwrap();
+        assert_eq!(got, class.course_id == course_id);
+    }
+}
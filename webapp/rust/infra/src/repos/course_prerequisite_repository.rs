@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::course::{CourseCode, CourseID};
+use isucholar_core::models::course_prerequisite::CoursePrerequisite;
+use isucholar_core::repos::course_prerequisite_repository::CoursePrerequisiteRepository;
+use isucholar_core::repos::error::Result;
+use sqlx::Arguments;
+
+#[cfg(test)]
+mod create;
+#[cfg(test)]
+mod delete_by_course_id;
+#[cfg(test)]
+mod find_by_course_ids;
+
+#[derive(Clone)]
+pub struct CoursePrerequisiteRepositoryInfra {}
+
+#[async_trait]
+impl CoursePrerequisiteRepository for CoursePrerequisiteRepositoryInfra {
+    async fn create(&self, conn: &mut DBConn, prerequisite: &CoursePrerequisite) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO `course_prerequisites` (`course_id`, `prerequisite_code`, `min_score`) VALUES (?, ?, ?)",
+            &prerequisite.course_id,
+            &prerequisite.code,
+            &prerequisite.min_score,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<Vec<CoursePrerequisite>> {
+        let prerequisites: Vec<CoursePrerequisite> = sqlx::query_as!(
+            CoursePrerequisite,
+            r"
+                SELECT
+                    course_id as `course_id:CourseID`,
+                    prerequisite_code as `code:CourseCode`,
+                    min_score
+                FROM `course_prerequisites`
+                WHERE `course_id` = ?
+                ORDER BY `prerequisite_code`
+            ",
+            course_id
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(prerequisites)
+    }
+
+    async fn find_by_course_ids(
+        &self,
+        conn: &mut DBConn,
+        course_ids: &[CourseID],
+    ) -> Result<Vec<CoursePrerequisite>> {
+        if course_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; course_ids.len()].join(", ");
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        for course_id in course_ids {
+            args.add(course_id);
+        }
+
+        let rows: Vec<(CourseID, CourseCode, u32)> = sqlx::query_as_with(
+            &format!(
+                "SELECT `course_id`, `prerequisite_code`, `min_score` FROM `course_prerequisites` WHERE `course_id` IN ({}) ORDER BY `course_id`, `prerequisite_code`",
+                placeholders
+            ),
+            args,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(course_id, code, min_score)| CoursePrerequisite {
+                course_id,
+                code,
+                min_score,
+            })
+            .collect())
+    }
+
+    async fn delete_by_course_id(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `course_prerequisites` WHERE `course_id` = ?",
+            course_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+}
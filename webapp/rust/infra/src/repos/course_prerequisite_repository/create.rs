@@ -0,0 +1,31 @@
+use crate::repos::course_prerequisite_repository::CoursePrerequisiteRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course_prerequisite::CoursePrerequisite;
+use isucholar_core::repos::course_prerequisite_repository::CoursePrerequisiteRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let prerequisite: CoursePrerequisite = Faker.fake();
+
+    let repo = CoursePrerequisiteRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &prerequisite).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_by_course_id(conn, &prerequisite.course_id)
+        .await
+        .unwrap();
+    assert_eq!(got, vec![prerequisite]);
+}
@@ -0,0 +1,54 @@
+use crate::repos::course_prerequisite_repository::CoursePrerequisiteRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::{CourseCode, CourseID};
+use isucholar_core::models::course_prerequisite::CoursePrerequisite;
+use isucholar_core::repos::course_prerequisite_repository::CoursePrerequisiteRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = CoursePrerequisiteRepositoryInfra {};
+    let course_id: CourseID = Faker.fake();
+    let other_course_id: CourseID = Faker.fake();
+    let prerequisite = CoursePrerequisite {
+        course_id: course_id.clone(),
+        code: CourseCode::new("PRE001".to_string()),
+        min_score: 60,
+    };
+    let other_prerequisite = CoursePrerequisite {
+        course_id: other_course_id.clone(),
+        code: CourseCode::new("PRE002".to_string()),
+        min_score: 0,
+    };
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &prerequisite).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &other_prerequisite).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_by_course_ids(conn, &[course_id, Faker.fake()])
+        .await
+        .unwrap();
+    assert_eq!(got, vec![prerequisite]);
+}
+
+#[tokio::test]
+async fn empty_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut conn = db_pool.acquire().await.unwrap();
+
+    let repo = CoursePrerequisiteRepositoryInfra {};
+    let got = repo.find_by_course_ids(&mut conn, &[]).await.unwrap();
+    assert!(got.is_empty());
+}
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use isucholar_core::db::{DBConn, DBPool};
 use isucholar_core::models::course::{
-    Course, CourseCode, CourseID, CourseWithTeacher, CreateCourse,
+    Course, CourseCode, CourseID, CourseWithTeacher, CreateCourse, UpdateCourse,
 };
 use isucholar_core::models::course_status::CourseStatus;
 use isucholar_core::models::course_type::CourseType;
@@ -15,18 +15,30 @@ use sqlx::Arguments;
 #[cfg(test)]
 mod create;
 #[cfg(test)]
+mod exist_by_code;
+#[cfg(test)]
 mod exist_by_id;
 #[cfg(test)]
 mod find_by_code;
 #[cfg(test)]
+mod find_capacity_limited_ids_by_ids;
+#[cfg(test)]
 mod find_for_share_lock_by_id;
 #[cfg(test)]
+mod find_for_share_lock_by_ids;
+#[cfg(test)]
 mod find_status_for_share_lock_by_id;
 #[cfg(test)]
 mod find_with_teacher_by_id;
 #[cfg(test)]
 mod for_update_by_id;
 #[cfg(test)]
+mod for_update_by_ids;
+#[cfg(test)]
+mod update_by_id;
+#[cfg(test)]
+mod update_capacity_by_id;
+#[cfg(test)]
 mod update_status_by_id;
 
 #[derive(Clone)]
@@ -36,7 +48,7 @@ pub struct CourseRepositoryInfra {}
 impl CourseRepository for CourseRepositoryInfra {
     async fn create(&self, conn: &mut DBConn, req: &CreateCourse) -> Result<CourseID> {
         let result = sqlx::query!(
-            "INSERT INTO `courses` (`id`, `code`, `type`, `name`, `description`, `credit`, `period`, `day_of_week`, `teacher_id`, `keywords`) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO `courses` (`id`, `code`, `type`, `name`, `description`, `credit`, `period`, `day_of_week`, `teacher_id`, `keywords`, `capacity`) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             &req.id,
             &req.code,
             &req.type_,
@@ -47,6 +59,7 @@ impl CourseRepository for CourseRepositoryInfra {
             &req.day_of_week,
             &req.teacher_id,
             &req.keywords,
+            &req.capacity,
         )
             .execute(conn)
             .await;
@@ -180,6 +193,34 @@ impl CourseRepository for CourseRepositoryInfra {
         Ok(course)
     }
 
+    async fn find_for_share_lock_by_ids(
+        &self,
+        conn: &mut DBConn,
+        ids: &[CourseID],
+    ) -> Result<Vec<Course>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(", ");
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        for id in ids {
+            args.add(id);
+        }
+
+        let courses: Vec<Course> = sqlx::query_as_with(
+            &format!(
+                "SELECT * FROM `courses` WHERE `id` IN ({}) FOR SHARE",
+                placeholders
+            ),
+            args,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(courses)
+    }
+
     async fn exist_by_id(&self, conn: &mut DBConn, id: &CourseID) -> Result<bool> {
         let count = sqlx::query_scalar!("SELECT COUNT(*) FROM `courses` WHERE `id` = ?", id)
             .fetch_one(conn)
@@ -188,6 +229,14 @@ impl CourseRepository for CourseRepositoryInfra {
         Ok(count == 1)
     }
 
+    async fn exist_by_code(&self, conn: &mut DBConn, code: &CourseCode) -> Result<bool> {
+        let count = sqlx::query_scalar!("SELECT COUNT(*) FROM `courses` WHERE `code` = ?", code)
+            .fetch_one(conn)
+            .await?;
+
+        Ok(count == 1)
+    }
+
     async fn for_update_by_id(&self, conn: &mut DBConn, id: &CourseID) -> Result<bool> {
         let count = sqlx::query_scalar!(
             "SELECT COUNT(*) FROM `courses` WHERE `id` = ? FOR UPDATE",
@@ -199,6 +248,58 @@ impl CourseRepository for CourseRepositoryInfra {
         Ok(count == 1)
     }
 
+    async fn find_capacity_limited_ids_by_ids(
+        &self,
+        conn: &mut DBConn,
+        ids: &[CourseID],
+    ) -> Result<Vec<CourseID>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(", ");
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        for id in ids {
+            args.add(id);
+        }
+
+        let course_ids: Vec<CourseID> = sqlx::query_scalar_with(
+            &format!(
+                "SELECT `id` FROM `courses` WHERE `id` IN ({}) AND `capacity` IS NOT NULL",
+                placeholders
+            ),
+            args,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(course_ids)
+    }
+
+    async fn for_update_by_ids(&self, conn: &mut DBConn, ids: &[CourseID]) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(", ");
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        for id in ids {
+            args.add(id);
+        }
+
+        sqlx::query_with(
+            &format!(
+                "SELECT `id` FROM `courses` WHERE `id` IN ({}) FOR UPDATE",
+                placeholders
+            ),
+            args,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(())
+    }
+
     async fn update_status_by_id(
         &self,
         conn: &mut DBConn,
@@ -216,6 +317,49 @@ impl CourseRepository for CourseRepositoryInfra {
         Ok(())
     }
 
+    async fn update_by_id(
+        &self,
+        conn: &mut DBConn,
+        id: &CourseID,
+        req: &UpdateCourse,
+    ) -> Result<()> {
+        sqlx::query!(
+            r"
+                UPDATE `courses`
+                SET `name` = ?, `description` = ?, `credit` = ?, `period` = ?, `day_of_week` = ?, `keywords` = ?
+                WHERE `id` = ?
+            ",
+            &req.name,
+            &req.description,
+            &req.credit,
+            &req.period,
+            &req.day_of_week,
+            &req.keywords,
+            id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update_capacity_by_id(
+        &self,
+        conn: &mut DBConn,
+        id: &CourseID,
+        capacity: Option<u32>,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE `courses` SET `capacity` = ? WHERE `id` = ?",
+            capacity,
+            id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
     async fn find_by_code(&self, conn: &mut DBConn, code: &CourseCode) -> Result<Course> {
         let course = sqlx::query_as!(
             Course,
@@ -231,7 +375,8 @@ impl CourseRepository for CourseRepositoryInfra {
                    day_of_week as `day_of_week:DayOfWeek`,
                    teacher_id as `teacher_id:UserID`,
                    keywords,
-                   status as `status:CourseStatus`
+                   status as `status:CourseStatus`,
+                   capacity
                 FROM `courses`
                 WHERE `code` = ?",
             code
@@ -262,6 +407,7 @@ impl CourseRepository for CourseRepositoryInfra {
                    teacher_id as `teacher_id:UserID`,
                    keywords,
                    status as `status:CourseStatus`,
+                   capacity,
                     `users`.`name` AS `teacher`
                 FROM `courses`
                 JOIN `users` ON `courses`.`teacher_id` = `users`.`id`
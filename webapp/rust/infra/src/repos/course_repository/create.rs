@@ -43,7 +43,8 @@ async fn success_case() {
                day_of_week as `day_of_week:DayOfWeek`,
                teacher_id as `teacher_id:UserID`,
                keywords,
-               status as `status:CourseStatus`
+               status as `status:CourseStatus`,
+               capacity
             FROM courses WHERE id = ?",
         &course_id
     )
@@ -58,6 +59,7 @@ async fn success_case() {
     assert_eq!(got.description, course.description);
     assert_eq!(got.period, course.period);
     assert_eq!(got.day_of_week, course.day_of_week);
+    assert_eq!(got.capacity, course.capacity);
     assert_eq!(got.keywords, course.keywords);
     assert_eq!(got.status, CourseStatus::Registration);
     assert_eq!(got.teacher_id, course.teacher_id);
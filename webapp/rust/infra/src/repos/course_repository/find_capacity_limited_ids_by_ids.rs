@@ -0,0 +1,64 @@
+use crate::repos::course_repository::CourseRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::{Course, CourseID};
+use isucholar_core::repos::course_repository::CourseRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+    let mut limited: Course = Faker.fake();
+    limited.capacity = Some(10);
+    let mut unlimited: Course = Faker.fake();
+    unlimited.capacity = None;
+    for course in [&limited, &unlimited] {
+        let conn = tx.acquire().await.unwrap();
+        sqlx::query!("INSERT INTO courses (id, code, type, name, description, credit, period, day_of_week, teacher_id, keywords, status, capacity) VALUES (?,?,?,?,?,?,?,?,?,?,?,?)",
+            &course.id,
+            &course.code,
+            &course.type_,
+            &course.name,
+            &course.description,
+            &course.credit,
+            &course.period,
+            &course.day_of_week,
+            &course.teacher_id,
+            &course.keywords,
+            &course.status,
+            &course.capacity,
+        ).execute(conn).await.unwrap();
+    }
+
+    let missing: CourseID = Faker.fake();
+    let ids = vec![unlimited.id.clone(), missing, limited.id.clone()];
+
+    let repo = CourseRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_capacity_limited_ids_by_ids(conn, &ids)
+        .await
+        .unwrap();
+    assert_eq!(got, vec![limited.id]);
+}
+
+#[tokio::test]
+async fn empty_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let repo = CourseRepositoryInfra {};
+    let got = repo
+        .find_capacity_limited_ids_by_ids(conn, &[])
+        .await
+        .unwrap();
+    assert!(got.is_empty())
+}
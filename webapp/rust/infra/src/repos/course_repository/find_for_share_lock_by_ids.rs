@@ -0,0 +1,58 @@
+use crate::repos::course_repository::CourseRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::{Course, CourseID};
+use isucholar_core::repos::course_repository::CourseRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+    let courses: Vec<Course> = vec![Faker.fake(), Faker.fake()];
+    for course in &courses {
+        let conn = tx.acquire().await.unwrap();
+        sqlx::query!("INSERT INTO courses (id, code, type, name, description, credit, period, day_of_week, teacher_id, keywords, status) VALUES (?,?,?,?,?,?,?,?,?,?,?)",
+            &course.id,
+            &course.code,
+            &course.type_,
+            &course.name,
+            &course.description,
+            &course.credit,
+            &course.period,
+            &course.day_of_week,
+            &course.teacher_id,
+            &course.keywords,
+            &course.status,
+        ).execute(conn).await.unwrap();
+    }
+
+    // 存在しない科目は結果に含まれない
+    let missing: CourseID = Faker.fake();
+    let ids = vec![courses[0].id.clone(), missing, courses[1].id.clone()];
+
+    let repo = CourseRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_for_share_lock_by_ids(conn, &ids).await.unwrap();
+    assert_eq!(got.len(), 2);
+    for course in &courses {
+        assert!(got.contains(course));
+    }
+}
+
+#[tokio::test]
+async fn empty_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let repo = CourseRepositoryInfra {};
+    let got = repo.find_for_share_lock_by_ids(conn, &[]).await.unwrap();
+    assert!(got.is_empty())
+}
@@ -0,0 +1,89 @@
+use crate::repos::course_repository::CourseRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::{Course, CourseCode, CourseID, UpdateCourse};
+use isucholar_core::models::course_status::CourseStatus;
+use isucholar_core::models::course_type::CourseType;
+use isucholar_core::models::day_of_week::DayOfWeek;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::course_repository::CourseRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+    let mut course: Course = Faker.fake();
+    course.status = CourseStatus::Registration;
+
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!("INSERT INTO courses (id, code, type, name, description, credit, period, day_of_week, teacher_id, keywords, status) VALUES (?,?,?,?,?,?,?,?,?,?,?)",
+        &course.id,
+        &course.code,
+        &course.type_,
+        &course.name,
+        &course.description,
+        &course.credit,
+        &course.period,
+        &course.day_of_week,
+        &course.teacher_id,
+        &course.keywords,
+        &course.status,
+    ).execute(conn).await.unwrap();
+
+    let req = UpdateCourse {
+        name: "updated".to_string(),
+        description: "updated description".to_string(),
+        credit: 2,
+        period: 3,
+        day_of_week: DayOfWeek::Friday,
+        keywords: "updated keywords".to_string(),
+    };
+
+    let repo = CourseRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    repo.update_by_id(conn, &course.id, &req).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = sqlx::query_as!(
+        Course,
+        r"
+            SELECT
+                id as `id:CourseID`,
+                code as `code:CourseCode`,
+                type as `type_:CourseType`,
+                name,
+                description,
+                credit,
+                period,
+                day_of_week as `day_of_week:DayOfWeek`,
+                teacher_id as `teacher_id:UserID`,
+                keywords,
+                status as `status:CourseStatus`,
+                capacity
+            FROM courses WHERE id = ?
+        ",
+        &course.id
+    )
+    .fetch_one(conn)
+    .await
+    .unwrap();
+
+    // 科目コードや担当教員、ステータスは変更しない
+    assert_eq!(got.code, course.code);
+    assert_eq!(got.type_, course.type_);
+    assert_eq!(got.teacher_id, course.teacher_id);
+    assert_eq!(got.status, course.status);
+    assert_eq!(got.name, req.name);
+    assert_eq!(got.description, req.description);
+    assert_eq!(got.credit, req.credit);
+    assert_eq!(got.period, req.period);
+    assert_eq!(got.day_of_week, req.day_of_week);
+    assert_eq!(got.keywords, req.keywords);
+}
@@ -0,0 +1,62 @@
+use crate::repos::course_repository::CourseRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::Course;
+use isucholar_core::models::course_status::CourseStatus;
+use isucholar_core::repos::course_repository::CourseRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+    let mut course: Course = Faker.fake();
+    course.status = CourseStatus::Registration;
+
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!("INSERT INTO courses (id, code, type, name, description, credit, period, day_of_week, teacher_id, keywords, status) VALUES (?,?,?,?,?,?,?,?,?,?,?)",
+        &course.id,
+        &course.code,
+        &course.type_,
+        &course.name,
+        &course.description,
+        &course.credit,
+        &course.period,
+        &course.day_of_week,
+        &course.teacher_id,
+        &course.keywords,
+        &course.status,
+    ).execute(conn).await.unwrap();
+
+    let repo = CourseRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    repo.update_capacity_by_id(conn, &course.id, Some(30))
+        .await
+        .unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let capacity = sqlx::query_scalar!("SELECT capacity FROM courses WHERE id = ?", &course.id)
+        .fetch_one(conn)
+        .await
+        .unwrap();
+    assert_eq!(capacity, Some(30));
+
+    // Noneを指定すると定員なしに戻る
+    let conn = tx.acquire().await.unwrap();
+    repo.update_capacity_by_id(conn, &course.id, None)
+        .await
+        .unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let capacity = sqlx::query_scalar!("SELECT capacity FROM courses WHERE id = ?", &course.id)
+        .fetch_one(conn)
+        .await
+        .unwrap();
+    assert_eq!(capacity, None);
+}
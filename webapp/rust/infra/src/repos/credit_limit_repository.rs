@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::credit_limit_repository::CreditLimitRepository;
+use isucholar_core::repos::error::Result;
+
+#[cfg(test)]
+mod create_or_update;
+#[cfg(test)]
+mod delete_by_user_id;
+
+#[derive(Clone)]
+pub struct CreditLimitRepositoryInfra {}
+
+#[async_trait]
+impl CreditLimitRepository for CreditLimitRepositoryInfra {
+    async fn find_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<Option<u32>> {
+        let credit_limit = sqlx::query_scalar!(
+            "SELECT `credit_limit` FROM `credit_limits` WHERE `user_id` = ?",
+            user_id
+        )
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(credit_limit)
+    }
+
+    async fn create_or_update(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        credit_limit: u32,
+    ) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO `credit_limits` (`user_id`, `credit_limit`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `credit_limit` = VALUES(`credit_limit`)",
+            user_id,
+            credit_limit,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()> {
+        sqlx::query!("DELETE FROM `credit_limits` WHERE `user_id` = ?", user_id)
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+}
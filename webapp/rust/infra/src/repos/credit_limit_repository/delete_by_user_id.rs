@@ -0,0 +1,30 @@
+use crate::repos::credit_limit_repository::CreditLimitRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::credit_limit_repository::CreditLimitRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = CreditLimitRepositoryInfra {};
+    let user_id: UserID = Faker.fake();
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create_or_update(conn, &user_id, 20).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.delete_by_user_id(conn, &user_id).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_by_user_id(conn, &user_id).await.unwrap();
+    assert!(got.is_none());
+}
@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::login_attempt::{LoginAttempt, LoginAttemptID};
+use isucholar_core::repos::error::Result;
+use isucholar_core::repos::login_attempt_repository::LoginAttemptRepository;
+use sqlx::Arguments;
+
+#[cfg(test)]
+mod create;
+#[cfg(test)]
+mod delete_by_user_code;
+#[cfg(test)]
+mod find_all_by_ip_address_since;
+#[cfg(test)]
+mod find_all_by_user_code;
+#[cfg(test)]
+mod find_all_by_user_code_since;
+#[cfg(test)]
+mod find_all_failed;
+
+#[derive(Clone)]
+pub struct LoginAttemptRepositoryInfra {}
+
+#[async_trait]
+impl LoginAttemptRepository for LoginAttemptRepositoryInfra {
+    async fn create(&self, conn: &mut DBConn, attempt: &LoginAttempt) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO `login_attempts` (`id`, `user_code`, `ip_address`, `succeeded`, `created_at`) VALUES (?, ?, ?, ?, ?)",
+            &attempt.id,
+            &attempt.user_code,
+            &attempt.ip_address,
+            &attempt.succeeded,
+            &attempt.created_at,
+        )
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_code(&self, conn: &mut DBConn, user_code: &str) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `login_attempts` WHERE `user_code` = ?",
+            user_code
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_all_by_user_code(
+        &self,
+        conn: &mut DBConn,
+        user_code: &str,
+    ) -> Result<Vec<LoginAttempt>> {
+        let attempts = sqlx::query_as!(
+            LoginAttempt,
+            r"
+                SELECT
+                    id as `id:LoginAttemptID`,
+                    user_code,
+                    ip_address,
+                    succeeded as `succeeded:bool`,
+                    created_at
+                FROM `login_attempts`
+                WHERE `user_code` = ?
+                ORDER BY `created_at` DESC, `id` DESC
+            ",
+            user_code
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(attempts)
+    }
+
+    async fn find_all_by_user_code_since(
+        &self,
+        conn: &mut DBConn,
+        user_code: &str,
+        since: &NaiveDateTime,
+    ) -> Result<Vec<LoginAttempt>> {
+        let attempts = sqlx::query_as!(
+            LoginAttempt,
+            r"
+                SELECT
+                    id as `id:LoginAttemptID`,
+                    user_code,
+                    ip_address,
+                    succeeded as `succeeded:bool`,
+                    created_at
+                FROM `login_attempts`
+                WHERE `user_code` = ? AND `created_at` >= ?
+                ORDER BY `created_at` DESC, `id` DESC
+            ",
+            user_code,
+            since
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(attempts)
+    }
+
+    async fn find_all_by_ip_address_since(
+        &self,
+        conn: &mut DBConn,
+        ip_address: &str,
+        since: &NaiveDateTime,
+    ) -> Result<Vec<LoginAttempt>> {
+        let attempts = sqlx::query_as!(
+            LoginAttempt,
+            r"
+                SELECT
+                    id as `id:LoginAttemptID`,
+                    user_code,
+                    ip_address,
+                    succeeded as `succeeded:bool`,
+                    created_at
+                FROM `login_attempts`
+                WHERE `ip_address` = ? AND `created_at` >= ?
+                ORDER BY `created_at` DESC, `id` DESC
+            ",
+            ip_address,
+            since
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(attempts)
+    }
+
+    async fn find_all_failed(
+        &self,
+        conn: &mut DBConn,
+        user_code: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<LoginAttempt>> {
+        let query = "SELECT * FROM `login_attempts` WHERE `succeeded` = false";
+        let mut condition = String::new();
+        let mut args = sqlx::mysql::MySqlArguments::default();
+
+        if let Some(user_code) = user_code {
+            condition.push_str(" AND `user_code` = ?");
+            args.add(user_code);
+        }
+
+        condition.push_str(" ORDER BY `created_at` DESC, `id` DESC");
+
+        // limitより多く上限を設定し、実際にlimitより多くレコードが取得できた場合は次のページが存在する
+        condition.push_str(" LIMIT ? OFFSET ?");
+        args.add(limit + 1);
+        args.add(offset);
+
+        let attempts: Vec<LoginAttempt> =
+            sqlx::query_as_with(&format!("{}{}", query, condition), args)
+                .fetch_all(conn)
+                .await?;
+
+        Ok(attempts)
+    }
+}
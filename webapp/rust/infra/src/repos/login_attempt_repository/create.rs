@@ -0,0 +1,39 @@
+use crate::repos::login_attempt_repository::LoginAttemptRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::login_attempt::LoginAttempt;
+use isucholar_core::repos::login_attempt_repository::LoginAttemptRepository;
+use sqlx::Acquire;
+
+fn attempt(
+    user_code: &str,
+    ip_address: &str,
+    succeeded: bool,
+    created_at: chrono::NaiveDateTime,
+) -> LoginAttempt {
+    let mut attempt: LoginAttempt = Faker.fake();
+    attempt.user_code = user_code.to_string();
+    attempt.ip_address = ip_address.to_string();
+    attempt.succeeded = succeeded;
+    attempt.created_at = created_at;
+    attempt
+}
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let repo = LoginAttemptRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let input = attempt("S00001", "192.0.2.1", false, now);
+
+    repo.create(conn, &input).await.unwrap();
+    let got = repo
+        .find_all_by_user_code_since(conn, "S00001", &now)
+        .await
+        .unwrap();
+    assert_eq!(got, vec![input]);
+}
@@ -0,0 +1,38 @@
+use crate::repos::login_attempt_repository::LoginAttemptRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::login_attempt::LoginAttempt;
+use isucholar_core::repos::login_attempt_repository::LoginAttemptRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let repo = LoginAttemptRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let mut deleted: LoginAttempt = Faker.fake();
+    deleted.user_code = "S00001".to_string();
+    deleted.created_at = now;
+    let mut other = deleted.clone();
+    other.id = Faker.fake();
+    other.user_code = "S00002".to_string();
+
+    repo.create(conn, &deleted).await.unwrap();
+    repo.create(conn, &other).await.unwrap();
+    repo.delete_by_user_code(conn, "S00001").await.unwrap();
+
+    let got = repo
+        .find_all_by_user_code_since(conn, "S00001", &now)
+        .await
+        .unwrap();
+    assert!(got.is_empty());
+    let got = repo
+        .find_all_by_user_code_since(conn, "S00002", &now)
+        .await
+        .unwrap();
+    assert_eq!(got, vec![other]);
+}
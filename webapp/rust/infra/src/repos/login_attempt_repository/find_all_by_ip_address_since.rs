@@ -0,0 +1,70 @@
+use crate::repos::login_attempt_repository::LoginAttemptRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::login_attempt::LoginAttempt;
+use isucholar_core::repos::login_attempt_repository::LoginAttemptRepository;
+use sqlx::Acquire;
+
+fn attempt(
+    user_code: &str,
+    ip_address: &str,
+    succeeded: bool,
+    created_at: chrono::NaiveDateTime,
+) -> LoginAttempt {
+    let mut attempt: LoginAttempt = Faker.fake();
+    attempt.user_code = user_code.to_string();
+    attempt.ip_address = ip_address.to_string();
+    attempt.succeeded = succeeded;
+    attempt.created_at = created_at;
+    attempt
+}
+
+#[tokio::test]
+async fn empty_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let repo = LoginAttemptRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let got = repo
+        .find_all_by_ip_address_since(conn, "192.0.2.1", &now)
+        .await
+        .unwrap();
+    assert!(got.is_empty());
+}
+
+#[tokio::test]
+async fn newest_first_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+
+    let repo = LoginAttemptRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let too_old = attempt(
+        "S00001",
+        "192.0.2.1",
+        false,
+        now - chrono::Duration::minutes(10),
+    );
+    let older = attempt(
+        "S00001",
+        "192.0.2.1",
+        true,
+        now - chrono::Duration::minutes(1),
+    );
+    let newer = attempt("S00001", "192.0.2.1", false, now);
+    let others = attempt("S00001", "192.0.2.2", false, now);
+    for input in [&too_old, &older, &newer, &others] {
+        let conn = tx.acquire().await.unwrap();
+        repo.create(conn, input).await.unwrap();
+    }
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_all_by_ip_address_since(conn, "192.0.2.1", &(now - chrono::Duration::minutes(5)))
+        .await
+        .unwrap();
+    assert_eq!(got, vec![newer, older]);
+}
@@ -0,0 +1,33 @@
+use crate::repos::login_attempt_repository::LoginAttemptRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::login_attempt::LoginAttempt;
+use isucholar_core::repos::login_attempt_repository::LoginAttemptRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let repo = LoginAttemptRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let mut newer: LoginAttempt = Faker.fake();
+    newer.user_code = "S00001".to_string();
+    newer.created_at = now;
+    let mut older = newer.clone();
+    older.id = Faker.fake();
+    older.created_at = now - chrono::Duration::days(30);
+    let mut other = newer.clone();
+    other.id = Faker.fake();
+    other.user_code = "S00002".to_string();
+
+    for attempt in [&newer, &older, &other] {
+        repo.create(conn, attempt).await.unwrap();
+    }
+
+    let got = repo.find_all_by_user_code(conn, "S00001").await.unwrap();
+    assert_eq!(got, vec![newer, older]);
+}
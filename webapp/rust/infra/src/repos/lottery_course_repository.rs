@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::repos::error::Result;
+use isucholar_core::repos::lottery_course_repository::LotteryCourseRepository;
+use sqlx::Arguments;
+
+#[cfg(test)]
+mod create;
+#[cfg(test)]
+mod delete;
+#[cfg(test)]
+mod find_course_ids_by_course_ids;
+
+#[derive(Clone)]
+pub struct LotteryCourseRepositoryInfra {}
+
+#[async_trait]
+impl LotteryCourseRepository for LotteryCourseRepositoryInfra {
+    async fn create(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()> {
+        sqlx::query!(
+            "INSERT IGNORE INTO `lottery_courses` (`course_id`) VALUES (?)",
+            course_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `lottery_courses` WHERE `course_id` = ?",
+            course_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn exist_by_course_id(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<bool> {
+        let count: i64 = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM `lottery_courses` WHERE `course_id` = ?",
+            course_id
+        )
+        .fetch_one(conn)
+        .await?;
+
+        Ok(count != 0)
+    }
+
+    async fn find_all_course_ids(&self, conn: &mut DBConn) -> Result<Vec<CourseID>> {
+        let course_ids: Vec<CourseID> = sqlx::query_scalar!(
+            "SELECT `course_id` AS `course_id:CourseID` FROM `lottery_courses` ORDER BY `course_id`"
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(course_ids)
+    }
+    async fn find_course_ids_by_course_ids(
+        &self,
+        conn: &mut DBConn,
+        course_ids: &[CourseID],
+    ) -> Result<Vec<CourseID>> {
+        if course_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; course_ids.len()].join(", ");
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        for course_id in course_ids {
+            args.add(course_id);
+        }
+
+        let found: Vec<CourseID> = sqlx::query_scalar_with(
+            &format!(
+                "SELECT `course_id` FROM `lottery_courses` WHERE `course_id` IN ({})",
+                placeholders
+            ),
+            args,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(found)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::repos::lottery_course_repository::LotteryCourseRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::repos::lottery_course_repository::LotteryCourseRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = LotteryCourseRepositoryInfra {};
+    let course_id: CourseID = Faker.fake();
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &course_id).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.delete(conn, &course_id).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    assert!(!repo.exist_by_course_id(conn, &course_id).await.unwrap());
+}
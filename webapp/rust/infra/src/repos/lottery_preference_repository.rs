@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::lottery_preference::LotteryPreference;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::error::Result;
+use isucholar_core::repos::lottery_preference_repository::LotteryPreferenceRepository;
+
+#[cfg(test)]
+mod create;
+#[cfg(test)]
+mod delete_by_course_id;
+#[cfg(test)]
+mod delete_by_user_id;
+
+#[derive(Clone)]
+pub struct LotteryPreferenceRepositoryInfra {}
+
+#[async_trait]
+impl LotteryPreferenceRepository for LotteryPreferenceRepositoryInfra {
+    async fn create(&self, conn: &mut DBConn, preference: &LotteryPreference) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO `lottery_preferences` (`user_id`, `course_id`, `rank`) VALUES (?, ?, ?)",
+            &preference.user_id,
+            &preference.course_id,
+            &preference.rank,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<LotteryPreference>> {
+        let preferences: Vec<LotteryPreference> = sqlx::query_as!(
+            LotteryPreference,
+            r"
+                SELECT
+                    user_id as `user_id:UserID`,
+                    course_id as `course_id:CourseID`,
+                    `rank`
+                FROM `lottery_preferences`
+                WHERE `user_id` = ?
+                ORDER BY `rank`
+            ",
+            user_id
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(preferences)
+    }
+
+    async fn find_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<Vec<LotteryPreference>> {
+        let preferences: Vec<LotteryPreference> = sqlx::query_as!(
+            LotteryPreference,
+            r"
+                SELECT
+                    user_id as `user_id:UserID`,
+                    course_id as `course_id:CourseID`,
+                    `rank`
+                FROM `lottery_preferences`
+                WHERE `course_id` = ?
+                ORDER BY `user_id`
+            ",
+            course_id
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(preferences)
+    }
+
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `lottery_preferences` WHERE `user_id` = ?",
+            user_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_by_course_id(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `lottery_preferences` WHERE `course_id` = ?",
+            course_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::repos::lottery_preference_repository::LotteryPreferenceRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::lottery_preference::LotteryPreference;
+use isucholar_core::repos::lottery_preference_repository::LotteryPreferenceRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = LotteryPreferenceRepositoryInfra {};
+    let mut first: LotteryPreference = Faker.fake();
+    first.rank = 1;
+    let mut second: LotteryPreference = Faker.fake();
+    second.user_id = first.user_id.clone();
+    second.rank = 2;
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &second).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &first).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_by_user_id(conn, &first.user_id).await.unwrap();
+    assert_eq!(got, vec![first.clone(), second]);
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_by_course_id(conn, &first.course_id)
+        .await
+        .unwrap();
+    assert_eq!(got, vec![first]);
+}
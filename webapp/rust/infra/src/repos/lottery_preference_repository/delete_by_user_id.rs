@@ -0,0 +1,35 @@
+use crate::repos::lottery_preference_repository::LotteryPreferenceRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::lottery_preference::LotteryPreference;
+use isucholar_core::repos::lottery_preference_repository::LotteryPreferenceRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = LotteryPreferenceRepositoryInfra {};
+    let preference: LotteryPreference = Faker.fake();
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &preference).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.delete_by_user_id(conn, &preference.user_id)
+        .await
+        .unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_by_user_id(conn, &preference.user_id)
+        .await
+        .unwrap();
+    assert!(got.is_empty());
+}
@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::personal_access_token::{PersonalAccessToken, PersonalAccessTokenID};
+use isucholar_core::models::personal_access_token_scope::PersonalAccessTokenScope;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::error::Result;
+use isucholar_core::repos::personal_access_token_repository::PersonalAccessTokenRepository;
+
+#[cfg(test)]
+mod create;
+#[cfg(test)]
+mod delete_by_id_and_user_id;
+#[cfg(test)]
+mod delete_by_user_id;
+#[cfg(test)]
+mod find_all_by_user_id;
+#[cfg(test)]
+mod find_unexpired_by_hashed_token;
+
+#[derive(Clone)]
+pub struct PersonalAccessTokenRepositoryInfra {}
+
+#[async_trait]
+impl PersonalAccessTokenRepository for PersonalAccessTokenRepositoryInfra {
+    async fn create(&self, conn: &mut DBConn, token: &PersonalAccessToken) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO `personal_access_tokens` (`id`, `user_id`, `name`, `hashed_token`, `scope`, `expires_at`, `created_at`) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            &token.id,
+            &token.user_id,
+            &token.name,
+            &token.hashed_token,
+            &token.scope,
+            &token.expires_at,
+            &token.created_at,
+        )
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `personal_access_tokens` WHERE `user_id` = ?",
+            user_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_all_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<PersonalAccessToken>> {
+        let tokens = sqlx::query_as!(
+            PersonalAccessToken,
+            r"
+                SELECT
+                    id as `id:PersonalAccessTokenID`,
+                    user_id as `user_id:UserID`,
+                    name,
+                    hashed_token,
+                    scope as `scope:PersonalAccessTokenScope`,
+                    expires_at,
+                    created_at
+                FROM `personal_access_tokens`
+                WHERE `user_id` = ?
+                ORDER BY `id`
+            ",
+            user_id
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(tokens)
+    }
+
+    async fn find_unexpired_by_hashed_token(
+        &self,
+        conn: &mut DBConn,
+        hashed_token: &str,
+        now: &NaiveDateTime,
+    ) -> Result<Option<PersonalAccessToken>> {
+        let token = sqlx::query_as!(
+            PersonalAccessToken,
+            r"
+                SELECT
+                    id as `id:PersonalAccessTokenID`,
+                    user_id as `user_id:UserID`,
+                    name,
+                    hashed_token,
+                    scope as `scope:PersonalAccessTokenScope`,
+                    expires_at,
+                    created_at
+                FROM `personal_access_tokens`
+                WHERE `hashed_token` = ? AND `expires_at` > ?
+            ",
+            hashed_token,
+            now
+        )
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(token)
+    }
+
+    async fn delete_by_id_and_user_id(
+        &self,
+        conn: &mut DBConn,
+        id: &PersonalAccessTokenID,
+        user_id: &UserID,
+    ) -> Result<bool> {
+        let result = sqlx::query!(
+            "DELETE FROM `personal_access_tokens` WHERE `id` = ? AND `user_id` = ?",
+            id,
+            user_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
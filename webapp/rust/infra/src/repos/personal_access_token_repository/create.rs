@@ -0,0 +1,46 @@
+use crate::repos::personal_access_token_repository::PersonalAccessTokenRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::personal_access_token::PersonalAccessToken;
+use isucholar_core::repos::personal_access_token_repository::PersonalAccessTokenRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = PersonalAccessTokenRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let mut input: PersonalAccessToken = Faker.fake();
+    input.hashed_token = "a".repeat(64);
+    input.created_at = now;
+    input.expires_at = now + chrono::Duration::days(1);
+    let conn = tx.acquire().await.unwrap();
+
+    repo.create(conn, &input).await.unwrap();
+    let got = repo
+        .find_all_by_user_id(conn, &input.user_id)
+        .await
+        .unwrap();
+    assert_eq!(got, vec![input]);
+}
+
+#[tokio::test]
+#[should_panic(expected = "SqlError")]
+async fn other_error_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let repo = PersonalAccessTokenRepositoryInfra {};
+    let input: PersonalAccessToken = Faker.fake();
+    repo.create(conn, &input).await.unwrap();
+}
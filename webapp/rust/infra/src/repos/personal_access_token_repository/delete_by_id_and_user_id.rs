@@ -0,0 +1,74 @@
+use crate::repos::personal_access_token_repository::PersonalAccessTokenRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::personal_access_token::PersonalAccessToken;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::personal_access_token_repository::PersonalAccessTokenRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = PersonalAccessTokenRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let mut input: PersonalAccessToken = Faker.fake();
+    input.hashed_token = "a".repeat(64);
+    input.created_at = now;
+    input.expires_at = now + chrono::Duration::days(1);
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &input).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .delete_by_id_and_user_id(conn, &input.id, &input.user_id)
+        .await
+        .unwrap();
+    assert!(got);
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_all_by_user_id(conn, &input.user_id)
+        .await
+        .unwrap();
+    assert!(got.is_empty());
+}
+
+#[tokio::test]
+async fn other_user_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = PersonalAccessTokenRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let mut input: PersonalAccessToken = Faker.fake();
+    input.hashed_token = "a".repeat(64);
+    input.created_at = now;
+    input.expires_at = now + chrono::Duration::days(1);
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &input).await.unwrap();
+
+    let other_user_id: UserID = Faker.fake();
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .delete_by_id_and_user_id(conn, &input.id, &other_user_id)
+        .await
+        .unwrap();
+    assert!(!got);
+}
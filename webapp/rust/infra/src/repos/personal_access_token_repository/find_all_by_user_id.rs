@@ -0,0 +1,53 @@
+use crate::repos::personal_access_token_repository::PersonalAccessTokenRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::personal_access_token::PersonalAccessToken;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::personal_access_token_repository::PersonalAccessTokenRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn empty_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let user_id: UserID = Faker.fake();
+
+    let repo = PersonalAccessTokenRepositoryInfra {};
+    let got = repo.find_all_by_user_id(conn, &user_id).await.unwrap();
+    assert!(got.is_empty());
+}
+
+#[tokio::test]
+async fn other_user_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = PersonalAccessTokenRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let mut mine: PersonalAccessToken = Faker.fake();
+    mine.hashed_token = "a".repeat(64);
+    mine.created_at = now;
+    mine.expires_at = now + chrono::Duration::days(1);
+    let mut others: PersonalAccessToken = Faker.fake();
+    others.hashed_token = "b".repeat(64);
+    others.created_at = now;
+    others.expires_at = now + chrono::Duration::days(1);
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &mine).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &others).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_all_by_user_id(conn, &mine.user_id).await.unwrap();
+    assert_eq!(got, vec![mine]);
+}
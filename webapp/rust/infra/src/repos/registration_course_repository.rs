@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use futures::StreamExt;
 use isucholar_core::db::DBConn;
 use isucholar_core::models::course::{Course, CourseCode, CourseID};
+use isucholar_core::models::course_result::CourseTotalScore;
 use isucholar_core::models::course_status::CourseStatus;
 use isucholar_core::models::course_type::CourseType;
 use isucholar_core::models::day_of_week::DayOfWeek;
@@ -10,6 +11,8 @@ use isucholar_core::repos::error::Result;
 use isucholar_core::repos::registration_course_repository::RegistrationCourseRepository;
 use num_traits::ToPrimitive;
 
+#[cfg(test)]
+mod find_closed_total_scores_by_user_id;
 #[cfg(test)]
 mod find_courses_by_user_id;
 #[cfg(test)]
@@ -41,10 +44,11 @@ impl RegistrationCourseRepository for RegistrationCourseRepositoryInfra {
                     courses.day_of_week as `day_of_week:DayOfWeek`,
                     courses.teacher_id as `teacher_id:UserID`,
                     courses.keywords,
-                    courses.status as `status:CourseStatus`
+                    courses.status as `status:CourseStatus`,
+                    courses.capacity
                 FROM `registrations`
                 JOIN `courses` ON `registrations`.`course_id` = `courses`.`id`
-                WHERE `user_id` = ?
+                WHERE `user_id` = ? AND `registrations`.`withdrawn_at` IS NULL
             ",
             &user_id
         )
@@ -73,10 +77,11 @@ impl RegistrationCourseRepository for RegistrationCourseRepositoryInfra {
                     courses.day_of_week as `day_of_week:DayOfWeek`,
                     courses.teacher_id as `teacher_id:UserID`,
                     courses.keywords,
-                    courses.status as `status:CourseStatus`
+                    courses.status as `status:CourseStatus`,
+                    courses.capacity
                 FROM `courses`
                 JOIN `registrations` ON `courses`.`id` = `registrations`.`course_id`
-                WHERE `courses`.`status` != ? AND `registrations`.`user_id` = ?
+                WHERE `courses`.`status` != ? AND `registrations`.`user_id` = ? AND `registrations`.`withdrawn_at` IS NULL
             ",
             CourseStatus::Closed,
             user_id
@@ -87,6 +92,40 @@ impl RegistrationCourseRepository for RegistrationCourseRepositoryInfra {
         Ok(courses)
     }
 
+    async fn find_closed_total_scores_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<CourseTotalScore>> {
+        let mut rows = sqlx::query!(
+            r"
+                SELECT
+                    `courses`.`code` AS `code:CourseCode`,
+                    IFNULL(SUM(`submissions`.`score`), 0) AS `total_score`
+                FROM `registrations`
+                JOIN `courses` ON `registrations`.`course_id` = `courses`.`id`
+                LEFT JOIN `classes` ON `courses`.`id` = `classes`.`course_id`
+                LEFT JOIN `submissions` ON `registrations`.`user_id` = `submissions`.`user_id` AND `submissions`.`class_id` = `classes`.`id`
+                WHERE `registrations`.`user_id` = ? AND `registrations`.`withdrawn_at` IS NULL AND `courses`.`status` = ?
+                GROUP BY `courses`.`id`
+            ",
+            user_id,
+            CourseStatus::Closed,
+        )
+        .fetch(conn);
+        let mut results = Vec::new();
+        while let Some(row) = rows.next().await {
+            let row = row?;
+            let total_score: sqlx::types::BigDecimal = row.total_score;
+            results.push(CourseTotalScore {
+                code: row.code,
+                total_score: total_score.to_i64().unwrap(),
+            });
+        }
+
+        Ok(results)
+    }
+
     async fn find_total_scores_by_course_id_group_by_user_id(
         &self,
         conn: &mut DBConn,
@@ -96,7 +135,7 @@ impl RegistrationCourseRepository for RegistrationCourseRepositoryInfra {
         r"
                 SELECT IFNULL(SUM(`submissions`.`score`), 0) AS `total_score`
                 FROM `users`
-                JOIN `registrations` ON `users`.`id` = `registrations`.`user_id`
+                JOIN `registrations` ON `users`.`id` = `registrations`.`user_id` AND `registrations`.`withdrawn_at` IS NULL
                 JOIN `courses` ON `registrations`.`course_id` = `courses`.`id`
                 LEFT JOIN `classes` ON `courses`.`id` = `classes`.`course_id`
                 LEFT JOIN `submissions` ON `users`.`id` = `submissions`.`user_id` AND `submissions`.`class_id` = `classes`.`id`
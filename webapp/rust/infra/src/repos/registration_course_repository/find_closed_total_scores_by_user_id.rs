@@ -0,0 +1,89 @@
+use crate::repos::registration_course_repository::RegistrationCourseRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::Course;
+use isucholar_core::models::course_result::CourseTotalScore;
+use isucholar_core::models::course_status::CourseStatus;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::registration_course_repository::RegistrationCourseRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let mut closed_course: Course = Faker.fake();
+    closed_course.status = CourseStatus::Closed;
+    let mut course: Course = Faker.fake();
+    course.status = CourseStatus::InProgress;
+    for c in [&closed_course, &course] {
+        let conn = tx.acquire().await.unwrap();
+        sqlx::query!("INSERT INTO courses (id, code, type, name, description, credit, period, day_of_week, teacher_id, keywords, status) VALUES (?,?,?,?,?,?,?,?,?,?,?)",
+            &c.id,
+            &c.code,
+            &c.type_,
+            &c.name,
+            &c.description,
+            &c.credit,
+            &c.period,
+            &c.day_of_week,
+            &c.teacher_id,
+            &c.keywords,
+            &c.status,
+        ).execute(conn).await.unwrap();
+    }
+
+    let user_id: UserID = Faker.fake();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO registrations (course_id, user_id) VALUES (?, ?), (?, ?)",
+        &closed_course.id,
+        &user_id,
+        &course.id,
+        &user_id,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO classes (id, course_id, part, title, description, submission_closed) VALUES ('class1', ?, 1, '', '', true), ('class2', ?, 2, '', '', true)",
+        &closed_course.id,
+        &closed_course.id,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO submissions (user_id, class_id, file_name, score) VALUES (?, 'class1', '', 40), (?, 'class2', '', 35)",
+        &user_id,
+        &user_id,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    // 終了していない科目の成績は含めない
+    let repo = RegistrationCourseRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_closed_total_scores_by_user_id(conn, &user_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        got,
+        vec![CourseTotalScore {
+            code: closed_course.code,
+            total_score: 75,
+        }]
+    );
+}
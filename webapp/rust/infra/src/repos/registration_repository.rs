@@ -1,17 +1,34 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use isucholar_core::db::DBConn;
 use isucholar_core::models::course::CourseID;
+use isucholar_core::models::course_student::CourseStudent;
 use isucholar_core::models::user::{User, UserCode, UserID};
 use isucholar_core::models::user_type::UserType;
 use isucholar_core::repos::error::Result;
 use isucholar_core::repos::registration_repository::RegistrationRepository;
+use sqlx::Arguments;
 
+#[cfg(test)]
+mod count_for_share_lock_by_course_id;
+#[cfg(test)]
+mod count_for_share_lock_by_course_ids;
 #[cfg(test)]
 mod create_or_update;
 #[cfg(test)]
+mod create_or_update_all;
+#[cfg(test)]
+mod delete;
+#[cfg(test)]
 mod exist_by_user_id_and_course_id;
 #[cfg(test)]
+mod find_course_ids_by_user_id_and_course_ids;
+#[cfg(test)]
+mod find_students_by_course_id;
+#[cfg(test)]
 mod find_users_by_course_id;
+#[cfg(test)]
+mod withdraw;
 
 #[derive(Clone)]
 pub struct RegistrationRepositoryInfra {}
@@ -24,8 +41,9 @@ impl RegistrationRepository for RegistrationRepositoryInfra {
         user_id: &UserID,
         course_id: &CourseID,
     ) -> Result<()> {
+        // 履修中止から再登録した場合は、登録日時を再登録した日時にする
         sqlx::query!(
-            "INSERT INTO `registrations` (`course_id`, `user_id`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `course_id` = VALUES(`course_id`), `user_id` = VALUES(`user_id`)",
+            "INSERT INTO `registrations` (`course_id`, `user_id`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `course_id` = VALUES(`course_id`), `user_id` = VALUES(`user_id`), `registered_at` = IF(`withdrawn_at` IS NULL, `registered_at`, CURRENT_TIMESTAMP(6)), `withdrawn_at` = NULL",
             course_id,
             user_id,
         )
@@ -35,6 +53,37 @@ impl RegistrationRepository for RegistrationRepositoryInfra {
         Ok(())
     }
 
+    async fn create_or_update_all(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<()> {
+        if course_ids.is_empty() {
+            return Ok(());
+        }
+
+        let placeholders = vec!["(?, ?)"; course_ids.len()].join(", ");
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        for course_id in course_ids {
+            args.add(course_id);
+            args.add(user_id);
+        }
+
+        // 更新時の扱いはcreate_or_updateと同じ
+        sqlx::query_with(
+            &format!(
+                "INSERT INTO `registrations` (`course_id`, `user_id`) VALUES {} ON DUPLICATE KEY UPDATE `course_id` = VALUES(`course_id`), `user_id` = VALUES(`user_id`), `registered_at` = IF(`withdrawn_at` IS NULL, `registered_at`, CURRENT_TIMESTAMP(6)), `withdrawn_at` = NULL",
+                placeholders
+            ),
+            args,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
     async fn exist_by_user_id_and_course_id(
         &self,
         conn: &mut DBConn,
@@ -42,7 +91,7 @@ impl RegistrationRepository for RegistrationRepositoryInfra {
         course_id: &CourseID,
     ) -> Result<bool> {
         let registration_count: i64 = sqlx::query_scalar!(
-            "SELECT COUNT(*) FROM `registrations` WHERE `user_id` = ? AND `course_id` = ?",
+            "SELECT COUNT(*) FROM `registrations` WHERE `user_id` = ? AND `course_id` = ? AND `withdrawn_at` IS NULL",
             user_id,
             course_id
         )
@@ -52,6 +101,116 @@ impl RegistrationRepository for RegistrationRepositoryInfra {
         Ok(registration_count != 0)
     }
 
+    async fn find_course_ids_by_user_id_and_course_ids(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        course_ids: &[CourseID],
+    ) -> Result<Vec<CourseID>> {
+        if course_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; course_ids.len()].join(", ");
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        args.add(user_id);
+        for course_id in course_ids {
+            args.add(course_id);
+        }
+
+        let registered: Vec<CourseID> = sqlx::query_scalar_with(
+            &format!(
+                "SELECT `course_id` FROM `registrations` WHERE `user_id` = ? AND `withdrawn_at` IS NULL AND `course_id` IN ({})",
+                placeholders
+            ),
+            args,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(registered)
+    }
+
+    // 同じ科目への履修登録が同時に定員を超えないように、科目の登録範囲を共有ロックする
+    async fn count_for_share_lock_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<i64> {
+        let registration_count: i64 = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM `registrations` WHERE `course_id` = ? AND `withdrawn_at` IS NULL FOR SHARE",
+            course_id
+        )
+        .fetch_one(conn)
+        .await?;
+
+        Ok(registration_count)
+    }
+
+    async fn count_for_share_lock_by_course_ids(
+        &self,
+        conn: &mut DBConn,
+        course_ids: &[CourseID],
+    ) -> Result<Vec<(CourseID, i64)>> {
+        if course_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; course_ids.len()].join(", ");
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        for course_id in course_ids {
+            args.add(course_id);
+        }
+
+        let counts: Vec<(CourseID, i64)> = sqlx::query_as_with(
+            &format!(
+                "SELECT `course_id`, COUNT(*) FROM `registrations` WHERE `course_id` IN ({}) AND `withdrawn_at` IS NULL GROUP BY `course_id` FOR SHARE",
+                placeholders
+            ),
+            args,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(counts)
+    }
+
+    async fn delete(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        course_id: &CourseID,
+    ) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `registrations` WHERE `user_id` = ? AND `course_id` = ?",
+            user_id,
+            course_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn withdraw(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        course_id: &CourseID,
+        withdrawn_at: &NaiveDateTime,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE `registrations` SET `withdrawn_at` = ? WHERE `user_id` = ? AND `course_id` = ? AND `withdrawn_at` IS NULL",
+            withdrawn_at,
+            user_id,
+            course_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
     async fn find_users_by_course_id(
         &self,
         conn: &mut DBConn,
@@ -65,10 +224,11 @@ impl RegistrationRepository for RegistrationRepositoryInfra {
                   `users`.code as `code:UserCode`,
                   `users`.name,
                   `users`.hashed_password,
-                  `users`.type as `type_:UserType`
+                  `users`.type as `type_:UserType`,
+                  `users`.deactivated_at
                 FROM `users`
                 JOIN `registrations` ON `users`.`id` = `registrations`.`user_id`
-                WHERE `registrations`.`course_id` = ?
+                WHERE `registrations`.`course_id` = ? AND `registrations`.`withdrawn_at` IS NULL
             ",
             course_id
         )
@@ -77,4 +237,46 @@ impl RegistrationRepository for RegistrationRepositoryInfra {
 
         Ok(users)
     }
+
+    async fn find_students_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+        limit: Option<i64>,
+        offset: i64,
+    ) -> Result<Vec<CourseStudent>> {
+        let query = r"
+            SELECT
+                `users`.`id` AS `user_id`,
+                `users`.`code`,
+                `users`.`name`,
+                `registrations`.`registered_at`,
+                COUNT(`submissions`.`class_id`) AS `submission_count`,
+                CAST(IFNULL(SUM(`submissions`.`score`), 0) AS SIGNED) AS `total_score`
+            FROM `registrations`
+            JOIN `users` ON `registrations`.`user_id` = `users`.`id`
+            LEFT JOIN `classes` ON `registrations`.`course_id` = `classes`.`course_id`
+            LEFT JOIN `submissions` ON `registrations`.`user_id` = `submissions`.`user_id` AND `submissions`.`class_id` = `classes`.`id`
+            WHERE `registrations`.`course_id` = ? AND `registrations`.`withdrawn_at` IS NULL
+            GROUP BY `users`.`id`, `registrations`.`registered_at`
+            ORDER BY `users`.`code`
+        ";
+        let mut condition = String::new();
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        args.add(course_id);
+
+        if let Some(limit) = limit {
+            // limitより多く上限を設定し、実際にlimitより多くレコードが取得できた場合は次のページが存在する
+            condition.push_str(" LIMIT ? OFFSET ?");
+            args.add(limit + 1);
+            args.add(offset);
+        }
+
+        let students: Vec<CourseStudent> =
+            sqlx::query_as_with(&format!("{}{}", query, condition), args)
+                .fetch_all(conn)
+                .await?;
+
+        Ok(students)
+    }
 }
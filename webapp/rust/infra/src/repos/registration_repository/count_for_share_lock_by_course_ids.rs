@@ -0,0 +1,59 @@
+use crate::repos::registration_repository::RegistrationRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::registration_repository::RegistrationRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let course_id: CourseID = Faker.fake();
+    let other_course_id: CourseID = Faker.fake();
+    let empty_course_id: CourseID = Faker.fake();
+    for course_id in [&course_id, &course_id, &other_course_id] {
+        let user_id: UserID = Faker.fake();
+        let conn = tx.acquire().await.unwrap();
+        sqlx::query!(
+            "INSERT INTO registrations (course_id, user_id) VALUES (?, ?)",
+            course_id,
+            &user_id,
+        )
+        .execute(conn)
+        .await
+        .unwrap();
+    }
+    let user_id: UserID = Faker.fake();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO registrations (course_id, user_id, withdrawn_at) VALUES (?, ?, NOW(6))",
+        &other_course_id,
+        &user_id,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    let repo = RegistrationRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .count_for_share_lock_by_course_ids(
+            conn,
+            &[course_id.clone(), other_course_id.clone(), empty_course_id],
+        )
+        .await
+        .unwrap();
+    // 履修中止した学生は数えず、履修者のいない科目は含まない
+    assert_eq!(got.len(), 2);
+    assert!(got.contains(&(course_id, 2)));
+    assert!(got.contains(&(other_course_id, 1)));
+}
@@ -0,0 +1,62 @@
+use crate::repos::registration_repository::RegistrationRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::registration_repository::RegistrationRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let user_id: UserID = Faker.fake();
+    let withdrawn: CourseID = Faker.fake();
+    let course_id: CourseID = Faker.fake();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO registrations (course_id, user_id, withdrawn_at) VALUES (?, ?, NOW(6))",
+        &withdrawn,
+        &user_id,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    let repo = RegistrationRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    repo.create_or_update_all(conn, &user_id, &[withdrawn.clone(), course_id.clone()])
+        .await
+        .unwrap();
+
+    // 履修中止していた科目も履修中に戻る
+    let conn = tx.acquire().await.unwrap();
+    let row_count = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM registrations WHERE user_id = ? AND withdrawn_at IS NULL",
+        &user_id,
+    )
+    .fetch_one(conn)
+    .await
+    .unwrap();
+    assert_eq!(row_count, 2);
+}
+
+#[tokio::test]
+async fn empty_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let user_id: UserID = Faker.fake();
+    let repo = RegistrationRepositoryInfra {};
+    repo.create_or_update_all(conn, &user_id, &[])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,55 @@
+use crate::repos::registration_repository::RegistrationRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::registration_repository::RegistrationRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let user_id: UserID = Faker.fake();
+    let registered: CourseID = Faker.fake();
+    let withdrawn: CourseID = Faker.fake();
+    let not_registered: CourseID = Faker.fake();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO registrations (course_id, user_id) VALUES (?, ?)",
+        &registered,
+        &user_id,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO registrations (course_id, user_id, withdrawn_at) VALUES (?, ?, NOW(6))",
+        &withdrawn,
+        &user_id,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    let repo = RegistrationRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_course_ids_by_user_id_and_course_ids(
+            conn,
+            &user_id,
+            &[registered.clone(), withdrawn, not_registered],
+        )
+        .await
+        .unwrap();
+    // 履修中止した科目は履修登録済みとみなさない
+    assert_eq!(got, vec![registered]);
+}
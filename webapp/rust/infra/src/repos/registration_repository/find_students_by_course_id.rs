@@ -0,0 +1,129 @@
+use crate::repos::registration_repository::RegistrationRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::class::ClassID;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::User;
+use isucholar_core::repos::registration_repository::RegistrationRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn empty_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let course_id: CourseID = Faker.fake();
+
+    let repo = RegistrationRepositoryInfra {};
+    let got = repo
+        .find_students_by_course_id(conn, &course_id, None, 0)
+        .await
+        .unwrap();
+    assert_eq!(got.len(), 0);
+}
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let course_id: CourseID = Faker.fake();
+    let class_ids: Vec<ClassID> = vec![Faker.fake(), Faker.fake()];
+    for (i, class_id) in class_ids.iter().enumerate() {
+        let conn = tx.acquire().await.unwrap();
+        sqlx::query!(
+            "INSERT INTO classes (id, course_id, part, title, description) VALUES (?, ?, ?, ?, ?)",
+            class_id,
+            &course_id,
+            i as u8 + 1,
+            "title",
+            "description",
+        )
+        .execute(conn)
+        .await
+        .unwrap();
+    }
+
+    let mut users = Vec::new();
+    for _ in 0..2 {
+        let mut user: User = Faker.fake();
+        user.hashed_password.resize(60, 0);
+
+        let conn = tx.acquire().await.unwrap();
+        sqlx::query!(
+            "INSERT INTO users (id, code, name, hashed_password, type) VALUES (?, ?, ?, ?, ?)",
+            &user.id,
+            &user.code,
+            &user.name,
+            &user.hashed_password,
+            &user.type_,
+        )
+        .execute(conn)
+        .await
+        .unwrap();
+
+        let conn = tx.acquire().await.unwrap();
+        sqlx::query!(
+            "INSERT INTO registrations (course_id, user_id) VALUES (?, ?)",
+            &course_id,
+            &user.id,
+        )
+        .execute(conn)
+        .await
+        .unwrap();
+        users.push(user);
+    }
+    users.sort_by_key(|user| user.code.inner().to_string());
+
+    // 先頭の学生だけが2回とも提出し、1回分だけ採点されている
+    for (class_id, score) in class_ids.iter().zip([Some(40u8), None]) {
+        let conn = tx.acquire().await.unwrap();
+        sqlx::query!(
+            "INSERT INTO submissions (user_id, class_id, file_name, score) VALUES (?, ?, ?, ?)",
+            &users[0].id,
+            class_id,
+            "file.pdf",
+            score,
+        )
+        .execute(conn)
+        .await
+        .unwrap();
+    }
+
+    let repo = RegistrationRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_students_by_course_id(conn, &course_id, None, 0)
+        .await
+        .unwrap();
+    assert_eq!(got.len(), 2);
+    assert_eq!(got[0].user_id, users[0].id);
+    assert_eq!(got[0].name, users[0].name);
+    assert_eq!(got[0].submission_count, 2);
+    assert_eq!(got[0].total_score, 40);
+    assert_eq!(got[1].user_id, users[1].id);
+    assert_eq!(got[1].submission_count, 0);
+    assert_eq!(got[1].total_score, 0);
+
+    // 次のページの有無を判定できるよう、limitより1件多く返す
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_students_by_course_id(conn, &course_id, Some(1), 0)
+        .await
+        .unwrap();
+    assert_eq!(got.len(), 2);
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_students_by_course_id(conn, &course_id, Some(1), 1)
+        .await
+        .unwrap();
+    assert_eq!(got.len(), 1);
+    assert_eq!(got[0].user_id, users[1].id);
+}
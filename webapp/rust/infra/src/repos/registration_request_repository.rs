@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::registration_request::RegistrationRequest;
+use isucholar_core::models::user::{UserCode, UserID};
+use isucholar_core::repos::error::Result;
+use isucholar_core::repos::registration_request_repository::RegistrationRequestRepository;
+
+#[cfg(test)]
+mod delete;
+#[cfg(test)]
+mod delete_by_course_id;
+#[cfg(test)]
+mod delete_by_user_id;
+#[cfg(test)]
+mod find_by_course_id;
+#[cfg(test)]
+mod find_course_ids_by_user_id;
+
+#[derive(Clone)]
+pub struct RegistrationRequestRepositoryInfra {}
+
+#[async_trait]
+impl RegistrationRequestRepository for RegistrationRequestRepositoryInfra {
+    async fn create(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+        user_id: &UserID,
+    ) -> Result<()> {
+        // 申請済みの場合は申請順を変えない
+        sqlx::query!(
+            "INSERT INTO `registration_requests` (`course_id`, `user_id`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `id` = `id`",
+            course_id,
+            user_id,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<Vec<RegistrationRequest>> {
+        let requests: Vec<RegistrationRequest> = sqlx::query_as!(
+            RegistrationRequest,
+            r"
+                SELECT
+                    `users`.`id` AS `user_id:UserID`,
+                    `users`.`code` AS `user_code:UserCode`,
+                    `users`.`name` AS `user_name`
+                FROM `registration_requests`
+                JOIN `users` ON `registration_requests`.`user_id` = `users`.`id`
+                WHERE `registration_requests`.`course_id` = ?
+                ORDER BY `registration_requests`.`id`
+            ",
+            course_id
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(requests)
+    }
+
+    async fn delete(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+        user_id: &UserID,
+    ) -> Result<bool> {
+        let result = sqlx::query!(
+            "DELETE FROM `registration_requests` WHERE `course_id` = ? AND `user_id` = ?",
+            course_id,
+            user_id,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_by_course_id(&self, conn: &mut DBConn, course_id: &CourseID) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `registration_requests` WHERE `course_id` = ?",
+            course_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_course_ids_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<CourseID>> {
+        let course_ids: Vec<CourseID> = sqlx::query_scalar!(
+            "SELECT `course_id` AS `course_id:CourseID` FROM `registration_requests` WHERE `user_id` = ? ORDER BY `id`",
+            user_id,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(course_ids)
+    }
+
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `registration_requests` WHERE `user_id` = ?",
+            user_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::repos::registration_request_repository::RegistrationRequestRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::registration_request_repository::RegistrationRequestRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = RegistrationRequestRepositoryInfra {};
+    let course_id: CourseID = Faker.fake();
+    let user_id: UserID = Faker.fake();
+    let other_user_id: UserID = Faker.fake();
+
+    for user_id in [&user_id, &other_user_id] {
+        let conn = tx.acquire().await.unwrap();
+        repo.create(conn, &course_id, user_id).await.unwrap();
+    }
+
+    let conn = tx.acquire().await.unwrap();
+    repo.delete_by_user_id(conn, &user_id).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    assert!(!repo.delete(conn, &course_id, &user_id).await.unwrap());
+    // 他のユーザーの申請は残る
+    let conn = tx.acquire().await.unwrap();
+    assert!(repo.delete(conn, &course_id, &other_user_id).await.unwrap());
+}
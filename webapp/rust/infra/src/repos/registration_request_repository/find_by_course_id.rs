@@ -0,0 +1,69 @@
+use crate::repos::registration_request_repository::RegistrationRequestRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::User;
+use isucholar_core::repos::registration_request_repository::RegistrationRequestRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn empty_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let course_id: CourseID = Faker.fake();
+
+    let repo = RegistrationRequestRepositoryInfra {};
+    let got = repo.find_by_course_id(conn, &course_id).await.unwrap();
+    assert_eq!(got.len(), 0);
+}
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = RegistrationRequestRepositoryInfra {};
+    let course_id: CourseID = Faker.fake();
+    let mut users = Vec::new();
+    for _ in 0..2 {
+        let mut user: User = Faker.fake();
+        user.hashed_password.resize(60, 0);
+
+        let conn = tx.acquire().await.unwrap();
+        sqlx::query!(
+            "INSERT INTO users (id, code, name, hashed_password, type) VALUES (?, ?, ?, ?, ?)",
+            &user.id,
+            &user.code,
+            &user.name,
+            &user.hashed_password,
+            &user.type_,
+        )
+        .execute(conn)
+        .await
+        .unwrap();
+
+        let conn = tx.acquire().await.unwrap();
+        repo.create(conn, &course_id, &user.id).await.unwrap();
+        users.push(user);
+    }
+    // 再申請しても申請順は変わらない
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &course_id, &users[0].id).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_by_course_id(conn, &course_id).await.unwrap();
+    assert_eq!(got.len(), 2);
+    for (request, user) in got.iter().zip(&users) {
+        assert_eq!(request.user_id, user.id);
+        assert_eq!(request.user_code, user.code);
+        assert_eq!(request.user_name, user.name);
+    }
+}
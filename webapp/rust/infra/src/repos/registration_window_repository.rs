@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::registration_window::{RegistrationWindow, RegistrationWindowID};
+use isucholar_core::repos::error::Result;
+use isucholar_core::repos::registration_window_repository::RegistrationWindowRepository;
+
+#[cfg(test)]
+mod create;
+#[cfg(test)]
+mod delete_by_id;
+
+#[derive(Clone)]
+pub struct RegistrationWindowRepositoryInfra {}
+
+#[async_trait]
+impl RegistrationWindowRepository for RegistrationWindowRepositoryInfra {
+    async fn create(&self, conn: &mut DBConn, window: &RegistrationWindow) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO `registration_windows` (`id`, `term`, `cohort`, `starts_at`, `ends_at`) VALUES (?, ?, ?, ?, ?)",
+            &window.id,
+            &window.term,
+            &window.cohort,
+            &window.starts_at,
+            &window.ends_at,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_all(&self, conn: &mut DBConn) -> Result<Vec<RegistrationWindow>> {
+        let windows = sqlx::query_as!(
+            RegistrationWindow,
+            r"
+                SELECT
+                    id as `id:RegistrationWindowID`,
+                    term,
+                    cohort,
+                    starts_at,
+                    ends_at
+                FROM `registration_windows`
+                ORDER BY `starts_at`, `id`
+            "
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(windows)
+    }
+
+    async fn delete_by_id(&self, conn: &mut DBConn, id: &RegistrationWindowID) -> Result<bool> {
+        let result = sqlx::query!("DELETE FROM `registration_windows` WHERE `id` = ?", id)
+            .execute(conn)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::repos::registration_window_repository::RegistrationWindowRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::registration_window::{RegistrationWindow, RegistrationWindowID};
+use isucholar_core::repos::registration_window_repository::RegistrationWindowRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+
+    let repo = RegistrationWindowRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let mut input: RegistrationWindow = Faker.fake();
+    input.starts_at = now;
+    input.ends_at = now + chrono::Duration::days(7);
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &input).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.delete_by_id(conn, &input.id).await.unwrap();
+    assert!(got);
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_all(conn).await.unwrap();
+    assert!(!got.contains(&input));
+}
+
+#[tokio::test]
+async fn not_found_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+
+    let repo = RegistrationWindowRepositoryInfra {};
+    let id: RegistrationWindowID = Faker.fake();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.delete_by_id(conn, &id).await.unwrap();
+    assert!(!got);
+}
@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use isucholar_core::db::DBConn;
 use isucholar_core::models::class::ClassID;
-use isucholar_core::models::submission::{CreateSubmission, SubmissionWithUserCode};
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::submission::{
+    CreateSubmission, SubmissionWithUserCode, UserSubmission,
+};
 use isucholar_core::models::user::{UserCode, UserID};
 use isucholar_core::repos::error::Result;
 use isucholar_core::repos::submission_repository::SubmissionRepository;
@@ -11,10 +14,14 @@ mod count_by_class_id;
 #[cfg(test)]
 mod create_or_update;
 #[cfg(test)]
+mod find_all_by_user_id;
+#[cfg(test)]
 mod find_all_with_user_code_by_class_id;
 #[cfg(test)]
 mod find_score_by_class_id_and_user_id;
 #[cfg(test)]
+mod update_file_name_by_user_id;
+#[cfg(test)]
 mod update_score_by_user_code_and_class_id;
 
 #[derive(Clone)]
@@ -92,6 +99,49 @@ impl SubmissionRepository for SubmissionRepositoryInfra {
         }
     }
 
+    async fn find_all_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<UserSubmission>> {
+        let submissions: Vec<UserSubmission> = sqlx::query_as!(
+            UserSubmission,
+            r"
+                SELECT
+                  `submissions`.`class_id` as `class_id:ClassID`,
+                  `classes`.`course_id` as `course_id:CourseID`,
+                  `submissions`.`file_name`,
+                  `submissions`.`score`
+                FROM `submissions`
+                JOIN `classes` ON `classes`.`id` = `submissions`.`class_id`
+                WHERE `submissions`.`user_id` = ?
+                ORDER BY `submissions`.`class_id`
+            ",
+            user_id
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(submissions)
+    }
+
+    async fn update_file_name_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        file_name: &str,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE `submissions` SET `file_name` = ? WHERE `user_id` = ?",
+            file_name,
+            user_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
     async fn find_all_with_user_code_by_class_id(
         &self,
         conn: &mut DBConn,
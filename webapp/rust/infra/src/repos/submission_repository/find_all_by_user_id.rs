@@ -0,0 +1,56 @@
+use crate::repos::submission_repository::SubmissionRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::class::ClassID;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::submission_repository::SubmissionRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let user_id: UserID = Faker.fake();
+    let class_id: ClassID = Faker.fake();
+    let course_id: CourseID = Faker.fake();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO classes (id, course_id, part, title, description) VALUES (?, ?, ?, ?, ?)",
+        &class_id,
+        &course_id,
+        1,
+        "title",
+        "description",
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO submissions (user_id, class_id, file_name, score) VALUES (?, ?, ?, ?)",
+        &user_id,
+        &class_id,
+        "report.pdf",
+        80,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    let repo = SubmissionRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_all_by_user_id(conn, &user_id).await.unwrap();
+    assert_eq!(got.len(), 1);
+    assert_eq!(got[0].class_id, class_id);
+    assert_eq!(got[0].course_id, course_id);
+    assert_eq!(got[0].file_name, "report.pdf");
+    assert_eq!(got[0].score, Some(80));
+}
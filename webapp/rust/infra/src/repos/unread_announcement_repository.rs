@@ -13,6 +13,8 @@ mod count_unread_by_user_id;
 #[cfg(test)]
 mod create;
 #[cfg(test)]
+mod find_all_by_user_id;
+#[cfg(test)]
 mod find_announcement_detail_by_announcement_id_and_user_id;
 #[cfg(test)]
 mod find_unread_announcements_by_user_id;
@@ -58,8 +60,18 @@ impl UnreadAnnouncementRepository for UnreadAnnouncementRepositoryInfra {
     }
 
     async fn count_unread_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<i64> {
+        // 一覧と同じく、履修登録を取り消したり履修中止したりした科目のお知らせは数えない
         let unread_count: i64 = sqlx::query_scalar!(
-            "SELECT COUNT(*) FROM `unread_announcements` WHERE `user_id` = ? AND NOT `is_deleted`",
+            r"
+                SELECT COUNT(*)
+                FROM `unread_announcements`
+                JOIN `announcements` ON `announcements`.`id` = `unread_announcements`.`announcement_id`
+                JOIN `registrations` ON `registrations`.`course_id` = `announcements`.`course_id`
+                    AND `registrations`.`user_id` = `unread_announcements`.`user_id`
+                WHERE `unread_announcements`.`user_id` = ?
+                    AND NOT `unread_announcements`.`is_deleted`
+                    AND `registrations`.`withdrawn_at` IS NULL
+            ",
             user_id
         )
         .fetch_one(conn)
@@ -92,6 +104,7 @@ impl UnreadAnnouncementRepository for UnreadAnnouncementRepositoryInfra {
         query.push_str(concat!(
             " AND `unread_announcements`.`user_id` = ?",
             " AND `registrations`.`user_id` = ?",
+            " AND `registrations`.`withdrawn_at` IS NULL",
             " ORDER BY `announcements`.`id` DESC",
             " LIMIT ? OFFSET ?",
         ));
@@ -106,6 +119,34 @@ impl UnreadAnnouncementRepository for UnreadAnnouncementRepositoryInfra {
         Ok(announcements)
     }
 
+    async fn find_all_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<AnnouncementWithoutDetail>> {
+        let announcements: Vec<AnnouncementWithoutDetail> = sqlx::query_as!(
+            AnnouncementWithoutDetail,
+            r"
+                SELECT
+                    `announcements`.`id` as `id:AnnouncementID`,
+                    `courses`.`id` AS `course_id:CourseID`,
+                    `courses`.`name` AS `course_name`,
+                    `announcements`.`title`,
+                    NOT `unread_announcements`.`is_deleted` AS `unread:bool`
+                FROM `unread_announcements`
+                JOIN `announcements` ON `announcements`.`id` = `unread_announcements`.`announcement_id`
+                JOIN `courses` ON `courses`.`id` = `announcements`.`course_id`
+                WHERE `unread_announcements`.`user_id` = ?
+                ORDER BY `announcements`.`id`
+            ",
+            user_id
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(announcements)
+    }
+
     async fn find_announcement_detail_by_announcement_id_and_user_id(
         &self,
         conn: &mut DBConn,
@@ -1,10 +1,41 @@
 use crate::repos::unread_announcement_repository::UnreadAnnouncementRepositoryInfra;
 use fake::{Fake, Faker};
-use isucholar_core::db::get_test_db_conn;
+use isucholar_core::db::{get_test_db_conn, DBConn};
+use isucholar_core::models::announcement::Announcement;
+use isucholar_core::models::course::CourseID;
 use isucholar_core::models::user::UserID;
 use isucholar_core::repos::unread_announcement_repository::UnreadAnnouncementRepository;
 use sqlx::Acquire;
 
+async fn insert_unread_announcement(
+    conn: &mut DBConn,
+    course_id: &CourseID,
+    user_id: &UserID,
+    is_deleted: bool,
+) {
+    let mut announcement: Announcement = Faker.fake();
+    announcement.course_id = course_id.clone();
+    sqlx::query!(
+        "INSERT INTO announcements (id, course_id, title, message) VALUES (?, ?, ?, ?)",
+        &announcement.id,
+        &announcement.course_id,
+        &announcement.title,
+        &announcement.message,
+    )
+    .execute(&mut *conn)
+    .await
+    .unwrap();
+    sqlx::query!(
+        "INSERT INTO unread_announcements (announcement_id, user_id, is_deleted) VALUES (?, ?, ?)",
+        &announcement.id,
+        user_id,
+        is_deleted,
+    )
+    .execute(&mut *conn)
+    .await
+    .unwrap();
+}
+
 #[tokio::test]
 async fn record_exist_case() {
     let pool = get_test_db_conn().await.unwrap();
@@ -18,30 +49,68 @@ async fn record_exist_case() {
 
     let repo = UnreadAnnouncementRepositoryInfra {};
     let user_id: UserID = Faker.fake();
-    let announcement_id1 = Faker.fake::<String>();
-    let announcement_id2 = Faker.fake::<String>();
-    let announcement_id3 = Faker.fake::<String>();
+    let course_id: CourseID = Faker.fake();
     let conn = tx.acquire().await.unwrap();
-
     sqlx::query!(
-        "INSERT INTO unread_announcements (announcement_id, user_id, is_deleted) VALUES (?, ?, ?), (?, ?, ?), (?, ?, ?)",
-        &announcement_id1,
+        "INSERT INTO registrations (course_id, user_id) VALUES (?, ?)",
+        &course_id,
         &user_id,
-        false,
-        &announcement_id2,
+    )
+    .execute(&mut *conn)
+    .await
+    .unwrap();
+
+    insert_unread_announcement(conn, &course_id, &user_id, false).await;
+    insert_unread_announcement(conn, &course_id, &user_id, false).await;
+    insert_unread_announcement(conn, &course_id, &user_id, true).await;
+
+    let conn = tx.acquire().await.unwrap();
+    let unread_count = repo.count_unread_by_user_id(conn, &user_id).await.unwrap();
+    assert_eq!(unread_count, 2);
+}
+
+#[tokio::test]
+async fn dropped_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = UnreadAnnouncementRepositoryInfra {};
+    let user_id: UserID = Faker.fake();
+    let registered: CourseID = Faker.fake();
+    let dropped: CourseID = Faker.fake();
+    let withdrawn: CourseID = Faker.fake();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO registrations (course_id, user_id) VALUES (?, ?)",
+        &registered,
         &user_id,
-        false,
-        &announcement_id3,
+    )
+    .execute(&mut *conn)
+    .await
+    .unwrap();
+    sqlx::query!(
+        "INSERT INTO registrations (course_id, user_id, withdrawn_at) VALUES (?, ?, NOW(6))",
+        &withdrawn,
         &user_id,
-        true,
     )
-    .execute(conn)
+    .execute(&mut *conn)
     .await
     .unwrap();
 
+    insert_unread_announcement(conn, &registered, &user_id, false).await;
+    // 履修登録を取り消した科目と履修中止した科目のお知らせは、一覧と同じく数えない
+    insert_unread_announcement(conn, &dropped, &user_id, false).await;
+    insert_unread_announcement(conn, &withdrawn, &user_id, false).await;
+
     let conn = tx.acquire().await.unwrap();
     let unread_count = repo.count_unread_by_user_id(conn, &user_id).await.unwrap();
-    assert_eq!(unread_count, 2);
+    assert_eq!(unread_count, 1);
 }
 
 #[tokio::test]
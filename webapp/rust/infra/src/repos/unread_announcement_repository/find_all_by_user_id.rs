@@ -0,0 +1,73 @@
+use crate::repos::unread_announcement_repository::UnreadAnnouncementRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::announcement::Announcement;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::unread_announcement_repository::UnreadAnnouncementRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let user_id: UserID = Faker.fake();
+    let teacher_id: UserID = Faker.fake();
+    let course_id: CourseID = Faker.fake();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO courses (id, code, type, name, description, credit, period, day_of_week, teacher_id, keywords) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        &course_id,
+        "X99999",
+        "major-subjects",
+        "course",
+        "description",
+        1,
+        1,
+        "monday",
+        &teacher_id,
+        "keywords",
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    let mut announcement: Announcement = Faker.fake();
+    announcement.course_id = course_id.clone();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO announcements (id, course_id, title, message) VALUES (?, ?, ?, ?)",
+        &announcement.id,
+        &announcement.course_id,
+        &announcement.title,
+        &announcement.message,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO unread_announcements (announcement_id, user_id, is_deleted) VALUES (?, ?, ?)",
+        &announcement.id,
+        &user_id,
+        true,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    let repo = UnreadAnnouncementRepositoryInfra {};
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_all_by_user_id(conn, &user_id).await.unwrap();
+    assert_eq!(got.len(), 1);
+    assert_eq!(got[0].id, announcement.id);
+    assert_eq!(got[0].course_id, course_id);
+    assert!(!got[0].unread);
+}
@@ -89,6 +89,83 @@ async fn record_exist_case() {
     assert!(ann.unread);
 }
 
+#[tokio::test]
+async fn withdrawn_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = UnreadAnnouncementRepositoryInfra {};
+
+    let course: Course = Faker.fake();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO courses (id, code, type, name, description, credit, period, day_of_week, teacher_id, keywords, status) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        &course.id,
+        &course.code,
+        &course.type_,
+        &course.name,
+        &course.description,
+        &course.credit,
+        &course.period,
+        &course.day_of_week,
+        &course.teacher_id,
+        &course.keywords,
+        &course.status,
+    )
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let user_id: UserID = Faker.fake();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO registrations (course_id, user_id, withdrawn_at) VALUES (?, ?, NOW(6))",
+        &course.id,
+        &user_id,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    let mut announcement: Announcement = Faker.fake();
+    announcement.course_id = course.id.clone();
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO announcements (id, course_id, title, message) VALUES (?, ?, ?, ?)",
+        &announcement.id,
+        &announcement.course_id,
+        &announcement.title,
+        &announcement.message,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    sqlx::query!(
+        "INSERT INTO unread_announcements (announcement_id, user_id) VALUES (?, ?)",
+        &announcement.id,
+        &user_id,
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    // 履修中止した科目のお知らせは表示しない
+    let announcements = repo
+        .find_unread_announcements_by_user_id(&mut tx, &user_id, 10, 0, None)
+        .await
+        .unwrap();
+
+    assert_eq!(announcements.len(), 0);
+}
+
 #[tokio::test]
 async fn empty_case() {
     let pool = get_test_db_conn().await.unwrap();
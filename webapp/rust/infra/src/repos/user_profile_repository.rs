@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::user::UserID;
+use isucholar_core::models::user_profile::UserProfile;
+use isucholar_core::repos::error::Result;
+use isucholar_core::repos::user_profile_repository::UserProfileRepository;
+
+#[cfg(test)]
+mod create_or_update;
+#[cfg(test)]
+mod delete_by_user_id;
+
+#[derive(Clone)]
+pub struct UserProfileRepositoryInfra {}
+
+#[async_trait]
+impl UserProfileRepository for UserProfileRepositoryInfra {
+    async fn find_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Option<UserProfile>> {
+        let profile = sqlx::query_as!(
+            UserProfile,
+            r"
+                SELECT
+                    user_id as `user_id:UserID`,
+                    name_kana,
+                    email,
+                    notify_announcements as `notify_announcements:bool`,
+                    notify_course_updates as `notify_course_updates:bool`
+                FROM `user_profiles`
+                WHERE `user_id` = ?
+            ",
+            user_id
+        )
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(profile)
+    }
+
+    async fn create_or_update(&self, conn: &mut DBConn, profile: &UserProfile) -> Result<()> {
+        sqlx::query!(
+            r"
+                INSERT INTO `user_profiles` (`user_id`, `name_kana`, `email`, `notify_announcements`, `notify_course_updates`)
+                VALUES (?, ?, ?, ?, ?)
+                ON DUPLICATE KEY UPDATE
+                    `name_kana` = VALUES(`name_kana`),
+                    `email` = VALUES(`email`),
+                    `notify_announcements` = VALUES(`notify_announcements`),
+                    `notify_course_updates` = VALUES(`notify_course_updates`)
+            ",
+            &profile.user_id,
+            &profile.name_kana,
+            &profile.email,
+            &profile.notify_announcements,
+            &profile.notify_course_updates,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()> {
+        sqlx::query!("DELETE FROM `user_profiles` WHERE `user_id` = ?", user_id)
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+}
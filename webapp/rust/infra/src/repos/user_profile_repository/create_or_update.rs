@@ -0,0 +1,33 @@
+use crate::repos::user_profile_repository::UserProfileRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user_profile::UserProfile;
+use isucholar_core::repos::user_profile_repository::UserProfileRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn update_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = UserProfileRepositoryInfra {};
+    let first: UserProfile = Faker.fake();
+    let mut second = first.clone();
+    second.email = None;
+    second.notify_announcements = !first.notify_announcements;
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create_or_update(conn, &first).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.create_or_update(conn, &second).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_by_user_id(conn, &first.user_id).await.unwrap();
+    assert_eq!(got, Some(second));
+}
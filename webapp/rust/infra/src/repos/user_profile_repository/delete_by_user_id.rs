@@ -0,0 +1,32 @@
+use crate::repos::user_profile_repository::UserProfileRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user_profile::UserProfile;
+use isucholar_core::repos::user_profile_repository::UserProfileRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = UserProfileRepositoryInfra {};
+    let profile: UserProfile = Faker.fake();
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create_or_update(conn, &profile).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.delete_by_user_id(conn, &profile.user_id)
+        .await
+        .unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_by_user_id(conn, &profile.user_id).await.unwrap();
+    assert!(got.is_none());
+}
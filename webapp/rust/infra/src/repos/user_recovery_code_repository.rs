@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::user::UserID;
+use isucholar_core::models::user_recovery_code::UserRecoveryCode;
+use isucholar_core::repos::error::Result;
+use isucholar_core::repos::user_recovery_code_repository::UserRecoveryCodeRepository;
+
+#[cfg(test)]
+mod use_by_user_id_and_hashed_code;
+
+#[derive(Clone)]
+pub struct UserRecoveryCodeRepositoryInfra {}
+
+#[async_trait]
+impl UserRecoveryCodeRepository for UserRecoveryCodeRepositoryInfra {
+    async fn create(&self, conn: &mut DBConn, code: &UserRecoveryCode) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO `user_recovery_codes` (`id`, `user_id`, `hashed_code`, `used_at`) VALUES (?, ?, ?, ?)",
+            &code.id,
+            &code.user_id,
+            &code.hashed_code,
+            &code.used_at,
+        )
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `user_recovery_codes` WHERE `user_id` = ?",
+            user_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn use_by_user_id_and_hashed_code(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        hashed_code: &str,
+        used_at: &NaiveDateTime,
+    ) -> Result<bool> {
+        let result = sqlx::query!(
+            r"
+                UPDATE `user_recovery_codes` SET `used_at` = ?
+                WHERE `user_id` = ? AND `hashed_code` = ? AND `used_at` IS NULL
+                LIMIT 1
+            ",
+            used_at,
+            user_id,
+            hashed_code
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
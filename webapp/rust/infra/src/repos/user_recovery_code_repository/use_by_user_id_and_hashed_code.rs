@@ -0,0 +1,39 @@
+use crate::repos::user_recovery_code_repository::UserRecoveryCodeRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user_recovery_code::UserRecoveryCode;
+use isucholar_core::repos::user_recovery_code_repository::UserRecoveryCodeRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn use_once_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = UserRecoveryCodeRepositoryInfra {};
+    let mut code: UserRecoveryCode = Faker.fake();
+    code.hashed_code = "a".repeat(64);
+    code.used_at = None;
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &code).await.unwrap();
+
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let conn = tx.acquire().await.unwrap();
+    assert!(repo
+        .use_by_user_id_and_hashed_code(conn, &code.user_id, &code.hashed_code, &now)
+        .await
+        .unwrap());
+    let conn = tx.acquire().await.unwrap();
+    assert!(!repo
+        .use_by_user_id_and_hashed_code(conn, &code.user_id, &code.hashed_code, &now)
+        .await
+        .unwrap());
+}
@@ -1,27 +1,113 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use futures::StreamExt;
 use isucholar_core::db::DBConn;
 use isucholar_core::models::course_status::CourseStatus;
-use isucholar_core::models::user::{User, UserCode, UserID};
+use isucholar_core::models::user::{UpdateUser, User, UserCode, UserID};
 use isucholar_core::models::user_type::UserType;
-use isucholar_core::repos::error::Result;
-use isucholar_core::repos::user_repository::UserRepository;
+use isucholar_core::repos::error::{ReposError, Result};
+use isucholar_core::repos::user_repository::{SearchUsersQuery, UserRepository};
+use isucholar_core::MYSQL_ERR_NUM_DUPLICATE_ENTRY;
 use num_traits::ToPrimitive;
+use sqlx::Arguments;
 
+#[cfg(test)]
+mod anonymize_by_id;
+#[cfg(test)]
+mod create;
+#[cfg(test)]
+mod create_bulk;
+#[cfg(test)]
+mod deactivate_by_id;
 #[cfg(test)]
 mod find;
 #[cfg(test)]
+mod find_all;
+#[cfg(test)]
 mod find_by_code;
 #[cfg(test)]
 mod find_code_by_id;
 #[cfg(test)]
+mod find_codes_in;
+#[cfg(test)]
 mod find_gpas_group_by_user_id;
+#[cfg(test)]
+mod update_by_id;
+#[cfg(test)]
+mod update_hashed_password_by_id;
 
 #[derive(Clone)]
 pub struct UserRepositoryInfra {}
 
 #[async_trait]
 impl UserRepository for UserRepositoryInfra {
+    async fn create(&self, conn: &mut DBConn, user: &User) -> Result<()> {
+        let result = sqlx::query!(
+            "INSERT INTO `users` (`id`, `code`, `name`, `hashed_password`, `type`, `deactivated_at`) VALUES (?, ?, ?, ?, ?, ?)",
+            &user.id,
+            &user.code,
+            &user.name,
+            &user.hashed_password,
+            &user.type_,
+            &user.deactivated_at,
+        )
+            .execute(conn)
+            .await;
+
+        if let Err(sqlx::Error::Database(ref db_error)) = result {
+            if let Some(mysql_error) =
+                db_error.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>()
+            {
+                if mysql_error.number() == MYSQL_ERR_NUM_DUPLICATE_ENTRY {
+                    return Err(ReposError::UserDuplicate);
+                }
+            }
+        }
+
+        result?;
+
+        Ok(())
+    }
+
+    async fn create_bulk(&self, conn: &mut DBConn, users: &[User]) -> Result<()> {
+        if users.is_empty() {
+            return Ok(());
+        }
+
+        let mut query = String::from(
+            "INSERT INTO `users` (`id`, `code`, `name`, `hashed_password`, `type`, `deactivated_at`) VALUES ",
+        );
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        for (i, user) in users.iter().enumerate() {
+            if i > 0 {
+                query.push_str(", ");
+            }
+            query.push_str("(?, ?, ?, ?, ?, ?)");
+            args.add(&user.id);
+            args.add(&user.code);
+            args.add(&user.name);
+            args.add(&user.hashed_password);
+            args.add(&user.type_);
+            args.add(user.deactivated_at);
+        }
+
+        let result = sqlx::query_with(&query, args).execute(conn).await;
+
+        if let Err(sqlx::Error::Database(ref db_error)) = result {
+            if let Some(mysql_error) =
+                db_error.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>()
+            {
+                if mysql_error.number() == MYSQL_ERR_NUM_DUPLICATE_ENTRY {
+                    return Err(ReposError::UserDuplicate);
+                }
+            }
+        }
+
+        result?;
+
+        Ok(())
+    }
+
     async fn find(&self, conn: &mut DBConn, id: &UserID) -> Result<User> {
         let user: User = sqlx::query_as!(
             User,
@@ -31,7 +117,8 @@ impl UserRepository for UserRepositoryInfra {
                     code as `code:UserCode`,
                     name,
                     hashed_password,
-                    type AS `type_:UserType`
+                    type AS `type_:UserType`,
+                    deactivated_at
                 FROM `users` WHERE `id` = ?
             ",
             id
@@ -51,7 +138,8 @@ impl UserRepository for UserRepositoryInfra {
                     code as `code:UserCode`,
                     name,
                     hashed_password,
-                    type AS `type_:UserType`
+                    type AS `type_:UserType`,
+                    deactivated_at
                 FROM `users` WHERE `code` = ?
             ",
             code,
@@ -73,6 +161,30 @@ impl UserRepository for UserRepositoryInfra {
         Ok(user_code)
     }
 
+    async fn find_codes_in(&self, conn: &mut DBConn, codes: &[String]) -> Result<Vec<UserCode>> {
+        if codes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; codes.len()].join(", ");
+        let mut args = sqlx::mysql::MySqlArguments::default();
+        for code in codes {
+            args.add(code);
+        }
+
+        let codes: Vec<UserCode> = sqlx::query_scalar_with(
+            &format!(
+                "SELECT `code` FROM `users` WHERE `code` IN ({})",
+                placeholders
+            ),
+            args,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(codes)
+    }
+
     async fn find_gpas_group_by_user_id(&self, conn: &mut DBConn) -> Result<Vec<f64>> {
         let gpas = {
             let mut rows = sqlx::query_scalar!(
@@ -83,11 +195,11 @@ impl UserRepository for UserRepositoryInfra {
                     JOIN (
                         SELECT `users`.`id` AS `user_id`, SUM(`courses`.`credit`) AS `credits`
                         FROM `users`
-                        JOIN `registrations` ON `users`.`id` = `registrations`.`user_id`
+                        JOIN `registrations` ON `users`.`id` = `registrations`.`user_id` AND `registrations`.`withdrawn_at` IS NULL
                         JOIN `courses` ON `registrations`.`course_id` = `courses`.`id` AND `courses`.`status` = ?
                         GROUP BY `users`.`id`
                     ) AS `credits` ON `credits`.`user_id` = `users`.`id`
-                    JOIN `registrations` ON `users`.`id` = `registrations`.`user_id`
+                    JOIN `registrations` ON `users`.`id` = `registrations`.`user_id` AND `registrations`.`withdrawn_at` IS NULL
                     JOIN `courses` ON `registrations`.`course_id` = `courses`.`id` AND `courses`.`status` = ?
                     LEFT JOIN `classes` ON `courses`.`id` = `classes`.`course_id`
                     LEFT JOIN `submissions` ON `users`.`id` = `submissions`.`user_id` AND `submissions`.`class_id` = `classes`.`id`
@@ -110,4 +222,130 @@ impl UserRepository for UserRepositoryInfra {
 
         Ok(gpas)
     }
+
+    async fn update_hashed_password_by_id(
+        &self,
+        conn: &mut DBConn,
+        id: &UserID,
+        hashed_password: &[u8],
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE `users` SET `hashed_password` = ? WHERE `id` = ?",
+            hashed_password,
+            id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update_by_id(&self, conn: &mut DBConn, id: &UserID, req: &UpdateUser) -> Result<()> {
+        sqlx::query!(
+            "UPDATE `users` SET `name` = IFNULL(?, `name`), `type` = IFNULL(?, `type`) WHERE `id` = ?",
+            &req.name,
+            &req.type_,
+            id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn anonymize_by_id(
+        &self,
+        conn: &mut DBConn,
+        id: &UserID,
+        code: &UserCode,
+        name: &str,
+        hashed_password: &[u8],
+        deactivated_at: &NaiveDateTime,
+    ) -> Result<()> {
+        let result = sqlx::query!(
+            r"
+                UPDATE `users`
+                SET `code` = ?, `name` = ?, `hashed_password` = ?, `deactivated_at` = IFNULL(`deactivated_at`, ?)
+                WHERE `id` = ?
+            ",
+            code,
+            name,
+            hashed_password,
+            deactivated_at,
+            id
+        )
+        .execute(conn)
+        .await;
+
+        if let Err(sqlx::Error::Database(ref db_error)) = result {
+            if let Some(mysql_error) =
+                db_error.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>()
+            {
+                if mysql_error.number() == MYSQL_ERR_NUM_DUPLICATE_ENTRY {
+                    return Err(ReposError::UserDuplicate);
+                }
+            }
+        }
+
+        result?;
+
+        Ok(())
+    }
+
+    async fn deactivate_by_id(
+        &self,
+        conn: &mut DBConn,
+        id: &UserID,
+        deactivated_at: &NaiveDateTime,
+    ) -> Result<()> {
+        // 無効化済みのユーザーは最初に無効化した日時を保持する
+        sqlx::query!(
+            "UPDATE `users` SET `deactivated_at` = ? WHERE `id` = ? AND `deactivated_at` IS NULL",
+            deactivated_at,
+            id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_all(
+        &self,
+        conn: &mut DBConn,
+        q: &SearchUsersQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>> {
+        let query = "SELECT * FROM `users` WHERE 1=1";
+        let mut condition = String::new();
+        let mut args = sqlx::mysql::MySqlArguments::default();
+
+        if let Some(ref type_) = q.type_ {
+            condition.push_str(" AND `type` = ?");
+            args.add(type_);
+        }
+
+        if let Some(ref code_prefix) = q.code_prefix {
+            condition.push_str(" AND `code` LIKE ?");
+            let escaped = code_prefix
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            args.add(format!("{}%", escaped));
+        }
+
+        condition.push_str(" ORDER BY `code`");
+
+        // limitより多く上限を設定し、実際にlimitより多くレコードが取得できた場合は次のページが存在する
+        condition.push_str(" LIMIT ? OFFSET ?");
+        args.add(limit + 1);
+        args.add(offset);
+
+        let users: Vec<User> = sqlx::query_as_with(&format!("{}{}", query, condition), args)
+            .fetch_all(conn)
+            .await?;
+
+        Ok(users)
+    }
 }
@@ -0,0 +1,36 @@
+use crate::repos::user_repository::UserRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user::{User, UserCode};
+use isucholar_core::repos::user_repository::UserRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let mut user: User = Faker.fake();
+    user.hashed_password.resize(60, 0);
+    user.deactivated_at = None;
+
+    let repo = UserRepositoryInfra {};
+    repo.create(conn, &user).await.unwrap();
+
+    let code = UserCode::new("~abcde".to_string().into());
+    let now = chrono::DateTime::from_timestamp(1700000000, 0)
+        .unwrap()
+        .naive_utc();
+    let conn = tx.acquire().await.unwrap();
+    repo.anonymize_by_id(conn, &user.id, &code, "erased", b"hashed", &now)
+        .await
+        .unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find(conn, &user.id).await.unwrap();
+    assert_eq!(got.code, code);
+    assert_eq!(got.name, "erased");
+    assert_eq!(got.hashed_password, b"hashed");
+    assert_eq!(got.deactivated_at, Some(now));
+}
@@ -0,0 +1,47 @@
+use crate::repos::user_repository::UserRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user::User;
+use isucholar_core::repos::user_repository::UserRepository;
+use sqlx::Acquire;
+
+fn user() -> User {
+    let mut user: User = Faker.fake();
+    user.hashed_password.resize(60, 0);
+    user
+}
+
+#[tokio::test]
+async fn success_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let users = vec![user(), user()];
+
+    let repo = UserRepositoryInfra {};
+    repo.create_bulk(conn, &users).await.unwrap();
+
+    for user in &users {
+        let conn = tx.acquire().await.unwrap();
+        let got = repo.find_by_code(conn, &user.code).await.unwrap().unwrap();
+        assert_eq!(&got, user);
+    }
+}
+
+#[tokio::test]
+#[should_panic(expected = "UserDuplicate")]
+async fn duplicate_code_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let existing = user();
+    let repo = UserRepositoryInfra {};
+    repo.create(conn, &existing).await.unwrap();
+
+    let mut other = user();
+    other.code = existing.code.clone();
+    let conn = tx.acquire().await.unwrap();
+    repo.create_bulk(conn, &[user(), other]).await.unwrap();
+}
@@ -0,0 +1,33 @@
+use crate::repos::user_repository::UserRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user::User;
+use isucholar_core::repos::user_repository::UserRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn keep_first_deactivated_at_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let mut user: User = Faker.fake();
+    user.hashed_password.resize(60, 0);
+
+    let repo = UserRepositoryInfra {};
+    repo.create(conn, &user).await.unwrap();
+
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.deactivate_by_id(conn, &user.id, &now).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.deactivate_by_id(conn, &user.id, &(now + chrono::Duration::hours(1)))
+        .await
+        .unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find(conn, &user.id).await.unwrap();
+    assert_eq!(got.deactivated_at, Some(now));
+    assert!(!got.is_active());
+}
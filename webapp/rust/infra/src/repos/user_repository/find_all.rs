@@ -0,0 +1,38 @@
+use crate::repos::user_repository::UserRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user::{User, UserCode};
+use isucholar_core::models::user_type::UserType;
+use isucholar_core::repos::user_repository::{SearchUsersQuery, UserRepository};
+use sqlx::Acquire;
+
+fn user(code: &str, type_: UserType) -> User {
+    let mut user: User = Faker.fake();
+    user.code = UserCode::new(code.to_string().into());
+    user.hashed_password.resize(60, 0);
+    user.type_ = type_;
+    user
+}
+
+#[tokio::test]
+async fn filter_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+
+    let repo = UserRepositoryInfra {};
+    let matched = user("X9_001", UserType::Student);
+    let other_type = user("X9_002", UserType::Teacher);
+    let other_prefix = user("X90003", UserType::Student);
+    for input in [&matched, &other_type, &other_prefix] {
+        let conn = tx.acquire().await.unwrap();
+        repo.create(conn, input).await.unwrap();
+    }
+
+    let conn = tx.acquire().await.unwrap();
+    let query = SearchUsersQuery {
+        type_: Some(UserType::Student),
+        code_prefix: Some("X9_".to_string()),
+    };
+    let got = repo.find_all(conn, &query, 20, 0).await.unwrap();
+    assert_eq!(got, vec![matched]);
+}
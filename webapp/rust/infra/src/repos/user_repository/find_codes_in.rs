@@ -0,0 +1,37 @@
+use crate::repos::user_repository::UserRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user::User;
+use isucholar_core::repos::user_repository::UserRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn empty_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let repo = UserRepositoryInfra {};
+    let got = repo.find_codes_in(conn, &[]).await.unwrap();
+    assert!(got.is_empty());
+}
+
+#[tokio::test]
+async fn success_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let mut user: User = Faker.fake();
+    user.hashed_password.resize(60, 0);
+
+    let repo = UserRepositoryInfra {};
+    repo.create(conn, &user).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_codes_in(conn, &[user.code.inner().to_string(), "ZZZZZZ".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(got, vec![user.code]);
+}
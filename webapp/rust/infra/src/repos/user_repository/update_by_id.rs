@@ -0,0 +1,33 @@
+use crate::repos::user_repository::UserRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user::{UpdateUser, User};
+use isucholar_core::models::user_type::UserType;
+use isucholar_core::repos::user_repository::UserRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn partial_update_case() {
+    let pool = get_test_db_conn().await.unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let mut user: User = Faker.fake();
+    user.hashed_password.resize(60, 0);
+    user.type_ = UserType::Student;
+
+    let repo = UserRepositoryInfra {};
+    repo.create(conn, &user).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let req = UpdateUser {
+        name: Some("updated".to_string()),
+        type_: None,
+    };
+    repo.update_by_id(conn, &user.id, &req).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find(conn, &user.id).await.unwrap();
+    assert_eq!(got.name, "updated");
+    assert_eq!(got.type_, UserType::Student);
+}
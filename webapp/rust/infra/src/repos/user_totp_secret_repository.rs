@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::user::UserID;
+use isucholar_core::models::user_totp_secret::UserTotpSecret;
+use isucholar_core::repos::error::Result;
+use isucholar_core::repos::user_totp_secret_repository::UserTotpSecretRepository;
+
+#[cfg(test)]
+mod create_or_replace;
+#[cfg(test)]
+mod delete_by_user_id;
+#[cfg(test)]
+mod update_last_used_step_by_user_id;
+
+#[derive(Clone)]
+pub struct UserTotpSecretRepositoryInfra {}
+
+#[async_trait]
+impl UserTotpSecretRepository for UserTotpSecretRepositoryInfra {
+    async fn find_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Option<UserTotpSecret>> {
+        let secret = sqlx::query_as!(
+            UserTotpSecret,
+            r"
+                SELECT
+                    user_id as `user_id:UserID`,
+                    secret,
+                    confirmed as `confirmed:bool`,
+                    last_used_step,
+                    created_at
+                FROM `user_totp_secrets`
+                WHERE `user_id` = ?
+            ",
+            user_id
+        )
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(secret)
+    }
+
+    async fn create_or_replace(&self, conn: &mut DBConn, secret: &UserTotpSecret) -> Result<()> {
+        sqlx::query!(
+            r"
+                INSERT INTO `user_totp_secrets` (`user_id`, `secret`, `confirmed`, `last_used_step`, `created_at`)
+                VALUES (?, ?, ?, ?, ?)
+                ON DUPLICATE KEY UPDATE
+                    `secret` = VALUES(`secret`),
+                    `confirmed` = VALUES(`confirmed`),
+                    `last_used_step` = VALUES(`last_used_step`),
+                    `created_at` = VALUES(`created_at`)
+            ",
+            &secret.user_id,
+            &secret.secret,
+            &secret.confirmed,
+            &secret.last_used_step,
+            &secret.created_at,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn confirm_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()> {
+        sqlx::query!(
+            "UPDATE `user_totp_secrets` SET `confirmed` = true WHERE `user_id` = ?",
+            user_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update_last_used_step_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+        step: i64,
+    ) -> Result<bool> {
+        let result = sqlx::query!(
+            r"
+                UPDATE `user_totp_secrets` SET `last_used_step` = ?
+                WHERE `user_id` = ? AND (`last_used_step` IS NULL OR `last_used_step` < ?)
+            ",
+            step,
+            user_id,
+            step
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `user_totp_secrets` WHERE `user_id` = ?",
+            user_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+}
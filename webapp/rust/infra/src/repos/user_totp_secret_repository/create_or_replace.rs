@@ -0,0 +1,38 @@
+use crate::repos::user_totp_secret_repository::UserTotpSecretRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user_totp_secret::UserTotpSecret;
+use isucholar_core::repos::user_totp_secret_repository::UserTotpSecretRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn replace_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = UserTotpSecretRepositoryInfra {};
+    let now = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+    let mut first: UserTotpSecret = Faker.fake();
+    first.secret = "A".repeat(32);
+    first.confirmed = false;
+    first.last_used_step = None;
+    first.created_at = now;
+    let mut second = first.clone();
+    second.secret = "B".repeat(32);
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create_or_replace(conn, &first).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.create_or_replace(conn, &second).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_by_user_id(conn, &first.user_id).await.unwrap();
+    assert_eq!(got, Some(second));
+}
@@ -0,0 +1,33 @@
+use crate::repos::user_totp_secret_repository::UserTotpSecretRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user_totp_secret::UserTotpSecret;
+use isucholar_core::repos::user_totp_secret_repository::UserTotpSecretRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = UserTotpSecretRepositoryInfra {};
+    let mut secret: UserTotpSecret = Faker.fake();
+    secret.secret = "A".repeat(32);
+    secret.created_at = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create_or_replace(conn, &secret).await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+    repo.delete_by_user_id(conn, &secret.user_id).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo.find_by_user_id(conn, &secret.user_id).await.unwrap();
+    assert!(got.is_none());
+}
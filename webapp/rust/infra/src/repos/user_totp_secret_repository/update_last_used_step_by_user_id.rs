@@ -0,0 +1,44 @@
+use crate::repos::user_totp_secret_repository::UserTotpSecretRepositoryInfra;
+use chrono::Timelike;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::user_totp_secret::UserTotpSecret;
+use isucholar_core::repos::user_totp_secret_repository::UserTotpSecretRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn only_newer_step_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = UserTotpSecretRepositoryInfra {};
+    let mut secret: UserTotpSecret = Faker.fake();
+    secret.secret = "A".repeat(32);
+    secret.last_used_step = None;
+    secret.created_at = chrono::Utc::now().naive_utc().with_nanosecond(0).unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    repo.create_or_replace(conn, &secret).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    assert!(repo
+        .update_last_used_step_by_user_id(conn, &secret.user_id, 100)
+        .await
+        .unwrap());
+    let conn = tx.acquire().await.unwrap();
+    assert!(!repo
+        .update_last_used_step_by_user_id(conn, &secret.user_id, 100)
+        .await
+        .unwrap());
+    let conn = tx.acquire().await.unwrap();
+    assert!(repo
+        .update_last_used_step_by_user_id(conn, &secret.user_id, 101)
+        .await
+        .unwrap());
+}
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use isucholar_core::db::DBConn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::error::Result;
+use isucholar_core::repos::waitlist_repository::WaitlistRepository;
+
+#[cfg(test)]
+mod create;
+#[cfg(test)]
+mod delete;
+#[cfg(test)]
+mod delete_by_user_id;
+#[cfg(test)]
+mod find_course_ids_by_user_id;
+#[cfg(test)]
+mod find_first_for_update_by_course_id;
+
+#[derive(Clone)]
+pub struct WaitlistRepositoryInfra {}
+
+#[async_trait]
+impl WaitlistRepository for WaitlistRepositoryInfra {
+    async fn create(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+        user_id: &UserID,
+    ) -> Result<()> {
+        // 登録済みの場合は順番を変えない
+        sqlx::query!(
+            "INSERT INTO `waitlist_entries` (`course_id`, `user_id`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `id` = `id`",
+            course_id,
+            user_id,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_position(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+        user_id: &UserID,
+    ) -> Result<Option<i64>> {
+        let position: Option<i64> = sqlx::query_scalar!(
+            r"
+                SELECT COUNT(*)
+                FROM `waitlist_entries` AS `others`
+                JOIN `waitlist_entries` AS `mine` ON `others`.`course_id` = `mine`.`course_id` AND `others`.`id` <= `mine`.`id`
+                WHERE `mine`.`course_id` = ? AND `mine`.`user_id` = ?
+                GROUP BY `mine`.`id`
+            ",
+            course_id,
+            user_id,
+        )
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(position)
+    }
+
+    async fn find_first_for_update_by_course_id(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+    ) -> Result<Option<UserID>> {
+        let user_id: Option<UserID> = sqlx::query_scalar!(
+            "SELECT `user_id` AS `user_id:UserID` FROM `waitlist_entries` WHERE `course_id` = ? ORDER BY `id` LIMIT 1 FOR UPDATE",
+            course_id,
+        )
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(user_id)
+    }
+
+    async fn delete(
+        &self,
+        conn: &mut DBConn,
+        course_id: &CourseID,
+        user_id: &UserID,
+    ) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `waitlist_entries` WHERE `course_id` = ? AND `user_id` = ?",
+            course_id,
+            user_id,
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_course_ids_by_user_id(
+        &self,
+        conn: &mut DBConn,
+        user_id: &UserID,
+    ) -> Result<Vec<CourseID>> {
+        let course_ids: Vec<CourseID> = sqlx::query_scalar!(
+            "SELECT `course_id` AS `course_id:CourseID` FROM `waitlist_entries` WHERE `user_id` = ? ORDER BY `id`",
+            user_id,
+        )
+        .fetch_all(conn)
+        .await?;
+
+        Ok(course_ids)
+    }
+
+    async fn delete_by_user_id(&self, conn: &mut DBConn, user_id: &UserID) -> Result<()> {
+        sqlx::query!(
+            "DELETE FROM `waitlist_entries` WHERE `user_id` = ?",
+            user_id
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+}
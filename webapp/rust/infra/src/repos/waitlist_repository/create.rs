@@ -0,0 +1,53 @@
+use crate::repos::waitlist_repository::WaitlistRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::waitlist_repository::WaitlistRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = WaitlistRepositoryInfra {};
+    let course_id: CourseID = Faker.fake();
+    let first: UserID = Faker.fake();
+    let second: UserID = Faker.fake();
+
+    for user_id in [&first, &second] {
+        let conn = tx.acquire().await.unwrap();
+        repo.create(conn, &course_id, user_id).await.unwrap();
+    }
+    // 再登録しても順番は変わらない
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &course_id, &first).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let position = repo.find_position(conn, &course_id, &first).await.unwrap();
+    assert_eq!(position, Some(1));
+    let conn = tx.acquire().await.unwrap();
+    let position = repo.find_position(conn, &course_id, &second).await.unwrap();
+    assert_eq!(position, Some(2));
+}
+
+#[tokio::test]
+async fn not_waiting_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    let repo = WaitlistRepositoryInfra {};
+    let position = repo
+        .find_position(conn, &Faker.fake(), &Faker.fake())
+        .await
+        .unwrap();
+    assert_eq!(position, None);
+}
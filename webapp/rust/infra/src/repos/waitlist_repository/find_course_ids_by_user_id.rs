@@ -0,0 +1,39 @@
+use crate::repos::waitlist_repository::WaitlistRepositoryInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::CourseID;
+use isucholar_core::models::user::UserID;
+use isucholar_core::repos::waitlist_repository::WaitlistRepository;
+use sqlx::Acquire;
+
+#[tokio::test]
+async fn success_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut tx = db_pool.begin().await.unwrap();
+    let conn = tx.acquire().await.unwrap();
+
+    sqlx::query!("SET foreign_key_checks=0")
+        .execute(conn)
+        .await
+        .unwrap();
+
+    let repo = WaitlistRepositoryInfra {};
+    let first: CourseID = Faker.fake();
+    let second: CourseID = Faker.fake();
+    let user_id: UserID = Faker.fake();
+    let other_user_id: UserID = Faker.fake();
+
+    for course_id in [&first, &second] {
+        let conn = tx.acquire().await.unwrap();
+        repo.create(conn, course_id, &user_id).await.unwrap();
+    }
+    let conn = tx.acquire().await.unwrap();
+    repo.create(conn, &first, &other_user_id).await.unwrap();
+
+    let conn = tx.acquire().await.unwrap();
+    let got = repo
+        .find_course_ids_by_user_id(conn, &user_id)
+        .await
+        .unwrap();
+    assert_eq!(got, vec![first, second]);
+}
@@ -1,21 +1,42 @@
+use crate::repos::announcement_repository::AnnouncementRepositoryInfra;
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
+use crate::repos::class_repository::ClassRepositoryInfra;
+use crate::repos::course_prerequisite_repository::CoursePrerequisiteRepositoryInfra;
 use crate::repos::course_repository::CourseRepositoryInfra;
 use crate::repos::registration_course_repository::RegistrationCourseRepositoryInfra;
+use crate::repos::registration_repository::RegistrationRepositoryInfra;
+use crate::repos::unread_announcement_repository::UnreadAnnouncementRepositoryInfra;
+use crate::repos::user_profile_repository::UserProfileRepositoryInfra;
 use crate::repos::user_repository::UserRepositoryInfra;
 use isucholar_core::db::DBPool;
+use isucholar_core::repos::announcement_repository::HaveAnnouncementRepository;
+use isucholar_core::repos::audit_log_repository::HaveAuditLogRepository;
+use isucholar_core::repos::class_repository::HaveClassRepository;
+use isucholar_core::repos::course_prerequisite_repository::HaveCoursePrerequisiteRepository;
 use isucholar_core::repos::course_repository::HaveCourseRepository;
 use isucholar_core::repos::registration_course_repository::HaveRegistrationCourseRepository;
+use isucholar_core::repos::registration_repository::HaveRegistrationRepository;
+use isucholar_core::repos::unread_announcement_repository::HaveUnreadAnnouncementRepository;
+use isucholar_core::repos::user_profile_repository::HaveUserProfileRepository;
 use isucholar_core::repos::user_repository::HaveUserRepository;
 use isucholar_core::services::course_service::CourseServiceImpl;
 use isucholar_core::services::HaveDBPool;
 use std::sync::Arc;
 
 pub mod announcement_service;
+pub mod audit_log_service;
 pub mod class_service;
 pub mod course_service;
 pub mod grade_summary_service;
+pub mod impersonation_service;
+pub mod login_attempt_service;
 pub mod manager;
+pub mod oidc_login_service;
+pub mod personal_access_token_service;
+pub mod personal_data_service;
 pub mod registration_course_service;
 pub mod submission_service;
+pub mod two_factor_service;
 pub mod unread_announcement_service;
 pub mod user_service;
 
@@ -24,7 +45,14 @@ pub struct CourseServiceInfra {
     db_pool: Arc<DBPool>,
     user_repo: UserRepositoryInfra,
     course_repo: CourseRepositoryInfra,
+    class_repo: ClassRepositoryInfra,
     registration_course_repo: RegistrationCourseRepositoryInfra,
+    registration_repo: RegistrationRepositoryInfra,
+    announcement_repo: AnnouncementRepositoryInfra,
+    unread_announcement_repo: UnreadAnnouncementRepositoryInfra,
+    user_profile_repo: UserProfileRepositoryInfra,
+    course_prerequisite_repo: CoursePrerequisiteRepositoryInfra,
+    audit_log_repo: AuditLogRepositoryInfra,
 }
 
 impl CourseServiceInfra {
@@ -33,7 +61,14 @@ impl CourseServiceInfra {
             db_pool,
             user_repo: UserRepositoryInfra {},
             course_repo: CourseRepositoryInfra {},
+            class_repo: ClassRepositoryInfra {},
             registration_course_repo: RegistrationCourseRepositoryInfra {},
+            registration_repo: RegistrationRepositoryInfra {},
+            announcement_repo: AnnouncementRepositoryInfra {},
+            unread_announcement_repo: UnreadAnnouncementRepositoryInfra {},
+            user_profile_repo: UserProfileRepositoryInfra {},
+            course_prerequisite_repo: CoursePrerequisiteRepositoryInfra {},
+            audit_log_repo: AuditLogRepositoryInfra {},
         }
     }
 }
@@ -68,3 +103,59 @@ impl HaveCourseRepository for CourseServiceInfra {
         &self.course_repo
     }
 }
+
+impl HaveRegistrationRepository for CourseServiceInfra {
+    type Repo = RegistrationRepositoryInfra;
+
+    fn registration_repo(&self) -> &Self::Repo {
+        &self.registration_repo
+    }
+}
+
+impl HaveAnnouncementRepository for CourseServiceInfra {
+    type Repo = AnnouncementRepositoryInfra;
+
+    fn announcement_repo(&self) -> &Self::Repo {
+        &self.announcement_repo
+    }
+}
+
+impl HaveUnreadAnnouncementRepository for CourseServiceInfra {
+    type Repo = UnreadAnnouncementRepositoryInfra;
+
+    fn unread_announcement_repo(&self) -> &Self::Repo {
+        &self.unread_announcement_repo
+    }
+}
+
+impl HaveUserProfileRepository for CourseServiceInfra {
+    type Repo = UserProfileRepositoryInfra;
+
+    fn user_profile_repo(&self) -> &Self::Repo {
+        &self.user_profile_repo
+    }
+}
+
+impl HaveClassRepository for CourseServiceInfra {
+    type Repo = ClassRepositoryInfra;
+
+    fn class_repo(&self) -> &Self::Repo {
+        &self.class_repo
+    }
+}
+
+impl HaveCoursePrerequisiteRepository for CourseServiceInfra {
+    type Repo = CoursePrerequisiteRepositoryInfra;
+
+    fn course_prerequisite_repo(&self) -> &Self::Repo {
+        &self.course_prerequisite_repo
+    }
+}
+
+impl HaveAuditLogRepository for CourseServiceInfra {
+    type Repo = AuditLogRepositoryInfra;
+
+    fn audit_log_repo(&self) -> &Self::Repo {
+        &self.audit_log_repo
+    }
+}
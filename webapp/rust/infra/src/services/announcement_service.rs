@@ -1,12 +1,16 @@
 use crate::repos::announcement_repository::AnnouncementRepositoryInfra;
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
 use crate::repos::course_repository::CourseRepositoryInfra;
 use crate::repos::registration_repository::RegistrationRepositoryInfra;
 use crate::repos::unread_announcement_repository::UnreadAnnouncementRepositoryInfra;
+use crate::repos::user_profile_repository::UserProfileRepositoryInfra;
 use isucholar_core::db::DBPool;
 use isucholar_core::repos::announcement_repository::HaveAnnouncementRepository;
+use isucholar_core::repos::audit_log_repository::HaveAuditLogRepository;
 use isucholar_core::repos::course_repository::HaveCourseRepository;
 use isucholar_core::repos::registration_repository::HaveRegistrationRepository;
 use isucholar_core::repos::unread_announcement_repository::HaveUnreadAnnouncementRepository;
+use isucholar_core::repos::user_profile_repository::HaveUserProfileRepository;
 use isucholar_core::services::announcement_service::AnnouncementServiceImpl;
 use isucholar_core::services::HaveDBPool;
 use std::sync::Arc;
@@ -18,6 +22,8 @@ pub struct AnnouncementServiceInfra {
     course: CourseRepositoryInfra,
     registration: RegistrationRepositoryInfra,
     unread_announcement: UnreadAnnouncementRepositoryInfra,
+    user_profile: UserProfileRepositoryInfra,
+    audit_log: AuditLogRepositoryInfra,
 }
 
 impl AnnouncementServiceInfra {
@@ -28,6 +34,8 @@ impl AnnouncementServiceInfra {
             course: CourseRepositoryInfra {},
             registration: RegistrationRepositoryInfra {},
             unread_announcement: UnreadAnnouncementRepositoryInfra {},
+            user_profile: UserProfileRepositoryInfra {},
+            audit_log: AuditLogRepositoryInfra {},
         }
     }
 }
@@ -71,3 +79,19 @@ impl HaveUnreadAnnouncementRepository for AnnouncementServiceInfra {
         &self.unread_announcement
     }
 }
+
+impl HaveUserProfileRepository for AnnouncementServiceInfra {
+    type Repo = UserProfileRepositoryInfra;
+
+    fn user_profile_repo(&self) -> &Self::Repo {
+        &self.user_profile
+    }
+}
+
+impl HaveAuditLogRepository for AnnouncementServiceInfra {
+    type Repo = AuditLogRepositoryInfra;
+
+    fn audit_log_repo(&self) -> &Self::Repo {
+        &self.audit_log
+    }
+}
@@ -0,0 +1,37 @@
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
+use isucholar_core::db::DBPool;
+use isucholar_core::repos::audit_log_repository::HaveAuditLogRepository;
+use isucholar_core::services::audit_log_service::AuditLogServiceImpl;
+use isucholar_core::services::HaveDBPool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct AuditLogServiceInfra {
+    db_pool: Arc<DBPool>,
+    audit_log_repo: AuditLogRepositoryInfra,
+}
+
+impl AuditLogServiceInfra {
+    pub fn new(db_pool: Arc<DBPool>) -> Self {
+        Self {
+            db_pool,
+            audit_log_repo: AuditLogRepositoryInfra {},
+        }
+    }
+}
+
+impl AuditLogServiceImpl for AuditLogServiceInfra {}
+
+impl HaveDBPool for AuditLogServiceInfra {
+    fn get_db_pool(&self) -> &DBPool {
+        &self.db_pool
+    }
+}
+
+impl HaveAuditLogRepository for AuditLogServiceInfra {
+    type Repo = AuditLogRepositoryInfra;
+
+    fn audit_log_repo(&self) -> &Self::Repo {
+        &self.audit_log_repo
+    }
+}
@@ -1,8 +1,10 @@
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
 use crate::repos::class_repository::ClassRepositoryInfra;
 use crate::repos::course_repository::CourseRepositoryInfra;
 use crate::repos::registration_course_repository::RegistrationCourseRepositoryInfra;
 use crate::repos::submission_repository::SubmissionRepositoryInfra;
 use isucholar_core::db::DBPool;
+use isucholar_core::repos::audit_log_repository::HaveAuditLogRepository;
 use isucholar_core::repos::class_repository::HaveClassRepository;
 use isucholar_core::repos::course_repository::HaveCourseRepository;
 use isucholar_core::repos::registration_course_repository::HaveRegistrationCourseRepository;
@@ -18,6 +20,7 @@ pub struct ClassServiceInfra {
     course_repo: CourseRepositoryInfra,
     submission_repo: SubmissionRepositoryInfra,
     registration_course_repo: RegistrationCourseRepositoryInfra,
+    audit_log_repo: AuditLogRepositoryInfra,
 }
 
 impl ClassServiceInfra {
@@ -28,6 +31,7 @@ impl ClassServiceInfra {
             course_repo: CourseRepositoryInfra {},
             submission_repo: SubmissionRepositoryInfra {},
             registration_course_repo: RegistrationCourseRepositoryInfra {},
+            audit_log_repo: AuditLogRepositoryInfra {},
         }
     }
 }
@@ -70,3 +74,11 @@ impl HaveCourseRepository for ClassServiceInfra {
         &self.course_repo
     }
 }
+
+impl HaveAuditLogRepository for ClassServiceInfra {
+    type Repo = AuditLogRepositoryInfra;
+
+    fn audit_log_repo(&self) -> &Self::Repo {
+        &self.audit_log_repo
+    }
+}
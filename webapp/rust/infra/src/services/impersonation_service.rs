@@ -0,0 +1,59 @@
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
+use crate::repos::user_repository::UserRepositoryInfra;
+use isucholar_core::db::DBPool;
+use isucholar_core::repos::audit_log_repository::HaveAuditLogRepository;
+use isucholar_core::repos::user_repository::HaveUserRepository;
+use isucholar_core::services::impersonation_service::{
+    HaveImpersonationConfig, ImpersonationConfig, ImpersonationServiceImpl,
+};
+use isucholar_core::services::HaveDBPool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ImpersonationServiceInfra {
+    db_pool: Arc<DBPool>,
+    impersonation_config: ImpersonationConfig,
+    user_repo: UserRepositoryInfra,
+    audit_log_repo: AuditLogRepositoryInfra,
+}
+
+impl ImpersonationServiceInfra {
+    pub fn new(db_pool: Arc<DBPool>) -> Self {
+        Self {
+            db_pool,
+            impersonation_config: ImpersonationConfig::from_env(),
+            user_repo: UserRepositoryInfra {},
+            audit_log_repo: AuditLogRepositoryInfra {},
+        }
+    }
+}
+
+impl ImpersonationServiceImpl for ImpersonationServiceInfra {}
+
+impl HaveDBPool for ImpersonationServiceInfra {
+    fn get_db_pool(&self) -> &DBPool {
+        &self.db_pool
+    }
+}
+
+impl HaveImpersonationConfig for ImpersonationServiceInfra {
+    fn impersonation_config(&self) -> &ImpersonationConfig {
+        &self.impersonation_config
+    }
+}
+
+impl HaveUserRepository for ImpersonationServiceInfra {
+    type Repo = UserRepositoryInfra;
+
+    fn user_repo(&self) -> &Self::Repo {
+        &self.user_repo
+    }
+}
+
+impl HaveAuditLogRepository for ImpersonationServiceInfra {
+    type Repo = AuditLogRepositoryInfra;
+
+    fn audit_log_repo(&self) -> &Self::Repo {
+        &self.audit_log_repo
+    }
+}
@@ -0,0 +1,47 @@
+use crate::repos::login_attempt_repository::LoginAttemptRepositoryInfra;
+use isucholar_core::db::DBPool;
+use isucholar_core::repos::login_attempt_repository::HaveLoginAttemptRepository;
+use isucholar_core::services::login_attempt_service::{
+    HaveLoginThrottleConfig, LoginAttemptServiceImpl, LoginThrottleConfig,
+};
+use isucholar_core::services::HaveDBPool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct LoginAttemptServiceInfra {
+    db_pool: Arc<DBPool>,
+    login_throttle_config: LoginThrottleConfig,
+    login_attempt_repo: LoginAttemptRepositoryInfra,
+}
+
+impl LoginAttemptServiceInfra {
+    pub fn new(db_pool: Arc<DBPool>) -> Self {
+        Self {
+            db_pool,
+            login_throttle_config: LoginThrottleConfig::from_env(),
+            login_attempt_repo: LoginAttemptRepositoryInfra {},
+        }
+    }
+}
+
+impl LoginAttemptServiceImpl for LoginAttemptServiceInfra {}
+
+impl HaveDBPool for LoginAttemptServiceInfra {
+    fn get_db_pool(&self) -> &DBPool {
+        &self.db_pool
+    }
+}
+
+impl HaveLoginThrottleConfig for LoginAttemptServiceInfra {
+    fn login_throttle_config(&self) -> &LoginThrottleConfig {
+        &self.login_throttle_config
+    }
+}
+
+impl HaveLoginAttemptRepository for LoginAttemptServiceInfra {
+    type Repo = LoginAttemptRepositoryInfra;
+
+    fn login_attempt_repo(&self) -> &Self::Repo {
+        &self.login_attempt_repo
+    }
+}
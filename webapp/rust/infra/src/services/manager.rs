@@ -1,19 +1,33 @@
 use crate::services::announcement_service::AnnouncementServiceInfra;
+use crate::services::audit_log_service::AuditLogServiceInfra;
 use crate::services::class_service::ClassServiceInfra;
 use crate::services::grade_summary_service::GradeSummaryServiceInfra;
+use crate::services::impersonation_service::ImpersonationServiceInfra;
+use crate::services::login_attempt_service::LoginAttemptServiceInfra;
+use crate::services::oidc_login_service::OidcLoginServiceInfra;
+use crate::services::personal_access_token_service::PersonalAccessTokenServiceInfra;
+use crate::services::personal_data_service::PersonalDataServiceInfra;
 use crate::services::registration_course_service::RegistrationCourseServiceInfra;
 use crate::services::submission_service::SubmissionServiceInfra;
+use crate::services::two_factor_service::TwoFactorServiceInfra;
 use crate::services::unread_announcement_service::UnreadAnnouncementServiceInfra;
 use crate::services::user_service::UserServiceInfra;
 use crate::services::CourseServiceInfra;
 use isucholar_core::db::DBPool;
 use isucholar_core::services::announcement_service::HaveAnnouncementService;
+use isucholar_core::services::audit_log_service::HaveAuditLogService;
 use isucholar_core::services::class_service::HaveClassService;
 use isucholar_core::services::course_service::HaveCourseService;
 use isucholar_core::services::grade_summary_service::HaveGradeSummaryService;
+use isucholar_core::services::impersonation_service::HaveImpersonationService;
+use isucholar_core::services::login_attempt_service::HaveLoginAttemptService;
 use isucholar_core::services::manager::ServiceManager;
+use isucholar_core::services::oidc_login_service::HaveOidcLoginService;
+use isucholar_core::services::personal_access_token_service::HavePersonalAccessTokenService;
+use isucholar_core::services::personal_data_service::HavePersonalDataService;
 use isucholar_core::services::registration_course_service::HaveRegistrationCourseService;
 use isucholar_core::services::submission_service::HaveSubmissionService;
+use isucholar_core::services::two_factor_service::HaveTwoFactorService;
 use isucholar_core::services::unread_announcement_service::HaveUnreadAnnouncementService;
 use isucholar_core::services::user_service::HaveUserService;
 use std::sync::Arc;
@@ -28,6 +42,13 @@ pub struct ServiceManagerInfra {
     registration_course_service: RegistrationCourseServiceInfra,
     grade_summary_service: GradeSummaryServiceInfra,
     submission_service: SubmissionServiceInfra,
+    personal_access_token_service: PersonalAccessTokenServiceInfra,
+    login_attempt_service: LoginAttemptServiceInfra,
+    oidc_login_service: OidcLoginServiceInfra,
+    two_factor_service: TwoFactorServiceInfra,
+    impersonation_service: ImpersonationServiceInfra,
+    audit_log_service: AuditLogServiceInfra,
+    personal_data_service: PersonalDataServiceInfra,
 }
 
 impl ServiceManager for ServiceManagerInfra {}
@@ -43,7 +64,14 @@ impl ServiceManagerInfra {
             class_service: ClassServiceInfra::new(pool.clone()),
             registration_course_service: RegistrationCourseServiceInfra::new(pool.clone()),
             grade_summary_service: GradeSummaryServiceInfra::new(pool.clone()),
-            submission_service: SubmissionServiceInfra::new(pool),
+            submission_service: SubmissionServiceInfra::new(pool.clone()),
+            personal_access_token_service: PersonalAccessTokenServiceInfra::new(pool.clone()),
+            login_attempt_service: LoginAttemptServiceInfra::new(pool.clone()),
+            oidc_login_service: OidcLoginServiceInfra::new(pool.clone()),
+            two_factor_service: TwoFactorServiceInfra::new(pool.clone()),
+            impersonation_service: ImpersonationServiceInfra::new(pool.clone()),
+            audit_log_service: AuditLogServiceInfra::new(pool.clone()),
+            personal_data_service: PersonalDataServiceInfra::new(pool),
         }
     }
 }
@@ -109,3 +137,59 @@ impl HaveSubmissionService for ServiceManagerInfra {
         &self.submission_service
     }
 }
+
+impl HavePersonalAccessTokenService for ServiceManagerInfra {
+    type Service = PersonalAccessTokenServiceInfra;
+
+    fn personal_access_token_service(&self) -> &Self::Service {
+        &self.personal_access_token_service
+    }
+}
+
+impl HaveLoginAttemptService for ServiceManagerInfra {
+    type Service = LoginAttemptServiceInfra;
+
+    fn login_attempt_service(&self) -> &Self::Service {
+        &self.login_attempt_service
+    }
+}
+
+impl HaveOidcLoginService for ServiceManagerInfra {
+    type Service = OidcLoginServiceInfra;
+
+    fn oidc_login_service(&self) -> &Self::Service {
+        &self.oidc_login_service
+    }
+}
+
+impl HaveTwoFactorService for ServiceManagerInfra {
+    type Service = TwoFactorServiceInfra;
+
+    fn two_factor_service(&self) -> &Self::Service {
+        &self.two_factor_service
+    }
+}
+
+impl HaveImpersonationService for ServiceManagerInfra {
+    type Service = ImpersonationServiceInfra;
+
+    fn impersonation_service(&self) -> &Self::Service {
+        &self.impersonation_service
+    }
+}
+
+impl HaveAuditLogService for ServiceManagerInfra {
+    type Service = AuditLogServiceInfra;
+
+    fn audit_log_service(&self) -> &Self::Service {
+        &self.audit_log_service
+    }
+}
+
+impl HavePersonalDataService for ServiceManagerInfra {
+    type Service = PersonalDataServiceInfra;
+
+    fn personal_data_service(&self) -> &Self::Service {
+        &self.personal_data_service
+    }
+}
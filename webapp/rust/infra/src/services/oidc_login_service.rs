@@ -0,0 +1,73 @@
+use crate::identity_providers::oidc_provider::OidcProviderInfra;
+use crate::repos::user_repository::UserRepositoryInfra;
+use isucholar_core::db::DBPool;
+use isucholar_core::identity_providers::oidc_provider::HaveOidcProvider;
+use isucholar_core::password_hashers::policy_password_hasher::{
+    PasswordHashPolicy, PolicyPasswordHasher,
+};
+use isucholar_core::password_hashers::HavePasswordHasher;
+use isucholar_core::repos::user_repository::HaveUserRepository;
+use isucholar_core::services::oidc_login_service::{
+    HaveOidcUserMappingConfig, OidcLoginServiceImpl, OidcUserMappingConfig,
+};
+use isucholar_core::services::HaveDBPool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct OidcLoginServiceInfra {
+    db_pool: Arc<DBPool>,
+    oidc_user_mapping_config: OidcUserMappingConfig,
+    oidc_provider: OidcProviderInfra,
+    user_repo: UserRepositoryInfra,
+    password_hasher: PolicyPasswordHasher,
+}
+
+impl OidcLoginServiceInfra {
+    pub fn new(db_pool: Arc<DBPool>) -> Self {
+        Self {
+            db_pool,
+            oidc_user_mapping_config: OidcUserMappingConfig::from_env(),
+            oidc_provider: OidcProviderInfra::default(),
+            user_repo: UserRepositoryInfra {},
+            password_hasher: PolicyPasswordHasher::new(PasswordHashPolicy::from_env()),
+        }
+    }
+}
+
+impl OidcLoginServiceImpl for OidcLoginServiceInfra {}
+
+impl HaveDBPool for OidcLoginServiceInfra {
+    fn get_db_pool(&self) -> &DBPool {
+        &self.db_pool
+    }
+}
+
+impl HaveOidcUserMappingConfig for OidcLoginServiceInfra {
+    fn oidc_user_mapping_config(&self) -> &OidcUserMappingConfig {
+        &self.oidc_user_mapping_config
+    }
+}
+
+impl HaveOidcProvider for OidcLoginServiceInfra {
+    type Provider = OidcProviderInfra;
+
+    fn oidc_provider(&self) -> &Self::Provider {
+        &self.oidc_provider
+    }
+}
+
+impl HaveUserRepository for OidcLoginServiceInfra {
+    type Repo = UserRepositoryInfra;
+
+    fn user_repo(&self) -> &Self::Repo {
+        &self.user_repo
+    }
+}
+
+impl HavePasswordHasher for OidcLoginServiceInfra {
+    type Hasher = PolicyPasswordHasher;
+
+    fn password_hasher(&self) -> &Self::Hasher {
+        &self.password_hasher
+    }
+}
@@ -0,0 +1,49 @@
+use crate::repos::personal_access_token_repository::PersonalAccessTokenRepositoryInfra;
+use crate::repos::user_repository::UserRepositoryInfra;
+use isucholar_core::db::DBPool;
+use isucholar_core::repos::personal_access_token_repository::HavePersonalAccessTokenRepository;
+use isucholar_core::repos::user_repository::HaveUserRepository;
+use isucholar_core::services::personal_access_token_service::PersonalAccessTokenServiceImpl;
+use isucholar_core::services::HaveDBPool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PersonalAccessTokenServiceInfra {
+    db_pool: Arc<DBPool>,
+    personal_access_token_repo: PersonalAccessTokenRepositoryInfra,
+    user_repo: UserRepositoryInfra,
+}
+
+impl PersonalAccessTokenServiceInfra {
+    pub fn new(db_pool: Arc<DBPool>) -> Self {
+        Self {
+            db_pool,
+            personal_access_token_repo: PersonalAccessTokenRepositoryInfra {},
+            user_repo: UserRepositoryInfra {},
+        }
+    }
+}
+
+impl PersonalAccessTokenServiceImpl for PersonalAccessTokenServiceInfra {}
+
+impl HaveDBPool for PersonalAccessTokenServiceInfra {
+    fn get_db_pool(&self) -> &DBPool {
+        &self.db_pool
+    }
+}
+
+impl HavePersonalAccessTokenRepository for PersonalAccessTokenServiceInfra {
+    type Repo = PersonalAccessTokenRepositoryInfra;
+
+    fn personal_access_token_repo(&self) -> &Self::Repo {
+        &self.personal_access_token_repo
+    }
+}
+
+impl HaveUserRepository for PersonalAccessTokenServiceInfra {
+    type Repo = UserRepositoryInfra;
+
+    fn user_repo(&self) -> &Self::Repo {
+        &self.user_repo
+    }
+}
@@ -0,0 +1,219 @@
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
+use crate::repos::credit_limit_repository::CreditLimitRepositoryInfra;
+use crate::repos::login_attempt_repository::LoginAttemptRepositoryInfra;
+use crate::repos::lottery_preference_repository::LotteryPreferenceRepositoryInfra;
+use crate::repos::personal_access_token_repository::PersonalAccessTokenRepositoryInfra;
+use crate::repos::registration_course_repository::RegistrationCourseRepositoryInfra;
+use crate::repos::registration_request_repository::RegistrationRequestRepositoryInfra;
+use crate::repos::submission_repository::SubmissionRepositoryInfra;
+use crate::repos::unread_announcement_repository::UnreadAnnouncementRepositoryInfra;
+use crate::repos::user_profile_repository::UserProfileRepositoryInfra;
+use crate::repos::user_recovery_code_repository::UserRecoveryCodeRepositoryInfra;
+use crate::repos::user_repository::UserRepositoryInfra;
+use crate::repos::user_totp_secret_repository::UserTotpSecretRepositoryInfra;
+use crate::repos::waitlist_repository::WaitlistRepositoryInfra;
+use crate::storages::personal_data_storage::PersonalDataStorageInfra;
+use isucholar_core::db::DBPool;
+use isucholar_core::password_hashers::policy_password_hasher::{
+    PasswordHashPolicy, PolicyPasswordHasher,
+};
+use isucholar_core::password_hashers::HavePasswordHasher;
+use isucholar_core::repos::audit_log_repository::HaveAuditLogRepository;
+use isucholar_core::repos::credit_limit_repository::HaveCreditLimitRepository;
+use isucholar_core::repos::login_attempt_repository::HaveLoginAttemptRepository;
+use isucholar_core::repos::lottery_preference_repository::HaveLotteryPreferenceRepository;
+use isucholar_core::repos::personal_access_token_repository::HavePersonalAccessTokenRepository;
+use isucholar_core::repos::registration_course_repository::HaveRegistrationCourseRepository;
+use isucholar_core::repos::registration_request_repository::HaveRegistrationRequestRepository;
+use isucholar_core::repos::submission_repository::HaveSubmissionRepository;
+use isucholar_core::repos::unread_announcement_repository::HaveUnreadAnnouncementRepository;
+use isucholar_core::repos::user_profile_repository::HaveUserProfileRepository;
+use isucholar_core::repos::user_recovery_code_repository::HaveUserRecoveryCodeRepository;
+use isucholar_core::repos::user_repository::HaveUserRepository;
+use isucholar_core::repos::user_totp_secret_repository::HaveUserTotpSecretRepository;
+use isucholar_core::repos::waitlist_repository::HaveWaitlistRepository;
+use isucholar_core::services::personal_data_service::PersonalDataServiceImpl;
+use isucholar_core::services::HaveDBPool;
+use isucholar_core::storages::personal_data_storage::HavePersonalDataStorage;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PersonalDataServiceInfra {
+    db_pool: Arc<DBPool>,
+    user_repo: UserRepositoryInfra,
+    user_profile_repo: UserProfileRepositoryInfra,
+    registration_course_repo: RegistrationCourseRepositoryInfra,
+    submission_repo: SubmissionRepositoryInfra,
+    unread_announcement_repo: UnreadAnnouncementRepositoryInfra,
+    user_totp_secret_repo: UserTotpSecretRepositoryInfra,
+    user_recovery_code_repo: UserRecoveryCodeRepositoryInfra,
+    personal_access_token_repo: PersonalAccessTokenRepositoryInfra,
+    login_attempt_repo: LoginAttemptRepositoryInfra,
+    waitlist_repo: WaitlistRepositoryInfra,
+    lottery_preference_repo: LotteryPreferenceRepositoryInfra,
+    registration_request_repo: RegistrationRequestRepositoryInfra,
+    credit_limit_repo: CreditLimitRepositoryInfra,
+    password_hasher: PolicyPasswordHasher,
+    personal_data_storage: PersonalDataStorageInfra,
+    audit_log_repo: AuditLogRepositoryInfra,
+}
+
+impl PersonalDataServiceInfra {
+    pub fn new(db_pool: Arc<DBPool>) -> Self {
+        Self {
+            db_pool,
+            user_repo: UserRepositoryInfra {},
+            user_profile_repo: UserProfileRepositoryInfra {},
+            registration_course_repo: RegistrationCourseRepositoryInfra {},
+            submission_repo: SubmissionRepositoryInfra {},
+            unread_announcement_repo: UnreadAnnouncementRepositoryInfra {},
+            user_totp_secret_repo: UserTotpSecretRepositoryInfra {},
+            user_recovery_code_repo: UserRecoveryCodeRepositoryInfra {},
+            personal_access_token_repo: PersonalAccessTokenRepositoryInfra {},
+            login_attempt_repo: LoginAttemptRepositoryInfra {},
+            waitlist_repo: WaitlistRepositoryInfra {},
+            lottery_preference_repo: LotteryPreferenceRepositoryInfra {},
+            registration_request_repo: RegistrationRequestRepositoryInfra {},
+            credit_limit_repo: CreditLimitRepositoryInfra {},
+            password_hasher: PolicyPasswordHasher::new(PasswordHashPolicy::from_env()),
+            personal_data_storage: PersonalDataStorageInfra::default(),
+            audit_log_repo: AuditLogRepositoryInfra {},
+        }
+    }
+}
+
+impl PersonalDataServiceImpl for PersonalDataServiceInfra {}
+
+impl HaveDBPool for PersonalDataServiceInfra {
+    fn get_db_pool(&self) -> &DBPool {
+        &self.db_pool
+    }
+}
+
+impl HaveUserRepository for PersonalDataServiceInfra {
+    type Repo = UserRepositoryInfra;
+
+    fn user_repo(&self) -> &Self::Repo {
+        &self.user_repo
+    }
+}
+
+impl HaveUserProfileRepository for PersonalDataServiceInfra {
+    type Repo = UserProfileRepositoryInfra;
+
+    fn user_profile_repo(&self) -> &Self::Repo {
+        &self.user_profile_repo
+    }
+}
+
+impl HaveRegistrationCourseRepository for PersonalDataServiceInfra {
+    type Repo = RegistrationCourseRepositoryInfra;
+
+    fn registration_course_repo(&self) -> &Self::Repo {
+        &self.registration_course_repo
+    }
+}
+
+impl HaveSubmissionRepository for PersonalDataServiceInfra {
+    type Repo = SubmissionRepositoryInfra;
+
+    fn submission_repo(&self) -> &Self::Repo {
+        &self.submission_repo
+    }
+}
+
+impl HaveUnreadAnnouncementRepository for PersonalDataServiceInfra {
+    type Repo = UnreadAnnouncementRepositoryInfra;
+
+    fn unread_announcement_repo(&self) -> &Self::Repo {
+        &self.unread_announcement_repo
+    }
+}
+
+impl HaveUserTotpSecretRepository for PersonalDataServiceInfra {
+    type Repo = UserTotpSecretRepositoryInfra;
+
+    fn user_totp_secret_repo(&self) -> &Self::Repo {
+        &self.user_totp_secret_repo
+    }
+}
+
+impl HaveUserRecoveryCodeRepository for PersonalDataServiceInfra {
+    type Repo = UserRecoveryCodeRepositoryInfra;
+
+    fn user_recovery_code_repo(&self) -> &Self::Repo {
+        &self.user_recovery_code_repo
+    }
+}
+
+impl HavePersonalAccessTokenRepository for PersonalDataServiceInfra {
+    type Repo = PersonalAccessTokenRepositoryInfra;
+
+    fn personal_access_token_repo(&self) -> &Self::Repo {
+        &self.personal_access_token_repo
+    }
+}
+
+impl HaveLoginAttemptRepository for PersonalDataServiceInfra {
+    type Repo = LoginAttemptRepositoryInfra;
+
+    fn login_attempt_repo(&self) -> &Self::Repo {
+        &self.login_attempt_repo
+    }
+}
+
+impl HaveWaitlistRepository for PersonalDataServiceInfra {
+    type Repo = WaitlistRepositoryInfra;
+
+    fn waitlist_repo(&self) -> &Self::Repo {
+        &self.waitlist_repo
+    }
+}
+
+impl HaveLotteryPreferenceRepository for PersonalDataServiceInfra {
+    type Repo = LotteryPreferenceRepositoryInfra;
+
+    fn lottery_preference_repo(&self) -> &Self::Repo {
+        &self.lottery_preference_repo
+    }
+}
+
+impl HaveRegistrationRequestRepository for PersonalDataServiceInfra {
+    type Repo = RegistrationRequestRepositoryInfra;
+
+    fn registration_request_repo(&self) -> &Self::Repo {
+        &self.registration_request_repo
+    }
+}
+
+impl HaveCreditLimitRepository for PersonalDataServiceInfra {
+    type Repo = CreditLimitRepositoryInfra;
+
+    fn credit_limit_repo(&self) -> &Self::Repo {
+        &self.credit_limit_repo
+    }
+}
+
+impl HavePasswordHasher for PersonalDataServiceInfra {
+    type Hasher = PolicyPasswordHasher;
+
+    fn password_hasher(&self) -> &Self::Hasher {
+        &self.password_hasher
+    }
+}
+
+impl HavePersonalDataStorage for PersonalDataServiceInfra {
+    type Storage = PersonalDataStorageInfra;
+
+    fn personal_data_storage(&self) -> &Self::Storage {
+        &self.personal_data_storage
+    }
+}
+
+impl HaveAuditLogRepository for PersonalDataServiceInfra {
+    type Repo = AuditLogRepositoryInfra;
+
+    fn audit_log_repo(&self) -> &Self::Repo {
+        &self.audit_log_repo
+    }
+}
@@ -1,29 +1,84 @@
+use crate::repos::announcement_repository::AnnouncementRepositoryInfra;
+use crate::repos::approval_required_course_repository::ApprovalRequiredCourseRepositoryInfra;
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
+use crate::repos::course_prerequisite_repository::CoursePrerequisiteRepositoryInfra;
 use crate::repos::course_repository::CourseRepositoryInfra;
+use crate::repos::credit_limit_repository::CreditLimitRepositoryInfra;
+use crate::repos::lottery_course_repository::LotteryCourseRepositoryInfra;
+use crate::repos::lottery_preference_repository::LotteryPreferenceRepositoryInfra;
 use crate::repos::registration_course_repository::RegistrationCourseRepositoryInfra;
 use crate::repos::registration_repository::RegistrationRepositoryInfra;
+use crate::repos::registration_request_repository::RegistrationRequestRepositoryInfra;
+use crate::repos::registration_window_repository::RegistrationWindowRepositoryInfra;
+use crate::repos::unread_announcement_repository::UnreadAnnouncementRepositoryInfra;
+use crate::repos::user_repository::UserRepositoryInfra;
+use crate::repos::waitlist_repository::WaitlistRepositoryInfra;
 use isucholar_core::db::DBPool;
+use isucholar_core::repos::announcement_repository::HaveAnnouncementRepository;
+use isucholar_core::repos::approval_required_course_repository::HaveApprovalRequiredCourseRepository;
+use isucholar_core::repos::audit_log_repository::HaveAuditLogRepository;
+use isucholar_core::repos::course_prerequisite_repository::HaveCoursePrerequisiteRepository;
 use isucholar_core::repos::course_repository::HaveCourseRepository;
+use isucholar_core::repos::credit_limit_repository::HaveCreditLimitRepository;
+use isucholar_core::repos::lottery_course_repository::HaveLotteryCourseRepository;
+use isucholar_core::repos::lottery_preference_repository::HaveLotteryPreferenceRepository;
 use isucholar_core::repos::registration_course_repository::HaveRegistrationCourseRepository;
 use isucholar_core::repos::registration_repository::HaveRegistrationRepository;
-use isucholar_core::services::registration_course_service::RegistrationCourseServiceImpl;
+use isucholar_core::repos::registration_request_repository::HaveRegistrationRequestRepository;
+use isucholar_core::repos::registration_window_repository::HaveRegistrationWindowRepository;
+use isucholar_core::repos::unread_announcement_repository::HaveUnreadAnnouncementRepository;
+use isucholar_core::repos::user_repository::HaveUserRepository;
+use isucholar_core::repos::waitlist_repository::HaveWaitlistRepository;
+use isucholar_core::services::registration_course_service::{
+    CreditLimitConfig, HaveCreditLimitConfig, RegistrationCourseServiceImpl,
+};
 use isucholar_core::services::HaveDBPool;
 use std::sync::Arc;
 
+#[cfg(test)]
+mod create;
+
 #[derive(Clone)]
 pub struct RegistrationCourseServiceInfra {
     db_pool: Arc<DBPool>,
+    credit_limit_config: CreditLimitConfig,
     registration_course_repo: RegistrationCourseRepositoryInfra,
     course_repo: CourseRepositoryInfra,
     registration_repo: RegistrationRepositoryInfra,
+    waitlist_repo: WaitlistRepositoryInfra,
+    announcement_repo: AnnouncementRepositoryInfra,
+    unread_announcement_repo: UnreadAnnouncementRepositoryInfra,
+    course_prerequisite_repo: CoursePrerequisiteRepositoryInfra,
+    credit_limit_repo: CreditLimitRepositoryInfra,
+    user_repo: UserRepositoryInfra,
+    registration_window_repo: RegistrationWindowRepositoryInfra,
+    lottery_course_repo: LotteryCourseRepositoryInfra,
+    lottery_preference_repo: LotteryPreferenceRepositoryInfra,
+    approval_required_course_repo: ApprovalRequiredCourseRepositoryInfra,
+    registration_request_repo: RegistrationRequestRepositoryInfra,
+    audit_log_repo: AuditLogRepositoryInfra,
 }
 
 impl RegistrationCourseServiceInfra {
     pub fn new(db_pool: Arc<DBPool>) -> Self {
         Self {
             db_pool,
+            credit_limit_config: CreditLimitConfig::from_env(),
             registration_course_repo: RegistrationCourseRepositoryInfra {},
             course_repo: CourseRepositoryInfra {},
             registration_repo: RegistrationRepositoryInfra {},
+            waitlist_repo: WaitlistRepositoryInfra {},
+            announcement_repo: AnnouncementRepositoryInfra {},
+            unread_announcement_repo: UnreadAnnouncementRepositoryInfra {},
+            course_prerequisite_repo: CoursePrerequisiteRepositoryInfra {},
+            credit_limit_repo: CreditLimitRepositoryInfra {},
+            user_repo: UserRepositoryInfra {},
+            registration_window_repo: RegistrationWindowRepositoryInfra {},
+            lottery_course_repo: LotteryCourseRepositoryInfra {},
+            lottery_preference_repo: LotteryPreferenceRepositoryInfra {},
+            approval_required_course_repo: ApprovalRequiredCourseRepositoryInfra {},
+            registration_request_repo: RegistrationRequestRepositoryInfra {},
+            audit_log_repo: AuditLogRepositoryInfra {},
         }
     }
 }
@@ -36,6 +91,12 @@ impl HaveDBPool for RegistrationCourseServiceInfra {
     }
 }
 
+impl HaveCreditLimitConfig for RegistrationCourseServiceInfra {
+    fn credit_limit_config(&self) -> &CreditLimitConfig {
+        &self.credit_limit_config
+    }
+}
+
 impl HaveRegistrationCourseRepository for RegistrationCourseServiceInfra {
     type Repo = RegistrationCourseRepositoryInfra;
 
@@ -59,3 +120,99 @@ impl HaveCourseRepository for RegistrationCourseServiceInfra {
         &self.course_repo
     }
 }
+
+impl HaveWaitlistRepository for RegistrationCourseServiceInfra {
+    type Repo = WaitlistRepositoryInfra;
+
+    fn waitlist_repo(&self) -> &Self::Repo {
+        &self.waitlist_repo
+    }
+}
+
+impl HaveAnnouncementRepository for RegistrationCourseServiceInfra {
+    type Repo = AnnouncementRepositoryInfra;
+
+    fn announcement_repo(&self) -> &Self::Repo {
+        &self.announcement_repo
+    }
+}
+
+impl HaveUnreadAnnouncementRepository for RegistrationCourseServiceInfra {
+    type Repo = UnreadAnnouncementRepositoryInfra;
+
+    fn unread_announcement_repo(&self) -> &Self::Repo {
+        &self.unread_announcement_repo
+    }
+}
+
+impl HaveCoursePrerequisiteRepository for RegistrationCourseServiceInfra {
+    type Repo = CoursePrerequisiteRepositoryInfra;
+
+    fn course_prerequisite_repo(&self) -> &Self::Repo {
+        &self.course_prerequisite_repo
+    }
+}
+
+impl HaveCreditLimitRepository for RegistrationCourseServiceInfra {
+    type Repo = CreditLimitRepositoryInfra;
+
+    fn credit_limit_repo(&self) -> &Self::Repo {
+        &self.credit_limit_repo
+    }
+}
+
+impl HaveUserRepository for RegistrationCourseServiceInfra {
+    type Repo = UserRepositoryInfra;
+
+    fn user_repo(&self) -> &Self::Repo {
+        &self.user_repo
+    }
+}
+
+impl HaveRegistrationWindowRepository for RegistrationCourseServiceInfra {
+    type Repo = RegistrationWindowRepositoryInfra;
+
+    fn registration_window_repo(&self) -> &Self::Repo {
+        &self.registration_window_repo
+    }
+}
+
+impl HaveLotteryCourseRepository for RegistrationCourseServiceInfra {
+    type Repo = LotteryCourseRepositoryInfra;
+
+    fn lottery_course_repo(&self) -> &Self::Repo {
+        &self.lottery_course_repo
+    }
+}
+
+impl HaveLotteryPreferenceRepository for RegistrationCourseServiceInfra {
+    type Repo = LotteryPreferenceRepositoryInfra;
+
+    fn lottery_preference_repo(&self) -> &Self::Repo {
+        &self.lottery_preference_repo
+    }
+}
+
+impl HaveApprovalRequiredCourseRepository for RegistrationCourseServiceInfra {
+    type Repo = ApprovalRequiredCourseRepositoryInfra;
+
+    fn approval_required_course_repo(&self) -> &Self::Repo {
+        &self.approval_required_course_repo
+    }
+}
+
+impl HaveRegistrationRequestRepository for RegistrationCourseServiceInfra {
+    type Repo = RegistrationRequestRepositoryInfra;
+
+    fn registration_request_repo(&self) -> &Self::Repo {
+        &self.registration_request_repo
+    }
+}
+
+impl HaveAuditLogRepository for RegistrationCourseServiceInfra {
+    type Repo = AuditLogRepositoryInfra;
+
+    fn audit_log_repo(&self) -> &Self::Repo {
+        &self.audit_log_repo
+    }
+}
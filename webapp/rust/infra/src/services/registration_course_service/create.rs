@@ -0,0 +1,90 @@
+use crate::services::registration_course_service::RegistrationCourseServiceInfra;
+use fake::{Fake, Faker};
+use isucholar_core::db::get_test_db_conn;
+use isucholar_core::models::course::Course;
+use isucholar_core::models::course_status::CourseStatus;
+use isucholar_core::models::user::User;
+use isucholar_core::models::user_type::UserType;
+use isucholar_core::services::error::Error;
+use isucholar_core::services::registration_course_service::RegistrationCourseService;
+use std::sync::Arc;
+
+// 最後の1席を同時に取り合っても、デッドロックせずに一方が定員超過になる
+#[tokio::test]
+async fn last_seat_race_case() {
+    let db_pool = get_test_db_conn().await.unwrap();
+    let mut conn = db_pool.acquire().await.unwrap();
+
+    // 別々のトランザクションから見えるように、データはコミットして最後に削除する
+    let mut users: Vec<User> = vec![Faker.fake(), Faker.fake(), Faker.fake()];
+    for (i, user) in users.iter_mut().enumerate() {
+        user.hashed_password.resize(60, 0);
+        user.type_ = if i == 0 {
+            UserType::Teacher
+        } else {
+            UserType::Student
+        };
+        sqlx::query!(
+            "INSERT INTO users (id, code, name, hashed_password, type) VALUES (?,?,?,?,?)",
+            &user.id,
+            &user.code,
+            &user.name,
+            &user.hashed_password,
+            &user.type_
+        )
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    }
+    let mut course: Course = Faker.fake();
+    course.teacher_id = users[0].id.clone();
+    course.status = CourseStatus::Registration;
+    course.capacity = Some(1);
+    sqlx::query!("INSERT INTO courses (id, code, type, name, description, credit, period, day_of_week, teacher_id, keywords, status, capacity) VALUES (?,?,?,?,?,?,?,?,?,?,?,?)",
+        &course.id,
+        &course.code,
+        &course.type_,
+        &course.name,
+        &course.description,
+        &course.credit,
+        &course.period,
+        &course.day_of_week,
+        &course.teacher_id,
+        &course.keywords,
+        &course.status,
+        &course.capacity,
+    ).execute(&mut *conn).await.unwrap();
+
+    let service = RegistrationCourseServiceInfra::new(Arc::new(db_pool.clone()));
+    let course_ids = vec![course.id.clone()];
+    let (first, second) = tokio::join!(
+        service.create(&users[1].id, &course_ids),
+        service.create(&users[2].id, &course_ids),
+    );
+
+    sqlx::query!("DELETE FROM registrations WHERE course_id = ?", &course.id)
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    sqlx::query!("DELETE FROM courses WHERE id = ?", &course.id)
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    for user in &users {
+        sqlx::query!("DELETE FROM users WHERE id = ?", &user.id)
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+    }
+
+    let err = match (first, second) {
+        (Ok(()), Err(e)) | (Err(e), Ok(())) => e,
+        results => panic!("unexpected results: {:?}", results),
+    };
+    match err {
+        Error::RegistrationCourseValidationError(errors) => {
+            assert_eq!(errors.course_full, vec![course.id]);
+        }
+        e => panic!("unexpected error: {:?}", e),
+    }
+}
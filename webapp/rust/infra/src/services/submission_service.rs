@@ -1,9 +1,11 @@
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
 use crate::repos::class_repository::ClassRepositoryInfra;
 use crate::repos::course_repository::CourseRepositoryInfra;
 use crate::repos::registration_repository::RegistrationRepositoryInfra;
 use crate::repos::submission_repository::SubmissionRepositoryInfra;
 use crate::storages::submission_file_storage::SubmissionFileStorageInfra;
 use isucholar_core::db::DBPool;
+use isucholar_core::repos::audit_log_repository::HaveAuditLogRepository;
 use isucholar_core::repos::class_repository::HaveClassRepository;
 use isucholar_core::repos::course_repository::HaveCourseRepository;
 use isucholar_core::repos::registration_repository::HaveRegistrationRepository;
@@ -21,6 +23,7 @@ pub struct SubmissionServiceInfra {
     submission_repo: SubmissionRepositoryInfra,
     submission_file_storage: SubmissionFileStorageInfra,
     registration_repo: RegistrationRepositoryInfra,
+    audit_log_repo: AuditLogRepositoryInfra,
 }
 
 impl SubmissionServiceInfra {
@@ -32,6 +35,7 @@ impl SubmissionServiceInfra {
             submission_repo: SubmissionRepositoryInfra {},
             submission_file_storage: SubmissionFileStorageInfra::default(),
             registration_repo: RegistrationRepositoryInfra {},
+            audit_log_repo: AuditLogRepositoryInfra {},
         }
     }
 }
@@ -83,3 +87,11 @@ impl HaveSubmissionFileStorage for SubmissionServiceInfra {
         &self.submission_file_storage
     }
 }
+
+impl HaveAuditLogRepository for SubmissionServiceInfra {
+    type Repo = AuditLogRepositoryInfra;
+
+    fn audit_log_repo(&self) -> &Self::Repo {
+        &self.audit_log_repo
+    }
+}
@@ -0,0 +1,71 @@
+use crate::repos::user_recovery_code_repository::UserRecoveryCodeRepositoryInfra;
+use crate::repos::user_repository::UserRepositoryInfra;
+use crate::repos::user_totp_secret_repository::UserTotpSecretRepositoryInfra;
+use isucholar_core::db::DBPool;
+use isucholar_core::repos::user_recovery_code_repository::HaveUserRecoveryCodeRepository;
+use isucholar_core::repos::user_repository::HaveUserRepository;
+use isucholar_core::repos::user_totp_secret_repository::HaveUserTotpSecretRepository;
+use isucholar_core::services::two_factor_service::{
+    HaveTwoFactorConfig, TwoFactorConfig, TwoFactorServiceImpl,
+};
+use isucholar_core::services::HaveDBPool;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct TwoFactorServiceInfra {
+    db_pool: Arc<DBPool>,
+    two_factor_config: TwoFactorConfig,
+    user_repo: UserRepositoryInfra,
+    user_totp_secret_repo: UserTotpSecretRepositoryInfra,
+    user_recovery_code_repo: UserRecoveryCodeRepositoryInfra,
+}
+
+impl TwoFactorServiceInfra {
+    pub fn new(db_pool: Arc<DBPool>) -> Self {
+        Self {
+            db_pool,
+            two_factor_config: TwoFactorConfig::from_env(),
+            user_repo: UserRepositoryInfra {},
+            user_totp_secret_repo: UserTotpSecretRepositoryInfra {},
+            user_recovery_code_repo: UserRecoveryCodeRepositoryInfra {},
+        }
+    }
+}
+
+impl TwoFactorServiceImpl for TwoFactorServiceInfra {}
+
+impl HaveDBPool for TwoFactorServiceInfra {
+    fn get_db_pool(&self) -> &DBPool {
+        &self.db_pool
+    }
+}
+
+impl HaveTwoFactorConfig for TwoFactorServiceInfra {
+    fn two_factor_config(&self) -> &TwoFactorConfig {
+        &self.two_factor_config
+    }
+}
+
+impl HaveUserRepository for TwoFactorServiceInfra {
+    type Repo = UserRepositoryInfra;
+
+    fn user_repo(&self) -> &Self::Repo {
+        &self.user_repo
+    }
+}
+
+impl HaveUserTotpSecretRepository for TwoFactorServiceInfra {
+    type Repo = UserTotpSecretRepositoryInfra;
+
+    fn user_totp_secret_repo(&self) -> &Self::Repo {
+        &self.user_totp_secret_repo
+    }
+}
+
+impl HaveUserRecoveryCodeRepository for TwoFactorServiceInfra {
+    type Repo = UserRecoveryCodeRepositoryInfra;
+
+    fn user_recovery_code_repo(&self) -> &Self::Repo {
+        &self.user_recovery_code_repo
+    }
+}
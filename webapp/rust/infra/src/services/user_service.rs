@@ -1,5 +1,13 @@
+use crate::repos::audit_log_repository::AuditLogRepositoryInfra;
+use crate::repos::user_profile_repository::UserProfileRepositoryInfra;
 use crate::repos::user_repository::UserRepositoryInfra;
 use isucholar_core::db::DBPool;
+use isucholar_core::password_hashers::policy_password_hasher::{
+    PasswordHashPolicy, PolicyPasswordHasher,
+};
+use isucholar_core::password_hashers::HavePasswordHasher;
+use isucholar_core::repos::audit_log_repository::HaveAuditLogRepository;
+use isucholar_core::repos::user_profile_repository::HaveUserProfileRepository;
 use isucholar_core::repos::user_repository::HaveUserRepository;
 use isucholar_core::services::user_service::UserServiceImpl;
 use isucholar_core::services::HaveDBPool;
@@ -9,6 +17,9 @@ use std::sync::Arc;
 pub struct UserServiceInfra {
     db_pool: Arc<DBPool>,
     user_repo: UserRepositoryInfra,
+    user_profile_repo: UserProfileRepositoryInfra,
+    password_hasher: PolicyPasswordHasher,
+    audit_log_repo: AuditLogRepositoryInfra,
 }
 
 impl UserServiceInfra {
@@ -16,6 +27,9 @@ impl UserServiceInfra {
         Self {
             db_pool,
             user_repo: UserRepositoryInfra {},
+            user_profile_repo: UserProfileRepositoryInfra {},
+            password_hasher: PolicyPasswordHasher::new(PasswordHashPolicy::from_env()),
+            audit_log_repo: AuditLogRepositoryInfra {},
         }
     }
 }
@@ -35,3 +49,27 @@ impl HaveUserRepository for UserServiceInfra {
         &self.user_repo
     }
 }
+
+impl HaveUserProfileRepository for UserServiceInfra {
+    type Repo = UserProfileRepositoryInfra;
+
+    fn user_profile_repo(&self) -> &Self::Repo {
+        &self.user_profile_repo
+    }
+}
+
+impl HavePasswordHasher for UserServiceInfra {
+    type Hasher = PolicyPasswordHasher;
+
+    fn password_hasher(&self) -> &Self::Hasher {
+        &self.password_hasher
+    }
+}
+
+impl HaveAuditLogRepository for UserServiceInfra {
+    type Repo = AuditLogRepositoryInfra;
+
+    fn audit_log_repo(&self) -> &Self::Repo {
+        &self.audit_log_repo
+    }
+}
@@ -1 +1,2 @@
+pub mod personal_data_storage;
 pub mod submission_file_storage;
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use isucholar_core::models::submission::UserSubmission;
+use isucholar_core::models::user::UserID;
+use isucholar_core::storages::personal_data_storage::PersonalDataStorage;
+use isucholar_core::storages::StorageResult;
+use isucholar_infra_storage_file::personal_data_storage::PersonalDataStorageFile;
+
+#[derive(Clone, Default)]
+pub struct PersonalDataStorageInfra {}
+
+#[async_trait]
+impl PersonalDataStorage for PersonalDataStorageInfra {
+    async fn create_personal_data_zip(
+        &self,
+        user_id: &UserID,
+        document: &[u8],
+        submissions: &[UserSubmission],
+    ) -> StorageResult<String> {
+        let file = PersonalDataStorageFile::default();
+        file.create_personal_data_zip(user_id, document, submissions)
+            .await
+    }
+
+    async fn delete_submission_files(
+        &self,
+        user_id: &UserID,
+        submissions: &[UserSubmission],
+    ) -> StorageResult<()> {
+        let file = PersonalDataStorageFile::default();
+        file.delete_submission_files(user_id, submissions).await
+    }
+}
@@ -1,4 +1,18 @@
 -- CREATEと逆順
+DROP TABLE IF EXISTS `registration_requests`;
+DROP TABLE IF EXISTS `approval_required_courses`;
+DROP TABLE IF EXISTS `lottery_preferences`;
+DROP TABLE IF EXISTS `lottery_courses`;
+DROP TABLE IF EXISTS `registration_windows`;
+DROP TABLE IF EXISTS `credit_limits`;
+DROP TABLE IF EXISTS `course_prerequisites`;
+DROP TABLE IF EXISTS `waitlist_entries`;
+DROP TABLE IF EXISTS `user_profiles`;
+DROP TABLE IF EXISTS `audit_logs`;
+DROP TABLE IF EXISTS `user_recovery_codes`;
+DROP TABLE IF EXISTS `user_totp_secrets`;
+DROP TABLE IF EXISTS `login_attempts`;
+DROP TABLE IF EXISTS `personal_access_tokens`;
 DROP TABLE IF EXISTS `unread_announcements`;
 DROP TABLE IF EXISTS `announcements`;
 DROP TABLE IF EXISTS `submissions`;
@@ -13,8 +27,9 @@ CREATE TABLE `users`
     `id`              CHAR(26) PRIMARY KEY,
     `code`            CHAR(6) UNIQUE              NOT NULL,
     `name`            VARCHAR(255)                NOT NULL,
-    `hashed_password` BINARY(60)                  NOT NULL,
-    `type`            ENUM ('student', 'teacher') NOT NULL
+    `hashed_password` VARBINARY(255)              NOT NULL,
+    `type`            ENUM ('student', 'teacher') NOT NULL,
+    `deactivated_at`  DATETIME(6)                 NULL
 );
 
 CREATE TABLE `courses`
@@ -30,13 +45,26 @@ CREATE TABLE `courses`
     `teacher_id`  CHAR(26)                                                      NOT NULL,
     `keywords`    TEXT                                                          NOT NULL,
     `status`      ENUM ('registration', 'in-progress', 'closed')                NOT NULL DEFAULT 'registration',
+    `capacity`    INT UNSIGNED                                                  NULL,
     CONSTRAINT FK_courses_teacher_id FOREIGN KEY (`teacher_id`) REFERENCES `users` (`id`)
 );
 
+CREATE TABLE `course_prerequisites`
+(
+    `course_id`         CHAR(26)     NOT NULL,
+    `prerequisite_code` VARCHAR(255) NOT NULL,
+    `min_score`         INT UNSIGNED NOT NULL DEFAULT 0,
+    PRIMARY KEY (`course_id`, `prerequisite_code`),
+    CONSTRAINT FK_course_prerequisites_course_id FOREIGN KEY (`course_id`) REFERENCES `courses` (`id`),
+    CONSTRAINT FK_course_prerequisites_prerequisite_code FOREIGN KEY (`prerequisite_code`) REFERENCES `courses` (`code`)
+);
+
 CREATE TABLE `registrations`
 (
-    `course_id` CHAR(26),
-    `user_id`   CHAR(26),
+    `course_id`     CHAR(26),
+    `user_id`       CHAR(26),
+    `withdrawn_at`  DATETIME(6) NULL,
+    `registered_at` DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
     PRIMARY KEY (`course_id`, `user_id`),
     CONSTRAINT FK_registrations_course_id FOREIGN KEY (`course_id`) REFERENCES `courses` (`id`),
     CONSTRAINT FK_registrations_user_id FOREIGN KEY (`user_id`) REFERENCES `users` (`id`)
@@ -83,3 +111,141 @@ CREATE TABLE `unread_announcements`
     CONSTRAINT FK_unread_announcements_announcement_id FOREIGN KEY (`announcement_id`) REFERENCES `announcements` (`id`),
     CONSTRAINT FK_unread_announcements_user_id FOREIGN KEY (`user_id`) REFERENCES `users` (`id`)
 );
+
+CREATE TABLE `personal_access_tokens`
+(
+    `id`           CHAR(26) PRIMARY KEY,
+    `user_id`      CHAR(26)               NOT NULL,
+    `name`         VARCHAR(255)           NOT NULL,
+    `hashed_token` CHAR(64) UNIQUE        NOT NULL,
+    `scope`        ENUM ('read', 'write') NOT NULL,
+    `expires_at`   DATETIME(6)            NOT NULL,
+    `created_at`   DATETIME(6)            NOT NULL,
+    CONSTRAINT FK_personal_access_tokens_user_id FOREIGN KEY (`user_id`) REFERENCES `users` (`id`)
+);
+
+CREATE TABLE `login_attempts`
+(
+    `id`         CHAR(26) PRIMARY KEY,
+    `user_code`  VARCHAR(255) NOT NULL,
+    `ip_address` VARCHAR(45)  NOT NULL,
+    `succeeded`  TINYINT(1)   NOT NULL,
+    `created_at` DATETIME(6)  NOT NULL,
+    INDEX idx_login_attempts_user_code_created_at (`user_code`, `created_at`),
+    INDEX idx_login_attempts_ip_address_created_at (`ip_address`, `created_at`)
+);
+
+CREATE TABLE `user_totp_secrets`
+(
+    `user_id`        CHAR(26) PRIMARY KEY,
+    `secret`         VARCHAR(64) NOT NULL,
+    `confirmed`      TINYINT(1)  NOT NULL DEFAULT false,
+    `last_used_step` BIGINT      NULL,
+    `created_at`     DATETIME(6) NOT NULL,
+    CONSTRAINT FK_user_totp_secrets_user_id FOREIGN KEY (`user_id`) REFERENCES `users` (`id`)
+);
+
+CREATE TABLE `user_recovery_codes`
+(
+    `id`          CHAR(26) PRIMARY KEY,
+    `user_id`     CHAR(26)    NOT NULL,
+    `hashed_code` CHAR(64)    NOT NULL,
+    `used_at`     DATETIME(6) NULL,
+    INDEX idx_user_recovery_codes_user_id_hashed_code (`user_id`, `hashed_code`),
+    CONSTRAINT FK_user_recovery_codes_user_id FOREIGN KEY (`user_id`) REFERENCES `users` (`id`)
+);
+
+CREATE TABLE `audit_logs`
+(
+    `id`                     CHAR(26) PRIMARY KEY,
+    `actor_id`               CHAR(26)    NOT NULL,
+    `action`                 VARCHAR(64) NOT NULL,
+    `target_user_id`         CHAR(26)    NULL,
+    `target_course_id`       CHAR(26)    NULL,
+    `target_class_id`        CHAR(26)    NULL,
+    `target_announcement_id` CHAR(26)    NULL,
+    `request_id`             VARCHAR(64) NULL,
+    `before_summary`         TEXT        NULL,
+    `after_summary`          TEXT        NULL,
+    `created_at`             DATETIME(6) NOT NULL,
+    INDEX idx_audit_logs_created_at (`created_at`),
+    INDEX idx_audit_logs_actor_id_created_at (`actor_id`, `created_at`),
+    INDEX idx_audit_logs_target_course_id_created_at (`target_course_id`, `created_at`),
+    CONSTRAINT FK_audit_logs_actor_id FOREIGN KEY (`actor_id`) REFERENCES `users` (`id`)
+);
+
+CREATE TABLE `user_profiles`
+(
+    `user_id`               CHAR(26) PRIMARY KEY,
+    `name_kana`             VARCHAR(255) NULL,
+    `email`                 VARCHAR(255) NULL,
+    `notify_announcements`  TINYINT(1)   NOT NULL DEFAULT true,
+    `notify_course_updates` TINYINT(1)   NOT NULL DEFAULT true,
+    CONSTRAINT FK_user_profiles_user_id FOREIGN KEY (`user_id`) REFERENCES `users` (`id`)
+);
+
+-- 定員に達した科目のキャンセル待ち。idの昇順に繰り上げる
+CREATE TABLE `waitlist_entries`
+(
+    `id`        BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+    `course_id` CHAR(26) NOT NULL,
+    `user_id`   CHAR(26) NOT NULL,
+    UNIQUE KEY `idx_waitlist_entries_course_id_user_id` (`course_id`, `user_id`),
+    CONSTRAINT FK_waitlist_entries_course_id FOREIGN KEY (`course_id`) REFERENCES `courses` (`id`),
+    CONSTRAINT FK_waitlist_entries_user_id FOREIGN KEY (`user_id`) REFERENCES `users` (`id`)
+);
+
+-- 学生ごとに上書きした学期あたりの履修単位数の上限
+CREATE TABLE `credit_limits`
+(
+    `user_id`      CHAR(26) PRIMARY KEY,
+    `credit_limit` INT UNSIGNED NOT NULL,
+    CONSTRAINT FK_credit_limits_user_id FOREIGN KEY (`user_id`) REFERENCES `users` (`id`)
+);
+
+-- 履修登録期間。cohortは学籍番号の接頭辞で、NULLの場合は全学生が対象
+CREATE TABLE `registration_windows`
+(
+    `id`        CHAR(26) PRIMARY KEY,
+    `term`      VARCHAR(255) NOT NULL,
+    `cohort`    VARCHAR(255) NULL,
+    `starts_at` DATETIME(6)  NOT NULL,
+    `ends_at`   DATETIME(6)  NOT NULL
+);
+
+-- 先着順ではなく抽選で履修者を決める科目
+CREATE TABLE `lottery_courses`
+(
+    `course_id` CHAR(26) PRIMARY KEY,
+    CONSTRAINT FK_lottery_courses_course_id FOREIGN KEY (`course_id`) REFERENCES `courses` (`id`)
+);
+
+-- 抽選科目に対する学生の希望順位。rankは1が第一希望
+CREATE TABLE `lottery_preferences`
+(
+    `user_id`   CHAR(26)     NOT NULL,
+    `course_id` CHAR(26)     NOT NULL,
+    `rank`      INT UNSIGNED NOT NULL,
+    PRIMARY KEY (`user_id`, `course_id`),
+    UNIQUE KEY `idx_lottery_preferences_user_id_rank` (`user_id`, `rank`),
+    CONSTRAINT FK_lottery_preferences_user_id FOREIGN KEY (`user_id`) REFERENCES `users` (`id`),
+    CONSTRAINT FK_lottery_preferences_course_id FOREIGN KEY (`course_id`) REFERENCES `courses` (`id`)
+);
+
+-- 履修登録に担当教員の承認が必要な科目
+CREATE TABLE `approval_required_courses`
+(
+    `course_id` CHAR(26) PRIMARY KEY,
+    CONSTRAINT FK_approval_required_courses_course_id FOREIGN KEY (`course_id`) REFERENCES `courses` (`id`)
+);
+
+-- 承認待ちの履修申請。承認・却下されると削除する
+CREATE TABLE `registration_requests`
+(
+    `id`        BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+    `course_id` CHAR(26) NOT NULL,
+    `user_id`   CHAR(26) NOT NULL,
+    UNIQUE KEY `idx_registration_requests_course_id_user_id` (`course_id`, `user_id`),
+    CONSTRAINT FK_registration_requests_course_id FOREIGN KEY (`course_id`) REFERENCES `courses` (`id`),
+    CONSTRAINT FK_registration_requests_user_id FOREIGN KEY (`user_id`) REFERENCES `users` (`id`)
+);